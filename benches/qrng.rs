@@ -3,13 +3,44 @@ extern crate criterion;
 
 extern crate qrng;
 
-use qrng::{HaltonSeq, QRng, SobolSeq};
+use qrng::{
+    FaureSeq, GeneralizedHaltonSeq, HaltonSeq, KroneckerSeq, LatticeRule, NiederreiterSeq, QRng,
+    SobolSeq,
+};
 
 use criterion::{black_box, Criterion};
 
 const NDIM_SET: &[usize] = &[2, 20, 100, 1000];
 const NDIM_LEN_SET: &[(usize, usize)] = &[(2, 20_000), (20, 5_000), (100, 1_000), (1000, 100)];
 
+fn faure_seq_gen(c: &mut Criterion) {
+    for &(ndim, len) in NDIM_LEN_SET {
+        c.bench_function(&format!("FaureSeq::gen (ndim={}, len={})", ndim, len), |b| {
+            let seq = FaureSeq::new(black_box(ndim)).with_buf();
+            b.iter(|| {
+                let mut seq = seq.clone();
+                for _ in 0..black_box(len) {
+                    seq.gen();
+                }
+            })
+        });
+    }
+}
+
+fn generalized_halton_seq_gen(c: &mut Criterion) {
+    for &(ndim, len) in &NDIM_LEN_SET[..3] {
+        c.bench_function(&format!("GeneralizedHaltonSeq::gen (ndim={}, len={})", ndim, len), |b| {
+            let seq = GeneralizedHaltonSeq::new(black_box(ndim)).with_buf();
+            b.iter(|| {
+                let mut seq = seq.clone();
+                for _ in 0..black_box(len) {
+                    seq.gen();
+                }
+            })
+        });
+    }
+}
+
 fn halton_seq_gen(c: &mut Criterion) {
     for &(ndim, len) in NDIM_LEN_SET {
         c.bench_function(&format!("HaltonSeq::gen (ndim={}, len={})", ndim, len), |b| {
@@ -24,6 +55,48 @@ fn halton_seq_gen(c: &mut Criterion) {
     }
 }
 
+fn kronecker_seq_gen(c: &mut Criterion) {
+    for &(ndim, len) in NDIM_LEN_SET {
+        c.bench_function(&format!("KroneckerSeq::gen (ndim={}, len={})", ndim, len), |b| {
+            let seq = KroneckerSeq::roberts(black_box(ndim)).with_buf();
+            b.iter(|| {
+                let mut seq = seq.clone();
+                for _ in 0..black_box(len) {
+                    seq.gen();
+                }
+            })
+        });
+    }
+}
+
+fn lattice_rule_gen(c: &mut Criterion) {
+    for &(ndim, len) in NDIM_LEN_SET {
+        c.bench_function(&format!("LatticeRule::gen (ndim={}, len={})", ndim, len), |b| {
+            let seq = LatticeRule::korobov(black_box(1_048_573), 1_140, ndim).with_buf();
+            b.iter(|| {
+                let mut seq = seq.clone();
+                for _ in 0..black_box(len) {
+                    seq.gen();
+                }
+            })
+        });
+    }
+}
+
+fn niederreiter_seq_gen(c: &mut Criterion) {
+    for &(ndim, len) in &[(2, 20_000), (12, 5_000)] {
+        c.bench_function(&format!("NiederreiterSeq::gen (ndim={}, len={})", ndim, len), |b| {
+            let seq = NiederreiterSeq::new(black_box(ndim)).with_buf();
+            b.iter(|| {
+                let mut seq = seq.clone();
+                for _ in 0..black_box(len) {
+                    seq.gen();
+                }
+            })
+        });
+    }
+}
+
 fn sobol_seq_new(c: &mut Criterion) {
     for &ndim in NDIM_SET {
         c.bench_function(&format!("SobolSeq::new (ndim={})", ndim), |b| {
@@ -46,5 +119,29 @@ fn sobol_seq_gen(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, halton_seq_gen, sobol_seq_new, sobol_seq_gen);
+fn sobol_seq_gen_points(c: &mut Criterion) {
+    for &(ndim, len) in &NDIM_LEN_SET[2..] {
+        c.bench_function(&format!("SobolSeq::gen_points_into (ndim={}, len={})", ndim, len), |b| {
+            let seq = SobolSeq::new(black_box(ndim));
+            let mut out = vec![0.; ndim * len];
+            b.iter(|| {
+                let mut seq = seq.clone();
+                seq.gen_points_into(black_box(len), &mut out);
+            })
+        });
+    }
+}
+
+criterion_group!(
+    benches,
+    faure_seq_gen,
+    generalized_halton_seq_gen,
+    halton_seq_gen,
+    kronecker_seq_gen,
+    lattice_rule_gen,
+    niederreiter_seq_gen,
+    sobol_seq_new,
+    sobol_seq_gen,
+    sobol_seq_gen_points
+);
 criterion_main!(benches);
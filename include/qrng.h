@@ -0,0 +1,116 @@
+#ifndef QRNG_H
+#define QRNG_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+/// Halton sequence generator.
+typedef struct QrngHaltonSeq QrngHaltonSeq;
+
+/// Niederreiter sequence generator.
+typedef struct QrngNiederreiterSeq QrngNiederreiterSeq;
+
+/// Owen-scrambled Sobol sequence generator.
+typedef struct QrngScrambledSobolSeq QrngScrambledSobolSeq;
+
+/// Sobol sequence generator.
+typedef struct QrngSobolSeq QrngSobolSeq;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/// Returns a new Sobol sequence generator with dimensionality `ndim`, or null if the
+/// dimensionality is not supported.
+///
+/// The generator must be destroyed with `qrng_sobol_free`.
+QrngSobolSeq *qrng_sobol_new(size_t ndim);
+
+/// Destroys a Sobol sequence generator.
+void qrng_sobol_free(QrngSobolSeq *seq);
+
+/// Returns the dimensionality of a Sobol sequence generator.
+size_t qrng_sobol_ndim(const QrngSobolSeq *seq);
+
+/// Moves a Sobol sequence generator back to the first point.
+void qrng_sobol_reset(QrngSobolSeq *seq);
+
+/// Skips the next `n` points of a Sobol sequence.
+void qrng_sobol_discard(QrngSobolSeq *seq, uint64_t n);
+
+/// Fills the buffer of length `len` with as many consecutive points of a Sobol
+/// sequence as fit in it, in row-major order, and returns the number of points.
+size_t qrng_sobol_gen_fill(QrngSobolSeq *seq, double *out, size_t len);
+
+/// Returns a new Owen-scrambled Sobol sequence generator with dimensionality `ndim`,
+/// randomized with the given seed, or null if the dimensionality is not supported.
+///
+/// The generator must be destroyed with `qrng_scrambled_sobol_free`.
+QrngScrambledSobolSeq *qrng_scrambled_sobol_new(size_t ndim, uint64_t seed);
+
+/// Destroys an Owen-scrambled Sobol sequence generator.
+void qrng_scrambled_sobol_free(QrngScrambledSobolSeq *seq);
+
+/// Returns the dimensionality of an Owen-scrambled Sobol sequence generator.
+size_t qrng_scrambled_sobol_ndim(const QrngScrambledSobolSeq *seq);
+
+/// Moves an Owen-scrambled Sobol sequence generator back to the first point.
+void qrng_scrambled_sobol_reset(QrngScrambledSobolSeq *seq);
+
+/// Skips the next `n` points of an Owen-scrambled Sobol sequence.
+void qrng_scrambled_sobol_discard(QrngScrambledSobolSeq *seq, uint64_t n);
+
+/// Fills the buffer of length `len` with as many consecutive points of an
+/// Owen-scrambled Sobol sequence as fit in it, in row-major order, and returns the
+/// number of points.
+size_t qrng_scrambled_sobol_gen_fill(QrngScrambledSobolSeq *seq, double *out, size_t len);
+
+/// Returns a new Halton sequence generator with dimensionality `ndim`.
+///
+/// The generator must be destroyed with `qrng_halton_free`.
+QrngHaltonSeq *qrng_halton_new(size_t ndim);
+
+/// Destroys a Halton sequence generator.
+void qrng_halton_free(QrngHaltonSeq *seq);
+
+/// Returns the dimensionality of a Halton sequence generator.
+size_t qrng_halton_ndim(const QrngHaltonSeq *seq);
+
+/// Moves a Halton sequence generator back to the first point.
+void qrng_halton_reset(QrngHaltonSeq *seq);
+
+/// Skips the next `n` points of a Halton sequence.
+void qrng_halton_discard(QrngHaltonSeq *seq, uint64_t n);
+
+/// Fills the buffer of length `len` with as many consecutive points of a Halton
+/// sequence as fit in it, in row-major order, and returns the number of points.
+size_t qrng_halton_gen_fill(QrngHaltonSeq *seq, double *out, size_t len);
+
+/// Returns a new Niederreiter sequence generator with dimensionality `ndim`, or null
+/// if the dimensionality is not supported.
+///
+/// The generator must be destroyed with `qrng_niederreiter_free`.
+QrngNiederreiterSeq *qrng_niederreiter_new(size_t ndim);
+
+/// Destroys a Niederreiter sequence generator.
+void qrng_niederreiter_free(QrngNiederreiterSeq *seq);
+
+/// Returns the dimensionality of a Niederreiter sequence generator.
+size_t qrng_niederreiter_ndim(const QrngNiederreiterSeq *seq);
+
+/// Moves a Niederreiter sequence generator back to the first point.
+void qrng_niederreiter_reset(QrngNiederreiterSeq *seq);
+
+/// Skips the next `n` points of a Niederreiter sequence.
+void qrng_niederreiter_discard(QrngNiederreiterSeq *seq, uint64_t n);
+
+/// Fills the buffer of length `len` with as many consecutive points of a
+/// Niederreiter sequence as fit in it, in row-major order, and returns the number of
+/// points.
+size_t qrng_niederreiter_gen_fill(QrngNiederreiterSeq *seq, double *out, size_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* QRNG_H */
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Float64Array, RecordBatch, RecordBatchOptions};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+
+/// Returns the schema of record batches of points: one non-nullable `Float64`
+/// column per dimension, named `x0`, `x1`, and so on.
+pub(crate) fn points_schema(ndim: usize) -> SchemaRef {
+    let fields: Vec<_> =
+        (0..ndim).map(|j| Field::new(format!("x{}", j), DataType::Float64, false)).collect();
+    Arc::new(Schema::new(fields))
+}
+
+/// Wraps `n` points stored in column-major order into a record batch.
+///
+/// The columns are zero-copy slices of a single array holding all the values.
+pub(crate) fn record_batch(points: Vec<f64>, ndim: usize, n: usize) -> RecordBatch {
+    let values = Float64Array::from(points);
+    let columns: Vec<_> = (0..ndim).map(|j| Arc::new(values.slice(j * n, n)) as ArrayRef).collect();
+    // the row count has to be set explicitly in case there are no columns
+    let options = RecordBatchOptions::new().with_row_count(Some(n));
+    RecordBatch::try_new_with_options(points_schema(ndim), columns, &options).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{cast::AsArray, types::Float64Type};
+
+    use crate::{HaltonSeq, QRng};
+
+    #[test]
+    fn test_gen_record_batch() {
+        let mut seq = HaltonSeq::new(4);
+        seq.gen_points(5);
+        let batch = seq.gen_record_batch(100);
+        assert_eq!((batch.num_rows(), batch.num_columns()), (100, 4));
+        let points = HaltonSeq::new_with_skip(4, 5).gen_points(100);
+        for j in 0..4 {
+            let field = batch.schema_ref().field(j).clone();
+            assert_eq!(field.name(), &format!("x{}", j));
+            assert!(!field.is_nullable());
+            let column = batch.column(j).as_primitive::<Float64Type>();
+            let expected: Vec<_> = points.chunks(4).map(|x| x[j]).collect();
+            assert_eq!(column.values().to_vec(), expected);
+        }
+        assert_eq!(HaltonSeq::new(0).gen_record_batch(7).num_rows(), 7);
+    }
+}
@@ -1 +0,0 @@
-&[0,0,1,3,5,8,11,15,19,23,27,31,35,40,45,50,55,60,65,71,77,83,89,95,101,107,113,119,125,131,137,143,149,155,161,167,173,180,187,194,201,208,215,222,229,236,243,250,257,264,271,278,285,293,301,309,317,325,333,341,349,357,365,373,381,389,397,405,413,421,429,437,445,453,461,469,477,485,493,501,509,517,525,533,541,549,557,565,573,581,589,597,605,613,621,629,637,645,653,661,669,678,687,696,705,714,723,732,741,750,759,768,777,786,795,804,813,822,831,840,849,858,867,876,885,894,903,912,921,930,939,948,957,966,975,984,993,1002,1011,1020,1029,1038,1047,1056,1065,1074,1083,1092,1101,1110,1119,1128,1137,1146,1155,1164,1173,1182,1191,1200,1209,1219,1229,1239,1249,1259,1269,1279,1289,1299,1309,1319,1329,1339,1349,1359,1369,1379,1389,1399,1409,1419,1429,1439,1449,1459,1469,1479,1489,1499,1509,1519,1529,1539,1549,1559,1569,1579,1589,1599,1609,1619,1629,1639,1649,1659,1669,1679,1689,1699,1709,1719,1729,1739,1749,1759,1769,1779,1789,1799,1809,1819,1829,1839,1849,1859,1869,1879,1889,1899,1909,1919,1929,1939,1949,1959,1969,1979,1989,1999,2009,2019,2029,2039,2049,2059,2069,2079,2089,2099,2109,2119,2129,2139,2149,2159,2169,2179,2189,2199,2209,2219,2229,2239,2249,2259,2269,2279,2289,2299,2309,2319,2329,2339,2349,2359,2369,2379,2389,2399,2409,2419,2429,2439,2449,2459,2469,2479,2489,2499,2509,2519,2529,2539,2549,2559,2569,2579,2589,2599,2609,2619,2629,2639,2649,2659,2669,2679,2689,2699,2709,2719,2729,2739,2749,2759,2769,2779,2789,2799,2809,2819,2829,2839,2849,2859,2869,2879,2889,2899,2909,2919,2929,2939,2949,2959,2969,2980,2991,3002,3013,3024,3035,3046,3057,3068,3079,3090,3101,3112,3123,3134,3145,3156,3167,3178,3189,3200,3211,3222,3233,3244,3255,3266,3277,3288,3299,3310,3321,3332,3343,3354,3365,3376,3387,3398,3409,3420,3431,3442,3453,3464,3475,3486,3497,3508,3519,3530,3541,3552,3563,3574,3585,3596,3607,3618,3629,3640,3651,3662,3673,3684,3695,3706,3717,3728,3739,3750,3761,3772,3783,3794,3805,3816,3827,3838,3849,3860,3871,3882,3893,3904,3915,3926,3937,3948,3959,3970,3981,3992,4003,4014,4025,4036,4047,4058,4069,4080,4091,4102,4113,4124,4135,4146,4157,4168,4179,4190,4201,4212,4223,4234,4245,4256,4267,4278,4289,4300,4311,4322,4333,4344,4355,4366,4377,4388,4399,4410,4421,4432,4443,4454,4465,4476,4487,4498,4509,4520,4531,4542,4553,4565,4577,4589,4601,4613,4625,4637,4649,4661,4673,4685,4697,4709,4721,4733,4745,4757,4769,4781,4793,4805,4817,4829,4841,4853,4865,4877,4889,4901,4913,4925,4937,4949,4961,4973,4985,4997,5009,5021,5033,5045,5057,5069,5081,5093,5105,5117,5129,5141,5153,5165,5177,5189,5201,5213,5225,5237,5249,5261,5273,5285,5297,5309,5321,5333,5345,5357,5369,5381,5393,5405,5417,5429,5441,5453,5465,5477,5489,5501,5513,5525,5537,5549,5561,5573,5585,5597,5609,5621,5633,5645,5657,5669,5681,5693,5705,5717,5729,5741,5753,5765,5777,5789,5801,5813,5825,5837,5849,5861,5873,5885,5897,5909,5921,5933,5945,5957,5969,5981,5993,6005,6017,6029,6041,6053,6065,6077,6089,6101,6113,6125,6137,6149,6161,6173,6185,6197,6209,6221,6233,6245,6257,6269,6281,6293,6305,6317,6329,6341,6353,6365,6377,6389,6401,6413,6425,6437,6449,6461,6473,6485,6497,6509,6521,6533,6545,6557,6569,6581,6593,6605,6617,6629,6641,6653,6665,6677,6689,6701,6713,6725,6737,6749,6761,6773,6785,6797,6809,6821,6833,6845,6857,6869,6881,6893,6905,6917,6929,6941,6953,6965,6977,6989,7001,7013,7025,7037,7049,7061,7073,7085,7097,7109,7121,7133,7145,7157,7169,7181,7193,7205,7217,7229,7241,7253,7265,7277,7289,7301,7313,7325,7337,7349,7361,7373,7385,7397,7409,7421,7433,7445,7457,7469,7481,7493,7505,7517,7529,7541,7553,7565,7577,7589,7601,7613,7625,7637,7649,7661,7673,7685,7697,7709,7721,7733,7745,7757,7769,7781,7793,7805,7817,7829,7841,7853,7865,7877,7889,7901,7913,7925,7937,7949,7961,7973,7985,7997,8009,8021,8033,8045,8057,8069,8081,8093,8105,8117,8129,8141,8153,8165,8177,8189,8201,8213,8225,8237,8249,8261,8273,8285,8297,8309,8321,8333,8345,8357,8369,8381,8393,8405,8417,8429,8441,8453,8465,8477,8489,8501,8513,8525,8537,8549,8561,8573,8585,8597,8609,8621,8633,8645,8657,8669,8681,8693,8705,8717,8729,8741,8753,8765,8777,8789,8801,8813,8825,8837,8849,8861,8873,8885,8897,8909,8921,8933,8945,8957,8969,8981,8993,9005,9017,9029,9041,9053,9065,9077,9089,9101,9113,9125,9137,9149,9161,9173,9185,9197,9209,9221,9233,9245,9257,9269,9281,9293,9305,9317,9329,9341,9353,9365,9377,9389,9401,9413,9425,9437,9449,9461,9473,9485,9497,9509,9521,9533,9545,9557,9569,9581,9593,9605,9617,9629,9641,9653,9665,9677,9689,9701,9713,9725,9737,9749,9761,9773,9785,9797,9809,9821,9833,9845,9857,9869,9881,9893,9905,9917,9929,9941,9953,9965,9977,9989,10001,10013,10025,10037,10049,10061,10073,10085,10097,10109,10121,10133,10145,10157,10169,10181,10193,10205,10217,10229,10241,10253,10265,10277,10289,10301,10313,10325,10337,10349,10361,10373,10385,10397,10409,10421,10433,10445,10457,10469,10481,10493,10505,10517,10529,10541,10553,10565,10577,10589,10601,10613,10625,10637,10649,10661,10673,10685,10697,10709,10721,10733,10745,10757,10769,10781,10793,10805,10817,10829,10841,10853,10865,10877,10889,10901,10913,10925,10937,10949,10961,10973,10985,10997,11009,11021,11033,11045,11057,11069,11081,11093,11105,11117,11129,11141,11153,11165,11177,11189,11201,11213,11225,11237,11249,11261,11273,11285,11297,11309,11321,11333,11345,11357,11369,11381,11393,11405,11417,11429,11441,11453,11465,11477,11489,11501,11513,11525,11537,11549,11561,11573,11585,11597,11609,11621,11633,11645,11657,11669,11681,11693,11705,11717,11729,11741,11753,11765,11777,11789,11801,11813,11825,11837,11849,11861,11873,11885,11897,11909,11921,11933,11945,11957,11969,11981,11993,12005,12017,12029,12041,12053,12065,12077,12089,12101,12113,12126,12139,12152,12165,12178,12191,12204,12217,12230,12243,12256,12269,12282,12295,12308,12321,12334,12347,12360,12373,12386,12399,12412,12425,12438,12451,12464,12477,12490,12503,12516,12529,12542,12555,12568,12581,12594,12607,12620,12633,12646,12659,12672,12685,12698,12711,12724,12737,12750,12763,12776,12789,12802,12815,12828,12841,12854,12867,12880,12893,12906,12919,12932,12945,12958,12971,12984,12997,13010,13023,13036,13049,13062,13075,13088,13101,13114,13127,13140,13153,13166,13179,13192,13205,13218,13231,13244,13257,13270,13283,13296,13309,13322,13335,13348,13361,13374,13387,13400,13413,13426,13439,13452,13465,13478,13491,13504,13517,13530,13543,13556,13569,13582,13595,13608,13621,13634,13647,13660,13673,13686,13699,13712,13725,13738,13751,13764,13777,13790,13803,13816,13829,13842,13855,13868,13881,13894,13907,13920,13933,13946,13959,13972,13985,13998,14011,14024,14037,14050,14063,14076,14089,14102,14115,14128,14141,14154,14167,14180,14193,14206,14219,14232,14245,14258,14271,14284,14297,14310,14323,14336,14349,14362,14375,14388,14401,14414,14427,14440,14453,14466,14479,14492,14505,14518,14531,14544,14557,14570,14583,14596,14609,14622,14635,14648,14661,14674,14687,14700,14713,14726,14739,14752,14765,14778,14791,14804,14817,14830,14843,14856,14869,14882,14895,14908,14921,14934,14947,14960,14973,14986,14999,15012,15025,15038,15051,15064,15077,15090,15103,15116,15129,15142,15155,15168,15181,15194,15207,15220,15233,15246,15259,15272,15285,15298,15311,15324,15337,15350,15363,15376,15389,15402,15415,15428,15441,15454,15467,15480,15493,15506,15519,15532,15545,15558,15571,15584,15597,15610,15623,15636,15649,15662,15675,15688,15701,15714,15727,15740,15753,15766,15779,15792,15805,15818,15831,15844,15857,15870,15883,15896,15909,15922,15935,15948,15961,15974,15987,16000,16013,16026,16039,16052,16065,16078,16091,16104,16117,16130,16143,16156,16169,16182,16195,16208,16221,16234,16247,16260,16273,16286,16299,16312,16325,16338,16351,16364,16377,16390,16403,16416,16429,16442,16455,16468,16481,16494,16507,16520,16533,16546,16559,16572,16585,16598,16611,16624,16637,16650,16663,16676,16689,16702,16715,16728,16741,16754,16767,16780,16793,16806,16819,16832,16845,16858,16871,16884,16897,16910,16923,16936,16949,16962,16975,16988,17001,17014,17027,17040,17053,17066,17079,17092,17105,17118,17131,17144,17157,17170,17183,17196,17209,17222,17235,17248,17261,17274,17287,17300,17313,17326,17339,17352,17365,17378,17391,17404,17417,17430,17443,17456,17469,17482,17495,17508,17521,17534,17547,17560,17573,17586,17599,17612,17625,17638,17651,17664,17677,17690,17703,17716,17729,17742,17755,17768,17781,17794,17807,17820,17833,17846,17859,17872,17885,17898,17911,17924,17937,17950,17963,17976,17989,18002,18015,18028,18041,18054,18067,18080,18093,18106,18119,18132,18145,18158,18171,18184,18197,18210,18223,18236,18249,18262,18275,18288,18301,18314,18327,18340,18353,18366,18379,18392,18405,18418,18431,18444,18457,18470,18483,18496,18509,18522,18535,18548,18561,18574,18587,18600,18613,18626,18639,18652,18665,18678,18691,18704,18717,18730,18743,18756,18769,18782,18795,18808,18821,18834,18847,18860,18873,18886,18899,18912,18925,18938,18951,18964,18977,18990,19003,19016,19029,19042,19055,19068,19081,19094,19107,19120,19133,19146,19159,19172,19185,19198,19211,19224,19237,19250,19263,19276,19289,19302,19315,19328,19341,19354,19367,19380,19393,19406,19419,19432,19445,19458,19471,19484,19497,19510,19523,19536,19549,19562,19575,19588,19601,19614,19627,19640,19653,19666,19679,19692,19705,19718,19731,19744,19757,19770,19783,19796,19809,19822,19835,19848,19861,19874,19887,19900,19913,19926,19939,19952,19965,19978,19991,20004,20017,20030,20043,20056,20069,20082,20095,20108,20121,20134,20147,20160,20173,20186,20199,20212,20225,20238,20251,20264,20277,20290,20303,20316,20329,20342,20355,20368,20381,20394,20407,20420,20433,20446,20459,20472,20485,20498,20511,20524,20537,20550,20563,20576,20589,20602,20615,20628,20641,20654,20667,20680,20693,20706,20719,20732,20745,20758,20771,20784,20797,20810,20823,20836,20849,20862,20875,20888,20901,20914,20927,20940,20953,20966,20979,20992,21005,21018,21031,21044,21057,21070,21083,21096,21109,21122,21135,21148,21161,21174,21187,21200,21213,21226,21239,21252,21265,21278,21291,21304,21317,21330,21343,21356,21369,21382,21395,21408,21421,21434,21447,21460,21473,21486,21499,21512,21525,21538,21551,21564,21577,21590,21603,21616,21629,21642,21655,21668,21681,21694,21707,21720,21733,21746,21759,21772,21785,21798,21811,21824,21837,21850,21863,21876,21889,21902,21915,21928,21941,21955,21969,21983,21997,22011,22025,22039,22053,22067,22081,22095,22109,22123,22137,22151,22165,22179,22193,22207,22221,22235,22249,22263,22277,22291,22305,22319,22333,22347,22361,22375,22389,22403,22417,22431,22445,22459,22473,22487,22501,22515,22529,22543,22557,22571,22585,22599,22613,22627,22641,22655,22669,22683,22697,22711,22725,22739,22753,22767,22781,22795,22809,22823,22837,22851,22865,22879,22893,22907,22921,22935,22949,22963,22977,22991,23005,23019,23033,23047,23061,23075,23089,23103,23117,23131,23145,23159,23173,23187,23201,23215,23229,23243,23257,23271,23285,23299,23313,23327,23341,23355,23369,23383,23397,23411,23425,23439,23453,23467,23481,23495,23509,23523,23537,23551,23565,23579,23593,23607,23621,23635,23649,23663,23677,23691,23705,23719,23733,23747,23761,23775,23789,23803,23817,23831,23845,23859,23873,23887,23901,23915,23929,23943,23957,23971,23985,23999,24013,24027,24041,24055,24069,24083,24097,24111,24125,24139,24153,24167,24181,24195,24209,24223,24237,24251,24265,24279,24293,24307,24321,24335,24349,24363,24377,24391,24405,24419,24433,24447,24461,24475,24489,24503,24517,24531,24545,24559,24573,24587,24601,24615,24629,24643,24657,24671,24685,24699,24713,24727,24741,24755,24769,24783,24797,24811,24825,24839,24853,24867,24881,24895,24909,24923,24937,24951,24965,24979,24993,25007,25021,25035,25049,25063,25077,25091,25105,25119,25133,25147,25161,25175,25189,25203,25217,25231,25245,25259,25273,25287,25301,25315,25329,25343,25357,25371,25385,25399,25413,25427,25441,25455,25469,25483,25497,25511,25525,25539,25553,25567,25581,25595,25609,25623,25637,25651,25665,25679,25693,25707,25721,25735,25749,25763,25777,25791,25805,25819,25833,25847,25861,25875,25889,25903,25917,25931,25945,25959,25973,25987,26001,26015,26029,26043,26057,26071,26085,26099,26113,26127,26141,26155,26169,26183,26197,26211,26225,26239,26253,26267,26281,26295,26309,26323,26337,26351,26365,26379,26393,26407,26421,26435,26449,26463,26477,26491,26505,26519,26533,26547,26561,26575,26589,26603,26617,26631,26645,26659,26673,26687,26701,26715,26729,26743,26757,26771,26785,26799,26813,26827,26841,26855,26869,26883,26897,26911,26925,26939,26953,26967,26981,26995,27009,27023,27037,27051,27065,27079,27093,27107,27121,27135,27149,27163,27177,27191,27205,27219,27233,27247,27261,27275,27289,27303,27317,27331,27345,27359,27373,27387,27401,27415,27429,27443,27457,27471,27485,27499,27513,27527,27541,27555,27569,27583,27597,27611,27625,27639,27653,27667,27681,27695,27709,27723,27737,27751,27765,27779,27793,27807,27821,27835,27849,27863,27877,27891,27905,27919,27933,27947,27961,27975,27989,28003,28017,28031,28045,28059,28073,28087,28101,28115,28129,28143,28157,28171,28185,28199,28213,28227,28241,28255,28269,28283,28297,28311,28325,28339,28353,28367,28381,28395,28409,28423,28437,28451,28465,28479,28493,28507,28521,28535,28549,28563,28577,28591,28605,28619,28633,28647,28661,28675,28689,28703,28717,28731,28745,28759,28773,28787,28801,28815,28829,28843,28857,28871,28885,28899,28913,28927,28941,28955,28969,28983,28997,29011,29025,29039,29053,29067,29081,29095,29109,29123,29137,29151,29165,29179,29193,29207,29221,29235,29249,29263,29277,29291,29305,29319,29333,29347,29361,29375,29389,29403,29417,29431,29445,29459,29473,29487,29501,29515,29529,29543,29557,29571,29585,29599,29613,29627,29641,29655,29669,29683,29697,29711,29725,29739,29753,29767,29781,29795,29809,29823,29837,29851,29865,29879,29893,29907,29921,29935,29949,29963,29977,29991,30005,30019,30033,30047,30061,30075,30089,30103,30117,30131,30145,30159,30173,30187,30201,30215,30229,30243,30257,30271,30285,30299,30313,30327,30341,30355,30369,30383,30397,30411,30425,30439,30453,30467,30481,30495,30509,30523,30537,30551,30565,30579,30593,30607,30621,30635,30649,30663,30677,30691,30705,30719,30733,30747,30761,30775,30789,30803,30817,30831,30845,30859,30873,30887,30901,30915,30929,30943,30957,30971,30985,30999,31013,31027,31041,31055,31069,31083,31097,31111,31125,31139,31153,31167,31181,31195,31209,31223,31237,31251,31265,31279,31293,31307,31321,31335,31349,31363,31377,31391,31405,31419,31433,31447,31461,31475,31489,31503,31517,31531,31545,31559,31573,31587,31601,31615,31629,31643,31657,31671,31685,31699,31713,31727,31741,31755,31769,31783,31797,31811,31825,31839,31853,31867,31881,31895,31909,31923,31937,31951,31965,31979,31993,32007,32021,32035,32049,32063,32077,32091,32105,32119,32133,32147,32161,32175,32189,32203,32217,32231,32245,32259,32273,32287,32301,32315,32329,32343,32357,32371,32385,32399,32413,32427,32441,32455,32469,32483,32497,32511,32525,32539,32553,32567,32581,32595,32609,32623,32637,32651,32665,32679,32693,32707,32721,32735,32749,32763,32777,32791,32805,32819,32833,32847,32861,32875,32889,32903,32917,32931,32945,32959,32973,32987,33001,33015,33029,33043,33057,33071,33085,33099,33113,33127,33141,33155,33169,33183,33197,33211,33225,33239,33253,33267,33281,33295,33309,33323,33337,33351,33365,33379,33393,33407,33421,33435,33449,33463,33477,33491,33505,33519,33533,33547,33561,33575,33589,33603,33617,33631,33645,33659,33673,33687,33701,33715,33729,33743,33757,33771,33785,33799,33813,33827,33841,33855,33869,33883,33897,33911,33925,33939,33953,33967,33981,33995,34009,34023,34037,34051,34065,34079,34093,34107,34121,34135,34149,34163,34177,34191,34205,34219,34233,34247,34261,34275,34289,34303,34317,34331,34345,34359,34373,34387,34401,34415,34429,34443,34457,34471,34485,34499,34513,34527,34541,34555,34569,34583,34597,34611,34625,34639,34653,34667,34681,34695,34709,34723,34737,34751,34765,34779,34793,34807,34821,34835,34849,34863,34877,34891,34905,34919,34933,34947,34961,34975,34989,35003,35017,35031,35045,35059,35073,35087,35101,35115,35129,35143,35157,35171,35185,35199,35213,35227,35241,35255,35269,35283,35297,35311,35325,35339,35353,35367,35381,35395,35409,35423,35437,35451,35465,35479,35493,35507,35521,35535,35549,35563,35577,35591,35605,35619,35633,35647,35661,35675,35689,35703,35717,35731,35745,35759,35773,35787,35801,35815,35829,35843,35857,35871,35885,35899,35913,35927,35941,35955,35969,35983,35997,36011,36025,36039,36053,36067,36081,36095,36109,36123,36137,36151,36165,36179,36193,36207,36221,36235,36249,36263,36277,36291,36305,36319,36333,36347,36361,36375,36389,36403,36417,36431,36445,36459,36473,36487,36501,36515,36529,36543,36557,36571,36585,36599,36613,36627,36641,36655,36669,36683,36697,36711,36725,36739,36753,36767,36781,36795,36809,36823,36837,36851,36865,36879,36893,36907,36921,36935,36949,36963,36977,36991,37005,37019,37033,37047,37061,37075,37089,37103,37117,37131,37145,37159,37173,37187,37201,37215,37229,37243,37257,37271,37285,37299,37313,37327,37341,37355,37369,37383,37397,37411,37425,37439,37453,37467,37481,37495,37509,37523,37537,37551,37565,37579,37593,37607,37621,37635,37649,37663,37677,37691,37705,37719,37733,37747,37761,37775,37789,37803,37817,37831,37845,37859,37873,37887,37901,37915,37929,37943,37957,37971,37985,37999,38013,38027,38041,38055,38069,38083,38097,38111,38125,38139,38153,38167,38181,38195,38209,38223,38237,38251,38265,38279,38293,38307,38321,38335,38349,38363,38377,38391,38405,38419,38433,38447,38461,38475,38489,38503,38517,38531,38545,38559,38573,38587,38601,38615,38629,38643,38657,38671,38685,38699,38713,38727,38741,38755,38769,38783,38797,38811,38825,38839,38853,38867,38881,38895,38909,38923,38937,38951,38965,38979,38993,39007,39021,39035,39049,39063,39077,39091,39105,39119,39133,39147,39161,39175,39189,39203,39217,39231,39245,39259,39273,39287,39301,39315,39329,39343,39357,39371,39385,39399,39413,39427,39441,39455,39469,39483,39497,39511,39525,39539,39553,39567,39581,39595,39609,39623,39637,39651,39665,39679,39693,39707,39721,39735,39749,39763,39777,39791,39805,39819,39833,39847,39861,39875,39889,39903,39917,39931,39945,39959,39973,39987,40001,40015,40029,40043,40057,40071,40085,40099,40113,40127,40141,40155,40169,40183,40197,40211,40225,40239,40253,40267,40281,40295,40309,40323,40337,40351,40365,40379,40393,40407,40421,40435,40449,40463,40477,40491,40505,40519,40533,40547,40561,40575,40589,40603,40617,40631,40645,40659,40673,40687,40701,40715,40729,40743,40757,40771,40785,40799,40813,40827,40841,40855,40869,40883,40897,40911,40925,40939,40953,40967,40981,40995,41009,41023,41037,41051,41065,41079,41093,41107,41121,41135,41149,41163,41177,41191,41205,41219,41233,41247,41261,41275,41289,41303,41317,41331,41345,41359,41373,41387,41401,41415,41429,41443,41457,41471,41485,41499,41513,41527,41541,41555,41569,41583,41597,41611,41625,41639,41653,41667,41681,41695,41709,41723,41737,41751,41765,41779,41793,41807,41821,41835,41849,41863,41877,41891,41905,41919,41933,41947,41961,41975,41989,42003,42017,42031,42045,42059,42073,42087,42101,42115,42129,42143,42157,42171,42185,42199,42213,42227,42241,42255,42269,42283,42297,42311,42325,42339,42353,42367,42381,42395,42409,42423,42437,42451,42465,42479,42493,42507,42521,42535,42549,42563,42577,42591,42605,42619,42633,42647,42661,42675,42689,42703,42717,42731,42745,42759,42773,42787,42801,42815,42829,42843,42857,42871,42885,42899,42913,42927,42941,42955,42969,42983,42997,43011,43025,43039,43053,43067,43081,43095,43109,43123,43137,43151,43165,43179,43193,43207,43221,43235,43249,43263,43277,43291,43305,43319,43333,43347,43361,43375,43389,43403,43417,43431,43445,43459,43473,43487,43501,43515,43529,43543,43557,43571,43585,43599,43613,43627,43641,43655,43669,43683,43697,43711,43725,43739,43753,43767,43781,43795,43809,43823,43837,43851,43865,43879,43893,43907,43921,43935,43949,43963,43977,43991,44005,44019,44033,44047,44061,44075,44089,44103,44117,44131,44145,44159,44173,44187,44201,44215,44229,44243,44257,44271,44285,44299,44313,44327,44341,44355,44369,44383,44397,44411,44425,44439,44453,44467,44481,44495,44509,44523,44537,44551,44565,44579,44593,44607,44621,44635,44649,44663,44677,44691,44705,44719,44733,44747,44761,44775,44789,44803,44817,44831,44845,44859,44873,44887,44901,44915,44929,44943,44957,44971,44985,44999,45013,45027,45041,45055,45069,45083,45097,45111,45125,45139,45153,45167,45181,45195,45209,45223,45237,45251,45265,45279,45293,45307,45321,45335,45349,45363,45377,45391,45405,45419,45433,45447,45461,45475,45489,45503,45517,45531,45545,45559,45573,45587,45601,45615,45629,45643,45657,45671,45685,45699,45713,45727,45741,45755,45769,45783,45797,45811,45825,45839,45853,45867,45881,45895,45909,45923,45937,45951,45965,45979,45993,46007,46021,46035,46049,46063,46077,46091,46105,46119,46133,46147,46161,46175,46189,46203,46217,46231,46245,46259,46273,46287,46301,46315,46329,46343,46357,46371,46385,46399,46413,46427,46441,46455,46469,46483,46497,46511,46525,46539,46553,46567,46581,46595,46609,46623,46637,46651,46665,46679,46693,46707,46721,46735,46749,46763,46777,46791,46805,46819,46833,46847,46861,46875,46889,46903,46917,46931,46945,46959,46973,46987,47001,47015,47029,47043,47057,47071,47085,47099,47113,47127,47141,47156,47171,47186,47201,47216,47231,47246,47261,47276,47291,47306,47321,47336,47351,47366,47381,47396,47411,47426,47441,47456,47471,47486,47501,47516,47531,47546,47561,47576,47591,47606,47621,47636,47651,47666,47681,47696,47711,47726,47741,47756,47771,47786,47801,47816,47831,47846,47861,47876,47891,47906,47921,47936,47951,47966,47981,47996,48011,48026,48041,48056,48071,48086,48101,48116,48131,48146,48161,48176,48191,48206,48221,48236,48251,48266,48281,48296,48311,48326,48341,48356,48371,48386,48401,48416,48431,48446,48461,48476,48491,48506,48521,48536,48551,48566,48581,48596,48611,48626,48641,48656,48671,48686,48701,48716,48731,48746,48761,48776,48791,48806,48821,48836,48851,48866,48881,48896,48911,48926,48941,48956,48971,48986,49001,49016,49031,49046,49061,49076,49091,49106,49121,49136,49151,49166,49181,49196,49211,49226,49241,49256,49271,49286,49301,49316,49331,49346,49361,49376,49391,49406,49421,49436,49451,49466,49481,49496,49511,49526,49541,49556,49571,49586,49601,49616,49631,49646,49661,49676,49691,49706,49721,49736,49751,49766,49781,49796,49811,49826,49841,49856,49871,49886,49901,49916,49931,49946,49961,49976,49991,50006,50021,50036,50051,50066,50081,50096,50111,50126,50141,50156,50171,50186,50201,50216,50231,50246,50261,50276,50291,50306,50321,50336,50351,50366,50381,50396,50411,50426,50441,50456,50471,50486,50501,50516,50531,50546,50561,50576,50591,50606,50621,50636,50651,50666,50681,50696,50711,50726,50741,50756,50771,50786,50801,50816,50831,50846,50861,50876,50891,50906,50921,50936,50951,50966,50981,50996,51011,51026,51041,51056,51071,51086,51101,51116,51131,51146,51161,51176,51191,51206,51221,51236,51251,51266,51281,51296,51311,51326,51341,51356,51371,51386,51401,51416,51431,51446,51461,51476,51491,51506,51521,51536,51551,51566,51581,51596,51611,51626,51641,51656,51671,51686,51701,51716,51731,51746,51761,51776,51791,51806,51821,51836,51851,51866,51881,51896,51911,51926,51941,51956,51971,51986,52001,52016,52031,52046,52061,52076,52091,52106,52121,52136,52151,52166,52181,52196,52211,52226,52241,52256,52271,52286,52301,52316,52331,52346,52361,52376,52391,52406,52421,52436,52451,52466,52481,52496,52511,52526,52541,52556,52571,52586,52601,52616,52631,52646,52661,52676,52691,52706,52721,52736,52751,52766,52781,52796,52811,52826,52841,52856,52871,52886,52901,52916,52931,52946,52961,52976,52991,53006,53021,53036,53051,53066,53081,53096,53111,53126,53141,53156,53171,53186,53201,53216,53231,53246,53261,53276,53291,53306,53321,53336,53351,53366,53381,53396,53411,53426,53441,53456,53471,53486,53501,53516,53531,53546,53561,53576,53591,53606,53621,53636,53651,53666,53681,53696,53711,53726,53741,53756,53771,53786,53801,53816,53831,53846,53861,53876,53891,53906,53921,53936,53951,53966,53981,53996,54011,54026,54041,54056,54071,54086,54101,54116,54131,54146,54161,54176,54191,54206,54221,54236,54251,54266,54281,54296,54311,54326,54341,54356,54371,54386,54401,54416,54431,54446,54461,54476,54491,54506,54521,54536,54551,54566,54581,54596,54611,54626,54641,54656,54671,54686,54701,54716,54731,54746,54761,54776,54791,54806,54821,54836,54851,54866,54881,54896,54911,54926,54941,54956,54971,54986,55001,55016,55031,55046,55061,55076,55091,55106,55121,55136,55151,55166,55181,55196,55211,55226,55241,55256,55271,55286,55301,55316,55331,55346,55361,55376,55391,55406,55421,55436,55451,55466,55481,55496,55511,55526,55541,55556,55571,55586,55601,55616,55631,55646,55661,55676,55691,55706,55721,55736,55751,55766,55781,55796,55811,55826,55841,55856,55871,55886,55901,55916,55931,55946,55961,55976,55991,56006,56021,56036,56051,56066,56081,56096,56111,56126,56141,56156,56171,56186,56201,56216,56231,56246,56261,56276,56291,56306,56321,56336,56351,56366,56381,56396,56411,56426,56441,56456,56471,56486,56501,56516,56531,56546,56561,56576,56591,56606,56621,56636,56651,56666,56681,56696,56711,56726,56741,56756,56771,56786,56801,56816,56831,56846,56861,56876,56891,56906,56921,56936,56951,56966,56981,56996,57011,57026,57041,57056,57071,57086,57101,57116,57131,57146,57161,57176,57191,57206,57221,57236,57251,57266,57281,57296,57311,57326,57341,57356,57371,57386,57401,57416,57431,57446,57461,57476,57491,57506,57521,57536,57551,57566,57581,57596,57611,57626,57641,57656,57671,57686,57701,57716,57731,57746,57761,57776,57791,57806,57821,57836,57851,57866,57881,57896,57911,57926,57941,57956,57971,57986,58001,58016,58031,58046,58061,58076,58091,58106,58121,58136,58151,58166,58181,58196,58211,58226,58241,58256,58271,58286,58301,58316,58331,58346,58361,58376,58391,58406,58421,58436,58451,58466,58481,58496,58511,58526,58541,58556,58571,58586,58601,58616,58631,58646,58661,58676,58691,58706,58721,58736,58751,58766,58781,58796,58811,58826,58841,58856,58871,58886,58901,58916,58931,58946,58961,58976,58991,59006,59021,59036,59051,59066,59081,59096,59111,59126,59141,59156,59171,59186,59201,59216,59231,59246,59261,59276,59291,59306,59321,59336,59351,59366,59381,59396,59411,59426,59441,59456,59471,59486,59501,59516,59531,59546,59561,59576,59591,59606,59621,59636,59651,59666,59681,59696,59711,59726,59741,59756,59771,59786,59801,59816,59831,59846,59861,59876,59891,59906,59921,59936,59951,59966,59981,59996,60011,60026,60041,60056,60071,60086,60101,60116,60131,60146,60161,60176,60191,60206,60221,60236,60251,60266,60281,60296,60311,60326,60341,60356,60371,60386,60401,60416,60431,60446,60461,60476,60491,60506,60521,60536,60551,60566,60581,60596,60611,60626,60641,60656,60671,60686,60701,60716,60731,60746,60761,60776,60791,60806,60821,60836,60851,60866,60881,60896,60911,60926,60941,60956,60971,60986,61001,61016,61031,61046,61061,61076,61091,61106,61121,61136,61151,61166,61181,61196,61211,61226,61241,61256,61271,61286,61301,61316,61331,61346,61361,61376,61391,61406,61421,61436,61451,61466,61481,61496,61511,61526,61541,61556,61571,61586,61601,61616,61631,61646,61661,61676,61691,61706,61721,61736,61751,61766,61781,61796,61811,61826,61841,61856,61871,61886,61901,61916,61931,61946,61961,61976,61991,62006,62021,62036,62051,62066,62081,62096,62111,62126,62141,62156,62171,62186,62201,62216,62231,62246,62261,62276,62291,62306,62321,62336,62351,62366,62381,62396,62411,62426,62441,62456,62471,62486,62501,62516,62531,62546,62561,62576,62591,62606,62621,62636,62651,62666,62681,62696,62711,62726,62741,62756,62771,62786,62801,62816,62831,62846,62861,62876,62891,62906,62921,62936,62951,62966,62981,62996,63011,63026,63041,63056,63071,63086,63101,63116,63131,63146,63161,63176,63191,63206,63221,63236,63251,63266,63281,63296,63311,63326,63341,63356,63371,63386,63401,63416,63431,63446,63461,63476,63491,63506,63521,63536,63551,63566,63581,63596,63611,63626,63641,63656,63671,63686,63701,63716,63731,63746,63761,63776,63791,63806,63821,63836,63851,63866,63881,63896,63911,63926,63941,63956,63971,63986,64001,64016,64031,64046,64061,64076,64091,64106,64121,64136,64151,64166,64181,64196,64211,64226,64241,64256,64271,64286,64301,64316,64331,64346,64361,64376,64391,64406,64421,64436,64451,64466,64481,64496,64511,64526,64541,64556,64571,64586,64601,64616,64631,64646,64661,64676,64691,64706,64721,64736,64751,64766,64781,64796,64811,64826,64841,64856,64871,64886,64901,64916,64931,64946,64961,64976,64991,65006,65021,65036,65051,65066,65081,65096,65111,65126,65141,65156,65171,65186,65201,65216,65231,65246,65261,65276,65291,65306,65321,65336,65351,65366,65381,65396,65411,65426,65441,65456,65471,65486,65501,65516,65531,65546,65561,65576,65591,65606,65621,65636,65651,65666,65681,65696,65711,65726,65741,65756,65771,65786,65801,65816,65831,65846,65861,65876,65891,65906,65921,65936,65951,65966,65981,65996,66011,66026,66041,66056,66071,66086,66101,66116,66131,66146,66161,66176,66191,66206,66221,66236,66251,66266,66281,66296,66311,66326,66341,66356,66371,66386,66401,66416,66431,66446,66461,66476,66491,66506,66521,66536,66551,66566,66581,66596,66611,66626,66641,66656,66671,66686,66701,66716,66731,66746,66761,66776,66791,66806,66821,66836,66851,66866,66881,66896,66911,66926,66941,66956,66971,66986,67001,67016,67031,67046,67061,67076,67091,67106,67121,67136,67151,67166,67181,67196,67211,67226,67241,67256,67271,67286,67301,67316,67331,67346,67361,67376,67391,67406,67421,67436,67451,67466,67481,67496,67511,67526,67541,67556,67571,67586,67601,67616,67631,67646,67661,67676,67691,67706,67721,67736,67751,67766,67781,67796,67811,67826,67841,67856,67871,67886,67901,67916,67931,67946,67961,67976,67991,68006,68021,68036,68051,68066,68081,68096,68111,68126,68141,68156,68171,68186,68201,68216,68231,68246,68261,68276,68291,68306,68321,68336,68351,68366,68381,68396,68411,68426,68441,68456,68471,68486,68501,68516,68531,68546,68561,68576,68591,68606,68621,68636,68651,68666,68681,68696,68711,68726,68741,68756,68771,68786,68801,68816,68831,68846,68861,68876,68891,68906,68921,68936,68951,68966,68981,68996,69011,69026,69041,69056,69071,69086,69101,69116,69131,69146,69161,69176,69191,69206,69221,69236,69251,69266,69281,69296,69311,69326,69341,69356,69371,69386,69401,69416,69431,69446,69461,69476,69491,69506,69521,69536,69551,69566,69581,69596,69611,69626,69641,69656,69671,69686,69701,69716,69731,69746,69761,69776,69791,69806,69821,69836,69851,69866,69881,69896,69911,69926,69941,69956,69971,69986,70001,70016,70031,70046,70061,70076,70091,70106,70121,70136,70151,70166,70181,70196,70211,70226,70241,70256,70271,70286,70301,70316,70331,70346,70361,70376,70391,70406,70421,70436,70451,70466,70481,70496,70511,70526,70541,70556,70571,70586,70601,70616,70631,70646,70661,70676,70691,70706,70721,70736,70751,70766,70781,70796,70811,70826,70841,70856,70871,70886,70901,70916,70931,70946,70961,70976,70991,71006,71021,71036,71051,71066,71081,71096,71111,71126,71141,71156,71171,71186,71201,71216,71231,71246,71261,71276,71291,71306,71321,71336,71351,71366,71381,71396,71411,71426,71441,71456,71471,71486,71501,71516,71531,71546,71561,71576,71591,71606,71621,71636,71651,71666,71681,71696,71711,71726,71741,71756,71771,71786,71801,71816,71831,71846,71861,71876,71891,71906,71921,71936,71951,71966,71981,71996,72011,72026,72041,72056,72071,72086,72101,72116,72131,72146,72161,72176,72191,72206,72221,72236,72251,72266,72281,72296,72311,72326,72341,72356,72371,72386,72401,72416,72431,72446,72461,72476,72491,72506,72521,72536,72551,72566,72581,72596,72611,72626,72641,72656,72671,72686,72701,72716,72731,72746,72761,72776,72791,72806,72821,72836,72851,72866,72881,72896,72911,72926,72941,72956,72971,72986,73001,73016,73031,73046,73061,73076,73091,73106,73121,73136,73151,73166,73181,73196,73211,73226,73241,73256,73271,73286,73301,73316,73331,73346,73361,73376,73391,73406,73421,73436,73451,73466,73481,73496,73511,73526,73541,73556,73571,73586,73601,73616,73631,73646,73661,73676,73691,73706,73721,73736,73751,73766,73781,73796,73811,73826,73841,73856,73871,73886,73901,73916,73931,73946,73961,73976,73991,74006,74021,74036,74051,74066,74081,74096,74111,74126,74141,74156,74171,74186,74201,74216,74231,74246,74261,74276,74291,74306,74321,74336,74351,74366,74381,74396,74411,74426,74441,74456,74471,74486,74501,74516,74531,74546,74561,74576,74591,74606,74621,74636,74651,74666,74681,74696,74711,74726,74741,74756,74771,74786,74801,74816,74831,74846,74861,74876,74891,74906,74921,74936,74951,74966,74981,74996,75011,75026,75041,75056,75071,75086,75101,75116,75131,75146,75161,75176,75191,75206,75221,75236,75251,75266,75281,75296,75311,75326,75341,75356,75371,75386,75401,75416,75431,75446,75461,75476,75491,75506,75521,75536,75551,75566,75581,75596,75611,75626,75641,75656,75671,75686,75701,75716,75731,75746,75761,75776,75791,75806,75821,75836,75851,75866,75881,75896,75911,75926,75941,75956,75971,75986,76001,76016,76031,76046,76061,76076,76091,76106,76121,76136,76151,76166,76181,76196,76211,76226,76241,76256,76271,76286,76301,76316,76331,76346,76361,76376,76391,76406,76421,76436,76451,76466,76481,76496,76511,76526,76541,76556,76571,76586,76601,76616,76631,76646,76661,76676,76691,76706,76721,76736,76751,76766,76781,76796,76811,76826,76841,76856,76871,76886,76901,76916,76931,76946,76961,76976,76991,77006,77021,77036,77051,77066,77081,77096,77111,77126,77141,77156,77171,77186,77201,77216,77231,77246,77261,77276,77291,77306,77321,77336,77351,77366,77381,77396,77411,77426,77441,77456,77471,77486,77501,77516,77531,77546,77561,77576,77591,77606,77621,77636,77651,77666,77681,77696,77711,77726,77741,77756,77771,77786,77801,77816,77831,77846,77861,77877,77893,77909,77925,77941,77957,77973,77989,78005,78021,78037,78053,78069,78085,78101,78117,78133,78149,78165,78181,78197,78213,78229,78245,78261,78277,78293,78309,78325,78341,78357,78373,78389,78405,78421,78437,78453,78469,78485,78501,78517,78533,78549,78565,78581,78597,78613,78629,78645,78661,78677,78693,78709,78725,78741,78757,78773,78789,78805,78821,78837,78853,78869,78885,78901,78917,78933,78949,78965,78981,78997,79013,79029,79045,79061,79077,79093,79109,79125,79141,79157,79173,79189,79205,79221,79237,79253,79269,79285,79301,79317,79333,79349,79365,79381,79397,79413,79429,79445,79461,79477,79493,79509,79525,79541,79557,79573,79589,79605,79621,79637,79653,79669,79685,79701,79717,79733,79749,79765,79781,79797,79813,79829,79845,79861,79877,79893,79909,79925,79941,79957,79973,79989,80005,80021,80037,80053,80069,80085,80101,80117,80133,80149,80165,80181,80197,80213,80229,80245,80261,80277,80293,80309,80325,80341,80357,80373,80389,80405,80421,80437,80453,80469,80485,80501,80517,80533,80549,80565,80581,80597,80613,80629,80645,80661,80677,80693,80709,80725,80741,80757,80773,80789,80805,80821,80837,80853,80869,80885,80901,80917,80933,80949,80965,80981,80997,81013,81029,81045,81061,81077,81093,81109,81125,81141,81157,81173,81189,81205,81221,81237,81253,81269,81285,81301,81317,81333,81349,81365,81381,81397,81413,81429,81445,81461,81477,81493,81509,81525,81541,81557,81573,81589,81605,81621,81637,81653,81669,81685,81701,81717,81733,81749,81765,81781,81797,81813,81829,81845,81861,81877,81893,81909,81925,81941,81957,81973,81989,82005,82021,82037,82053,82069,82085,82101,82117,82133,82149,82165,82181,82197,82213,82229,82245,82261,82277,82293,82309,82325,82341,82357,82373,82389,82405,82421,82437,82453,82469,82485,82501,82517,82533,82549,82565,82581,82597,82613,82629,82645,82661,82677,82693,82709,82725,82741,82757,82773,82789,82805,82821,82837,82853,82869,82885,82901,82917,82933,82949,82965,82981,82997,83013,83029,83045,83061,83077,83093,83109,83125,83141,83157,83173,83189,83205,83221,83237,83253,83269,83285,83301,83317,83333,83349,83365,83381,83397,83413,83429,83445,83461,83477,83493,83509,83525,83541,83557,83573,83589,83605,83621,83637,83653,83669,83685,83701,83717,83733,83749,83765,83781,83797,83813,83829,83845,83861,83877,83893,83909,83925,83941,83957,83973,83989,84005,84021,84037,84053,84069,84085,84101,84117,84133,84149,84165,84181,84197,84213,84229,84245,84261,84277,84293,84309,84325,84341,84357,84373,84389,84405,84421,84437,84453,84469,84485,84501,84517,84533,84549,84565,84581,84597,84613,84629,84645,84661,84677,84693,84709,84725,84741,84757,84773,84789,84805,84821,84837,84853,84869,84885,84901,84917,84933,84949,84965,84981,84997,85013,85029,85045,85061,85077,85093,85109,85125,85141,85157,85173,85189,85205,85221,85237,85253,85269,85285,85301,85317,85333,85349,85365,85381,85397,85413,85429,85445,85461,85477,85493,85509,85525,85541,85557,85573,85589,85605,85621,85637,85653,85669,85685,85701,85717,85733,85749,85765,85781,85797,85813,85829,85845,85861,85877,85893,85909,85925,85941,85957,85973,85989,86005,86021,86037,86053,86069,86085,86101,86117,86133,86149,86165,86181,86197,86213,86229,86245,86261,86277,86293,86309,86325,86341,86357,86373,86389,86405,86421,86437,86453,86469,86485,86501,86517,86533,86549,86565,86581,86597,86613,86629,86645,86661,86677,86693,86709,86725,86741,86757,86773,86789,86805,86821,86837,86853,86869,86885,86901,86917,86933,86949,86965,86981,86997,87013,87029,87045,87061,87077,87093,87109,87125,87141,87157,87173,87189,87205,87221,87237,87253,87269,87285,87301,87317,87333,87349,87365,87381,87397,87413,87429,87445,87461,87477,87493,87509,87525,87541,87557,87573,87589,87605,87621,87637,87653,87669,87685,87701,87717,87733,87749,87765,87781,87797,87813,87829,87845,87861,87877,87893,87909,87925,87941,87957,87973,87989,88005,88021,88037,88053,88069,88085,88101,88117,88133,88149,88165,88181,88197,88213,88229,88245,88261,88277,88293,88309,88325,88341,88357,88373,88389,88405,88421,88437,88453,88469,88485,88501,88517,88533,88549,88565,88581,88597,88613,88629,88645,88661,88677,88693,88709,88725,88741,88757,88773,88789,88805,88821,88837,88853,88869,88885,88901,88917,88933,88949,88965,88981,88997,89013,89029,89045,89061,89077,89093,89109,89125,89141,89157,89173,89189,89205,89221,89237,89253,89269,89285,89301,89317,89333,89349,89365,89381,89397,89413,89429,89445,89461,89477,89493,89509,89525,89541,89557,89573,89589,89605,89621,89637,89653,89669,89685,89701,89717,89733,89749,89765,89781,89797,89813,89829,89845,89861,89877,89893,89909,89925,89941,89957,89973,89989,90005,90021,90037,90053,90069,90085,90101,90117,90133,90149,90165,90181,90197,90213,90229,90245,90261,90277,90293,90309,90325,90341,90357,90373,90389,90405,90421,90437,90453,90469,90485,90501,90517,90533,90549,90565,90581,90597,90613,90629,90645,90661,90677,90693,90709,90725,90741,90757,90773,90789,90805,90821,90837,90853,90869,90885,90901,90917,90933,90949,90965,90981,90997,91013,91029,91045,91061,91077,91093,91109,91125,91141,91157,91173,91189,91205,91221,91237,91253,91269,91285,91301,91317,91333,91349,91365,91381,91397,91413,91429,91445,91461,91477,91493,91509,91525,91541,91557,91573,91589,91605,91621,91637,91653,91669,91685,91701,91717,91733,91749,91765,91781,91797,91813,91829,91845,91861,91877,91893,91909,91925,91941,91957,91973,91989,92005,92021,92037,92053,92069,92085,92101,92117,92133,92149,92165,92181,92197,92213,92229,92245,92261,92277,92293,92309,92325,92341,92357,92373,92389,92405,92421,92437,92453,92469,92485,92501,92517,92533,92549,92565,92581,92597,92613,92629,92645,92661,92677,92693,92709,92725,92741,92757,92773,92789,92805,92821,92837,92853,92869,92885,92901,92917,92933,92949,92965,92981,92997,93013,93029,93045,93061,93077,93093,93109,93125,93141,93157,93173,93189,93205,93221,93237,93253,93269,93285,93301,93317,93333,93349,93365,93381,93397,93413,93429,93445,93461,93477,93493,93509,93525,93541,93557,93573,93589,93605,93621,93637,93653,93669,93685,93701,93717,93733,93749,93765,93781,93797,93813,93829,93845,93861,93877,93893,93909,93925,93941,93957,93973,93989,94005,94021,94037,94053,94069,94085,94101,94117,94133,94149,94165,94181,94197,94213,94229,94245,94261,94277,94293,94309,94325,94341,94357,94373,94389,94405,94421,94437,94453,94469,94485,94501,94517,94533,94549,94565,94581,94597,94613,94629,94645,94661,94677,94693,94709,94725,94741,94757,94773,94789,94805,94821,94837,94853,94869,94885,94901,94917,94933,94949,94965,94981,94997,95013,95029,95045,95061,95077,95093,95109,95125,95141,95157,95173,95189,95205,95221,95237,95253,95269,95285,95301,95317,95333,95349,95365,95381,95397,95413,95429,95445,95461,95477,95493,95509,95525,95541,95557,95573,95589,95605,95621,95637,95653,95669,95685,95701,95717,95733,95749,95765,95781,95797,95813,95829,95845,95861,95877,95893,95909,95925,95941,95957,95973,95989,96005,96021,96037,96053,96069,96085,96101,96117,96133,96149,96165,96181,96197,96213,96229,96245,96261,96277,96293,96309,96325,96341,96357,96373,96389,96405,96421,96437,96453,96469,96485,96501,96517,96533,96549,96565,96581,96597,96613,96629,96645,96661,96677,96693,96709,96725,96741,96757,96773,96789,96805,96821,96837,96853,96869,96885,96901,96917,96933,96949,96965,96981,96997,97013,97029,97045,97061,97077,97093,97109,97125,97141,97157,97173,97189,97205,97221,97237,97253,97269,97285,97301,97317,97333,97349,97365,97381,97397,97413,97429,97445,97461,97477,97493,97509,97525,97541,97557,97573,97589,97605,97621,97637,97653,97669,97685,97701,97717,97733,97749,97765,97781,97797,97813,97829,97845,97861,97877,97893,97909,97925,97941,97957,97973,97989,98005,98021,98037,98053,98069,98085,98101,98117,98133,98149,98165,98181,98197,98213,98229,98245,98261,98277,98293,98309,98325,98341,98357,98373,98389,98405,98421,98437,98453,98469,98485,98501,98517,98533,98549,98565,98581,98597,98613,98629,98645,98661,98677,98693,98709,98725,98741,98757,98773,98789,98805,98821,98837,98853,98869,98885,98901,98917,98933,98949,98965,98981,98997,99013,99029,99045,99061,99077,99093,99109,99125,99141,99157,99173,99189,99205,99221,99237,99253,99269,99285,99301,99317,99333,99349,99365,99381,99397,99413,99429,99445,99461,99477,99493,99509,99525,99541,99557,99573,99589,99605,99621,99637,99653,99669,99685,99701,99717,99733,99749,99765,99781,99797,99813,99829,99845,99861,99877,99893,99909,99925,99941,99957,99973,99989,100005,100021,100037,100053,100069,100085,100101,100117,100133,100149,100165,100181,100197,100213,100229,100245,100261,100277,100293,100309,100325,100341,100357,100373,100389,100405,100421,100437,100453,100469,100485,100501,100517,100533,100549,100565,100581,100597,100613,100629,100645,100661,100677,100693,100709,100725,100741,100757,100773,100789,100805,100821,100837,100853,100869,100885,100901,100917,100933,100949,100965,100981,100997,101013,101029,101045,101061,101077,101093,101109,101125,101141,101157,101173,101189,101205,101221,101237,101253,101269,101285,101301,101317,101333,101349,101365,101381,101397,101413,101429,101445,101461,101477,101493,101509,101525,101541,101557,101573,101589,101605,101621,101637,101653,101669,101685,101701,101717,101733,101749,101765,101781,101797,101813,101829,101845,101861,101877,101893,101909,101925,101941,101957,101973,101989,102005,102021,102037,102053,102069,102085,102101,102117,102133,102149,102165,102181,102197,102213,102229,102245,102261,102277,102293,102309,102325,102341,102357,102373,102389,102405,102421,102437,102453,102469,102485,102501,102517,102533,102549,102565,102581,102597,102613,102629,102645,102661,102677,102693,102709,102725,102741,102757,102773,102789,102805,102821,102837,102853,102869,102885,102901,102917,102933,102949,102965,102981,102997,103013,103029,103045,103061,103077,103093,103109,103125,103141,103157,103173,103189,103205,103221,103237,103253,103269,103285,103301,103317,103333,103349,103365,103381,103397,103413,103429,103445,103461,103477,103493,103509,103525,103541,103557,103573,103589,103605,103621,103637,103653,103669,103685,103701,103717,103733,103749,103765,103781,103797,103813,103829,103845,103861,103877,103893,103909,103925,103941,103957,103973,103989,104005,104021,104037,104053,104069,104085,104101,104117,104133,104149,104165,104181,104197,104213,104229,104245,104261,104277,104293,104309,104325,104341,104357,104373,104389,104405,104421,104437,104453,104469,104485,104501,104517,104533,104549,104565,104581,104597,104613,104629,104645,104661,104677,104693,104709,104725,104741,104757,104773,104789,104805,104821,104837,104853,104869,104885,104901,104917,104933,104949,104965,104981,104997,105013,105029,105045,105061,105077,105093,105109,105125,105141,105157,105173,105189,105205,105221,105237,105253,105269,105285,105301,105317,105333,105349,105365,105381,105397,105413,105429,105445,105461,105477,105493,105509,105525,105541,105557,105573,105589,105605,105621,105637,105653,105669,105685,105701,105717,105733,105749,105765,105781,105797,105813,105829,105845,105861,105877,105893,105909,105925,105941,105957,105973,105989,106005,106021,106037,106053,106069,106085,106101,106117,106133,106149,106165,106181,106197,106213,106229,106245,106261,106277,106293,106309,106325,106341,106357,106373,106389,106405,106421,106437,106453,106469,106485,106501,106517,106533,106549,106565,106581,106597,106613,106629,106645,106661,106677,106693,106709,106725,106741,106757,106773,106789,106805,106821,106837,106853,106869,106885,106901,106917,106933,106949,106965,106981,106997,107013,107029,107045,107061,107077,107093,107109,107125,107141,107157,107173,107189,107205,107221,107237,107253,107269,107285,107301,107317,107333,107349,107365,107381,107397,107413,107429,107445,107461,107477,107493,107509,107525,107541,107557,107573,107589,107605,107621,107637,107653,107669,107685,107701,107717,107733,107749,107765,107781,107797,107813,107829,107845,107861,107877,107893,107909,107925,107941,107957,107973,107989,108005,108021,108037,108053,108069,108085,108101,108117,108133,108149,108165,108181,108197,108213,108229,108245,108261,108277,108293,108309,108325,108341,108357,108373,108389,108405,108421,108437,108453,108469,108485,108501,108517,108533,108549,108565,108581,108597,108613,108629,108645,108661,108677,108693,108709,108725,108741,108757,108773,108789,108805,108821,108837,108853,108869,108885,108901,108917,108933,108949,108965,108981,108997,109013,109029,109045,109061,109077,109093,109109,109125,109141,109157,109173,109189,109205,109221,109237,109253,109269,109285,109301,109317,109333,109349,109365,109381,109397,109413,109429,109445,109461,109477,109493,109509,109525,109541,109557,109573,109589,109605,109621,109637,109653,109669,109685,109701,109717,109733,109749,109765,109781,109797,109813,109829,109845,109861,109877,109893,109909,109925,109941,109957,109973,109989,110005,110021,110037,110053,110069,110085,110101,110117,110133,110149,110165,110181,110197,110213,110229,110245,110261,110277,110293,110309,110325,110341,110357,110373,110389,110405,110421,110437,110453,110469,110485,110501,110517,110533,110549,110565,110581,110597,110613,110629,110645,110661,110677,110693,110709,110725,110741,110757,110773,110789,110805,110821,110837,110853,110869,110885,110901,110917,110933,110949,110965,110981,110997,111013,111029,111045,111061,111077,111093,111109,111125,111141,111157,111173,111189,111205,111221,111237,111253,111269,111285,111301,111317,111333,111349,111365,111381,111397,111413,111429,111445,111461,111477,111493,111509,111525,111541,111557,111573,111589,111605,111621,111637,111653,111669,111685,111701,111717,111733,111749,111765,111781,111797,111813,111829,111845,111861,111877,111893,111909,111925,111941,111957,111973,111989,112005,112021,112037,112053,112069,112085,112101,112117,112133,112149,112165,112181,112197,112213,112229,112245,112261,112277,112293,112309,112325,112341,112357,112373,112389,112405,112421,112437,112453,112469,112485,112501,112517,112533,112549,112565,112581,112597,112613,112629,112645,112661,112677,112693,112709,112725,112741,112757,112773,112789,112805,112821,112837,112853,112869,112885,112901,112917,112933,112949,112965,112981,112997,113013,113029,113045,113061,113077,113093,113109,113125,113141,113157,113173,113189,113205,113221,113237,113253,113269,113285,113301,113317,113333,113349,113365,113381,113397,113413,113429,113445,113461,113477,113493,113509,113525,113541,113557,113573,113589,113605,113621,113637,113653,113669,113685,113701,113717,113733,113749,113765,113781,113797,113813,113829,113845,113861,113877,113893,113909,113925,113941,113957,113973,113989,114005,114021,114037,114053,114069,114085,114101,114117,114133,114149,114165,114181,114197,114213,114229,114245,114261,114277,114293,114309,114325,114341,114357,114373,114389,114405,114421,114437,114453,114469,114485,114501,114517,114533,114549,114565,114581,114597,114613,114629,114645,114661,114677,114693,114709,114725,114741,114757,114773,114789,114805,114821,114837,114853,114869,114885,114901,114917,114933,114949,114965,114981,114997,115013,115029,115045,115061,115077,115093,115109,115125,115141,115157,115173,115189,115205,115221,115237,115253,115269,115285,115301,115317,115333,115349,115365,115381,115397,115413,115429,115445,115461,115477,115493,115509,115525,115541,115557,115573,115589,115605,115621,115637,115653,115669,115685,115701,115717,115733,115749,115765,115781,115797,115813,115829,115845,115861,115877,115893,115909,115925,115941,115957,115973,115989,116005,116021,116037,116053,116069,116085,116101,116117,116133,116149,116165,116181,116197,116213,116229,116245,116261,116277,116293,116309,116325,116341,116357,116373,116389,116405,116421,116437,116453,116469,116485,116501,116517,116533,116549,116565,116581,116597,116613,116629,116645,116661,116677,116693,116709,116725,116741,116757,116773,116789,116805,116821,116837,116853,116869,116885,116901,116917,116933,116949,116965,116981,116997,117013,117029,117045,117061,117077,117093,117109,117125,117141,117157,117173,117189,117205,117221,117237,117253,117269,117285,117301,117317,117333,117349,117365,117381,117397,117413,117429,117445,117461,117477,117493,117509,117525,117541,117557,117573,117589,117605,117621,117637,117653,117669,117685,117701,117717,117733,117749,117765,117781,117797,117813,117829,117845,117861,117877,117893,117909,117925,117941,117957,117973,117989,118005,118021,118037,118053,118069,118085,118101,118117,118133,118149,118165,118181,118197,118213,118229,118245,118261,118277,118293,118309,118325,118341,118357,118373,118389,118405,118421,118437,118453,118469,118485,118501,118517,118533,118549,118565,118581,118597,118613,118629,118645,118661,118677,118693,118709,118725,118741,118757,118773,118789,118805,118821,118837,118853,118869,118885,118901,118917,118933,118949,118965,118981,118997,119013,119029,119045,119061,119077,119093,119109,119125,119141,119157,119173,119189,119205,119221,119237,119253,119269,119285,119301,119317,119333,119349,119365,119381,119397,119413,119429,119445,119461,119477,119493,119509,119525,119541,119557,119573,119589,119605,119621,119637,119653,119669,119685,119701,119717,119733,119749,119765,119781,119797,119813,119829,119845,119861,119877,119893,119909,119925,119941,119957,119973,119989,120005,120021,120037,120053,120069,120085,120101,120117,120133,120149,120165,120181,120197,120213,120229,120245,120261,120277,120293,120309,120325,120341,120357,120373,120389,120405,120421,120437,120453,120469,120485,120501,120517,120533,120549,120565,120581,120597,120613,120629,120645,120661,120677,120693,120709,120725,120741,120757,120773,120789,120805,120821,120837,120853,120869,120885,120901,120917,120933,120949,120965,120981,120997,121013,121029,121045,121061,121077,121093,121109,121125,121141,121157,121173,121189,121205,121221,121237,121253,121269,121285,121301,121317,121333,121349,121365,121381,121397,121413,121429,121445,121461,121477,121493,121509,121525,121541,121557,121573,121589,121605,121621,121637,121653,121669,121685,121701,121717,121733,121749,121765,121781,121797,121813,121829,121845,121861,121877,121893,121909,121925,121941,121957,121973,121989,122005,122021,122037,122053,122069,122085,122101,122117,122133,122149,122165,122181,122197,122213,122229,122245,122261,122277,122293,122309,122325,122341,122357,122373,122389,122405,122421,122437,122453,122469,122485,122501,122517,122533,122549,122565,122581,122597,122613,122629,122645,122661,122677,122693,122709,122725,122741,122757,122773,122789,122805,122821,122837,122853,122869,122885,122901,122917,122933,122949,122965,122981,122997,123013,123029,123045,123061,123077,123093,123109,123125,123141,123157,123173,123189,123205,123221,123237,123253,123269,123285,123301,123317,123333,123349,123365,123381,123397,123413,123429,123445,123461,123477,123493,123509,123525,123541,123557,123573,123589,123605,123621,123637,123653,123669,123685,123701,123717,123733,123749,123765,123781,123797,123813,123829,123845,123861,123877,123893,123909,123925,123941,123957,123973,123989,124005,124021,124037,124053,124069,124085,124101,124117,124133,124149,124165,124181,124197,124213,124229,124245,124261,124277,124293,124309,124325,124341,124357,124373,124389,124405,124421,124437,124453,124469,124485,124501,124517,124533,124549,124565,124581,124597,124613,124629,124645,124661,124677,124693,124709,124725,124741,124757,124773,124789,124805,124821,124837,124853,124869,124885,124901,124917,124933,124949,124965,124981,124997,125013,125029,125045,125061,125077,125093,125109,125125,125141,125157,125173,125189,125205,125221,125237,125253,125269,125285,125301,125317,125333,125349,125365,125381,125397,125413,125429,125445,125461,125477,125493,125509,125525,125541,125557,125573,125589,125605,125621,125637,125653,125669,125685,125701,125717,125733,125749,125765,125781,125797,125813,125829,125845,125861,125877,125893,125909,125925,125941,125957,125973,125989,126005,126021,126037,126053,126069,126085,126101,126117,126133,126149,126165,126181,126197,126213,126229,126245,126261,126277,126293,126309,126325,126341,126357,126373,126389,126405,126421,126437,126453,126469,126485,126501,126517,126533,126549,126565,126581,126597,126613,126629,126645,126661,126677,126693,126709,126725,126741,126757,126773,126789,126805,126821,126837,126853,126869,126885,126901,126917,126933,126949,126965,126981,126997,127013,127029,127045,127061,127077,127093,127109,127125,127141,127157,127173,127189,127205,127221,127237,127253,127269,127285,127301,127317,127333,127349,127365,127381,127397,127413,127429,127445,127461,127477,127493,127509,127525,127541,127557,127573,127589,127605,127621,127637,127653,127669,127685,127701,127717,127733,127749,127765,127781,127797,127813,127829,127845,127861,127877,127893,127909,127925,127941,127957,127973,127989,128005,128021,128037,128053,128069,128085,128101,128117,128133,128149,128165,128181,128197,128213,128229,128245,128261,128277,128293,128309,128325,128341,128357,128373,128389,128405,128421,128437,128453,128469,128485,128501,128517,128533,128549,128565,128581,128597,128613,128629,128645,128661,128677,128693,128709,128725,128741,128757,128773,128789,128805,128821,128837,128853,128869,128885,128901,128917,128933,128949,128965,128981,128997,129013,129029,129045,129061,129077,129093,129109,129125,129141,129157,129173,129189,129205,129221,129237,129253,129269,129285,129301,129317,129333,129349,129365,129381,129397,129413,129429,129445,129461,129477,129493,129509,129525,129541,129557,129573,129589,129605,129621,129637,129653,129669,129685,129701,129717,129733,129749,129765,129781,129797,129813,129829,129845,129861,129877,129893,129909,129925,129941,129957,129973,129989,130005,130021,130037,130053,130069,130085,130101,130117,130133,130149,130165,130181,130197,130213,130229,130245,130261,130277,130293,130309,130325,130341,130357,130373,130389,130405,130421,130437,130453,130469,130485,130501,130517,130533,130549,130565,130581,130597,130613,130629,130645,130661,130677,130693,130709,130725,130741,130757,130773,130789,130805,130821,130837,130853,130869,130885,130901,130917,130933,130949,130965,130981,130997,131013,131029,131045,131061,131077,131093,131109,131125,131141,131157,131173,131189,131205,131221,131237,131253,131269,131285,131301,131317,131333,131349,131365,131381,131397,131413,131429,131445,131461,131477,131493,131509,131525,131541,131557,131573,131589,131605,131621,131637,131653,131669,131685,131701,131717,131733,131749,131765,131781,131797,131813,131829,131845,131861,131877,131893,131909,131925,131941,131957,131973,131989,132005,132021,132037,132053,132069,132085,132101,132117,132133,132149,132165,132181,132197,132213,132229,132245,132261,132277,132293,132309,132325,132341,132357,132373,132389,132405,132421,132437,132453,132469,132485,132501,132517,132533,132549,132565,132581,132597,132613,132629,132645,132661,132677,132693,132709,132725,132741,132757,132773,132789,132805,132821,132837,132853,132869,132885,132901,132917,132933,132949,132965,132981,132997,133013,133029,133045,133061,133077,133093,133109,133125,133141,133157,133173,133189,133205,133221,133237,133253,133269,133285,133301,133317,133333,133349,133365,133381,133397,133413,133429,133445,133461,133477,133493,133509,133525,133541,133557,133573,133589,133605,133621,133637,133653,133669,133685,133701,133717,133733,133749,133765,133781,133797,133813,133829,133845,133861,133877,133893,133909,133925,133941,133957,133973,133989,134005,134021,134037,134053,134069,134085,134101,134117,134133,134149,134165,134181,134197,134213,134229,134245,134261,134277,134293,134309,134325,134341,134357,134373,134389,134405,134421,134437,134453,134469,134485,134501,134517,134533,134549,134565,134581,134597,134613,134629,134645,134661,134677,134693,134709,134725,134741,134757,134773,134789,134805,134821,134837,134853,134869,134885,134901,134917,134933,134949,134965,134981,134997,135013,135029,135045,135061,135077,135093,135109,135125,135141,135157,135173,135189,135205,135221,135237,135253,135269,135285,135301,135317,135333,135349,135365,135381,135397,135413,135429,135445,135461,135477,135493,135509,135525,135541,135557,135573,135589,135605,135621,135637,135653,135669,135685,135701,135717,135733,135749,135765,135781,135797,135813,135829,135845,135861,135877,135893,135909,135925,135941,135957,135973,135989,136005,136021,136037,136053,136069,136085,136101,136117,136133,136149,136165,136181,136197,136213,136229,136245,136261,136277,136293,136309,136325,136341,136357,136373,136389,136405,136421,136437,136453,136469,136485,136501,136517,136533,136549,136565,136581,136597,136613,136629,136645,136661,136677,136693,136709,136725,136741,136757,136773,136789,136805,136821,136837,136853,136869,136885,136901,136917,136933,136949,136965,136981,136997,137013,137029,137045,137061,137077,137093,137109,137125,137141,137157,137173,137189,137205,137221,137237,137253,137269,137285,137301,137317,137333,137349,137365,137381,137397,137413,137429,137445,137461,137477,137493,137509,137525,137541,137557,137573,137589,137605,137621,137637,137653,137669,137685,137701,137717,137733,137749,137765,137781,137797,137813,137829,137845,137861,137877,137893,137909,137925,137941,137957,137973,137989,138005,138021,138037,138053,138069,138085,138101,138117,138133,138149,138165,138181,138197,138213,138229,138245,138261,138277,138293,138309,138325,138341,138357,138373,138389,138405,138421,138437,138453,138469,138485,138501,138517,138533,138549,138565,138581,138597,138613,138629,138645,138661,138677,138693,138709,138725,138741,138757,138773,138789,138805,138821,138837,138853,138869,138885,138901,138917,138933,138949,138965,138981,138997,139013,139029,139045,139061,139077,139093,139109,139125,139141,139157,139173,139189,139205,139221,139237,139253,139269,139285,139301,139317,139333,139349,139365,139381,139397,139413,139429,139445,139461,139477,139493,139509,139525,139541,139557,139573,139589,139605,139621,139637,139653,139669,139685,139701,139717,139733,139749,139765,139781,139797,139813,139829,139845,139861,139877,139893,139909,139925,139941,139957,139973,139989,140005,140021,140037,140053,140069,140085,140101,140117,140133,140149,140165,140181,140197,140213,140229,140245,140261,140277,140293,140309,140325,140341,140357,140373,140389,140405,140421,140437,140453,140469,140485,140501,140517,140533,140549,140565,140581,140597,140613,140629,140645,140661,140677,140693,140709,140725,140741,140757,140773,140789,140805,140821,140837,140853,140869,140885,140901,140917,140933,140949,140965,140981,140997,141013,141029,141045,141061,141077,141093,141109,141125,141141,141157,141173,141189,141205,141221,141237,141253,141269,141285,141301,141317,141333,141349,141365,141381,141397,141413,141429,141445,141461,141477,141493,141509,141525,141541,141557,141573,141589,141605,141621,141637,141653,141669,141685,141701,141717,141733,141749,141765,141781,141797,141813,141829,141845,141861,141877,141893,141909,141925,141941,141957,141973,141989,142005,142021,142037,142053,142069,142085,142101,142117,142133,142149,142165,142181,142197,142213,142229,142245,142261,142277,142293,142309,142325,142341,142357,142373,142389,142405,142421,142437,142453,142469,142485,142501,142517,142533,142549,142565,142581,142597,142613,142629,142645,142661,142677,142693,142709,142725,142741,142757,142773,142789,142805,142821,142837,142853,142869,142885,142901,142917,142933,142949,142965,142981,142997,143013,143029,143045,143061,143077,143093,143109,143125,143141,143157,143173,143189,143205,143221,143237,143253,143269,143285,143301,143317,143333,143349,143365,143381,143397,143413,143429,143445,143461,143477,143493,143509,143525,143541,143557,143573,143589,143605,143621,143637,143653,143669,143685,143701,143717,143733,143749,143765,143781,143797,143813,143829,143845,143861,143877,143893,143909,143925,143941,143957,143973,143989,144005,144021,144037,144053,144069,144085,144101,144117,144133,144149,144165,144181,144197,144213,144229,144245,144261,144277,144293,144309,144325,144341,144357,144373,144389,144405,144421,144437,144453,144469,144485,144501,144517,144533,144549,144565,144581,144597,144613,144629,144645,144661,144677,144693,144709,144725,144741,144757,144773,144789,144805,144821,144837,144853,144869,144885,144901,144917,144933,144949,144965,144981,144997,145013,145029,145045,145061,145077,145093,145109,145125,145141,145157,145173,145189,145205,145221,145237,145253,145269,145285,145301,145317,145333,145349,145365,145381,145397,145413,145429,145445,145461,145477,145493,145509,145525,145541,145557,145573,145589,145605,145621,145637,145653,145669,145685,145701,145717,145733,145749,145765,145781,145797,145813,145829,145845,145861,145877,145893,145909,145925,145941,145957,145973,145989,146005,146021,146037,146053,146069,146085,146101,146117,146133,146149,146165,146181,146197,146213,146229,146245,146261,146277,146293,146309,146325,146341,146357,146373,146389,146405,146421,146437,146453,146469,146485,146501,146517,146533,146549,146565,146581,146597,146613,146629,146645,146661,146677,146693,146709,146725,146741,146757,146773,146789,146805,146821,146837,146853,146869,146885,146901,146917,146933,146949,146965,146981,146997,147013,147029,147045,147061,147077,147093,147109,147125,147141,147157,147173,147189,147205,147221,147237,147253,147269,147285,147301,147317,147333,147349,147365,147381,147397,147413,147429,147445,147461,147477,147493,147509,147525,147541,147557,147573,147589,147605,147621,147637,147653,147669,147685,147701,147717,147733,147749,147765,147781,147797,147813,147829,147845,147861,147877,147893,147909,147925,147941,147957,147973,147989,148005,148021,148037,148053,148069,148085,148101,148117,148133,148149,148165,148181,148197,148213,148229,148245,148261,148277,148293,148309,148325,148341,148357,148373,148389,148405,148421,148437,148453,148469,148485,148501,148517,148533,148549,148565,148581,148597,148613,148629,148645,148661,148677,148693,148709,148725,148741,148757,148773,148789,148805,148821,148837,148853,148869,148885,148901,148917,148933,148949,148965,148981,148997,149013,149029,149045,149061,149077,149093,149109,149125,149141,149157,149173,149189,149205,149221,149237,149253,149269,149285,149301,149317,149333,149349,149365,149381,149397,149413,149429,149445,149461,149477,149493,149509,149525,149541,149557,149573,149589,149605,149621,149637,149653,149669,149685,149701,149717,149733,149749,149765,149781,149797,149813,149829,149845,149861,149877,149893,149909,149925,149941,149957,149973,149989,150005,150021,150037,150053,150069,150085,150101,150117,150133,150149,150165,150181,150197,150213,150229,150245,150261,150277,150293,150309,150325,150341,150357,150373,150389,150405,150421,150437,150453,150469,150485,150501,150517,150533,150549,150565,150581,150597,150613,150629,150645,150661,150677,150693,150709,150725,150741,150757,150773,150789,150805,150821,150837,150853,150869,150885,150901,150917,150933,150949,150965,150981,150997,151013,151029,151045,151061,151077,151093,151109,151125,151141,151157,151173,151189,151205,151221,151237,151253,151269,151285,151301,151317,151333,151349,151365,151381,151397,151413,151429,151445,151461,151477,151493,151509,151525,151541,151557,151573,151589,151605,151621,151637,151653,151669,151685,151701,151717,151733,151749,151765,151781,151797,151813,151829,151845,151861,151877,151893,151909,151925,151941,151957,151973,151989,152005,152021,152037,152053,152069,152085,152101,152117,152133,152149,152165,152181,152197,152213,152229,152245,152261,152277,152293,152309,152325,152341,152357,152373,152389,152405,152421,152437,152453,152469,152485,152501,152517,152533,152549,152565,152581,152597,152613,152629,152645,152661,152677,152693,152709,152725,152741,152757,152773,152789,152805,152821,152837,152853,152869,152885,152901,152917,152933,152949,152965,152981,152997,153013,153029,153045,153061,153077,153093,153109,153125,153141,153157,153173,153189,153205,153221,153237,153253,153269,153285,153301,153317,153333,153349,153365,153381,153397,153413,153429,153445,153461,153477,153493,153509,153525,153541,153557,153573,153589,153605,153621,153637,153653,153669,153685,153701,153717,153733,153749,153765,153781,153797,153813,153829,153845,153861,153877,153893,153909,153925,153941,153957,153973,153989,154005,154021,154037,154053,154069,154085,154101,154117,154133,154149,154165,154181,154197,154213,154229,154245,154261,154277,154293,154309,154325,154341,154357,154373,154389,154405,154421,154437,154453,154469,154485,154501,154517,154533,154549,154565,154581,154597,154613,154629,154645,154661,154677,154693,154709,154725,154741,154757,154773,154789,154805,154821,154837,154853,154869,154885,154901,154917,154933,154949,154965,154981,154997,155013,155029,155045,155061,155077,155093,155109,155125,155141,155157,155173,155189,155205,155221,155237,155253,155269,155285,155301,155317,155333,155349,155365,155381,155397,155413,155429,155445,155461,155477,155493,155509,155525,155541,155557,155573,155589,155605,155621,155637,155653,155669,155685,155701,155717,155733,155749,155765,155781,155797,155813,155829,155845,155861,155877,155893,155909,155925,155941,155957,155973,155989,156005,156021,156037,156053,156069,156085,156101,156117,156133,156149,156165,156181,156197,156213,156229,156245,156261,156277,156293,156309,156325,156341,156357,156373,156389,156405,156421,156437,156453,156469,156485,156501,156517,156533,156549,156565,156581,156597,156613,156629,156645,156661,156677,156693,156709,156725,156741,156757,156773,156789,156805,156821,156837,156853,156869,156885,156901,156917,156933,156949,156965,156981,156997,157013,157029,157045,157061,157077,157093,157109,157125,157141,157157,157173,157189,157205,157221,157237,157253,157269,157285,157301,157317,157333,157349,157365,157381,157397,157413,157429,157445,157461,157477,157493,157509,157525,157541,157557,157573,157589,157605,157621,157637,157653,157669,157685,157701,157717,157733,157749,157765,157781,157797,157813,157829,157845,157861,157877,157893,157909,157925,157941,157957,157973,157989,158005,158021,158037,158053,158069,158085,158101,158117,158133,158149,158165,158181,158197,158213,158229,158245,158261,158277,158293,158309,158325,158341,158357,158373,158389,158405,158421,158437,158453,158469,158485,158501,158517,158533,158549,158565,158581,158597,158613,158629,158645,158661,158677,158693,158709,158725,158741,158757,158773,158789,158805,158821,158837,158853,158869,158885,158901,158917,158933,158949,158965,158981,158997,159013,159029,159045,159061,159077,159093,159109,159125,159141,159157,159173,159189,159205,159221,159237,159253,159269,159285,159301,159317,159333,159349,159365,159381,159397,159413,159429,159445,159461,159477,159493,159509,159525,159541,159557,159573,159589,159605,159621,159637,159653,159669,159685,159701,159717,159733,159749,159765,159781,159797,159813,159829,159845,159861,159877,159893,159909,159925,159941,159957,159973,159989,160005,160021,160037,160053,160069,160085,160101,160117,160133,160149,160165,160181,160197,160213,160229,160245,160261,160277,160293,160309,160325,160341,160357,160373,160389,160405,160421,160437,160453,160469,160485,160501,160517,160533,160549,160565,160581,160597,160613,160629,160645,160661,160677,160693,160709,160725,160741,160757,160773,160789,160805,160821,160837,160853,160869,160885,160901,160917,160933,160949,160965,160981,160997,161013,161029,161045,161061,161077,161093,161109,161125,161141,161157,161173,161189,161205,161221,161237,161253,161269,161285,161301,161317,161333,161349,161365,161381,161397,161413,161429,161445,161461,161477,161493,161509,161525,161541,161557,161573,161589,161605,161621,161637,161653,161669,161685,161701,161717,161733,161749,161765,161781,161797,161813,161829,161845,161861,161877,161893,161909,161925,161941,161957,161973,161989,162005,162021,162037,162053,162069,162085,162101,162117,162133,162149,162165,162181,162197,162213,162229,162245,162261,162277,162293,162309,162325,162341,162357,162373,162389,162405,162421,162437,162453,162469,162485,162501,162517,162533,162549,162565,162581,162597,162613,162629,162645,162661,162677,162693,162709,162725,162741,162757,162773,162789,162805,162821,162837,162853,162869,162885,162901,162917,162933,162949,162965,162981,162997,163013,163029,163045,163061,163077,163093,163109,163125,163141,163157,163173,163189,163205,163221,163237,163253,163269,163285,163301,163317,163333,163349,163365,163381,163397,163413,163429,163445,163461,163477,163493,163509,163525,163541,163557,163573,163589,163605,163621,163637,163653,163669,163685,163701,163717,163733,163749,163765,163781,163797,163813,163829,163845,163861,163877,163893,163909,163925,163941,163957,163973,163989,164005,164021,164037,164053,164069,164085,164101,164117,164133,164149,164165,164181,164197,164213,164229,164245,164261,164277,164293,164309,164325,164341,164357,164373,164389,164405,164421,164437,164453,164469,164485,164501,164517,164533,164549,164565,164581,164597,164613,164629,164645,164661,164677,164693,164709,164725,164741,164757,164773,164789,164805,164821,164837,164853,164869,164885,164901,164917,164933,164949,164965,164981,164997,165013,165029,165045,165061,165077,165093,165109,165125,165141,165157,165173,165189,165205,165221,165237,165253,165269,165285,165301,165317,165333,165349,165365,165381,165397,165413,165429,165445,165461,165477,165493,165509,165525,165541,165557,165573,165589,165605,165621,165637,165653,165669,165685,165701,165717,165733,165749,165765,165781,165797,165813,165829,165845,165861,165877,165893,165909,165925,165941,165957,165973,165989,166005,166021,166037,166053,166069,166085,166101,166117,166133,166149,166165,166181,166197,166213,166229,166245,166261,166277,166293,166309,166325,166341,166357,166373,166389,166405,166421,166437,166453,166469,166485,166501,166517,166533,166549,166565,166581,166597,166613,166629,166645,166661,166677,166693,166709,166725,166741,166757,166773,166789,166805,166821,166837,166853,166869,166885,166901,166917,166933,166949,166965,166981,166997,167013,167029,167045,167061,167077,167093,167109,167125,167141,167157,167173,167189,167205,167221,167237,167253,167269,167285,167301,167317,167333,167349,167365,167381,167397,167413,167429,167445,167461,167477,167493,167509,167525,167541,167557,167573,167589,167605,167621,167637,167653,167669,167685,167701,167717,167733,167749,167765,167781,167797,167813,167829,167845,167861,167877,167893,167909,167925,167941,167957,167973,167989,168005,168021,168037,168053,168069,168085,168101,168117,168133,168149,168165,168181,168197,168213,168229,168245,168261,168277,168293,168309,168325,168341,168357,168373,168389,168405,168421,168437,168453,168469,168485,168501,168517,168533,168549,168565,168581,168597,168613,168629,168645,168661,168677,168693,168709,168725,168741,168757,168773,168789,168805,168821,168837,168853,168869,168885,168901,168917,168933,168949,168965,168981,168997,169013,169029,169045,169061,169077,169093,169109,169125,169141,169157,169173,169189,169205,169221,169237,169253,169269,169285,169301,169317,169333,169349,169365,169381,169397,169413,169429,169445,169461,169477,169493,169509,169525,169541,169557,169573,169589,169605,169621,169637,169653,169669,169685,169701,169717,169733,169749,169765,169781,169797,169813,169829,169845,169861,169877,169893,169909,169925,169941,169957,169973,169989,170005,170021,170037,170053,170069,170085,170101,170117,170133,170149,170165,170181,170197,170213,170229,170245,170261,170277,170293,170309,170325,170341,170357,170373,170389,170405,170421,170437,170453,170469,170485,170501,170517,170533,170549,170565,170581,170597,170613,170629,170645,170661,170677,170693,170709,170725,170741,170757,170773,170789,170805,170821,170837,170853,170869,170885,170901,170917,170933,170949,170965,170981,170997,171013,171029,171045,171061,171077,171093,171109,171125,171141,171157,171173,171189,171205,171221,171237,171253,171269,171285,171301,171317,171333,171349,171365,171381,171397,171413,171429,171445,171461,171477,171493,171509,171525,171541,171557,171573,171589,171605,171621,171637,171653,171669,171685,171701,171717,171733,171749,171765,171781,171797,171813,171829,171845,171861,171877,171893,171909,171925,171941,171957,171973,171989,172005,172021,172037,172053,172069,172085,172101,172117,172133,172149,172165,172181,172197,172213,172229,172245,172261,172277,172293,172309,172325,172341,172357,172373,172389,172405,172421,172437,172453,172469,172485,172501,172517,172533,172549,172565,172581,172597,172613,172629,172645,172661,172677,172693,172709,172725,172741,172757,172773,172789,172805,172821,172837,172853,172869,172885,172901,172917,172933,172949,172965,172981,172997,173013,173029,173045,173061,173077,173093,173109,173125,173141,173157,173173,173189,173205,173221,173237,173253,173269,173285,173301,173317,173333,173349,173365,173381,173397,173413,173429,173445,173461,173477,173493,173509,173525,173541,173557,173573,173589,173605,173621,173637,173653,173669,173685,173701,173717,173733,173749,173765,173781,173797,173813,173829,173845,173861,173877,173893,173909,173925,173941,173957,173973,173989,174005,174021,174037,174053,174069,174085,174101,174117,174133,174149,174165,174181,174197,174213,174229,174245,174261,174277,174293,174309,174325,174341,174357,174373,174389,174405,174421,174437,174453,174469,174485,174501,174517,174533,174549,174565,174581,174597,174613,174629,174645,174661,174677,174693,174709,174725,174741,174757,174773,174789,174805,174821,174837,174853,174869,174885,174901,174917,174933,174949,174965,174981,174997,175013,175029,175045,175061,175077,175093,175109,175125,175141,175157,175173,175189,175205,175221,175237,175253,175269,175285,175301,175317,175333,175349,175365,175381,175397,175413,175429,175445,175461,175477,175493,175509,175525,175541,175557,175573,175589,175605,175621,175637,175653,175669,175685,175701,175717,175733,175749,175765,175781,175797,175813,175829,175845,175861,175877,175893,175909,175925,175941,175957,175973,175989,176005,176021,176037,176053,176069,176085,176101,176117,176133,176149,176165,176181,176197,176213,176229,176245,176261,176277,176293,176309,176325,176341,176357,176373,176389,176405,176421,176437,176453,176469,176485,176501,176517,176533,176549,176565,176581,176597,176613,176629,176645,176661,176677,176693,176709,176725,176741,176757,176773,176789,176805,176821,176837,176853,176869,176885,176901,176917,176933,176949,176965,176981,176997,177013,177029,177045,177061,177077,177093,177109,177125,177141,177157,177173,177189,177205,177221,177237,177253,177269,177285,177301,177317,177333,177349,177365,177381,177397,177413,177429,177445,177461,177477,177493,177509,177525,177541,177557,177573,177589,177605,177621,177637,177653,177669,177685,177701,177717,177733,177749,177765,177781,177797,177813,177829,177845,177861,177877,177893,177909,177925,177941,177957,177973,177989,178005,178021,178037,178053,178069,178085,178101,178117,178133,178149,178165,178181,178197,178213,178229,178245,178261,178277,178293,178309,178325,178341,178357,178373,178389,178405,178421,178437,178453,178469,178485,178501,178517,178533,178549,178565,178581,178597,178613,178629,178645,178661,178677,178693,178709,178725,178741,178757,178773,178789,178805,178821,178837,178853,178869,178885,178901,178917,178933,178949,178965,178981,178997,179013,179029,179045,179061,179077,179093,179109,179125,179141,179157,179173,179189,179205,179221,179237,179253,179269,179285,179301,179317,179333,179349,179365,179381,179397,179413,179429,179445,179461,179477,179493,179509,179525,179541,179557,179573,179589,179605,179621,179637,179653,179669,179685,179701,179717,179733,179749,179765,179781,179797,179813,179829,179845,179861,179877,179893,179909,179925,179941,179957,179973,179989,180005,180021,180037,180053,180069,180085,180101,180117,180133,180149,180165,180181,180197,180213,180229,180245,180261,180277,180293,180309,180325,180341,180357,180373,180389,180405,180421,180437,180453,180469,180485,180501,180517,180533,180549,180565,180581,180597,180613,180629,180645,180661,180677,180693,180709,180725,180741,180757,180773,180789,180805,180821,180837,180853,180869,180885,180901,180917,180933,180949,180965,180981,180997,181013,181029,181045,181061,181077,181093,181109,181125,181141,181157,181173,181189,181205,181221,181237,181253,181269,181285,181301,181317,181333,181349,181365,181381,181397,181413,181429,181445,181461,181477,181493,181509,181525,181541,181557,181573,181589,181605,181621,181637,181653,181669,181685,181701,181717,181733,181749,181765,181781,181797,181813,181829,181845,181861,181877,181893,181909,181925,181941,181957,181973,181989,182005,182021,182037,182053,182069,182085,182101,182117,182133,182149,182165,182181,182197,182213,182229,182245,182261,182277,182293,182309,182325,182341,182357,182373,182389,182405,182421,182437,182453,182469,182485,182501,182517,182533,182549,182565,182581,182597,182613,182629,182645,182661,182677,182693,182709,182725,182741,182757,182773,182789,182805,182821,182837,182853,182869,182885,182901,182917,182933,182949,182965,182981,182997,183013,183029,183045,183061,183077,183093,183109,183125,183141,183157,183173,183189,183205,183221,183237,183253,183269,183285,183301,183317,183333,183349,183365,183381,183397,183413,183429,183445,183461,183477,183493,183509,183525,183541,183557,183573,183589,183605,183621,183637,183653,183669,183685,183701,183717,183733,183749,183765,183781,183797,183813,183829,183845,183861,183877,183893,183909,183925,183941,183957,183973,183989,184005,184021,184037,184053,184069,184085,184101,184117,184133,184149,184165,184181,184197,184213,184229,184245,184261,184277,184293,184309,184325,184341,184357,184373,184389,184405,184421,184437,184453,184469,184485,184501,184517,184533,184549,184565,184581,184597,184613,184629,184645,184661,184677,184693,184709,184725,184741,184757,184773,184789,184805,184821,184837,184853,184869,184885,184901,184917,184933,184949,184965,184981,184997,185013,185029,185045,185061,185077,185093,185109,185125,185141,185157,185173,185189,185205,185221,185237,185253,185269,185285,185301,185317,185333,185349,185365,185381,185397,185413,185429,185445,185461,185477,185493,185509,185525,185541,185557,185573,185589,185605,185621,185637,185653,185669,185685,185701,185717,185733,185749,185765,185781,185797,185813,185829,185845,185861,185877,185893,185909,185925,185941,185957,185973,185989,186005,186021,186037,186053,186069,186085,186101,186117,186133,186149,186165,186181,186197,186213,186229,186245,186261,186277,186293,186309,186325,186341,186357,186373,186389,186405,186421,186437,186453,186469,186485,186501,186517,186533,186549,186565,186581,186597,186613,186629,186645,186661,186677,186693,186709,186725,186741,186757,186773,186789,186805,186821,186837,186853,186869,186885,186901,186917,186933,186949,186965,186981,186997,187013,187029,187045,187061,187077,187093,187109,187125,187141,187157,187173,187189,187205,187221,187237,187253,187269,187285,187301,187317,187333,187349,187365,187381,187397,187413,187429,187445,187461,187477,187493,187509,187525,187541,187557,187573,187589,187605,187621,187637,187653,187669,187685,187701,187717,187733,187749,187765,187781,187797,187813,187829,187845,187861,187877,187893,187909,187925,187941,187957,187973,187989,188005,188021,188037,188053,188069,188085,188101,188117,188133,188149,188165,188181,188197,188213,188229,188245,188261,188277,188293,188309,188325,188341,188357,188373,188389,188405,188421,188437,188453,188469,188485,188501,188517,188533,188549,188565,188581,188597,188613,188629,188645,188661,188677,188693,188709,188725,188741,188757,188773,188789,188805,188821,188837,188853,188869,188885,188901,188917,188933,188949,188965,188981,188997,189013,189029,189045,189061,189077,189093,189109,189125,189141,189157,189173,189189,189205,189221,189237,189253,189269,189285,189301,189317,189333,189349,189365,189381,189397,189413,189429,189445,189461,189477,189493,189509,189525,189541,189557,189573,189589,189605,189621,189637,189653,189669,189685,189701,189717,189733,189749,189765,189781,189797,189813,189829,189845,189861,189877,189893,189909,189925,189941,189957,189973,189989,190005,190021,190037,190053,190069,190085,190101,190117,190133,190149,190165,190181,190197,190213,190229,190245,190261,190277,190293,190309,190325,190341,190357,190373,190389,190405,190421,190437,190453,190469,190485,190501,190517,190533,190549,190565,190581,190597,190613,190629,190645,190661,190677,190693,190709,190725,190741,190757,190773,190789,190805,190821,190837,190853,190869,190885,190901,190917,190933,190949,190965,190981,190997,191013,191029,191045,191061,191077,191093,191109,191125,191141,191157,191173,191189,191205,191221,191237,191253,191269,191285,191301,191317,191333,191349,191365,191381,191397,191413,191429,191445,191461,191477,191493,191509,191525,191541,191557,191573,191589,191605,191621,191637,191653,191669,191685,191701,191717,191733,191749,191765,191781,191797,191813,191829,191845,191861,191877,191893,191909,191925,191941,191957,191973,191989,192005,192021,192037,192053,192069,192085,192101,192117,192133,192149,192165,192181,192197,192213,192229,192245,192261,192277,192293,192309,192325,192341,192357,192373,192389,192405,192421,192437,192453,192469,192485,192501,192517,192533,192549,192565,192581,192597,192613,192629,192645,192661,192677,192693,192709,192725,192741,192757,192773,192789,192805,192821,192837,192853,192869,192885,192901,192917,192933,192949,192965,192981,192997,193013,193029,193045,193061,193077,193093,193109,193125,193141,193157,193173,193189,193205,193221,193237,193253,193269,193285,193301,193317,193333,193349,193365,193381,193397,193413,193429,193445,193461,193477,193493,193509,193525,193541,193557,193573,193589,193605,193621,193637,193653,193669,193685,193701,193717,193733,193749,193765,193781,193797,193813,193829,193845,193861,193877,193893,193909,193925,193941,193957,193973,193989,194005,194021,194037,194053,194069,194085,194101,194117,194133,194149,194165,194181,194197,194213,194229,194245,194261,194277,194293,194309,194325,194341,194357,194373,194389,194405,194421,194437,194453,194469,194485,194501,194517,194533,194549,194565,194581,194597,194613,194629,194645,194661,194677,194693,194709,194725,194741,194757,194773,194789,194805,194821,194837,194853,194869,194885,194901,194917,194933,194949,194965,194981,194997,195013,195029,195045,195061,195077,195093,195109,195125,195141,195157,195173,195189,195205,195221,195237,195253,195269,195285,195301,195317,195333,195349,195365,195381,195397,195413,195429,195445,195461,195477,195493,195509,195525,195541,195557,195573,195589,195605,195621,195637,195653,195669,195685,195701,195717,195733,195749,195765,195781,195797,195813,195829,195845,195861,195877,195893,195909,195925,195941,195957,195973,195989,196005,196021,196037,196053,196069,196085,196101,196117,196133,196149,196165,196181,196197,196213,196229,196245,196261,196277,196293,196309,196325,196341,196357,196373,196389,196405,196421,196437,196453,196469,196485,196501,196517,196533,196549,196565,196581,196597,196613,196629,196645,196661,196677,196693,196709,196725,196741,196757,196773,196789,196805,196821,196837,196853,196869,196885,196901,196917,196933,196949,196965,196981,196997,197013,197029,197045,197061,197077,197093,197109,197125,197141,197157,197173,197189,197205,197221,197237,197253,197269,197285,197301,197317,197333,197349,197365,197381,197397,197413,197429,197445,197461,197477,197493,197509,197525,197541,197557,197573,197589,197605,197621,197637,197653,197669,197685,197701,197717,197733,197749,197765,197781,197797,197813,197829,197845,197861,197877,197893,197909,197925,197941,197957,197973,197989,198005,198021,198037,198053,198069,198085,198101,198117,198133,198149,198165,198181,198197,198213,198229,198245,198261,198277,198293,198309,198325,198341,198357,198373,198389,198405,198421,198437,198453,198469,198485,198501,198517,198533,198549,198565,198581,198597,198613,198629,198645,198661,198677,198693,198709,198725,198741,198757,198773,198789,198805,198821,198837,198853,198869,198885,198901,198917,198933,198949,198965,198981,198997,199013,199029,199045,199061,199077,199093,199109,199125,199141,199157,199173,199189,199205,199221,199237,199253,199269,199285,199301,199317,199333,199349,199365,199381,199397,199413,199429,199445,199461,199477,199493,199509,199525,199541,199557,199573,199589,199605,199621,199637,199653,199669,199685,199701,199717,199733,199749,199765,199781,199797,199813,199829,199845,199861,199877,199893,199909,199925,199941,199957,199973,199989,200005,200021,200037,200053,200069,200085,200101,200117,200133,200149,200165,200181,200197,200213,200229,200245,200261,200277,200293,200309,200325,200341,200357,200373,200389,200405,200421,200437,200453,200469,200485,200501,200517,200533,200549,200565,200581,200597,200613,200629,200645,200661,200677,200693,200709,200725,200741,200757,200773,200789,200805,200821,200837,200853,200869,200885,200901,200917,200933,200949,200965,200981,200997,201013,201029,201045,201061,201077,201093,201109,201125,201141,201157,201173,201189,201205,201221,201238,201255,201272,201289,201306,201323,201340,201357,201374,201391,201408,201425,201442,201459,201476,201493,201510,201527,201544,201561,201578,201595,201612,201629,201646,201663,201680,201697,201714,201731,201748,201765,201782,201799,201816,201833,201850,201867,201884,201901,201918,201935,201952,201969,201986,202003,202020,202037,202054,202071,202088,202105,202122,202139,202156,202173,202190,202207,202224,202241,202258,202275,202292,202309,202326,202343,202360,202377,202394,202411,202428,202445,202462,202479,202496,202513,202530,202547,202564,202581,202598,202615,202632,202649,202666,202683,202700,202717,202734,202751,202768,202785,202802,202819,202836,202853,202870,202887,202904,202921,202938,202955,202972,202989,203006,203023,203040,203057,203074,203091,203108,203125,203142,203159,203176,203193,203210,203227,203244,203261,203278,203295,203312,203329,203346,203363,203380,203397,203414,203431,203448,203465,203482,203499,203516,203533,203550,203567,203584,203601,203618,203635,203652,203669,203686,203703,203720,203737,203754,203771,203788,203805,203822,203839,203856,203873,203890,203907,203924,203941,203958,203975,203992,204009,204026,204043,204060,204077,204094,204111,204128,204145,204162,204179,204196,204213,204230,204247,204264,204281,204298,204315,204332,204349,204366,204383,204400,204417,204434,204451,204468,204485,204502,204519,204536,204553,204570,204587,204604,204621,204638,204655,204672,204689,204706,204723,204740,204757,204774,204791,204808,204825,204842,204859,204876,204893,204910,204927,204944,204961,204978,204995,205012,205029,205046,205063,205080,205097,205114,205131,205148,205165,205182,205199,205216,205233,205250,205267,205284,205301,205318,205335,205352,205369,205386,205403,205420,205437,205454,205471,205488,205505,205522,205539,205556,205573,205590,205607,205624,205641,205658,205675,205692,205709,205726,205743,205760,205777,205794,205811,205828,205845,205862,205879,205896,205913,205930,205947,205964,205981,205998,206015,206032,206049,206066,206083,206100,206117,206134,206151,206168,206185,206202,206219,206236,206253,206270,206287,206304,206321,206338,206355,206372,206389,206406,206423,206440,206457,206474,206491,206508,206525,206542,206559,206576,206593,206610,206627,206644,206661,206678,206695,206712,206729,206746,206763,206780,206797,206814,206831,206848,206865,206882,206899,206916,206933,206950,206967,206984,207001,207018,207035,207052,207069,207086,207103,207120,207137,207154,207171,207188,207205,207222,207239,207256,207273,207290,207307,207324,207341,207358,207375,207392,207409,207426,207443,207460,207477,207494,207511,207528,207545,207562,207579,207596,207613,207630,207647,207664,207681,207698,207715,207732,207749,207766,207783,207800,207817,207834,207851,207868,207885,207902,207919,207936,207953,207970,207987,208004,208021,208038,208055,208072,208089,208106,208123,208140,208157,208174,208191,208208,208225,208242,208259,208276,208293,208310,208327,208344,208361,208378,208395,208412,208429,208446,208463,208480,208497,208514,208531,208548,208565,208582,208599,208616,208633,208650,208667,208684,208701,208718,208735,208752,208769,208786,208803,208820,208837,208854,208871,208888,208905,208922,208939,208956,208973,208990,209007,209024,209041,209058,209075,209092,209109,209126,209143,209160,209177,209194,209211,209228,209245,209262,209279,209296,209313,209330,209347,209364,209381,209398,209415,209432,209449,209466,209483,209500,209517,209534,209551,209568,209585,209602,209619,209636,209653,209670,209687,209704,209721,209738,209755,209772,209789,209806,209823,209840,209857,209874,209891,209908,209925,209942,209959,209976,209993,210010,210027,210044,210061,210078,210095,210112,210129,210146,210163,210180,210197,210214,210231,210248,210265,210282,210299,210316,210333,210350,210367,210384,210401,210418,210435,210452,210469,210486,210503,210520,210537,210554,210571,210588,210605,210622,210639,210656,210673,210690,210707,210724,210741,210758,210775,210792,210809,210826,210843,210860,210877,210894,210911,210928,210945,210962,210979,210996,211013,211030,211047,211064,211081,211098,211115,211132,211149,211166,211183,211200,211217,211234,211251,211268,211285,211302,211319,211336,211353,211370,211387,211404,211421,211438,211455,211472,211489,211506,211523,211540,211557,211574,211591,211608,211625,211642,211659,211676,211693,211710,211727,211744,211761,211778,211795,211812,211829,211846,211863,211880,211897,211914,211931,211948,211965,211982,211999,212016,212033,212050,212067,212084,212101,212118,212135,212152,212169,212186,212203,212220,212237,212254,212271,212288,212305,212322,212339,212356,212373,212390,212407,212424,212441,212458,212475,212492,212509,212526,212543,212560,212577,212594,212611,212628,212645,212662,212679,212696,212713,212730,212747,212764,212781,212798,212815,212832,212849,212866,212883,212900,212917,212934,212951,212968,212985,213002,213019,213036,213053,213070,213087,213104,213121,213138,213155,213172,213189,213206,213223,213240,213257,213274,213291,213308,213325,213342,213359,213376,213393,213410,213427,213444,213461,213478,213495,213512,213529,213546,213563,213580,213597,213614,213631,213648,213665,213682,213699,213716,213733,213750,213767,213784,213801,213818,213835,213852,213869,213886,213903,213920,213937,213954,213971,213988,214005,214022,214039,214056,214073,214090,214107,214124,214141,214158,214175,214192,214209,214226,214243,214260,214277,214294,214311,214328,214345,214362,214379,214396,214413,214430,214447,214464,214481,214498,214515,214532,214549,214566,214583,214600,214617,214634,214651,214668,214685,214702,214719,214736,214753,214770,214787,214804,214821,214838,214855,214872,214889,214906,214923,214940,214957,214974,214991,215008,215025,215042,215059,215076,215093,215110,215127,215144,215161,215178,215195,215212,215229,215246,215263,215280,215297,215314,215331,215348,215365,215382,215399,215416,215433,215450,215467,215484,215501,215518,215535,215552,215569,215586,215603,215620,215637,215654,215671,215688,215705,215722,215739,215756,215773,215790,215807,215824,215841,215858,215875,215892,215909,215926,215943,215960,215977,215994,216011,216028,216045,216062,216079,216096,216113,216130,216147,216164,216181,216198,216215,216232,216249,216266,216283,216300,216317,216334,216351,216368,216385,216402,216419,216436,216453,216470,216487,216504,216521,216538,216555,216572,216589,216606,216623,216640,216657,216674,216691,216708,216725,216742,216759,216776,216793,216810,216827,216844,216861,216878,216895,216912,216929,216946,216963,216980,216997,217014,217031,217048,217065,217082,217099,217116,217133,217150,217167,217184,217201,217218,217235,217252,217269,217286,217303,217320,217337,217354,217371,217388,217405,217422,217439,217456,217473,217490,217507,217524,217541,217558,217575,217592,217609,217626,217643,217660,217677,217694,217711,217728,217745,217762,217779,217796,217813,217830,217847,217864,217881,217898,217915,217932,217949,217966,217983,218000,218017,218034,218051,218068,218085,218102,218119,218136,218153,218170,218187,218204,218221,218238,218255,218272,218289,218306,218323,218340,218357,218374,218391,218408,218425,218442,218459,218476,218493,218510,218527,218544,218561,218578,218595,218612,218629,218646,218663,218680,218697,218714,218731,218748,218765,218782,218799,218816,218833,218850,218867,218884,218901,218918,218935,218952,218969,218986,219003,219020,219037,219054,219071,219088,219105,219122,219139,219156,219173,219190,219207,219224,219241,219258,219275,219292,219309,219326,219343,219360,219377,219394,219411,219428,219445,219462,219479,219496,219513,219530,219547,219564,219581,219598,219615,219632,219649,219666,219683,219700,219717,219734,219751,219768,219785,219802,219819,219836,219853,219870,219887,219904,219921,219938,219955,219972,219989,220006,220023,220040,220057,220074,220091,220108,220125,220142,220159,220176,220193,220210,220227,220244,220261,220278,220295,220312,220329,220346,220363,220380,220397,220414,220431,220448,220465,220482,220499,220516,220533,220550,220567,220584,220601,220618,220635,220652,220669,220686,220703,220720,220737,220754,220771,220788,220805,220822,220839,220856,220873,220890,220907,220924,220941,220958,220975,220992,221009,221026,221043,221060,221077,221094,221111,221128,221145,221162,221179,221196,221213,221230,221247,221264,221281,221298,221315,221332,221349,221366,221383,221400,221417,221434,221451,221468,221485,221502,221519,221536,221553,221570,221587,221604,221621,221638,221655,221672,221689,221706,221723,221740,221757,221774,221791,221808,221825,221842,221859,221876,221893,221910,221927,221944,221961,221978,221995,222012,222029,222046,222063,222080,222097,222114,222131,222148,222165,222182,222199,222216,222233,222250,222267,222284,222301,222318,222335,222352,222369,222386,222403,222420,222437,222454,222471,222488,222505,222522,222539,222556,222573,222590,222607,222624,222641,222658,222675,222692,222709,222726,222743,222760,222777,222794,222811,222828,222845,222862,222879,222896,222913,222930,222947,222964,222981,222998,223015,223032,223049,223066,223083,223100,223117,223134,223151,223168,223185,223202,223219,223236,223253,223270,223287,223304,223321,223338,223355,223372,223389,223406,223423,223440,223457,223474,223491,223508,223525,223542,223559,223576,223593,223610,223627,223644,223661,223678,223695,223712,223729,223746,223763,223780,223797,223814,223831,223848,223865,223882,223899,223916,223933,223950,223967,223984,224001,224018,224035,224052,224069,224086,224103,224120,224137,224154,224171,224188,224205,224222,224239,224256,224273,224290,224307,224324,224341,224358,224375,224392,224409,224426,224443,224460,224477,224494,224511,224528,224545,224562,224579,224596,224613,224630,224647,224664,224681,224698,224715,224732,224749,224766,224783,224800,224817,224834,224851,224868,224885,224902,224919,224936,224953,224970,224987,225004,225021,225038,225055,225072,225089,225106,225123,225140,225157,225174,225191,225208,225225,225242,225259,225276,225293,225310,225327,225344,225361,225378,225395,225412,225429,225446,225463,225480,225497,225514,225531,225548,225565,225582,225599,225616,225633,225650,225667,225684,225701,225718,225735,225752,225769,225786,225803,225820,225837,225854,225871,225888,225905,225922,225939,225956,225973,225990,226007,226024,226041,226058,226075,226092,226109,226126,226143,226160,226177,226194,226211,226228,226245,226262,226279,226296,226313,226330,226347,226364,226381,226398,226415,226432,226449,226466,226483,226500,226517,226534,226551,226568,226585,226602,226619,226636,226653,226670,226687,226704,226721,226738,226755,226772,226789,226806,226823,226840,226857,226874,226891,226908,226925,226942,226959,226976,226993,227010,227027,227044,227061,227078,227095,227112,227129,227146,227163,227180,227197,227214,227231,227248,227265,227282,227299,227316,227333,227350,227367,227384,227401,227418,227435,227452,227469,227486,227503,227520,227537,227554,227571,227588,227605,227622,227639,227656,227673,227690,227707,227724,227741,227758,227775,227792,227809,227826,227843,227860,227877,227894,227911,227928,227945,227962,227979,227996,228013,228030,228047,228064,228081,228098,228115,228132,228149,228166,228183,228200,228217,228234,228251,228268,228285,228302,228319,228336,228353,228370,228387,228404,228421,228438,228455,228472,228489,228506,228523,228540,228557,228574,228591,228608,228625,228642,228659,228676,228693,228710,228727,228744,228761,228778,228795,228812,228829,228846,228863,228880,228897,228914,228931,228948,228965,228982,228999,229016,229033,229050,229067,229084,229101,229118,229135,229152,229169,229186,229203,229220,229237,229254,229271,229288,229305,229322,229339,229356,229373,229390,229407,229424,229441,229458,229475,229492,229509,229526,229543,229560,229577,229594,229611,229628,229645,229662,229679,229696,229713,229730,229747,229764,229781,229798,229815,229832,229849,229866,229883,229900,229917,229934,229951,229968,229985,230002,230019,230036,230053,230070,230087,230104,230121,230138,230155,230172,230189,230206,230223,230240,230257,230274,230291,230308,230325,230342,230359,230376,230393,230410,230427,230444,230461,230478,230495,230512,230529,230546,230563,230580,230597,230614,230631,230648,230665,230682,230699,230716,230733,230750,230767,230784,230801,230818,230835,230852,230869,230886,230903,230920,230937,230954,230971,230988,231005,231022,231039,231056,231073,231090,231107,231124,231141,231158,231175,231192,231209,231226,231243,231260,231277,231294,231311,231328,231345,231362,231379,231396,231413,231430,231447,231464,231481,231498,231515,231532,231549,231566,231583,231600,231617,231634,231651,231668,231685,231702,231719,231736,231753,231770,231787,231804,231821,231838,231855,231872,231889,231906,231923,231940,231957,231974,231991,232008,232025,232042,232059,232076,232093,232110,232127,232144,232161,232178,232195,232212,232229,232246,232263,232280,232297,232314,232331,232348,232365,232382,232399,232416,232433,232450,232467,232484,232501,232518,232535,232552,232569,232586,232603,232620,232637,232654,232671,232688,232705,232722,232739,232756,232773,232790,232807,232824,232841,232858,232875,232892,232909,232926,232943,232960,232977,232994,233011,233028,233045,233062,233079,233096,233113,233130,233147,233164,233181,233198,233215,233232,233249,233266,233283,233300,233317,233334,233351,233368,233385,233402,233419,233436,233453,233470,233487,233504,233521,233538,233555,233572,233589,233606,233623,233640,233657,233674,233691,233708,233725,233742,233759,233776,233793,233810,233827,233844,233861,233878,233895,233912,233929,233946,233963,233980,233997,234014,234031,234048,234065,234082,234099,234116,234133,234150,234167,234184,234201,234218,234235,234252,234269,234286,234303,234320,234337,234354,234371,234388,234405,234422,234439,234456,234473,234490,234507,234524,234541,234558,234575,234592,234609,234626,234643,234660,234677,234694,234711,234728,234745,234762,234779,234796,234813,234830,234847,234864,234881,234898,234915,234932,234949,234966,234983,235000,235017,235034,235051,235068,235085,235102,235119,235136,235153,235170,235187,235204,235221,235238,235255,235272,235289,235306,235323,235340,235357,235374,235391,235408,235425,235442,235459,235476,235493,235510,235527,235544,235561,235578,235595,235612,235629,235646,235663,235680,235697,235714,235731,235748,235765,235782,235799,235816,235833,235850,235867,235884,235901,235918,235935,235952,235969,235986,236003,236020,236037,236054,236071,236088,236105,236122,236139,236156,236173,236190,236207,236224,236241,236258,236275,236292,236309,236326,236343,236360,236377,236394,236411,236428,236445,236462,236479,236496,236513,236530,236547,236564,236581,236598,236615,236632,236649,236666,236683,236700,236717,236734,236751,236768,236785,236802,236819,236836,236853,236870,236887,236904,236921,236938,236955,236972,236989,237006,237023,237040,237057,237074,237091,237108,237125,237142,237159,237176,237193,237210,237227,237244,237261,237278,237295,237312,237329,237346,237363,237380,237397,237414,237431,237448,237465,237482,237499,237516,237533,237550,237567,237584,237601,237618,237635,237652,237669,237686,237703,237720,237737,237754,237771,237788,237805,237822,237839,237856,237873,237890,237907,237924,237941,237958,237975,237992,238009,238026,238043,238060,238077,238094,238111,238128,238145,238162,238179,238196,238213,238230,238247,238264,238281,238298,238315,238332,238349,238366,238383,238400,238417,238434,238451,238468,238485,238502,238519,238536,238553,238570,238587,238604,238621,238638,238655,238672,238689,238706,238723,238740,238757,238774,238791,238808,238825,238842,238859,238876,238893,238910,238927,238944,238961,238978,238995,239012,239029,239046,239063,239080,239097,239114,239131,239148,239165,239182,239199,239216,239233,239250,239267,239284,239301,239318,239335,239352,239369,239386,239403,239420,239437,239454,239471,239488,239505,239522,239539,239556,239573,239590,239607,239624,239641,239658,239675,239692,239709,239726,239743,239760,239777,239794,239811,239828,239845,239862,239879,239896,239913,239930,239947,239964,239981,239998,240015,240032,240049,240066,240083,240100,240117,240134,240151,240168,240185,240202,240219,240236,240253,240270,240287,240304,240321,240338,240355,240372,240389,240406,240423,240440,240457,240474,240491,240508,240525,240542,240559,240576,240593,240610,240627,240644,240661,240678,240695,240712,240729,240746,240763,240780,240797,240814,240831,240848,240865,240882,240899,240916,240933,240950,240967,240984,241001,241018,241035,241052,241069,241086,241103,241120,241137,241154,241171,241188,241205,241222,241239,241256,241273,241290,241307,241324,241341,241358,241375,241392,241409,241426,241443,241460,241477,241494,241511,241528,241545,241562,241579,241596,241613,241630,241647,241664,241681,241698,241715,241732,241749,241766,241783,241800,241817,241834,241851,241868,241885,241902,241919,241936,241953,241970,241987,242004,242021,242038,242055,242072,242089,242106,242123,242140,242157,242174,242191,242208,242225,242242,242259,242276,242293,242310,242327,242344,242361,242378,242395,242412,242429,242446,242463,242480,242497,242514,242531,242548,242565,242582,242599,242616,242633,242650,242667,242684,242701,242718,242735,242752,242769,242786,242803,242820,242837,242854,242871,242888,242905,242922,242939,242956,242973,242990,243007,243024,243041,243058,243075,243092,243109,243126,243143,243160,243177,243194,243211,243228,243245,243262,243279,243296,243313,243330,243347,243364,243381,243398,243415,243432,243449,243466,243483,243500,243517,243534,243551,243568,243585,243602,243619,243636,243653,243670,243687,243704,243721,243738,243755,243772,243789,243806,243823,243840,243857,243874,243891,243908,243925,243942,243959,243976,243993,244010,244027,244044,244061,244078,244095,244112,244129,244146,244163,244180,244197,244214,244231,244248,244265,244282,244299,244316,244333,244350,244367,244384,244401,244418,244435,244452,244469,244486,244503,244520,244537,244554,244571,244588,244605,244622,244639,244656,244673,244690,244707,244724,244741,244758,244775,244792,244809,244826,244843,244860,244877,244894,244911,244928,244945,244962,244979,244996,245013,245030,245047,245064,245081,245098,245115,245132,245149,245166,245183,245200,245217,245234,245251,245268,245285,245302,245319,245336,245353,245370,245387,245404,245421,245438,245455,245472,245489,245506,245523,245540,245557,245574,245591,245608,245625,245642,245659,245676,245693,245710,245727,245744,245761,245778,245795,245812,245829,245846,245863,245880,245897,245914,245931,245948,245965,245982,245999,246016,246033,246050,246067,246084,246101,246118,246135,246152,246169,246186,246203,246220,246237,246254,246271,246288,246305,246322,246339,246356,246373,246390,246407,246424,246441,246458,246475,246492,246509,246526,246543,246560,246577,246594,246611,246628,246645,246662,246679,246696,246713,246730,246747,246764,246781,246798,246815,246832,246849,246866,246883,246900,246917,246934,246951,246968,246985,247002,247019,247036,247053,247070,247087,247104,247121,247138,247155,247172,247189,247206,247223,247240,247257,247274,247291,247308,247325,247342,247359,247376,247393,247410,247427,247444,247461,247478,247495,247512,247529,247546,247563,247580,247597,247614,247631,247648,247665,247682,247699,247716,247733,247750,247767,247784,247801,247818,247835,247852,247869,247886,247903,247920,247937,247954,247971,247988,248005,248022,248039,248056,248073,248090,248107,248124,248141,248158,248175,248192,248209,248226,248243,248260,248277,248294,248311,248328,248345,248362,248379,248396,248413,248430,248447,248464,248481,248498,248515,248532,248549,248566,248583,248600,248617,248634,248651,248668,248685,248702,248719,248736,248753,248770,248787,248804,248821,248838,248855,248872,248889,248906,248923,248940,248957,248974,248991,249008,249025,249042,249059,249076,249093,249110,249127,249144,249161,249178,249195,249212,249229,249246,249263,249280,249297,249314,249331,249348,249365,249382,249399,249416,249433,249450,249467,249484,249501,249518,249535,249552,249569,249586,249603,249620,249637,249654,249671,249688,249705,249722,249739,249756,249773,249790,249807,249824,249841,249858,249875,249892,249909,249926,249943,249960,249977,249994,250011,250028,250045,250062,250079,250096,250113,250130,250147,250164,250181,250198,250215,250232,250249,250266,250283,250300,250317,250334,250351,250368,250385,250402,250419,250436,250453,250470,250487,250504,250521,250538,250555,250572,250589,250606,250623,250640,250657,250674,250691,250708,250725,250742,250759,250776,250793,250810,250827,250844,250861,250878,250895,250912,250929,250946,250963,250980,250997,251014,251031,251048,251065,251082,251099,251116,251133,251150,251167,251184,251201,251218,251235,251252,251269,251286,251303,251320,251337,251354,251371,251388,251405,251422,251439,251456,251473,251490,251507,251524,251541,251558,251575,251592,251609,251626,251643,251660,251677,251694,251711,251728,251745,251762,251779,251796,251813,251830,251847,251864,251881,251898,251915,251932,251949,251966,251983,252000,252017,252034,252051,252068,252085,252102,252119,252136,252153,252170,252187,252204,252221,252238,252255,252272,252289,252306,252323,252340,252357,252374,252391,252408,252425,252442,252459,252476,252493,252510,252527,252544,252561,252578,252595,252612,252629,252646,252663,252680,252697,252714,252731,252748,252765,252782,252799,252816,252833,252850,252867,252884,252901,252918,252935,252952,252969,252986,253003,253020,253037,253054,253071,253088,253105,253122,253139,253156,253173,253190,253207,253224,253241,253258,253275,253292,253309,253326,253343,253360,253377,253394,253411,253428,253445,253462,253479,253496,253513,253530,253547,253564,253581,253598,253615,253632,253649,253666,253683,253700,253717,253734,253751,253768,253785,253802,253819,253836,253853,253870,253887,253904,253921,253938,253955,253972,253989,254006,254023,254040,254057,254074,254091,254108,254125,254142,254159,254176,254193,254210,254227,254244,254261,254278,254295,254312,254329,254346,254363,254380,254397,254414,254431,254448,254465,254482,254499,254516,254533,254550,254567,254584,254601,254618,254635,254652,254669,254686,254703,254720,254737,254754,254771,254788,254805,254822,254839,254856,254873,254890,254907,254924,254941,254958,254975,254992,255009,255026,255043,255060,255077,255094,255111,255128,255145,255162,255179,255196,255213,255230,255247,255264,255281,255298,255315,255332,255349,255366,255383,255400,255417,255434,255451,255468,255485,255502,255519,255536,255553,255570,255587,255604,255621,255638,255655,255672,255689,255706,255723,255740,255757,255774,255791,255808,255825,255842,255859,255876,255893,255910,255927,255944,255961,255978,255995,256012,256029,256046,256063,256080,256097,256114,256131,256148,256165,256182,256199,256216,256233,256250,256267,256284,256301,256318,256335,256352,256369,256386,256403,256420,256437,256454,256471,256488,256505,256522,256539,256556,256573,256590,256607,256624,256641,256658,256675,256692,256709,256726,256743,256760,256777,256794,256811,256828,256845,256862,256879,256896,256913,256930,256947,256964,256981,256998,257015,257032,257049,257066,257083,257100,257117,257134,257151,257168,257185,257202,257219,257236,257253,257270,257287,257304,257321,257338,257355,257372,257389,257406,257423,257440,257457,257474,257491,257508,257525,257542,257559,257576,257593,257610,257627,257644,257661,257678,257695,257712,257729,257746,257763,257780,257797,257814,257831,257848,257865,257882,257899,257916,257933,257950,257967,257984,258001,258018,258035,258052,258069,258086,258103,258120,258137,258154,258171,258188,258205,258222,258239,258256,258273,258290,258307,258324,258341,258358,258375,258392,258409,258426,258443,258460,258477,258494,258511,258528,258545,258562,258579,258596,258613,258630,258647,258664,258681,258698,258715,258732,258749,258766,258783,258800,258817,258834,258851,258868,258885,258902,258919,258936,258953,258970,258987,259004,259021,259038,259055,259072,259089,259106,259123,259140,259157,259174,259191,259208,259225,259242,259259,259276,259293,259310,259327,259344,259361,259378,259395,259412,259429,259446,259463,259480,259497,259514,259531,259548,259565,259582,259599,259616,259633,259650,259667,259684,259701,259718,259735,259752,259769,259786,259803,259820,259837,259854,259871,259888,259905,259922,259939,259956,259973,259990,260007,260024,260041,260058,260075,260092,260109,260126,260143,260160,260177,260194,260211,260228,260245,260262,260279,260296,260313,260330,260347,260364,260381,260398,260415,260432,260449,260466,260483,260500,260517,260534,260551,260568,260585,260602,260619,260636,260653,260670,260687,260704,260721,260738,260755,260772,260789,260806,260823,260840,260857,260874,260891,260908,260925,260942,260959,260976,260993,261010,261027,261044,261061,261078,261095,261112,261129,261146,261163,261180,261197,261214,261231,261248,261265,261282,261299,261316,261333,261350,261367,261384,261401,261418,261435,261452,261469,261486,261503,261520,261537,261554,261571,261588,261605,261622,261639,261656,261673,261690,261707,261724,261741,261758,261775,261792,261809,261826,261843,261860,261877,261894,261911,261928,261945,261962,261979,261996,262013,262030,262047,262064,262081,262098,262115,262132,262149,262166,262183,262200,262217,262234,262251,262268,262285,262302,262319,262336,262353,262370,262387,262404,262421,262438,262455,262472,262489,262506,262523,262540,262557,262574,262591,262608,262625,262642,262659,262676,262693,262710,262727,262744,262761,262778,262795,262812,262829,262846,262863,262880,262897,262914,262931,262948,262965,262982,262999,263016,263033,263050,263067,263084,263101,263118,263135,263152,263169,263186,263203,263220,263237,263254,263271,263288,263305,263322,263339,263356,263373,263390,263407,263424,263441,263458,263475,263492,263509,263526,263543,263560,263577,263594,263611,263628,263645,263662,263679,263696,263713,263730,263747,263764,263781,263798,263815,263832,263849,263866,263883,263900,263917,263934,263951,263968,263985,264002,264019,264036,264053,264070,264087,264104,264121,264138,264155,264172,264189,264206,264223,264240,264257,264274,264291,264308,264325,264342,264359,264376,264393,264410,264427,264444,264461,264478,264495,264512,264529,264546,264563,264580,264597,264614,264631,264648,264665,264682,264699,264716,264733,264750,264767,264784,264801,264818,264835,264852,264869,264886,264903,264920,264937,264954,264971,264988,265005,265022,265039,265056,265073,265090,265107,265124,265141,265158,265175,265192,265209,265226,265243,265260,265277,265294,265311,265328,265345,265362,265379,265396,265413,265430,265447,265464,265481,265498,265515,265532,265549,265566,265583,265600,265617,265634,265651,265668,265685,265702,265719,265736,265753,265770,265787,265804,265821,265838,265855,265872,265889,265906,265923,265940,265957,265974,265991,266008,266025,266042,266059,266076,266093,266110,266127,266144,266161,266178,266195,266212,266229,266246,266263,266280,266297,266314,266331,266348,266365,266382,266399,266416,266433,266450,266467,266484,266501,266518,266535,266552,266569,266586,266603,266620,266637,266654,266671,266688,266705,266722,266739,266756,266773,266790,266807,266824,266841,266858,266875,266892,266909,266926,266943,266960,266977,266994,267011,267028,267045,267062,267079,267096,267113,267130,267147,267164,267181,267198,267215,267232,267249,267266,267283,267300,267317,267334,267351,267368,267385,267402,267419,267436,267453,267470,267487,267504,267521,267538,267555,267572,267589,267606,267623,267640,267657,267674,267691,267708,267725,267742,267759,267776,267793,267810,267827,267844,267861,267878,267895,267912,267929,267946,267963,267980,267997,268014,268031,268048,268065,268082,268099,268116,268133,268150,268167,268184,268201,268218,268235,268252,268269,268286,268303,268320,268337,268354,268371,268388,268405,268422,268439,268456,268473,268490,268507,268524,268541,268558,268575,268592,268609,268626,268643,268660,268677,268694,268711,268728,268745,268762,268779,268796,268813,268830,268847,268864,268881,268898,268915,268932,268949,268966,268983,269000,269017,269034,269051,269068,269085,269102,269119,269136,269153,269170,269187,269204,269221,269238,269255,269272,269289,269306,269323,269340,269357,269374,269391,269408,269425,269442,269459,269476,269493,269510,269527,269544,269561,269578,269595,269612,269629,269646,269663,269680,269697,269714,269731,269748,269765,269782,269799,269816,269833,269850,269867,269884,269901,269918,269935,269952,269969,269986,270003,270020,270037,270054,270071,270088,270105,270122,270139,270156,270173,270190,270207,270224,270241,270258,270275,270292,270309,270326,270343,270360,270377,270394,270411,270428,270445,270462,270479,270496,270513,270530,270547,270564,270581,270598,270615,270632,270649,270666,270683,270700,270717,270734,270751,270768,270785,270802,270819,270836,270853,270870,270887,270904,270921,270938,270955,270972,270989,271006,271023,271040,271057,271074,271091,271108,271125,271142,271159,271176,271193,271210,271227,271244,271261,271278,271295,271312,271329,271346,271363,271380,271397,271414,271431,271448,271465,271482,271499,271516,271533,271550,271567,271584,271601,271618,271635,271652,271669,271686,271703,271720,271737,271754,271771,271788,271805,271822,271839,271856,271873,271890,271907,271924,271941,271958,271975,271992,272009,272026,272043,272060,272077,272094,272111,272128,272145,272162,272179,272196,272213,272230,272247,272264,272281,272298,272315,272332,272349,272366,272383,272400,272417,272434,272451,272468,272485,272502,272519,272536,272553,272570,272587,272604,272621,272638,272655,272672,272689,272706,272723,272740,272757,272774,272791,272808,272825,272842,272859,272876,272893,272910,272927,272944,272961,272978,272995,273012,273029,273046,273063,273080,273097,273114,273131,273148,273165,273182,273199,273216,273233,273250,273267,273284,273301,273318,273335,273352,273369,273386,273403,273420,273437,273454,273471,273488,273505,273522,273539,273556,273573,273590,273607,273624,273641,273658,273675,273692,273709,273726,273743,273760,273777,273794,273811,273828,273845,273862,273879,273896,273913,273930,273947,273964,273981,273998,274015,274032,274049,274066,274083,274100,274117,274134,274151,274168,274185,274202,274219,274236,274253,274270,274287,274304,274321,274338,274355,274372,274389,274406,274423,274440,274457,274474,274491,274508,274525,274542,274559,274576,274593,274610,274627,274644,274661,274678,274695,274712,274729,274746,274763,274780,274797,274814,274831,274848,274865,274882,274899,274916,274933,274950,274967,274984,275001,275018,275035,275052,275069,275086,275103,275120,275137,275154,275171,275188,275205,275222,275239,275256,275273,275290,275307,275324,275341,275358,275375,275392,275409,275426,275443,275460,275477,275494,275511,275528,275545,275562,275579,275596,275613,275630,275647,275664,275681,275698,275715,275732,275749,275766,275783,275800,275817,275834,275851,275868,275885,275902,275919,275936,275953,275970,275987,276004,276021,276038,276055,276072,276089,276106,276123,276140,276157,276174,276191,276208,276225,276242,276259,276276,276293,276310,276327,276344,276361,276378,276395,276412,276429,276446,276463,276480,276497,276514,276531,276548,276565,276582,276599,276616,276633,276650,276667,276684,276701,276718,276735,276752,276769,276786,276803,276820,276837,276854,276871,276888,276905,276922,276939,276956,276973,276990,277007,277024,277041,277058,277075,277092,277109,277126,277143,277160,277177,277194,277211,277228,277245,277262,277279,277296,277313,277330,277347,277364,277381,277398,277415,277432,277449,277466,277483,277500,277517,277534,277551,277568,277585,277602,277619,277636,277653,277670,277687,277704,277721,277738,277755,277772,277789,277806,277823,277840,277857,277874,277891,277908,277925,277942,277959,277976,277993,278010,278027,278044,278061,278078,278095,278112,278129,278146,278163,278180,278197,278214,278231,278248,278265,278282,278299,278316,278333,278350,278367,278384,278401,278418,278435,278452,278469,278486,278503,278520,278537,278554,278571,278588,278605,278622,278639,278656,278673,278690,278707,278724,278741,278758,278775,278792,278809,278826,278843,278860,278877,278894,278911,278928,278945,278962,278979,278996,279013,279030,279047,279064,279081,279098,279115,279132,279149,279166,279183,279200,279217,279234,279251,279268,279285,279302,279319,279336,279353,279370,279387,279404,279421,279438,279455,279472,279489,279506,279523,279540,279557,279574,279591,279608,279625,279642,279659,279676,279693,279710,279727,279744,279761,279778,279795,279812,279829,279846,279863,279880,279897,279914,279931,279948,279965,279982,279999,280016,280033,280050,280067,280084,280101,280118,280135,280152,280169,280186,280203,280220,280237,280254,280271,280288,280305,280322,280339,280356,280373,280390,280407,280424,280441,280458,280475,280492,280509,280526,280543,280560,280577,280594,280611,280628,280645,280662,280679,280696,280713,280730,280747,280764,280781,280798,280815,280832,280849,280866,280883,280900,280917,280934,280951,280968,280985,281002,281019,281036,281053,281070,281087,281104,281121,281138,281155,281172,281189,281206,281223,281240,281257,281274,281291,281308,281325,281342,281359,281376,281393,281410,281427,281444,281461,281478,281495,281512,281529,281546,281563,281580,281597,281614,281631,281648,281665,281682,281699,281716,281733,281750,281767,281784,281801,281818,281835,281852,281869,281886,281903,281920,281937,281954,281971,281988,282005,282022,282039,282056,282073,282090,282107,282124,282141,282158,282175,282192,282209,282226,282243,282260,282277,282294,282311,282328,282345,282362,282379,282396,282413,282430,282447,282464,282481,282498,282515,282532,282549,282566,282583,282600,282617,282634,282651,282668,282685,282702,282719,282736,282753,282770,282787,282804,282821,282838,282855,282872,282889,282906,282923,282940,282957,282974,282991,283008,283025,283042,283059,283076,283093,283110,283127,283144,283161,283178,283195,283212,283229,283246,283263,283280,283297,283314,283331,283348,283365,283382,283399,283416,283433,283450,283467,283484,283501,283518,283535,283552,283569,283586,283603,283620,283637,283654,283671,283688,283705,283722,283739,283756,283773,283790,283807,283824,283841,283858,283875,283892,283909,283926,283943,283960,283977,283994,284011,284028,284045,284062,284079,284096,284113,284130,284147,284164,284181,284198,284215,284232,284249,284266,284283,284300,284317,284334,284351,284368,284385,284402,284419,284436,284453,284470,284487,284504,284521,284538,284555,284572,284589,284606,284623,284640,284657,284674,284691,284708,284725,284742,284759,284776,284793,284810,284827,284844,284861,284878,284895,284912,284929,284946,284963,284980,284997,285014,285031,285048,285065,285082,285099,285116,285133,285150,285167,285184,285201,285218,285235,285252,285269,285286,285303,285320,285337,285354,285371,285388,285405,285422,285439,285456,285473,285490,285507,285524,285541,285558,285575,285592,285609,285626,285643,285660,285677,285694,285711,285728,285745,285762,285779,285796,285813,285830,285847,285864,285881,285898,285915,285932,285949,285966,285983,286000,286017,286034,286051,286068,286085,286102,286119,286136,286153,286170,286187,286204,286221,286238,286255,286272,286289,286306,286323,286340,286357,286374,286391,286408,286425,286442,286459,286476,286493,286510,286527,286544,286561,286578,286595,286612,286629,286646,286663,286680,286697,286714,286731,286748,286765,286782,286799,286816,286833,286850,286867,286884,286901,286918,286935,286952,286969,286986,287003,287020,287037,287054,287071,287088,287105,287122,287139,287156,287173,287190,287207,287224,287241,287258,287275,287292,287309,287326,287343,287360,287377,287394,287411,287428,287445,287462,287479,287496,287513,287530,287547,287564,287581,287598,287615,287632,287649,287666,287683,287700,287717,287734,287751,287768,287785,287802,287819,287836,287853,287870,287887,287904,287921,287938,287955,287972,287989,288006,288023,288040,288057,288074,288091,288108,288125,288142,288159,288176,288193,288210,288227,288244,288261,288278,288295,288312,288329,288346,288363,288380,288397,288414,288431,288448,288465,288482,288499,288516,288533,288550,288567,288584,288601,288618,288635,288652,288669,288686,288703,288720,288737,288754,288771,288788,288805,288822,288839,288856,288873,288890,288907,288924,288941,288958,288975,288992,289009,289026,289043,289060,289077,289094,289111,289128,289145,289162,289179,289196,289213,289230,289247,289264,289281,289298,289315,289332,289349,289366,289383,289400,289417,289434,289451,289468,289485,289502,289519,289536,289553,289570,289587,289604,289621,289638,289655,289672,289689,289706,289723,289740,289757,289774,289791,289808,289825,289842,289859,289876,289893,289910,289927,289944,289961,289978,289995,290012,290029,290046,290063,290080,290097,290114,290131,290148,290165,290182,290199,290216,290233,290250,290267,290284,290301,290318,290335,290352,290369,290386,290403,290420,290437,290454,290471,290488,290505,290522,290539,290556,290573,290590,290607,290624,290641,290658,290675,290692,290709,290726,290743,290760,290777,290794,290811,290828,290845,290862,290879,290896,290913,290930,290947,290964,290981,290998,291015,291032,291049,291066,291083,291100,291117,291134,291151,291168,291185,291202,291219,291236,291253,291270,291287,291304,291321,291338,291355,291372,291389,291406,291423,291440,291457,291474,291491,291508,291525,291542,291559,291576,291593,291610,291627,291644,291661,291678,291695,291712,291729,291746,291763,291780,291797,291814,291831,291848,291865,291882,291899,291916,291933,291950,291967,291984,292001,292018,292035,292052,292069,292086,292103,292120,292137,292154,292171,292188,292205,292222,292239,292256,292273,292290,292307,292324,292341,292358,292375,292392,292409,292426,292443,292460,292477,292494,292511,292528,292545,292562,292579,292596,292613,292630,292647,292664,292681,292698,292715,292732,292749,292766,292783,292800,292817,292834,292851,292868,292885,292902,292919,292936,292953,292970,292987,293004,293021,293038,293055,293072,293089,293106,293123,293140,293157,293174,293191,293208,293225,293242,293259,293276,293293,293310,293327,293344,293361,293378,293395,293412,293429,293446,293463,293480,293497,293514,293531,293548,293565,293582,293599,293616,293633,293650,293667,293684,293701,293718,293735,293752,293769,293786,293803,293820,293837,293854,293871,293888,293905,293922,293939,293956,293973,293990,294007,294024,294041,294058,294075,294092,294109,294126,294143,294160,294177,294194,294211,294228,294245,294262,294279,294296,294313,294330,294347,294364,294381,294398,294415,294432,294449,294466,294483,294500,294517,294534,294551,294568,294585,294602,294619,294636,294653,294670,294687,294704,294721,294738,294755,294772,294789,294806,294823,294840,294857,294874,294891,294908,294925,294942,294959,294976,294993,295010,295027,295044,295061,295078,295095,295112,295129,295146,295163,295180,295197,295214,295231,295248,295265,295282,295299,295316,295333,295350,295367,295384,295401,295418,295435,295452,295469,295486,295503,295520,295537,295554,295571,295588,295605,295622,295639,295656,295673,295690,295707,295724,295741,295758,295775,295792,295809,295826,295843,295860,295877,295894,295911,295928,295945,295962,295979,295996,296013,296030,296047,296064,296081,296098,296115,296132,296149,296166,296183,296200,296217,296234,296251,296268,296285,296302,296319,296336,296353,296370,296387,296404,296421,296438,296455,296472,296489,296506,296523,296540,296557,296574,296591,296608,296625,296642,296659,296676,296693,296710,296727,296744,296761,296778,296795,296812,296829,296846,296863,296880,296897,296914,296931,296948,296965,296982,296999,297016,297033,297050,297067,297084,297101,297118,297135,297152,297169,297186,297203,297220,297237,297254,297271,297288,297305,297322,297339,297356,297373,297390,297407,297424,297441,297458,297475,297492,297509,297526,297543,297560,297577,297594,297611,297628,297645,297662,297679,297696,297713,297730,297747,297764,297781,297798,297815,297832,297849,297866,297883,297900,297917,297934,297951,297968,297985,298002,298019,298036,298053,298070,298087,298104,298121,298138,298155,298172,298189,298206,298223,298240,298257,298274,298291,298308,298325,298342,298359,298376,298393,298410,298427,298444,298461,298478,298495,298512,298529,298546,298563,298580,298597,298614,298631,298648,298665,298682,298699,298716,298733,298750,298767,298784,298801,298818,298835,298852,298869,298886,298903,298920,298937,298954,298971,298988,299005,299022,299039,299056,299073,299090,299107,299124,299141,299158,299175,299192,299209,299226,299243,299260,299277,299294,299311,299328,299345,299362,299379,299396,299413,299430,299447,299464,299481,299498,299515,299532,299549,299566,299583,299600,299617,299634,299651,299668,299685,299702,299719,299736,299753,299770,299787,299804,299821,299838,299855,299872,299889,299906,299923,299940,299957,299974,299991,300008,300025,300042,300059,300076,300093,300110,300127,300144,300161,300178,300195,300212,300229,300246,300263,300280,300297,300314,300331,300348,300365,300382,300399,300416,300433,300450,300467,300484,300501,300518,300535,300552,300569,300586,300603,300620,300637,300654,300671,300688,300705,300722,300739,300756,300773,300790,300807,300824,300841,300858,300875,300892,300909,300926,300943,300960,300977,300994,301011,301028,301045,301062,301079,301096,301113,301130,301147,301164,301181,301198,301215,301232,301249,301266,301283,301300,301317,301334,301351,301368,301385,301402,301419,301436,301453,301470,301487,301504,301521,301538,301555,301572,301589,301606,301623,301640,301657,301674,301691,301708,301725,301742,301759,301776,301793,301810,301827,301844,301861,301878,301895,301912,301929,301946,301963,301980,301997,302014,302031,302048,302065,302082,302099,302116,302133,302150,302167,302184,302201,302218,302235,302252,302269,302286,302303,302320,302337,302354,302371,302388,302405,302422,302439,302456,302473,302490,302507,302524,302541,302558,302575,302592,302609,302626,302643,302660,302677,302694,302711,302728,302745,302762,302779,302796,302813,302830,302847,302864,302881,302898,302915,302932,302949,302966,302983,303000,303017,303034,303051,303068,303085,303102,303119,303136,303153,303170,303187,303204,303221,303238,303255,303272,303289,303306,303323,303340,303357,303374,303391,303408,303425,303442,303459,303476,303493,303510,303527,303544,303561,303578,303595,303612,303629,303646,303663,303680,303697,303714,303731,303748,303765,303782,303799,303816,303833,303850,303867,303884,303901,303918,303935,303952,303969,303986,304003,304020,304037,304054,304071,304088,304105,304122,304139,304156,304173,304190,304207,304224,304241,304258,304275,304292,304309,304326,304343,304360,304377,304394,304411,304428,304445,304462,304479,304496,304513,304530,304547,304564,304581,304598,304615,304632,304649,304666,304683,304700,304717,304734,304751,304768,304785,304802,304819,304836,304853,304870,304887,304904,304921,304938,304955,304972,304989,305006,305023,305040,305057,305074,305091,305108,305125,305142,305159,305176,305193,305210,305227,305244,305261,305278,305295,305312,305329,305346,305363,305380,305397,305414,305431,305448,305465,305482,305499,305516,305533,305550,305567,305584,305601,305618,305635,305652,305669,305686,305703,305720,305737,305754,305771,305788,305805,305822,305839,305856,305873,305890,305907,305924,305941,305958,305975,305992,306009,306026,306043,306060,306077,306094,306111,306128,306145,306162,306179,306196,306213,306230,306247,306264,306281,306298,306315,306332,306349,306366,306383,306400,306417,306434,306451,306468,306485,306502,306519,306536,306553,306570,306587,306604,306621,306638,306655,306672,306689,306706,306723,306740,306757,306774,306791,306808,306825,306842,306859,306876,306893,306910,306927,306944,306961,306978,306995,307012,307029,307046,307063,307080,307097,307114,307131,307148,307165,307182,307199,307216,307233,307250,307267,307284,307301,307318,307335,307352,307369,307386,307403,307420,307437,307454,307471,307488,307505,307522,307539,307556,307573,307590,307607,307624,307641,307658,307675,307692,307709,307726,307743,307760,307777,307794,307811,307828,307845,307862,307879,307896,307913,307930,307947,307964,307981,307998,308015,308032,308049,308066,308083,308100,308117,308134,308151,308168,308185,308202,308219,308236,308253,308270,308287,308304,308321,308338,308355,308372,308389,308406,308423,308440,308457,308474,308491,308508,308525,308542,308559,308576,308593,308610,308627,308644,308661,308678,308695,308712,308729,308746,308763,308780,308797,308814,308831,308848,308865,308882,308899,308916,308933,308950,308967,308984,309001,309018,309035,309052,309069,309086,309103,309120,309137,309154,309171,309188,309205,309222,309239,309256,309273,309290,309307,309324,309341,309358,309375,309392,309409,309426,309443,309460,309477,309494,309511,309528,309545,309562,309579,309596,309613,309630,309647,309664,309681,309698,309715,309732,309749,309766,309783,309800,309817,309834,309851,309868,309885,309902,309919,309936,309953,309970,309987,310004,310021,310038,310055,310072,310089,310106,310123,310140,310157,310174,310191,310208,310225,310242,310259,310276,310293,310310,310327,310344,310361,310378,310395,310412,310429,310446,310463,310480,310497,310514,310531,310548,310565,310582,310599,310616,310633,310650,310667,310684,310701,310718,310735,310752,310769,310786,310803,310820,310837,310854,310871,310888,310905,310922,310939,310956,310973,310990,311007,311024,311041,311058,311075,311092,311109,311126,311143,311160,311177,311194,311211,311228,311245,311262,311279,311296,311313,311330,311347,311364,311381,311398,311415,311432,311449,311466,311483,311500,311517,311534,311551,311568,311585,311602,311619,311636,311653,311670,311687,311704,311721,311738,311755,311772,311789,311806,311823,311840,311857,311874,311891,311908,311925,311942,311959,311976,311993,312010,312027,312044,312061,312078,312095,312112,312129,312146,312163,312180,312197,312214,312231,312248,312265,312282,312299,312316,312333,312350,312367,312384,312401,312418,312435,312452,312469,312486,312503,312520,312537,312554,312571,312588,312605,312622,312639,312656,312673,312690,312707,312724,312741,312758,312775,312792,312809,312826,312843,312860,312877,312894,312911,312928,312945,312962,312979,312996,313013,313030,313047,313064,313081,313098,313115,313132,313149,313166,313183,313200,313217,313234,313251,313268,313285,313302,313319,313336,313353,313370,313387,313404,313421,313438,313455,313472,313489,313506,313523,313540,313557,313574,313591,313608,313625,313642,313659,313676,313693,313710,313727,313744,313761,313778,313795,313812,313829,313846,313863,313880,313897,313914,313931,313948,313965,313982,313999,314016,314033,314050,314067,314084,314101,314118,314135,314152,314169,314186,314203,314220,314237,314254,314271,314288,314305,314322,314339,314356,314373,314390,314407,314424,314441,314458,314475,314492,314509,314526,314543,314560,314577,314594,314611,314628,314645,314662,314679,314696,314713,314730,314747,314764,314781,314798,314815,314832,314849,314866,314883,314900,314917,314934,314951,314968,314985,315002,315019,315036,315053,315070,315087,315104,315121,315138,315155,315172,315189,315206,315223,315240,315257,315274,315291,315308,315325,315342,315359,315376,315393,315410,315427,315444,315461,315478,315495,315512,315529,315546,315563,315580,315597,315614,315631,315648,315665,315682,315699,315716,315733,315750,315767,315784,315801,315818,315835,315852,315869,315886,315903,315920,315937,315954,315971,315988,316005,316022,316039,316056,316073,316090,316107,316124,316141,316158,316175,316192,316209,316226,316243,316260,316277,316294,316311,316328,316345,316362,316379,316396,316413,316430,316447,316464,316481,316498,316515,316532,316549,316566,316583,316600,316617,316634,316651,316668,316685,316702,316719,316736,316753,316770,316787,316804,316821,316838,316855,316872,316889,316906,316923,316940,316957,316974,316991,317008,317025,317042,317059,317076,317093,317110,317127,317144,317161,317178,317195,317212,317229,317246,317263,317280,317297,317314,317331,317348,317365,317382,317399,317416,317433,317450,317467,317484,317501,317518,317535,317552,317569,317586,317603,317620,317637,317654,317671,317688,317705,317722,317739,317756,317773,317790,317807,317824,317841,317858,317875,317892,317909,317926,317943,317960,317977,317994,318011,318028,318045,318062,318079,318096,318113,318130,318147,318164,318181,318198,318215,318232,318249,318266,318283,318300,318317,318334,318351,318368,318385,318402,318419,318436,318453,318470,318487,318504,318521,318538,318555,318572,318589,318606,318623,318640,318657,318674,318691,318708,318725,318742,318759,318776,318793,318810,318827,318844,318861,318878,318895,318912,318929,318946,318963,318980,318997,319014,319031,319048,319065,319082,319099,319116,319133,319150,319167,319184,319201,319218,319235,319252,319269,319286,319303,319320,319337,319354,319371,319388,319405,319422,319439,319456,319473,319490,319507,319524,319541,319558,319575,319592,319609,319626,319643,319660,319677,319694,319711,319728,319745,319762,319779,319796,319813,319830,319847,319864,319881,319898,319915,319932,319949,319966,319983,320000,320017,320034,320051,320068,320085,320102,320119,320136,320153,320170,320187,320204,320221,320238,320255,320272,320289,320306,320323,320340,320357,320374,320391,320408,320425,320442,320459,320476,320493,320510,320527,320544,320561,320578,320595,320612,320629,320646,320663,320680,320697,320714,320731,320748,320765,320782,320799,320816,320833,320850,320867,320884,320901,320918,320935,320952,320969,320986,321003,321020,321037,321054,321071,321088,321105,321122,321139,321156,321173,321190,321207,321224,321241,321258,321275,321292,321309,321326,321343,321360,321377,321394,321411,321428,321445,321462,321479,321496,321513,321530,321547,321564,321581,321598,321615,321632,321649,321666,321683,321700,321717,321734,321751,321768,321785,321802,321819,321836,321853,321870,321887,321904,321921,321938,321955,321972,321989,322006,322023,322040,322057,322074,322091,322108,322125,322142,322159,322176,322193,322210,322227,322244,322261,322278,322295,322312,322329,322346,322363,322380,322397,322414,322431,322448,322465,322482,322499,322516,322533,322550,322567,322584,322601,322618,322635,322652,322669,322686,322703,322720,322737,322754,322771,322788,322805,322822,322839,322856,322873,322890,322907,322924,322941,322958,322975,322992,323009,323026,323043,323060,323077,323094,323111,323128,323145,323162,323179,323196,323213,323230,323247,323264,323281,323298,323315,323332,323349,323366,323383,323400,323417,323434,323451,323468,323485,323502,323519,323536,323553,323570,323587,323604,323621,323638,323655,323672,323689,323706,323723,323740,323757,323774,323791,323808,323825,323842,323859,323876,323893,323910,323927,323944,323961,323978,323995,324012,324029,324046,324063,324080,324097,324114,324131,324148,324165,324182,324199,324216,324233,324250,324267,324284,324301,324318,324335,324352,324369,324386,324403,324420,324437,324454,324471,324488,324505,324522,324539,324556,324573,324590,324607,324624,324641,324658,324675,324692,324709,324726,324743,324760,324777,324794,324811,324828,324845,324862,324879,324896,324913,324930,324947,324964,324981,324998,325015,325032,325049,325066,325083,325100,325117,325134,325151,325168,325185,325202,325219,325236,325253,325270,325287,325304,325321,325338,325355,325372,325389,325406,325423,325440,325457,325474,325491,325508,325525,325542,325559,325576,325593,325610,325627,325644,325661,325678,325695,325712,325729,325746,325763,325780,325797,325814,325831,325848,325865,325882,325899,325916,325933,325950,325967,325984,326001,326018,326035,326052,326069,326086,326103,326120,326137,326154,326171,326188,326205,326222,326239,326256,326273,326290,326307,326324,326341,326358,326375,326392,326409,326426,326443,326460,326477,326494,326511,326528,326545,326562,326579,326596,326613,326630,326647,326664,326681,326698,326715,326732,326749,326766,326783,326800,326817,326834,326851,326868,326885,326902,326919,326936,326953,326970,326987,327004,327021,327038,327055,327072,327089,327106,327123,327140,327157,327174,327191,327208,327225,327242,327259,327276,327293,327310,327327,327344,327361,327378,327395,327412,327429,327446,327463,327480,327497,327514,327531,327548,327565,327582,327599,327616,327633,327650,327667,327684,327701,327718,327735,327752,327769,327786,327803,327820,327837,327854,327871,327888,327905,327922,327939,327956,327973,327990,328007,328024,328041,328058,328075,328092,328109,328126,328143,328160,328177,328194,328211,328228,328245,328262,328279,328296,328313,328330,328347,328364,328381,328398,328415,328432,328449,328466,328483,328500,328517,328534,328551,328568,328585,328602,328619,328636,328653,328670,328687,328704,328721,328738,328755,328772,328789,328806,328823,328840,328857,328874,328891,328908,328925,328942,328959,328976,328993,329010,329027,329044,329061,329078,329095,329112,329129,329146,329163,329180,329197,329214,329231,329248,329265,329282,329299,329316,329333,329350,329367,329384,329401,329418,329435,329452,329469,329486,329503,329520,329537,329554,329571,329588,329605,329622,329639,329656,329673,329690,329707,329724,329741,329758,329775,329792,329809,329826,329843,329860,329877,329894,329911,329928,329945,329962,329979,329996,330013,330030,330047,330064,330081,330098,330115,330132,330149,330166,330183,330200,330217,330234,330251,330268,330285,330302,330319,330336,330353,330370,330387,330404,330421,330438,330455,330472,330489,330506,330523,330540,330557,330574,330591,330608,330625,330642,330659,330676,330693,330710,330727,330744,330761,330778,330795,330812,330829,330846,330863,330880,330897,330914,330931,330948,330965,330982,330999,331016,331033,331050,331067,331084,331101,331118,331135,331152,331169,331186,331203,331220,331237,331254,331271,331288,331305,331322,331339,331356,331373,331390,331407,331424,331441,331458,331475,331492,331509,331526,331543,331560,331577,331594,331611,331628,331645,331662,331679,331696,331713,331730,331747,331764,331781,331798,331815,331832,331849,331866,331883,331900,331917,331934,331951,331968,331985,332002,332019,332036,332053,332070,332087,332104,332121,332138,332155,332172,332189,332206,332223,332240,332257,332274,332291,332308,332325,332342,332359,332376,332393,332410,332427,332444,332461,332478,332495,332512,332529,332546,332563,332580,332597,332614,332631,332648,332665,332682,332699,332716,332733,332750,332767,332784,332801,332818,332835,332852,332869,332886,332903,332920,332937,332954,332971,332988,333005,333022,333039,333056,333073,333090,333107,333124,333141,333158,333175,333192,333209,333226,333243,333260,333277,333294,333311,333328,333345,333362,333379,333396,333413]
\ No newline at end of file
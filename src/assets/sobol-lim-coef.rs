@@ -1 +0,0 @@
-&[0,1,1,2,1,4,2,4,7,11,13,14,1,13,16,19,22,25,1,4,7,8,14,19,21,28,31,32,37,41,42,50,55,56,59,62,14,21,22,38,47,49,50,52,56,67,70,84,97,103,115,122,8,13,16,22,25,44,47,52,55,59,62,67,74,81,82,87,91,94,103,104,109,122,124,137,138,143,145,152,157,167,173,176,181,182,185,191,194,199,218,220,227,229,230,234,236,241,244,253,4,13,19,22,50,55,64,69,98,107,115,121,127,134,140,145,152,158,161,171,181,194,199,203,208,227,242,251,253,265,266,274,283,289,295,301,316,319,324,346,352,361,367,382,395,398,400,412,419,422,426,428,433,446,454,457,472,493,505,508,2,11,21,22,35,49,50,56,61,70,74,79,84,88,103,104,112,115,117,122,134,137,146,148,157,158,162,164,168,173,185,186,191,193,199,213,214,220,227,236,242,251,256,259,265,266,276,292,304,310,316,319,322,328,334,339,341,345,346,362,367,372,375,376,381,385,388,392,409,415,416,421,428,431,434,439,446,451,453,457,458,471,475,478,484,493,494,499,502,517,518,524,527,555,560,565,569,578,580,587,589,590,601,607,611,614,617,618,625,628,635,641,647,654,659,662,672,675,682,684,689,695,696,713,719,724,733,734,740,747,749,752,755,762,770,782,784,787,789,793,796,803,805,810,815,824,829,830,832,841,847,849,861,871,878,889,892,901,908,920,923,942,949,950,954,961,968,971,973,979,982,986,998,1001,1010,1012,41,52,61,62,76,104,117,131,143,145,157,167,171,176,181,194,217,236,239,262,283,286,307,313,319,348,352,357,391,398,400,412,415,422,440,460,465,468,515,536,539,551,558,563,570,595,598,617,647,654,678,713,738,747,750,757,772,803,810,812,850,862,906,908,929,930,954,964,982,985,991,992,1067,1070,1096,1099,1116,1143,1165,1178,1184,1202,1213,1221,1240,1246,1252,1255,1267,1293,1301,1305,1332,1349,1384,1392,1402,1413,1417,1423,1451,1480,1491,1503,1504,1513,1538,1544,1547,1555,1574,1603,1615,1618,1629,1634,1636,1639,1657,1667,1681,1697,1704,1709,1722,1730,1732,1802,1804,1815,1826,1832,1843,1849,1863,1905,1928,1933,1939,1976,1996,2013,2014,2020,13,19,26,41,50,55,69,70,79,82,87,93,94,97,100,112,121,134,138,148,151,157,161,179,181,188,196,203,206,223,224,227,230,239,241,248,253,268,274,283,286,289,301,302,316,319,324,331,333,345,351,358,375,379,381,386,403,405,419,426,428,439,440,446,451,454,458,465,468,472,475,477,496,502,508,517,521,527,530,532,542,552,555,560,566,575,577,589,590,602,607,608,611,613,625,644,651,654,656,662,668,681,682,689,696,699,707,709,714,716,719,727,734,738,743,747,757,769,770,776,790,799,805,809,812,820,827,829,835,841,844,856,859,862,865,885,890,905,916,925,935,939,942,949,953,956,961,968,976,988,995,997,1007,1015,1016,1027,1036,1039,1041,1048,1053,1054,1058,1075,1082,1090,1109,1110,1119,1126,1130,1135,1137,1140,1149,1156,1159,1160,1165,1173,1178,1183,1184,1189,1194,1211,1214,1216,1225,1231,1239,1243,1246,1249,1259,1273,1274,1281,1287,1294,1296,1305,1306,1318,1332,1335,1336,1341,1342,1362,1364,1368,1378,1387,1389,1397,1401,1408,1418,1425,1426,1431,1435,1441,1444,1462,1471,1474,1483,1485,1494,1497,1516,1522,1534,1543,1552,1557,1558,1567,1568,1574,1592,1605,1606,1610,1617,1623,1630,1634,1640,1643,1648,1651,1653,1670,1676,1684,1687,1691,1693,1698,1709,1715,1722,1732,1735,1747,1749,1754,1777,1784,1790,1795,1801,1802,1812,1828,1831,1837,1838,1840,1845,1863,1864,1867,1870,1877,1881,1884,1903,1917,1918,1922,1924,1928,1931,1951,1952,1957,1958,1964,1967,1970,1972,1994,2002,2007,2008,2023,2030,2035,2038,2042,2047,2051,2058,2060,2071,2084,2087,2099,2108,2111,2120,2128,2138,2143,2144,2153,2156,2162,2167,2178,2183,2202,2211,2214,2223,2225,2232,2237,2257,2260,2267,2274,2276,2285,2288,2293,2294,2297,2303,2308,2311,2318,2323,2332,2341,2345,2348,2354,2368,2377,2380,2383,2388,2395,2397,2401,2411,2413,2419,2435,2442,2455,2472,2478,2490,2507,2509,2517,2524,2528,2531,2538,2545,2546,2555,2557,2564,2573,2579,2592,2598,2607,2612,2619,2621,2627,2633,2636,2642,2654,2660,2669,2675,2684,2694,2703,2706,2712,2715,2722,2727,2734,2742,2745,2751,2766,2768,2780,2790,2794,2796,2801,2804,2807,2816,2821,2831,2834,2839,2845,2852,2856,2861,2873,2874,2888,2893,2894,2902,2917,2921,2922,2929,2935,2946,2951,2957,2960,2966,2972,2976,2979,2985,3000,3003,3013,3018,3020,3025,3042,3047,3048,3051,3054,3056,3065,3073,3074,3083,3086,3091,3097,3109,3116,3124,3128,3153,3160,3165,3172,3175,3184,3193,3196,3200,3203,3205,3209,3224,3239,3251,3254,3265,3266,3275,3280,3283,3286,3301,3302,3305,3319,3323,3326,3331,3348,3351,3358,3368,3374,3376,3379,3385,3386,3396,3420,3423,3430,3433,3434,3439,3442,3444,3453,3464,3477,3478,3482,3487,3497,3500,3505,3506,3511,3512,3515,3525,3532,3538,3540,3547,3549,3560,3571,3577,3583,3590,3593,3594,3599,3601,3602,3613,3623,3630,3638,3649,3655,3662,3667,3669,3676,3683,3700,3709,3710,3713,3723,3725,3728,3734,3737,3738,3744,3750,3762,3764,3774,3776,3786,3800,3803,3809,3816,3821,3827,3829,3836,3842,3844,3847,3853,3861,3871,3872,3881,3890,3892,3909,3921,3934,3938,3947,3950,3952,3964,3974,3980,3983,3986,3995,3998,4001,4002,4004,4008,4011,4016,4033,4036,4040,4053,4058,4081,4091,4094]
\ No newline at end of file
@@ -1 +0,0 @@
-&[1,1,0,0,0,0,1,1,1,2,6,0,2,2,8,0,2,2,2,0,3,5,9,0,2,0,0,0,0,1,5,1,2,2,15,1,1,4,3,24,0,0,7,10,10,1,0,6,13,24,0,0,7,3,2,1,0,7,6,12,0,2,2,9,30,1,3,5,11,7,51,1,3,6,6,7,34,0,1,6,3,17,31,1,2,4,0,12,26,1,0,6,4,17,53,1,0,2,13,30,15,0,2,5,9,20,30,1,2,1,1,6,34,0,3,6,0,9,0,1,3,2,6,9,29,0,1,4,12,14,20,1,2,6,11,0,27,1,3,1,6,29,8,1,0,1,2,26,34,0,2,2,11,16,6,0,3,3,0,30,61,0,3,4,6,30,24,1,1,2,1,27,16,1,0,7,15,6,24,122,1,2,7,15,29,31,48,1,0,5,5,5,38,124,1,0,5,13,21,35,4,0,3,7,10,5,40,22,1,3,1,12,15,32,39,1,0,0,9,5,1,102,0,2,4,9,10,14,78,1,3,5,0,16,44,92,1,1,1,7,4,39,35,1,3,5,7,19,59,13,0,1,0,5,15,48,112,0,0,1,11,21,28,88,1,3,3,8,8,18,35,1,0,2,13,31,61,106,0,1,2,5,21,26,66,1,2,2,14,8,23,86,239,1,1,5,1,0,54,4,34,0,0,2,8,19,11,2,171,1,0,2,12,7,15,51,249,0,0,5,5,8,31,52,91,0,2,5,4,14,48,115,181,0,2,7,9,22,20,3,191,1,3,3,15,9,41,68,110,0,0,1,11,7,55,111,41,0,2,6,15,7,27,12,80,0,1,6,12,23,19,43,128,0,0,5,10,26,62,124,146,0,3,5,5,3,28,39,161,0,2,2,8,6,40,1,65,0,3,6,11,3,32,125,237,1,2,0,4,21,1,74,5,0,1,6,15,6,6,127,243,1,1,0,2,31,44,45,63,0,1,1,0,9,61,63,118,0,2,3,11,15,18,121,144,0,2,5,8,26,58,91,245,0,0,2,0,6,6,104,172,0,1,7,0,28,57,3,16,1,0,5,3,21,40,103,87,1,0,0,7,13,31,127,24,1,2,1,13,30,52,85,152,0,2,1,0,1,28,124,74,0,1,2,2,28,7,6,79,0,0,5,3,5,52,70,112,1,1,2,13,29,60,50,135,1,2,4,5,24,25,29,57,0,3,0,11,22,62,35,209,0,1,2,11,2,52,54,37,0,3,7,3,5,33,60,226,1,3,1,4,6,15,13,224,1,0,7,9,19,19,44,7,0,0,0,0,16,36,72,189,1,0,7,7,21,14,6,241,0,3,1,9,13,42,65,215,1,1,1,2,17,11,97,174,1,1,3,4,13,19,29,148,0,1,4,5,8,6,120,78,1,3,7,12,28,16,94,106,0,3,0,4,27,36,41,108,1,1,6,9,13,11,56,124,1,2,1,11,21,1,126,239,0,2,2,5,2,22,58,108,1,1,3,14,18,16,61,73,1,0,7,2,2,18,113,111,229,0,3,2,2,19,31,127,67,243,1,0,3,4,3,43,124,108,299,0,1,6,4,23,3,112,181,123,1,3,6,9,6,4,33,4,368,1,2,2,9,29,3,20,159,338,0,2,1,15,31,7,21,103,394,0,3,4,6,19,1,23,248,84,1,0,3,10,8,48,9,207,452,1,3,0,1,15,35,55,82,63,0,2,5,0,30,41,59,101,423,1,1,6,4,30,9,48,23,17,0,3,3,7,14,31,47,208,234,1,0,4,12,4,35,28,106,192,1,2,6,15,23,50,28,19,170,0,1,1,15,28,62,86,182,275,1,3,0,6,28,33,78,225,353,0,0,3,10,6,52,44,214,482,0,2,4,8,25,22,59,78,70,1,3,3,6,22,45,4,64,370,1,3,0,11,28,33,70,75,285,0,1,5,8,23,46,53,187,78,1,1,2,5,10,21,25,84,457,0,2,1,7,27,50,33,227,312,1,2,4,0,11,14,23,172,297,1,3,3,2,24,14,77,161,294,1,1,3,2,20,63,30,130,358,1,3,3,8,11,58,33,64,504,0,1,6,5,19,10,103,61,152,0,1,4,14,1,47,23,115,36,1,0,4,0,14,58,10,220,129,1,0,6,10,19,62,105,219,361,0,3,1,8,31,57,44,24,386,1,3,6,5,16,50,53,31,36,0,2,2,6,28,31,67,218,88,0,1,3,13,31,46,23,208,241,0,1,0,11,14,0,95,24,11,0,1,7,12,27,4,50,109,303,1,0,3,3,9,25,125,196,153,1,1,1,12,27,8,37,168,1,0,0,6,12,8,32,22,239,206,0,3,3,13,24,49,80,106,363,1,2,0,11,2,21,20,125,428,1,1,3,5,30,19,43,191,417,0,1,7,6,3,14,3,252,461,1,3,0,2,15,23,78,222,250,0,1,3,0,21,4,73,57,302,1,1,6,2,0,59,105,227,500,0,1,2,6,9,1,121,37,421,1,3,3,0,9,45,124,178,294,0,0,4,0,12,54,98,139,205,1,0,7,11,28,29,67,95,37,0,2,7,14,10,19,126,191,174,1,1,2,9,22,30,75,99,490,1,2,6,4,30,53,70,70,0,1,0,5,13,12,42,52,154,489,1,1,5,9,3,57,111,174,21,0,3,4,10,19,61,10,137,463,0,3,6,7,20,23,121,151,218,0,0,3,3,1,7,49,204,359,1,1,7,13,24,56,61,56,33,234,1,3,5,1,11,43,84,59,241,99,0,2,7,3,8,54,114,89,106,370,0,2,6,5,8,12,67,201,278,716,1,0,0,0,30,33,107,94,472,621,0,3,6,8,16,4,110,214,108,839,0,1,5,13,1,7,46,46,432,524,1,3,3,12,20,60,17,186,189,773,1,1,4,5,17,22,102,120,4,29,1,0,3,1,25,3,88,26,487,44,0,1,2,13,0,56,115,149,379,430,1,1,7,12,14,2,127,69,445,1015,1,0,0,6,4,54,96,209,47,8,0,3,4,1,3,14,20,67,419,433,0,3,4,12,24,61,108,56,454,107,0,3,1,11,7,21,66,108,163,450,0,1,1,6,26,31,61,238,355,693,0,1,7,3,14,37,59,90,478,123,0,0,5,13,12,54,75,133,49,730,1,3,7,2,2,26,72,5,362,750,1,3,0,4,21,35,114,78,303,917,1,1,6,12,0,2,13,235,174,63,0,0,0,11,18,4,110,134,448,842,0,1,1,15,14,25,9,155,276,984,1,3,2,2,27,8,19,237,335,764,0,3,0,0,17,23,13,218,197,817,0,3,1,6,11,21,67,163,69,194,1,3,1,4,12,45,12,214,109,256,0,1,2,6,14,59,100,138,78,1021,1,2,1,14,28,6,8,83,369,515,1,1,2,14,10,47,13,127,339,765,1,3,7,4,2,10,35,30,480,600,1,2,6,7,28,16,46,229,433,111,0,0,7,8,21,63,95,33,88,536,0,0,7,11,3,10,99,37,146,805,1,3,6,7,19,10,74,32,370,159,1,3,5,11,6,50,44,138,259,355,1,3,7,9,13,42,101,220,48,947,1,0,1,14,12,10,77,5,95,98,0,3,2,13,5,40,50,228,337,843,1,0,2,12,2,32,96,20,283,390,1,0,2,5,7,56,38,205,347,555,0,1,4,5,26,59,85,27,148,254,0,0,0,5,19,56,69,82,173,297,1,3,5,4,8,50,6,40,162,866,1,0,0,10,21,57,4,56,453,322,0,3,1,4,12,58,98,79,235,237,1,0,4,5,10,28,103,242,306,830,0,3,3,13,27,24,111,44,42,761,0,2,1,9,20,22,25,223,149,677,1,0,6,0,16,58,71,156,93,536,0,3,3,2,5,32,48,188,188,750,1,0,0,10,17,47,32,49,11,619,0,2,4,1,18,47,83,57,212,433,1,1,6,0,18,13,94,40,339,386,0,1,5,0,30,49,116,214,484,24,0,0,3,12,31,49,82,122,396,571,0,2,5,5,21,27,32,35,141,136,0,2,2,4,1,50,125,177,189,805,0,0,7,10,31,42,49,24,374,667,0,2,6,13,4,60,21,127,357,144,1,0,2,13,9,8,111,38,285,707,0,2,1,6,29,62,125,97,275,868,1,1,7,6,13,24,52,194,485,377,1,2,7,11,21,17,53,223,381,126,1,2,5,10,1,8,19,248,203,305,0,3,6,7,15,56,8,11,253,997,0,3,7,1,7,15,76,211,39,251,0,3,4,9,12,11,85,252,461,994,0,2,4,10,13,60,111,66,43,348,0,2,2,4,9,53,49,159,382,730,0,1,1,9,12,1,50,85,364,93,0,1,0,6,11,42,46,145,104,18,0,0,7,12,12,38,126,166,473,536,0,1,4,8,14,27,23,127,152,1018,1,1,4,14,31,4,51,244,469,761,1,3,7,3,15,44,87,184,169,297,1,3,6,12,2,35,103,125,183,332,1,1,1,10,12,37,17,15,160,801,0,0,4,5,0,32,2,5,164,267,0,2,1,9,6,8,21,189,242,191,1,2,6,6,4,42,73,244,393,566,1,0,0,2,25,18,64,97,148,891,0,1,7,9,28,29,90,227,348,1016,1,3,0,13,4,32,72,162,94,100,1,0,7,15,11,9,2,242,290,269,0,3,6,5,7,32,41,92,423,415,1,2,3,3,27,36,7,151,255,952,1,2,4,3,10,22,7,198,192,298,1,3,1,11,6,36,110,255,441,632,0,1,5,0,25,36,92,16,487,720,1,1,4,9,29,10,19,169,18,71,0,3,0,15,16,9,83,58,317,319,0,0,1,2,6,29,41,177,174,983,0,0,2,9,1,26,66,48,431,491,1,0,6,4,20,45,52,86,48,312,0,2,1,3,24,57,66,35,115,531,0,3,2,8,21,23,22,248,273,378,1,2,7,10,30,61,95,124,15,315,1,3,4,8,3,5,92,63,84,975,0,2,6,5,5,4,24,14,62,395,0,0,7,15,20,6,83,136,214,28,1,2,1,13,3,17,104,32,132,696,1,0,6,15,9,26,71,67,4,510,0,3,6,15,2,57,76,71,478,311,0,2,5,12,9,14,15,148,471,221,1,1,2,10,5,63,40,239,12,349,0,1,5,12,15,48,9,97,390,352,0,2,2,15,5,37,103,98,442,1018,0,0,5,4,11,14,115,153,8,748,0,2,5,5,21,55,116,153,261,629,0,3,2,0,10,53,114,171,466,108,0,0,5,1,10,62,65,202,299,734,1,2,2,4,19,16,40,194,75,405,0,3,3,3,0,29,111,132,264,1010,1,0,1,4,11,42,90,23,132,24,1,2,5,9,11,4,3,78,149,991,1,0,2,7,2,10,52,14,169,520,0,0,0,2,16,32,42,55,352,239,0,0,3,4,17,38,43,75,160,50,0,2,3,8,0,25,98,87,405,614,1,1,7,11,18,42,92,119,271,365,1,0,3,3,27,55,54,144,219,121,0,3,5,8,26,17,108,129,426,833,1,0,4,0,31,43,8,36,282,545,0,1,1,5,20,0,28,147,131,514,0,2,0,13,22,54,80,205,210,697,1,2,5,12,17,23,95,169,208,863,0,2,7,10,0,46,125,175,108,883,1,1,5,1,3,37,77,156,105,245,1,1,2,5,4,50,80,226,456,533,0,1,0,7,22,63,70,81,363,798,1,1,3,0,16,31,36,36,170,845,1,2,6,7,19,26,117,38,49,474,0,2,6,15,8,48,6,107,150,963,0,3,0,0,18,45,46,220,125,565,1,3,4,12,2,52,34,40,471,729,1,3,5,15,21,6,104,13,508,250,0,3,7,0,16,15,116,80,253,193,1,1,2,2,26,16,88,251,313,963,0,3,5,3,30,59,15,228,114,937,0,2,7,9,2,26,100,78,442,528,1,3,4,0,17,25,56,124,212,504,1,2,3,10,26,18,77,59,172,315,1,2,3,7,15,54,34,251,297,939,1,1,0,12,17,32,65,201,352,251,1,3,3,9,16,5,76,22,316,249,1,1,2,5,1,14,46,243,16,351,0,1,7,10,26,53,89,193,463,878,0,1,3,10,22,25,73,87,158,180,0,0,3,3,6,7,121,134,397,982,0,1,2,9,16,28,57,221,268,313,1,1,4,1,19,12,30,92,358,524,1,3,1,3,18,53,76,3,134,790,0,3,1,3,20,45,20,72,244,622,0,2,4,3,3,52,40,201,203,141,0,3,4,13,27,14,38,96,481,474,0,2,1,12,25,53,31,201,458,407,0,3,1,3,30,9,25,228,299,267,1,3,0,11,25,52,76,119,107,923,0,1,2,13,11,39,24,247,22,967,0,0,5,5,23,27,66,247,499,730,0,1,7,13,25,46,8,177,381,837,1,0,1,0,1,39,59,249,8,497,0,0,0,7,21,22,8,83,486,399,0,0,1,13,24,44,14,241,456,1011,0,1,1,2,5,37,3,20,425,305,1,0,1,3,28,19,61,128,141,253,1,1,5,13,11,56,114,93,149,66,0,1,6,4,31,50,38,225,84,168,1,3,1,1,29,22,97,114,207,204,1,2,1,5,9,35,46,21,428,184,1,3,4,9,16,57,9,120,351,123,1,2,5,2,17,10,77,231,502,536,1,3,1,12,7,54,41,46,34,594,1,2,3,2,10,46,66,67,83,451,0,3,3,1,29,60,80,142,407,884,1852,1,0,0,1,23,51,85,190,304,92,186,1,1,7,11,16,53,65,220,222,344,1029,1,1,5,3,26,50,83,217,401,627,1890,0,2,5,7,29,20,9,67,417,631,252,0,3,5,10,24,11,109,63,480,532,192,1,2,7,3,23,58,108,22,365,819,366,0,3,5,13,28,45,43,40,17,634,503,0,1,5,7,18,26,109,96,468,949,1866,1,2,1,6,5,13,9,99,196,482,1097,1,0,1,2,0,18,86,206,511,276,204,1,0,3,7,14,61,47,127,186,899,1920,1,2,6,10,28,25,8,255,97,578,915,0,0,7,14,9,3,36,147,259,293,1761,0,2,6,6,17,57,95,61,267,358,830,1,1,2,11,10,23,125,189,460,559,148,1,1,4,14,26,60,100,67,96,261,1471,0,0,3,14,22,62,4,49,433,212,300,1,0,4,6,7,33,90,54,146,652,1539,1,1,4,2,17,7,104,152,43,383,1397,1,1,5,13,28,56,61,89,321,74,261,0,1,7,5,8,33,111,31,328,167,1654,0,0,4,12,14,54,79,19,256,285,880,0,1,0,2,31,37,9,227,300,61,345,0,0,1,10,2,22,84,188,256,975,1282,0,1,5,1,16,59,34,126,453,402,724,0,2,6,15,7,8,3,249,30,343,933,1,3,5,8,16,36,38,149,121,320,1172,0,3,5,4,17,15,117,179,323,189,580,1,1,7,15,12,2,33,16,22,218,2033,0,1,5,3,8,18,43,166,126,758,1460,0,3,7,3,7,53,94,76,384,760,1713,1,2,6,2,30,56,18,146,196,56,21,0,0,7,14,21,53,15,83,73,150,510,0,0,6,1,0,17,46,97,90,1013,745,1,1,1,6,16,38,99,76,110,849,1835,1,2,6,3,24,61,77,247,340,409,404,1,2,7,13,30,58,94,91,443,308,2026,0,0,3,15,29,62,117,194,184,223,519,1,2,0,2,19,57,44,124,188,215,1873,0,0,2,3,23,29,78,38,222,349,1719,0,1,2,5,10,9,37,5,299,787,367,1,2,1,9,6,20,34,99,71,880,1607,1,2,3,9,21,12,20,20,5,823,1391,1,0,4,9,22,55,48,202,199,228,1609,0,1,0,11,7,32,60,29,492,414,1129,0,1,3,8,6,53,114,37,275,649,1181,0,2,2,10,28,11,99,254,69,1003,1937,1,0,5,9,26,7,114,107,370,347,411,1,3,0,14,1,8,81,208,279,274,159,1,0,6,8,4,23,66,182,3,968,535,1,2,3,9,18,27,81,150,124,344,1163,1,2,6,5,11,30,102,128,188,307,728,1,2,0,11,18,6,37,165,247,289,1683,0,0,4,0,11,24,64,237,271,441,1265,1,0,2,11,29,25,17,171,347,109,184,1,1,0,13,8,31,48,35,253,964,306,0,2,0,10,15,5,54,123,204,908,1086,0,1,7,11,4,3,104,150,11,73,845,0,3,2,2,9,18,114,124,138,557,1154,0,0,2,2,31,2,124,142,215,171,1233,0,0,5,3,22,17,37,252,268,14,1459,1,2,7,5,19,7,31,131,4,99,222,1,1,1,13,31,26,85,113,31,524,413,0,1,6,3,5,57,91,89,468,892,190,1,0,5,6,7,53,40,26,147,892,1878,1,1,6,5,2,54,121,1,252,161,686,1,1,5,10,25,8,88,190,468,631,1944,1,2,4,13,12,42,96,71,286,594,1497,1,2,5,6,4,40,10,79,476,45,875,0,1,1,13,30,5,126,195,166,552,317,1,1,7,4,28,47,40,209,367,125,570,0,2,4,15,19,29,6,159,403,620,1216,1,1,2,13,6,53,70,211,468,1013,1616,1,1,4,4,12,62,11,221,417,622,423,0,3,7,8,8,41,53,205,142,423,785,0,1,6,14,30,18,40,174,363,726,978,1,3,5,15,6,29,38,136,295,632,766,0,3,3,6,8,12,12,93,164,173,736,1,3,3,2,25,18,49,110,76,251,1291,1,0,6,9,13,5,34,90,239,591,1614,1,1,6,11,10,51,73,161,454,473,157,1,0,1,11,0,15,29,46,256,22,1135,1,2,0,3,21,54,29,115,20,757,1192,1,0,2,15,28,24,111,141,506,5,350,0,2,0,9,26,27,15,15,149,247,346,1,1,4,2,16,38,126,213,395,365,509,1,3,5,0,4,59,101,26,438,853,1749,0,1,3,6,19,27,79,211,56,826,1727,0,1,2,10,23,25,29,27,205,465,125,1,3,1,15,12,40,57,202,119,370,227,0,2,0,15,1,50,41,239,245,889,1112,1,1,1,4,18,53,80,101,251,383,1717,1,3,4,0,13,30,59,116,19,687,2044,0,2,4,0,15,22,25,184,293,191,1406,1,3,2,15,3,24,59,243,295,813,26,0,3,0,4,23,0,111,184,355,801,958,1,2,1,10,18,55,8,241,369,596,1387,1,1,3,8,5,25,58,227,95,746,1910,0,2,4,11,19,49,90,171,242,49,965,1,0,3,14,24,15,35,244,263,881,1454,0,2,5,2,2,36,94,160,28,595,1842,0,2,7,6,22,62,103,185,207,157,491,1,1,2,12,29,16,15,119,459,929,1354,1,2,6,13,30,11,57,30,206,637,1779,1,3,7,2,29,50,40,23,483,404,1594,0,2,5,15,7,19,12,86,252,404,1338,0,2,4,9,6,47,44,255,63,697,1467,0,2,2,15,22,4,28,45,151,647,1607,1,1,1,9,7,56,93,108,244,642,901,0,1,0,6,14,28,69,127,98,268,1091,1,0,7,5,3,26,127,233,4,378,1583,1,1,7,10,6,4,94,179,161,24,166,1,3,5,3,18,10,59,200,78,829,534,0,2,3,8,16,57,114,74,75,1013,139,0,2,7,2,24,38,77,191,192,992,472,1,3,1,3,27,42,20,178,263,857,809,0,1,0,10,22,57,10,99,483,790,1903,0,1,3,10,19,58,95,84,36,206,1708,0,0,6,0,15,28,97,115,160,183,513,1,3,1,5,14,23,80,35,209,860,218,0,3,1,5,4,21,32,78,0,925,411,0,0,2,10,7,15,50,146,149,63,660,0,3,0,13,0,5,114,120,352,21,737,1,3,0,2,7,36,91,96,27,672,24,1,1,1,9,1,27,10,84,331,837,68,0,0,6,3,10,34,33,186,482,636,1139,0,3,3,10,11,8,21,170,422,232,1677,1,2,2,12,2,40,50,116,69,179,1028,0,1,5,7,19,27,1,235,382,571,1970,0,3,7,4,28,40,39,107,216,166,1927,0,2,2,9,22,41,15,104,181,350,651,1,3,2,0,6,27,81,217,403,143,1015,1,1,3,1,1,8,98,19,84,244,884,0,1,2,14,21,43,80,144,169,616,1176,1,1,4,10,4,38,0,226,83,821,1113,0,3,0,7,3,33,16,96,120,515,1169,1,0,5,0,31,22,32,132,330,424,989,1,0,6,9,24,1,5,79,106,329,1419,1,2,5,4,14,13,113,126,224,701,1713,0,1,0,3,1,38,71,138,389,749,237,0,0,2,5,11,43,65,196,424,96,1594,1,2,5,1,1,44,4,224,121,750,869,1,0,4,14,14,56,7,32,305,67,1843,0,0,4,10,9,19,75,197,250,669,479,1362,1,3,0,3,17,22,16,59,112,815,847,729,0,0,1,12,27,18,39,83,453,537,135,2029,1,2,6,2,6,26,82,218,33,852,1588,4047,1,1,6,13,28,47,27,221,122,972,862,964,1,0,4,2,16,54,17,49,413,170,1200,1205,0,2,4,3,16,21,19,43,399,317,1740,3579,1,0,0,15,7,22,13,168,56,493,1032,1264,0,2,4,2,7,52,61,239,144,804,1088,2314,1,2,5,15,23,48,43,192,97,520,325,1635,0,1,3,8,1,50,27,43,314,843,693,1372,1,2,2,3,10,4,118,156,274,553,58,3091,0,1,4,4,2,27,100,243,425,551,1496,2027,0,2,4,15,9,29,3,181,190,583,1028,2857,1,1,7,11,31,9,113,193,413,243,524,3735,1,0,2,11,12,30,122,181,431,481,1791,3237,0,2,0,2,13,40,42,137,24,117,1645,597,0,2,3,11,26,42,53,255,389,632,546,3929,1,1,0,4,10,37,109,29,242,869,1922,554,1,2,0,6,20,9,71,146,195,1011,895,2199,1,3,7,10,6,10,97,107,206,261,1049,1170,0,0,1,14,25,23,28,67,287,471,836,270,1,2,0,4,6,56,87,223,57,328,2038,2986,0,0,5,8,20,18,47,148,289,455,1103,1193,1,2,1,11,5,11,115,46,333,355,781,3980,0,3,1,8,29,6,90,70,495,908,228,855,1,1,2,15,29,40,102,122,268,524,498,907,1,3,2,8,6,4,39,8,92,2,1105,3131,1,3,6,3,26,30,72,6,142,601,473,1466,0,3,1,15,9,34,108,23,220,946,336,2225,0,0,0,12,4,11,112,192,314,301,1873,2120,1,0,4,2,18,15,118,215,39,760,229,1261,1,3,1,4,21,52,89,2,112,399,888,2446,0,1,0,14,22,14,79,133,123,227,423,1954,0,1,3,12,10,60,28,233,137,359,760,3659,1,0,1,5,17,59,61,40,489,593,1811,2146,0,0,3,7,12,60,117,12,243,436,893,988,0,0,5,1,3,8,67,172,176,191,2005,1286,1,3,7,13,6,48,61,32,337,475,642,3279,1,3,1,3,0,35,9,162,382,168,598,1348,1,2,0,15,18,5,35,84,141,41,1900,3541,0,1,7,8,14,41,32,137,339,874,2003,3874,0,1,0,10,5,20,47,118,180,909,1391,1191,1,3,5,14,28,55,93,232,72,302,993,4054,0,1,1,9,7,27,41,178,500,321,758,3264,1,0,2,14,17,36,11,38,309,761,862,4072,0,2,2,9,11,3,98,224,168,358,1460,157,1,2,4,3,31,58,48,48,406,962,1408,789,0,0,5,15,3,12,117,115,66,503,685,776,0,3,2,9,3,23,85,133,121,665,283,3016,0,2,0,3,24,27,44,54,367,727,1596,3119,0,0,3,0,30,4,51,1,464,740,1463,1478,0,2,6,8,10,37,24,127,509,580,1066,588,1,0,1,6,7,20,123,105,204,581,261,1317,1,3,3,10,29,45,74,239,195,340,1155,3124,0,2,5,13,26,10,105,98,407,359,802,127,0,1,1,4,16,29,1,161,0,50,567,4052,1,1,0,14,2,8,70,25,495,420,163,1929,1,0,2,5,9,11,44,87,86,82,1440,940,0,0,7,6,25,43,19,247,305,670,765,3514,0,1,5,6,27,37,92,28,30,958,1025,2982,0,2,2,3,26,5,108,106,466,460,1803,2587,1,1,2,8,26,51,125,184,390,659,1858,2219,1,2,6,0,19,12,117,160,386,125,1555,3198,0,3,1,15,2,12,14,162,192,656,63,2352,0,2,7,7,13,7,42,119,121,816,1736,1310,1,1,1,4,9,56,6,68,82,12,1478,3774,1,0,1,5,10,1,48,208,91,602,718,123,0,3,1,8,10,62,27,33,193,192,1161,443,1,2,2,14,5,51,111,116,320,66,207,648,1,1,5,0,4,2,94,117,503,681,1992,444,1,3,4,11,9,9,91,134,201,821,1779,2594,1,3,1,14,22,8,34,237,74,645,1344,3812,1,3,1,13,18,20,36,126,500,215,555,3943,0,3,2,1,3,43,71,144,247,315,1505,3075,0,0,6,2,22,8,83,11,487,400,987,3416,1,0,5,3,10,19,11,106,214,650,1029,98,1,1,7,1,28,60,66,14,355,980,1248,94,0,1,2,5,27,57,68,116,336,492,1424,2955,0,3,7,14,22,0,120,164,161,462,1410,1665,0,2,3,6,15,40,52,99,72,97,682,2559,1,3,5,1,27,5,15,58,171,632,918,1225,0,1,3,14,28,30,89,214,295,88,972,1079,1,2,5,11,24,50,68,169,161,517,874,3868,1,0,6,10,17,27,39,9,134,527,1325,3541,1,1,5,4,4,47,83,218,180,592,2041,301,0,0,3,15,30,38,32,244,328,345,1211,2073,1,2,3,10,18,43,95,155,226,1006,414,1309,0,2,4,8,23,17,50,2,406,578,639,3682,0,2,1,5,17,56,99,184,360,450,735,3900,1,0,2,4,30,41,78,195,369,978,1061,2170,1,2,5,9,9,55,112,191,109,498,358,3752,1,0,5,6,31,17,63,104,415,250,1508,1753,1,3,4,14,3,5,81,40,281,722,1607,3188,1,3,5,12,1,19,97,245,22,419,2010,2449,1,3,7,6,2,33,71,58,252,640,1839,2847,1,3,4,4,9,10,110,73,381,341,1105,294,0,1,2,10,23,26,54,149,403,576,604,3980,1,3,5,4,15,22,21,252,323,563,1340,2458,0,2,7,15,20,31,56,199,363,336,1293,2629,0,0,6,8,26,17,49,28,121,723,959,1415,1,3,5,11,25,6,4,24,224,498,1536,2203,1,2,3,11,16,44,20,207,26,348,556,744,0,1,3,0,6,14,6,127,374,38,1731,880,1,1,3,6,7,46,95,154,434,369,520,1526,1,2,6,2,9,54,105,173,419,446,1473,3867,1,0,6,13,1,59,78,242,49,851,1947,286,1,3,5,0,11,61,52,15,179,137,887,1842,1,1,2,13,5,62,1,206,99,1021,1447,1472,1,1,1,7,24,60,79,116,271,96,2003,160,0,1,2,4,23,43,0,25,505,797,1119,3233,1,3,4,0,16,43,68,234,374,706,402,3408,1,0,6,9,22,47,113,14,338,637,1697,2225,0,3,2,3,31,16,35,221,280,655,1534,3471,0,0,6,4,18,11,34,6,207,739,598,430,1,1,6,13,10,6,116,52,388,172,1221,552,0,3,5,11,6,10,73,110,274,36,1364,3139,0,3,3,12,13,7,22,113,19,37,595,1781,0,2,3,6,24,49,83,113,6,176,523,4037,0,1,6,15,4,13,3,230,368,779,1621,26,1,0,0,10,20,48,82,85,410,293,1068,1146,1,0,5,8,20,14,93,43,299,733,697,2965,0,0,4,4,24,44,102,204,226,30,961,628,1,3,1,4,21,44,71,215,41,621,897,1799,1,2,6,1,12,29,109,21,111,398,1325,3007,0,2,7,3,27,32,103,106,155,643,634,3233,1,3,5,10,28,15,91,175,428,455,841,3577,1,2,5,13,0,10,23,193,191,796,57,1902,1,0,0,6,11,43,86,90,309,826,1965,3036,0,3,2,8,21,18,30,153,310,892,27,57,1,3,7,12,30,61,7,118,335,736,233,953,0,3,2,14,28,37,118,42,349,79,1788,2385,0,0,5,12,9,25,0,73,15,447,1308,312,1,3,2,14,7,57,87,197,195,570,913,590,1,2,3,8,3,5,96,44,121,280,1893,2275,1,0,5,3,28,3,62,201,473,630,204,4041,0,2,6,10,31,57,116,115,460,873,1817,1259,0,2,5,1,13,7,45,252,295,725,1940,1498,0,1,5,10,4,54,76,158,266,296,1983,1398,1,1,6,4,28,60,122,109,433,483,395,3547,0,0,4,14,10,49,17,187,479,164,2043,3585,0,0,4,5,8,8,48,44,67,315,1904,1626,0,0,7,10,25,45,124,229,400,378,1176,1016,1,2,4,11,14,38,26,199,383,908,1085,814,0,1,2,14,2,21,60,8,429,739,1892,3320,0,1,3,3,30,22,54,185,416,45,76,2276,0,1,5,3,27,40,61,194,69,966,445,894,1,3,7,12,8,46,82,251,358,776,737,813,0,0,6,6,31,6,112,178,285,16,2036,1897,0,1,5,0,15,53,72,203,480,250,1493,51,0,3,0,11,31,24,96,86,140,12,1232,2963,0,3,0,0,0,42,38,136,346,174,619,2251,0,2,5,3,30,4,60,12,178,721,202,3913,0,3,6,5,26,5,103,72,105,851,540,1058,0,1,5,13,11,9,4,148,139,740,1136,3193,1,1,2,7,22,1,20,152,43,907,1730,2674,1,1,6,4,18,39,62,129,280,543,2045,396,1,2,3,15,27,3,72,173,464,294,1391,2952,0,3,7,1,12,0,90,6,121,326,1117,3722,1,2,2,8,26,32,3,16,291,681,656,1159,1,1,3,13,23,48,100,93,160,31,757,3958,0,1,2,11,4,1,82,30,9,894,1891,1518,1,0,6,7,21,62,95,33,136,775,1113,2626,0,0,6,12,26,53,16,149,124,737,1116,453,1,2,0,11,18,42,8,103,321,332,1466,2599,0,3,3,12,28,29,20,7,375,375,874,3526,1,1,0,6,12,63,46,140,306,437,1111,3172,0,2,1,14,27,39,124,21,158,266,497,995,1,1,7,8,24,39,15,96,116,718,1307,409,0,2,7,12,1,61,72,188,4,227,595,1976,1,2,1,7,9,20,115,40,196,1,9,1204,0,1,0,13,21,56,89,3,426,473,1365,148,0,0,5,14,19,26,95,221,344,264,1664,3715,1,3,2,1,14,9,33,220,56,474,1384,2084,1,2,5,5,27,42,84,107,407,401,1172,1983,0,3,4,2,22,55,2,209,187,151,862,2244,1,2,7,14,21,39,9,11,208,190,270,2461,0,1,7,1,15,58,19,58,152,613,611,71,0,2,4,2,23,43,119,90,176,780,1656,960,1,1,0,1,7,26,110,220,493,998,1264,4029,0,3,5,7,28,55,69,68,441,940,1411,2830,1,2,2,10,5,2,6,13,486,293,665,686,0,3,5,14,25,46,14,108,110,27,1238,989,1,1,6,1,5,24,37,189,185,720,396,3816,0,0,6,9,22,44,124,45,324,847,457,2809,1,0,3,3,14,0,38,156,447,259,385,147,1,0,7,2,1,0,28,165,54,242,1426,3415,0,0,7,8,1,17,49,122,485,419,1254,1401,1,1,1,4,18,28,125,162,158,264,656,3189,0,0,7,12,29,0,59,47,7,397,1187,3231,1,0,2,0,24,58,10,23,89,431,42,834,1,3,1,4,18,9,110,227,486,285,713,408,0,0,7,8,4,33,106,63,443,649,1456,3725,1,0,6,13,13,20,21,85,311,345,195,2442,1,0,6,8,8,61,119,71,113,575,259,3271,1,3,2,3,31,48,19,50,277,528,190,3945,1,2,0,1,13,42,64,80,437,972,1770,347,1,1,2,10,29,12,91,17,12,493,729,90,1,2,6,0,7,63,118,174,168,745,1191,3905,1,2,2,15,2,54,25,204,366,697,1603,3024,0,2,3,6,17,56,12,131,194,149,1260,891,1,3,5,7,23,48,36,27,37,56,1347,511,1,0,0,1,6,34,105,144,241,667,393,338,0,1,1,8,3,18,38,252,68,556,172,1487,0,0,6,1,5,47,99,226,54,239,1862,119,0,3,7,9,26,1,72,179,431,173,1916,1521,0,3,7,12,31,63,64,62,97,77,1105,4076,0,3,6,4,24,60,57,36,59,925,363,23,1,1,6,6,5,35,3,22,295,66,1203,2781,0,0,6,11,14,43,44,250,35,879,559,343,0,3,3,6,3,6,91,26,475,938,1995,3385,1,3,5,3,0,13,23,30,10,459,480,545,1,2,2,0,13,0,2,31,78,648,524,2946,1,3,4,9,16,8,66,212,398,860,76,59,1,1,3,6,18,0,107,254,501,30,1176,3755,0,3,0,14,9,15,39,99,277,604,801,3044,1,0,0,2,15,55,63,166,214,931,1962,2705,0,3,2,2,2,61,95,23,496,134,2025,1055,0,2,7,0,4,43,2,23,231,432,906,3678,1,0,1,11,31,61,41,255,388,31,642,2268,1,1,3,13,12,15,32,220,264,907,946,161,1,3,2,5,9,3,2,198,405,377,1441,2108,1,0,6,4,10,6,3,135,269,884,1621,2662,0,3,0,15,6,23,65,90,228,779,1331,3326,1,1,3,14,27,12,101,209,45,218,579,2845,0,1,6,14,9,35,108,168,164,250,469,1102,0,1,0,0,13,8,100,48,142,634,2021,1103,0,0,0,1,20,6,99,70,64,757,1564,2984,1,1,4,1,8,59,20,135,466,438,350,1098,0,0,3,7,23,1,97,57,410,362,421,3035,1,2,7,8,16,42,32,148,285,561,1371,2863,0,2,5,13,7,18,117,207,146,719,1369,2085,1,3,3,0,27,35,17,153,5,200,940,466,1,0,5,10,18,1,88,59,169,279,1995,1718,1,1,4,8,8,48,59,150,84,78,1633,1130,1,1,4,14,1,55,50,177,434,187,1304,3688,1,2,4,3,10,61,49,171,346,963,802,2461,0,1,2,6,15,49,8,37,192,769,776,3538,1,1,2,15,17,53,5,203,509,658,1796,3601,1,1,6,8,16,49,122,200,478,78,974,785,1,0,5,13,7,5,54,214,153,955,1350,430,0,2,6,6,17,27,127,155,478,901,1336,2597,0,0,5,9,1,44,18,105,391,677,1783,3567,0,2,2,10,24,39,8,254,165,91,1915,427,1,3,2,14,9,42,54,52,261,422,1692,3738,0,0,3,12,8,62,65,26,378,126,1494,1469,1,1,4,9,11,52,19,175,338,105,200,4051,1,2,0,2,5,8,1,202,234,784,1432,1566,0,1,6,7,2,58,89,69,72,238,568,1268,0,3,4,2,10,4,46,105,481,603,1671,2455,0,0,4,6,21,8,26,40,396,785,1261,1841,1,1,6,12,10,2,29,244,493,970,85,3004,1,1,3,0,19,44,85,201,233,883,1711,1395,0,1,4,9,24,45,62,81,506,44,1424,3392,0,2,4,4,5,7,120,21,148,859,770,910,1,3,7,14,11,51,119,95,16,521,1824,3289,1,1,4,10,25,61,27,111,322,731,2010,2945,0,2,3,1,20,13,117,195,151,1010,1593,3803,0,0,4,2,24,24,14,188,125,943,508,650,0,1,1,6,20,13,23,111,11,258,1613,3365,0,3,0,15,12,23,4,255,311,1023,631,755,0,1,7,7,11,26,0,130,297,42,120,3523,1,1,5,8,2,40,36,74,390,1017,1581,2123,1,3,3,14,29,24,39,198,450,552,1095,3138,1,1,5,6,13,12,86,53,36,632,292,2625,0,3,7,14,11,36,114,117,443,734,2036,1295,0,1,4,8,7,41,86,103,439,850,754,5,0,1,2,2,18,32,80,19,210,576,1003,2677,0,3,5,11,18,2,5,4,249,8,78,2873,1,3,6,12,4,24,3,19,472,674,879,720,0,2,1,10,7,56,40,132,418,166,1812,3066,1,0,5,6,13,36,54,148,163,149,1626,3478,0,1,6,9,19,61,36,32,2,530,1093,2527,0,1,0,5,15,10,57,226,428,355,247,274,1,3,3,7,14,39,51,23,356,867,1560,3160,0,2,2,14,4,48,16,235,352,164,750,674,1,1,0,10,4,55,104,35,23,245,1071,898,1,1,1,5,19,10,67,222,129,303,1905,2724,0,3,4,5,12,56,125,197,158,158,45,989,1,0,4,1,10,51,66,194,471,617,874,3531,0,1,3,0,5,2,7,248,238,239,1539,3484,0,1,1,7,19,52,65,237,232,90,432,1906,0,3,4,9,31,61,65,207,262,228,1235,1567,1,3,7,12,17,61,22,170,402,242,2024,3532,0,0,2,14,4,23,113,25,433,936,796,1135,0,3,7,15,4,35,58,142,355,418,717,3137,1,0,0,2,9,39,12,150,207,935,322,1625,1,0,1,8,25,49,92,223,21,261,109,214,1,0,6,14,6,25,46,3,497,378,1508,3432,0,1,7,3,12,37,8,77,490,615,614,997,1,2,1,13,22,35,36,112,381,188,569,1431,0,1,0,0,19,34,56,14,185,525,396,1874,0,1,6,11,30,13,91,153,215,672,1378,2015,1,3,2,2,29,58,98,151,360,438,361,800,1,2,0,13,16,49,118,242,355,332,1538,2552,0,1,0,6,4,51,100,11,475,1014,82,1046,1,2,6,2,14,27,42,110,338,305,1806,2283,0,0,0,3,30,4,116,130,280,476,2011,1221,1,1,6,0,8,51,35,111,106,416,873,3499,1,2,7,12,26,28,93,12,347,603,2044,1438,0,3,0,3,15,43,64,246,259,777,577,2318,0,0,7,10,8,11,14,9,127,463,895,1546,0,1,4,8,16,47,64,87,230,143,1316,1162,1,2,3,11,9,31,104,124,291,686,1019,1112,1,1,2,2,9,39,120,229,177,727,1656,1819,0,3,4,10,20,48,59,64,384,770,1747,3870,0,3,5,4,14,17,127,70,468,881,20,696,1,3,0,6,25,30,78,88,423,914,1769,142,0,0,7,10,6,4,27,198,9,747,627,3617,0,3,3,12,18,26,118,159,98,134,602,742,0,2,7,11,8,17,123,161,403,116,1840,2203,0,1,3,4,29,42,52,246,381,819,195,725,1,1,4,7,16,2,126,64,312,763,1396,3028,1,0,0,3,23,10,80,117,41,198,1781,2976,1,3,5,1,20,12,58,187,389,648,1857,4058,0,1,3,15,9,51,86,237,94,1017,1460,553,0,3,1,12,3,46,127,153,56,946,1116,3459,1,2,7,4,28,39,71,82,2,694,96,346,1,2,0,14,22,45,24,94,230,219,641,3917,0,1,6,5,30,20,115,186,347,197,457,2696,1,3,5,2,25,33,26,241,47,971,123,2826,1,3,2,2,28,22,117,68,396,534,830,778,1,2,1,12,27,51,88,40,430,575,71,3827,0,1,0,10,20,33,65,126,215,634,1590,1714,1,0,0,10,3,38,110,128,331,35,1474,1240,1,2,1,1,11,22,53,149,369,506,1,1582,0,2,0,1,18,54,18,121,491,610,845,1934,0,2,2,15,3,2,96,198,433,747,1717,3720,0,0,0,8,29,48,116,194,298,506,815,241,0,0,5,3,20,53,26,55,62,756,960,3823,1,1,1,15,14,58,1,182,485,569,1061,2956,0,0,6,11,1,0,83,237,319,905,1920,1540,0,2,1,2,23,32,61,137,391,47,59,3795,1,0,7,6,16,46,118,233,215,352,2006,2017,1,2,0,9,3,50,115,77,368,690,1671,1025,0,2,4,7,24,22,81,216,382,1015,100,1294,1,3,4,9,20,15,44,46,311,52,372,2204,0,2,0,5,22,63,42,194,219,414,238,3982,1,1,7,6,20,0,103,217,292,155,862,1368,1,1,1,6,24,10,15,98,399,705,1479,3566,1,0,1,3,21,4,70,66,289,529,46,478,1,3,0,7,25,11,106,190,425,349,1130,1709,1,2,4,12,17,33,70,17,204,711,182,822,1,1,5,7,16,13,90,46,43,880,1755,676,1,2,1,12,31,55,68,160,409,352,773,3635,1,0,0,2,28,49,29,205,378,685,1976,1847,1,2,5,5,10,12,73,119,227,354,476,3587,1,1,7,2,26,45,102,170,31,361,782,3567,0,3,7,5,10,49,39,31,296,1003,1814,2635,1,1,0,4,10,22,87,226,217,927,1324,3479,0,1,7,7,16,60,60,125,215,563,1652,2099,0,0,4,15,7,35,14,172,195,579,1404,172,1,3,0,11,14,47,75,163,363,323,811,1485,0,3,3,4,14,39,45,63,454,646,657,2657,0,2,5,6,18,44,36,74,238,954,1671,262,1,2,3,2,29,27,127,111,229,1013,118,2102,0,0,3,13,5,47,32,162,417,453,1900,1893,0,0,5,13,16,49,87,25,456,165,925,2066,1,2,2,6,18,15,49,136,204,913,1922,2745,0,1,3,11,9,53,42,141,261,254,225,210,1,2,3,6,4,25,40,43,309,30,1401,2635,0,0,7,4,22,17,109,200,135,476,324,3423,0,3,5,4,22,8,109,84,418,741,802,1450,0,3,3,10,21,18,16,145,179,35,1449,3518,1,1,6,15,26,18,7,74,474,275,1722,2727,1,0,2,9,22,40,111,96,219,1023,1939,394,0,3,1,5,31,17,30,127,281,229,1495,1679,0,2,4,6,24,23,92,119,110,766,1817,1022,1,3,1,12,18,63,111,25,178,241,1918,3436,0,3,4,15,18,56,15,193,416,621,771,2767,1,0,4,11,29,59,110,36,92,1003,1442,1281,0,0,6,3,16,26,89,33,92,770,903,2329,1,0,5,15,18,11,107,134,178,103,322,2109,1,1,6,9,13,53,27,45,35,847,907,44,0,1,7,1,9,17,123,24,264,761,1658,3075,0,3,3,11,12,53,69,241,251,638,121,3939,1,1,6,1,7,5,98,67,419,492,137,2763,1,2,1,12,23,47,10,56,153,500,1532,147,0,1,4,9,9,49,106,181,224,367,1425,1260,0,1,4,2,24,31,30,78,428,248,1400,3493,0,0,4,0,20,54,59,249,469,433,1837,4011,1,0,0,6,16,54,61,144,1,635,1386,2132,1,0,5,4,28,41,110,47,21,594,228,3566,0,3,1,5,24,16,109,114,144,342,1679,2247,1,0,1,9,21,33,96,20,385,203,40,1945,0,3,5,2,14,25,87,148,269,0,1122,3219,1,3,7,10,16,58,91,255,244,641,1640,2989,1,3,2,4,1,62,73,179,274,184,1524,1202,1,2,3,9,2,32,48,241,188,761,728,1497,0,2,0,5,10,20,56,138,65,737,521,1183,1,1,0,7,8,50,34,221,432,408,710,2615,0,1,1,1,27,47,49,37,97,964,1965,2927,1,0,1,9,11,46,106,120,275,653,292,3864,1,0,5,11,7,26,124,233,259,47,370,204,0,0,7,14,18,21,101,116,438,38,966,1364,1,3,5,13,19,21,80,127,7,731,416,247,0,3,5,1,26,40,33,187,411,951,1530,197,0,0,0,7,18,46,116,123,250,660,1637,2704,1,1,3,3,5,2,52,69,491,619,265,1940,0,2,1,9,24,53,113,180,50,177,1324,3691,0,3,2,12,20,50,60,104,146,968,1129,2778,0,1,3,3,0,4,6,231,509,497,1579,53,1,2,5,2,17,63,48,130,394,403,403,3128,0,3,2,5,6,22,45,208,50,986,1822,1053,0,1,3,2,31,28,24,101,78,57,696,4058,1,2,2,1,21,7,77,63,244,582,1850,2433,0,3,3,14,14,34,107,207,183,185,950,3037,0,0,1,5,16,44,74,216,352,718,798,252,1,2,0,6,18,9,59,2,290,1018,816,1049,1,3,6,2,24,51,122,107,257,66,1003,966,1,0,4,0,1,12,98,126,193,841,1133,110,1,2,7,10,4,36,100,202,499,218,1938,3022,0,1,0,15,27,12,41,210,197,903,1064,3898,0,1,0,11,10,60,91,62,173,71,1842,2158,1,1,1,8,22,8,111,133,397,907,654,77,0,0,7,8,29,2,66,7,357,751,76,1443,0,0,0,13,6,59,38,121,497,925,1859,2347,1,0,2,15,24,21,82,24,304,632,570,252,0,3,6,5,31,10,126,114,292,771,1859,2070,1,3,5,11,13,8,65,147,447,746,705,1623,0,2,4,14,3,48,7,56,222,429,741,560,1,0,4,6,24,49,53,161,100,340,1535,2640,0,0,7,4,9,30,80,3,43,293,1099,1405,1,1,7,7,9,47,22,149,414,490,1739,243,0,0,4,1,18,3,9,113,6,198,256,628,0,2,7,7,6,8,55,67,464,572,405,900,1,0,1,13,28,15,9,139,51,346,315,1704,0,0,0,7,6,33,41,11,399,867,1031,1681,1,1,3,1,0,30,15,20,266,1012,2033,3481,0,2,3,8,13,40,39,53,102,14,48,2441,0,0,2,9,24,45,100,141,474,325,1909,2536,0,3,4,5,6,36,98,18,109,965,1684,3008,0,3,7,5,3,37,102,3,409,199,330,3243,1,1,1,13,18,47,20,153,82,538,1742,281,1,2,1,10,24,28,89,54,313,894,215,1470,0,3,2,5,9,21,68,74,339,771,122,690,1,2,2,7,1,34,40,67,79,681,1700,3177,1,2,0,4,30,24,26,159,12,823,648,307,1,2,5,15,21,4,50,35,459,167,1573,2911,1,0,0,7,2,14,54,255,472,433,1838,3457,1,1,7,8,24,45,55,107,14,939,48,1252,1,0,6,9,30,5,55,81,388,266,556,2669,0,3,4,8,27,58,45,227,144,278,456,2227,1,0,3,12,9,61,18,0,138,358,1482,2234,1,3,1,9,11,43,117,104,228,1020,1446,902,1,1,2,2,21,11,30,175,395,29,1004,1454,0,1,3,2,0,13,115,192,128,630,1350,903,1,0,0,13,9,43,126,65,342,871,1991,1325,1,3,5,10,8,5,40,95,320,910,1502,3625,1,1,2,7,15,20,106,27,465,976,24,3018,0,3,7,3,13,32,111,56,39,937,455,2722,1,3,3,11,27,25,83,247,12,792,1723,399,0,1,3,13,7,47,96,168,207,487,1542,483,0,3,7,9,3,46,20,216,275,200,1584,1985,0,3,5,6,7,26,34,216,29,558,1679,3115,0,3,1,11,2,57,100,112,54,951,1948,3132,0,0,5,8,0,19,71,180,329,552,11,2461,0,0,4,13,28,42,113,130,59,940,1982,3499,1,3,3,7,3,53,8,157,24,795,452,3894,1,0,3,14,1,23,118,78,384,419,1599,1597,0,1,7,12,19,31,7,55,428,440,752,3835,0,3,0,1,17,20,107,49,447,512,741,2353,1,2,0,0,15,12,123,56,420,198,912,3484,0,1,2,9,20,24,121,112,486,120,87,520,0,0,3,7,7,52,70,41,37,837,1761,2609,0,3,2,6,13,23,99,222,420,479,578,1104,1,2,7,11,15,15,40,42,16,392,1319,3899,0,2,6,10,1,23,49,117,471,865,1233,3945,0,0,1,8,26,42,109,36,65,669,437,595,0,2,3,8,31,56,3,92,278,374,1781,2486,1,1,7,7,10,21,55,77,344,172,211,1798,0,2,0,7,14,46,2,180,356,347,1968,212,1,3,3,6,20,57,87,157,468,61,1420,2228,0,1,5,12,2,51,26,211,405,328,199,3628,0,0,0,0,6,50,105,191,162,48,851,2214,1,3,4,15,22,41,78,254,350,420,552,1821,0,0,3,0,4,34,8,64,140,580,1472,3846,1,3,0,5,14,25,71,38,216,861,1158,2820,0,0,0,10,21,6,33,88,252,814,633,2442,0,1,5,13,31,55,23,116,390,226,839,1604,0,1,6,14,13,59,70,246,485,230,579,316,0,1,7,11,2,39,107,81,74,902,1199,30,1,2,6,9,2,0,19,204,280,354,414,678,1,1,6,9,21,4,88,224,223,36,1053,2834,1,2,0,11,6,31,54,101,296,414,2008,3440,0,2,3,1,4,26,87,195,84,641,1896,2225,0,2,3,14,21,4,2,104,38,463,1470,4072,1,2,7,8,24,2,71,65,385,842,462,1087,0,1,5,13,13,13,79,80,507,793,2024,991,1,0,1,11,28,59,33,240,288,194,1659,2662,1,2,0,9,19,43,30,164,328,886,15,853,0,1,0,2,12,7,120,65,407,875,1514,4019,1,1,6,13,6,38,43,218,28,310,515,3945,1,0,6,11,25,58,18,165,372,302,1589,2356,0,2,2,9,8,49,83,43,360,368,394,1082,1,2,6,0,25,59,105,82,149,663,1526,1671,0,2,7,14,22,8,64,33,172,776,1352,3684,0,0,4,11,3,6,104,3,203,158,1538,3643,0,0,2,4,29,44,1,243,225,252,1249,3781,1,0,3,10,0,10,101,50,208,694,1375,698,1,3,6,3,15,1,123,174,242,629,274,3160,0,3,3,13,16,53,98,146,364,876,1285,51,1,2,4,12,17,2,126,68,106,1020,1693,904,0,3,6,7,17,33,41,147,87,419,1415,419,1,1,5,1,8,27,70,123,495,58,1899,610,0,2,0,5,18,43,116,228,326,449,1466,1552,0,1,7,1,15,33,83,218,4,325,554,569,0,1,0,3,31,33,8,5,441,927,970,2375,1,3,4,9,16,56,58,247,19,897,1280,2759,0,3,2,0,1,51,18,100,111,550,438,3241,0,2,4,14,24,25,16,219,458,430,660,1067,0,1,1,0,2,8,46,108,309,306,678,3047,1,0,5,1,10,2,20,7,87,421,1468,3424,1,1,3,4,28,27,63,39,143,222,1102,3994,0,3,6,11,8,46,64,78,67,873,906,2091,0,0,2,15,29,49,16,212,164,443,183,880,0,3,4,8,26,38,69,217,193,24,1824,886,1,1,7,10,28,22,80,165,359,136,1739,2086,0,1,4,1,1,52,100,186,438,459,631,3324,1,0,7,6,21,6,49,36,81,176,1784,2800,1,3,1,2,4,34,88,224,23,390,562,2122,0,0,2,1,22,0,61,204,451,102,1028,3818,1,2,4,9,23,43,67,240,399,50,1704,1120,1,0,6,1,12,7,13,90,483,334,1288,3624,0,3,1,15,2,51,26,0,455,604,1848,3342,0,1,0,2,2,24,67,140,373,380,1486,3981,1,1,2,9,30,62,99,149,257,682,184,3513,1,0,3,2,20,31,114,141,285,73,223,328,1,0,5,2,7,27,3,129,30,13,714,2815,0,2,0,1,26,25,126,77,276,646,1867,3283,1,2,4,2,20,10,79,50,392,990,1899,3846,1,3,3,4,1,47,52,64,106,607,513,2849,0,1,1,14,6,4,126,224,160,170,1439,85,1,3,5,10,5,37,17,21,482,337,1108,3587,0,2,7,15,2,14,68,155,375,23,683,2960,0,1,7,8,0,22,34,27,324,417,284,3807,1,0,6,15,3,11,7,195,72,922,912,701,0,1,7,2,4,39,38,52,199,966,1251,2390,1,0,1,8,23,9,6,53,237,379,1466,1880,0,3,5,1,3,60,104,198,438,146,423,3519,0,0,7,14,22,2,54,167,230,71,465,2022,1,0,3,5,28,36,44,100,86,401,1976,2602,0,2,5,5,16,18,14,131,509,328,726,3903,1,1,6,15,12,18,23,130,303,851,1301,208,0,0,0,15,30,22,57,137,119,994,948,2164,1,2,1,15,1,5,86,167,289,596,1109,3937,0,3,4,14,22,6,33,199,88,646,1932,1112,0,3,5,5,25,60,113,234,452,464,1317,2082,1,3,4,6,19,27,83,11,73,801,1041,2322,0,1,7,13,26,5,77,78,314,129,1504,2302,1,0,3,7,23,25,0,129,301,443,1416,3290,1,2,1,0,23,45,21,180,285,14,726,2134,0,1,4,5,25,27,11,207,138,711,1737,763,0,1,5,14,24,50,37,149,354,402,2018,2194,0,3,1,11,0,18,25,189,385,650,1858,3336,0,2,1,11,5,62,88,187,332,475,788,1301,0,0,0,0,2,35,127,10,229,233,1041,2707,0,2,6,11,14,54,78,181,485,274,323,588,0,1,4,3,7,50,1,182,106,372,577,3049,1,2,7,7,9,23,89,151,260,639,109,1207,1,1,6,13,5,41,82,184,494,130,1966,2404,0,1,5,15,29,0,92,26,351,735,1467,553,1,1,3,12,1,40,13,46,260,216,1429,2930,1,1,5,14,7,24,83,157,463,271,1736,2153,1,0,1,14,16,26,7,91,345,351,655,1696,1,2,6,11,24,1,5,0,178,703,207,3605,1,3,7,0,12,45,56,161,185,94,462,590,1,1,1,8,29,59,99,57,111,438,1096,96,0,0,2,2,17,15,29,218,205,18,1202,1898,1,0,6,4,18,0,120,29,78,892,611,281,1,2,6,1,10,12,47,7,372,42,350,2680,1,3,0,15,16,55,97,17,456,1006,1475,3305,1,2,0,9,1,37,59,55,204,475,728,2478,1,0,7,9,29,1,77,118,328,983,1661,3117,0,2,0,1,9,22,52,188,440,83,1127,2241,0,3,3,6,6,49,44,100,139,80,1241,3000,0,3,1,6,8,48,64,68,188,759,91,1862,0,3,4,4,2,22,67,57,90,842,1752,2193,0,0,0,9,17,34,56,152,209,474,1484,123,0,2,6,11,30,6,69,250,405,33,750,3246,0,1,6,7,20,13,108,146,6,72,1315,3495,1,1,6,7,18,35,61,142,24,313,641,2996,1,1,5,4,12,5,0,101,176,673,999,1399,1,2,0,3,24,50,115,249,31,988,1103,3914,0,3,0,8,7,57,69,190,471,311,2018,1485,0,1,2,6,27,11,43,69,397,834,687,592,1,1,2,2,22,48,126,120,166,322,277,3933,1,2,0,0,0,44,13,203,254,716,304,1177,1,3,0,13,14,2,78,247,405,646,571,413,0,1,1,12,24,63,55,95,1,422,691,1260,0,2,3,2,25,50,77,118,230,415,1545,1925,1,3,0,14,17,52,45,142,352,65,197,3005,1,2,1,6,10,41,86,110,413,887,965,3363,0,1,2,1,12,47,57,102,284,723,466,3212,0,3,4,15,1,8,87,72,223,660,534,3263,0,1,1,11,0,39,25,210,209,436,1969,900,0,2,0,1,19,7,42,84,334,459,198,2789,1,2,0,10,30,43,108,125,309,545,2004,114,0,0,5,11,27,42,60,181,433,157,223,1686,1,1,6,14,9,44,42,68,234,936,1382,1987,1,3,6,9,31,30,38,33,180,5,893,2351,0,1,5,3,7,63,52,89,428,835,1823,1694,0,0,3,9,10,49,80,249,259,643,1486,239,0,1,6,14,25,47,125,27,259,977,1440,2975,0,1,5,11,31,60,118,87,155,350,769,1191,0,3,2,2,22,36,48,2,76,357,1018,1676,0,0,1,6,3,33,86,212,421,748,1364,2596,0,3,0,11,1,59,5,38,70,952,1134,2134,0,3,7,0,11,39,125,219,301,202,1224,3191,1,3,5,14,13,23,127,23,330,983,503,1844,1,3,2,9,19,17,57,208,186,145,164,301,1,0,4,5,16,13,96,103,211,655,684,3653,0,1,5,4,14,41,8,248,246,164,1570,2967,1,0,2,15,25,14,85,25,246,810,1750,2045,0,2,4,10,21,52,103,122,181,595,349,569,0,1,5,9,2,40,59,123,84,668,22,3282,1,0,5,1,25,1,50,79,5,126,149,2521,1,0,2,5,26,42,19,28,322,1003,519,1813,1,2,1,8,30,48,82,207,178,141,300,2752,1,3,1,4,25,24,42,1,113,68,154,121,0,2,1,5,29,5,65,204,351,227,61,3363,1,3,4,12,24,10,85,143,189,333,156,356,0,1,4,3,17,23,1,183,290,813,832,1952,1,0,0,14,28,17,27,127,326,411,1098,3089,1,3,7,8,7,58,41,179,81,57,1499,2686,0,2,1,10,30,17,48,35,343,103,1458,524,0,0,7,6,7,62,40,131,330,208,1621,834,0,3,1,1,9,55,96,221,169,329,605,778,1,0,1,13,1,1,86,195,106,401,1640,1603,0,2,7,9,0,3,105,78,301,201,693,791]
\ No newline at end of file
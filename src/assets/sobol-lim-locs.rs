@@ -1 +0,0 @@
-&[0,0,1,3,5,8,11,15,19,23,27,31,35,40,45,50,55,60,65,71,77,83,89,95,101,107,113,119,125,131,137,143,149,155,161,167,173,180,187,194,201,208,215,222,229,236,243,250,257,264,271,278,285,293,301,309,317,325,333,341,349,357,365,373,381,389,397,405,413,421,429,437,445,453,461,469,477,485,493,501,509,517,525,533,541,549,557,565,573,581,589,597,605,613,621,629,637,645,653,661,669,678,687,696,705,714,723,732,741,750,759,768,777,786,795,804,813,822,831,840,849,858,867,876,885,894,903,912,921,930,939,948,957,966,975,984,993,1002,1011,1020,1029,1038,1047,1056,1065,1074,1083,1092,1101,1110,1119,1128,1137,1146,1155,1164,1173,1182,1191,1200,1209,1219,1229,1239,1249,1259,1269,1279,1289,1299,1309,1319,1329,1339,1349,1359,1369,1379,1389,1399,1409,1419,1429,1439,1449,1459,1469,1479,1489,1499,1509,1519,1529,1539,1549,1559,1569,1579,1589,1599,1609,1619,1629,1639,1649,1659,1669,1679,1689,1699,1709,1719,1729,1739,1749,1759,1769,1779,1789,1799,1809,1819,1829,1839,1849,1859,1869,1879,1889,1899,1909,1919,1929,1939,1949,1959,1969,1979,1989,1999,2009,2019,2029,2039,2049,2059,2069,2079,2089,2099,2109,2119,2129,2139,2149,2159,2169,2179,2189,2199,2209,2219,2229,2239,2249,2259,2269,2279,2289,2299,2309,2319,2329,2339,2349,2359,2369,2379,2389,2399,2409,2419,2429,2439,2449,2459,2469,2479,2489,2499,2509,2519,2529,2539,2549,2559,2569,2579,2589,2599,2609,2619,2629,2639,2649,2659,2669,2679,2689,2699,2709,2719,2729,2739,2749,2759,2769,2779,2789,2799,2809,2819,2829,2839,2849,2859,2869,2879,2889,2899,2909,2919,2929,2939,2949,2959,2969,2980,2991,3002,3013,3024,3035,3046,3057,3068,3079,3090,3101,3112,3123,3134,3145,3156,3167,3178,3189,3200,3211,3222,3233,3244,3255,3266,3277,3288,3299,3310,3321,3332,3343,3354,3365,3376,3387,3398,3409,3420,3431,3442,3453,3464,3475,3486,3497,3508,3519,3530,3541,3552,3563,3574,3585,3596,3607,3618,3629,3640,3651,3662,3673,3684,3695,3706,3717,3728,3739,3750,3761,3772,3783,3794,3805,3816,3827,3838,3849,3860,3871,3882,3893,3904,3915,3926,3937,3948,3959,3970,3981,3992,4003,4014,4025,4036,4047,4058,4069,4080,4091,4102,4113,4124,4135,4146,4157,4168,4179,4190,4201,4212,4223,4234,4245,4256,4267,4278,4289,4300,4311,4322,4333,4344,4355,4366,4377,4388,4399,4410,4421,4432,4443,4454,4465,4476,4487,4498,4509,4520,4531,4542,4553,4565,4577,4589,4601,4613,4625,4637,4649,4661,4673,4685,4697,4709,4721,4733,4745,4757,4769,4781,4793,4805,4817,4829,4841,4853,4865,4877,4889,4901,4913,4925,4937,4949,4961,4973,4985,4997,5009,5021,5033,5045,5057,5069,5081,5093,5105,5117,5129,5141,5153,5165,5177,5189,5201,5213,5225,5237,5249,5261,5273,5285,5297,5309,5321,5333,5345,5357,5369,5381,5393,5405,5417,5429,5441,5453,5465,5477,5489,5501,5513,5525,5537,5549,5561,5573,5585,5597,5609,5621,5633,5645,5657,5669,5681,5693,5705,5717,5729,5741,5753,5765,5777,5789,5801,5813,5825,5837,5849,5861,5873,5885,5897,5909,5921,5933,5945,5957,5969,5981,5993,6005,6017,6029,6041,6053,6065,6077,6089,6101,6113,6125,6137,6149,6161,6173,6185,6197,6209,6221,6233,6245,6257,6269,6281,6293,6305,6317,6329,6341,6353,6365,6377,6389,6401,6413,6425,6437,6449,6461,6473,6485,6497,6509,6521,6533,6545,6557,6569,6581,6593,6605,6617,6629,6641,6653,6665,6677,6689,6701,6713,6725,6737,6749,6761,6773,6785,6797,6809,6821,6833,6845,6857,6869,6881,6893,6905,6917,6929,6941,6953,6965,6977,6989,7001,7013,7025,7037,7049,7061,7073,7085,7097,7109,7121,7133,7145,7157,7169,7181,7193,7205,7217,7229,7241,7253,7265,7277,7289,7301,7313,7325,7337,7349,7361,7373,7385,7397,7409,7421,7433,7445,7457,7469,7481,7493,7505,7517,7529,7541,7553,7565,7577,7589,7601,7613,7625,7637,7649,7661,7673,7685,7697,7709,7721,7733,7745,7757,7769,7781,7793,7805,7817,7829,7841,7853,7865,7877,7889,7901,7913,7925,7937,7949,7961,7973,7985,7997,8009,8021,8033,8045,8057,8069,8081,8093,8105,8117,8129,8141,8153,8165,8177,8189,8201,8213,8225,8237,8249,8261,8273,8285,8297,8309,8321,8333,8345,8357,8369,8381,8393,8405,8417,8429,8441,8453,8465,8477,8489,8501,8513,8525,8537,8549,8561,8573,8585,8597,8609,8621,8633,8645,8657,8669,8681,8693,8705,8717,8729,8741,8753,8765,8777,8789,8801,8813,8825,8837,8849,8861,8873,8885,8897,8909,8921,8933,8945,8957,8969,8981,8993,9005,9017,9029,9041,9053,9065,9077,9089,9101,9113,9125,9137,9149,9161,9173,9185,9197,9209,9221,9233,9245,9257,9269,9281,9293,9305,9317,9329,9341,9353,9365,9377,9389,9401,9413,9425,9437,9449,9461,9473,9485,9497,9509,9521,9533,9545,9557,9569,9581,9593,9605,9617,9629,9641,9653,9665,9677,9689,9701,9713,9725,9737,9749,9761,9773,9785,9797,9809,9821,9833,9845,9857,9869,9881,9893,9905,9917,9929,9941,9953,9965,9977,9989,10001,10013,10025,10037,10049,10061,10073,10085,10097,10109,10121,10133,10145,10157,10169,10181,10193,10205,10217,10229,10241,10253,10265,10277,10289,10301,10313,10325,10337,10349,10361,10373,10385,10397,10409,10421,10433,10445,10457,10469,10481,10493,10505,10517,10529,10541,10553,10565,10577,10589,10601,10613,10625,10637,10649,10661,10673,10685,10697,10709,10721,10733,10745,10757,10769,10781,10793,10805,10817,10829,10841,10853,10865,10877,10889,10901,10913,10925,10937,10949,10961,10973,10985,10997,11009,11021,11033,11045,11057,11069,11081,11093,11105,11117,11129,11141,11153,11165,11177,11189,11201,11213,11225,11237,11249,11261,11273,11285,11297,11309,11321,11333,11345,11357,11369,11381,11393,11405,11417,11429,11441,11453,11465,11477,11489,11501,11513,11525,11537,11549,11561,11573,11585,11597,11609,11621,11633,11645,11657,11669,11681,11693,11705,11717,11729,11741,11753,11765,11777,11789,11801,11813,11825,11837,11849,11861,11873,11885,11897,11909,11921,11933,11945,11957,11969,11981,11993,12005,12017,12029,12041,12053,12065,12077,12089,12101,12113]
\ No newline at end of file
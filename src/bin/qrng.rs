@@ -0,0 +1,174 @@
+//! Command-line tool dumping points of quasi-random sequences to stdout.
+//!
+//! This binary is only built if the `cli` feature is enabled.
+
+use std::{
+    env,
+    io::{self, ErrorKind},
+    process,
+};
+
+use qrng::{
+    export, DynQRng, FaureSeq, GeneralizedHaltonSeq, HaltonSeq, KroneckerSeq, NiederreiterSeq,
+    QRng, QrngError, Randomize, SobolSeq,
+};
+
+const USAGE: &str = "\
+Usage: qrng <SEQUENCE> <NDIM> <N> [OPTIONS]
+
+Writes the first N points of a NDIM-dimensional quasi-random sequence to stdout.
+
+Sequences:
+    sobol, halton, niederreiter, faure, ghalton (generalized Halton),
+    kronecker (R_d sequence)
+
+Options:
+    --seed <SEED>       randomize the sequence (Owen scrambling for sobol and
+                        niederreiter, random shift otherwise)
+    --skip <K>          skip the first K points
+    --format <FORMAT>   csv (default), tsv, or binary (little-endian f64 values
+                        in row-major order)
+    -h, --help          print this message
+";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Format {
+    Csv,
+    Tsv,
+    Binary,
+}
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    seq: String,
+    ndim: usize,
+    n: u64,
+    seed: Option<u64>,
+    skip: u64,
+    format: Format,
+}
+
+fn parse_num<T: std::str::FromStr>(name: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("missing value of {}", name))?;
+    value.parse().map_err(|_| format!("invalid {}: {}", name, value))
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut args = args.into_iter();
+    let mut positional = Vec::new();
+    let (mut seed, mut skip, mut format) = (None, 0, Format::Csv);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = Some(parse_num("seed", args.next())?),
+            "--skip" => skip = parse_num("skip", args.next())?,
+            "--format" => {
+                format = match args.next().as_deref() {
+                    Some("csv") => Format::Csv,
+                    Some("tsv") => Format::Tsv,
+                    Some("binary") => Format::Binary,
+                    Some(f) => return Err(format!("invalid format: {}", f)),
+                    None => return Err("missing value of format".into()),
+                }
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option: {}", arg)),
+            _ => positional.push(arg),
+        }
+    }
+    if positional.len() != 3 {
+        return Err("expected exactly three arguments".into());
+    }
+    let mut positional = positional.into_iter();
+    let seq = positional.next().unwrap();
+    let ndim = parse_num("dimension", positional.next())?;
+    let n = parse_num("number of points", positional.next())?;
+    Ok(Args { seq, ndim, n, seed, skip, format })
+}
+
+fn boxed<R: Randomize + 'static>(seq: R, seed: Option<u64>) -> Box<dyn DynQRng> {
+    match seed {
+        Some(seed) => Box::new(seq.randomize(seed)),
+        None => Box::new(seq),
+    }
+}
+
+fn make_seq(args: &Args) -> Result<Box<dyn DynQRng>, String> {
+    let (ndim, seed) = (args.ndim, args.seed);
+    let err = |e: QrngError| e.to_string();
+    let seq = match args.seq.as_str() {
+        "sobol" => boxed(SobolSeq::try_new(ndim).map_err(err)?, seed),
+        "halton" => boxed(HaltonSeq::new(ndim), seed),
+        "niederreiter" => boxed(NiederreiterSeq::try_new(ndim).map_err(err)?, seed),
+        "faure" => boxed(FaureSeq::new(ndim), seed),
+        "ghalton" => boxed(GeneralizedHaltonSeq::try_new(ndim).map_err(err)?, seed),
+        "kronecker" => boxed(KroneckerSeq::roberts(ndim), seed),
+        name => return Err(format!("invalid sequence: {}", name)),
+    };
+    Ok(seq)
+}
+
+fn run() -> Result<(), String> {
+    let args: Vec<_> = env::args().skip(1).collect();
+    if args.iter().any(|a| a == "-h" || a == "--help") {
+        print!("{}", USAGE);
+        return Ok(());
+    }
+    let args = parse_args(args)?;
+    if args.ndim == 0 {
+        return Err(format!("invalid dimension: {}", args.ndim));
+    }
+    let mut seq = make_seq(&args)?;
+    seq.discard(args.skip);
+    let (n, out) = (args.n, io::stdout().lock());
+    let result = match args.format {
+        Format::Csv => export::write_csv(&mut seq, n, out),
+        Format::Tsv => export::write_tsv(&mut seq, n, out),
+        Format::Binary => export::write_f64_le(&mut seq, n, out),
+    };
+    match result {
+        // e.g. when piped into `head`
+        Err(e) if e.kind() == ErrorKind::BrokenPipe => Ok(()),
+        result => result.map_err(|e| e.to_string()),
+    }
+}
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("qrng: {}\n\n{}", e, USAGE);
+        process::exit(2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{make_seq, parse_args, Args, Format};
+    use qrng::{HaltonSeq, QRng, Randomize, SobolSeq};
+
+    fn args(s: &str) -> Result<Args, String> {
+        parse_args(s.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let expected =
+            Args { seq: "sobol".into(), ndim: 3, n: 10, seed: None, skip: 0, format: Format::Csv };
+        assert_eq!(args("sobol 3 10"), Ok(expected));
+        let a = args("halton --seed 7 2 5 --format binary --skip 100").unwrap();
+        assert_eq!((a.seq.as_str(), a.ndim, a.n), ("halton", 2, 5));
+        assert_eq!((a.seed, a.skip, a.format), (Some(7), 100, Format::Binary));
+        assert!(args("sobol 3").is_err());
+        assert!(args("sobol 3 x").is_err());
+        assert!(args("sobol 3 10 --format json").is_err());
+        assert!(args("sobol 3 10 --seed").is_err());
+        assert!(args("sobol 3 10 --verbose").is_err());
+    }
+
+    #[test]
+    fn test_make_seq() {
+        let mut seq = make_seq(&args("sobol 3 10 --seed 1").unwrap()).unwrap();
+        assert_eq!(seq.gen_points(100), SobolSeq::new(3).randomize(1).gen_points(100));
+        let mut seq = make_seq(&args("halton 2 10").unwrap()).unwrap();
+        assert_eq!(seq.gen_points(100), HaltonSeq::new(2).gen_points(100));
+        assert!(make_seq(&args("sobol 100000 1").unwrap()).is_err());
+        assert!(make_seq(&args("lattice 2 1").unwrap()).is_err());
+    }
+}
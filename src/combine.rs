@@ -0,0 +1,189 @@
+use crate::traits::QRng;
+
+/// Sequence generator concatenating the points of two generators.
+///
+/// Each point consists of the coordinates of the next point of the first generator,
+/// followed by the coordinates of the next point of the second one, so that the
+/// dimensionality is the sum of their dimensionalities. This allows mixing
+/// generator families, e.g. using a Sobol sequence for the most important
+/// dimensions and a Kronecker sequence for the rest.
+///
+/// Both generators are advanced in lockstep; the index of the stacked generator is
+/// that of the first one.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = Stacked::new(SobolSeq::new(2), HaltonSeq::new(3)).with_buf();
+/// assert_eq!(seq.ndim(), 5);
+/// assert_eq!(seq.gen(), &[0.5, 0.5, 0.5, 1. / 3., 0.2]);
+/// ```
+#[derive(Clone)]
+pub struct Stacked<A: QRng, B: QRng> {
+    a: A,
+    b: B,
+}
+
+impl<A: QRng, B: QRng> Stacked<A, B> {
+    /// Returns a new generator stacking the points of the two given generators.
+    #[inline]
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+
+    /// Returns references to the underlying generators.
+    #[inline]
+    pub fn inner(&self) -> (&A, &B) {
+        (&self.a, &self.b)
+    }
+}
+
+impl<A: QRng, B: QRng> QRng for Stacked<A, B> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.a.ndim() + self.b.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.a.reset();
+        self.b.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.a.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.a.gen_fill_unchecked(out);
+        self.b.gen_fill_unchecked(out.get_unchecked_mut(self.a.ndim()..));
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.a.discard(n);
+        self.b.discard(n);
+    }
+}
+
+/// Sequence generator exposing a subset of the coordinates of another generator.
+///
+/// The coordinates of each point are the coordinates of the next point of the
+/// underlying generator with the given indices, in the given order; the full
+/// underlying sequence is advanced on each step. Indices may be repeated.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = Project::new(HaltonSeq::new(5), &[4, 0]).with_buf();
+/// assert_eq!(seq.gen(), &[1. / 11., 0.5]);
+/// ```
+#[derive(Clone)]
+pub struct Project<R: QRng> {
+    seq: R,
+    dims: Vec<usize>,
+    buf: Vec<f64>,
+}
+
+impl<R: QRng> Project<R> {
+    /// Returns a new generator exposing the coordinates of the given sequence with
+    /// the given indices.
+    ///
+    /// Panics if any of the indices is not less than the dimensionality of the
+    /// sequence.
+    pub fn new(seq: R, dims: &[usize]) -> Self {
+        let ndim = seq.ndim();
+        if let Some(&dim) = dims.iter().find(|&&dim| dim >= ndim) {
+            panic!("invalid projection dimension: {}", dim);
+        }
+        Self { seq, dims: dims.to_vec(), buf: vec![0.; ndim] }
+    }
+
+    /// Returns the indices of the exposed coordinates.
+    #[inline]
+    pub fn dims(&self) -> &[usize] {
+        &self.dims
+    }
+
+    /// Returns a reference to the underlying (unprojected) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng> QRng for Project<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.dims.len()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(&mut self.buf);
+        for (i, &dim) in self.dims.iter().enumerate() {
+            *out.get_unchecked_mut(i) = *self.buf.get_unchecked(dim);
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Project, Stacked};
+    use crate::{KroneckerSeq, QRng, SobolSeq};
+
+    #[test]
+    fn test_stacked() {
+        let mut seq = Stacked::new(SobolSeq::new(3), KroneckerSeq::roberts(2)).with_buf();
+        let mut sobol = SobolSeq::new(3).with_buf();
+        let mut kronecker = KroneckerSeq::roberts(2).with_buf();
+        assert_eq!(seq.ndim(), 5);
+        seq.discard(3);
+        sobol.discard(3);
+        kronecker.discard(3);
+        for _ in 0..100 {
+            let x = seq.gen().to_vec();
+            assert_eq!(&x[..3], sobol.gen());
+            assert_eq!(&x[3..], kronecker.gen());
+        }
+        assert_eq!(seq.index(), 103);
+        seq.reset();
+        assert_eq!(seq.gen()[3..], *KroneckerSeq::roberts(2).with_buf().gen());
+    }
+
+    #[test]
+    fn test_project() {
+        let mut seq = Project::new(SobolSeq::new(20), &[12, 3, 7, 3]).with_buf();
+        let mut sobol = SobolSeq::new(20).with_buf();
+        assert_eq!(seq.ndim(), 4);
+        for _ in 0..100 {
+            let (x, y) = (seq.gen().to_vec(), sobol.gen());
+            assert_eq!(x, vec![y[12], y[3], y[7], y[3]]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_project_panic() {
+        Project::new(SobolSeq::new(3), &[0, 3]);
+    }
+}
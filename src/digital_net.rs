@@ -0,0 +1,468 @@
+use crate::{
+    scramble::{faure_tezuka_scramble, linear_scramble},
+    sobol::SobolSeq,
+    traits::{DigitalQRng, QRng},
+};
+
+/// Base-2 digital net generator with user-supplied generating matrices.
+///
+/// Each coordinate of the `i`-th point is obtained by multiplying the generating
+/// matrix of the corresponding dimension by the binary digits of `i` over GF(2).
+/// Points are generated in the Gray code order, same as in
+/// [`SobolSeq`](struct.SobolSeq.html), so each step only costs one XOR per
+/// dimension.
+///
+/// As with the Sobol sequence, the zero point is skipped at first. With `m`
+/// columns per matrix, the net contains `2^m` points: after `2^m - 1` nonzero
+/// points the zero point is generated, and then the net repeats itself.
+#[derive(Clone)]
+pub struct DigitalNet {
+    ndim: usize,
+    ncols: usize,
+    precision: u32,
+    /// Number of least significant digits dropped when converting to floats, so that
+    /// at most 53 digits remain (converting more could round values up to 1).
+    shift: u32,
+    scale: f64,
+    columns: Vec<u64>,
+    value: Vec<u64>,
+    index: u64,
+}
+
+impl DigitalNet {
+    /// Returns a new digital net generator from the given generating matrices.
+    ///
+    /// `matrices[j][k]` is the `k`-th column of the generating matrix for the `j`-th
+    /// dimension, stored as a `precision`-bit integer whose most significant bit
+    /// corresponds to the first row, i.e. to the first binary digit after the radix
+    /// point. This is the format used by the generating matrix files in the Magic
+    /// Point Shop (Kuo and Nuyens). With `precision` above 53, the digits beyond the
+    /// resolution of `f64` are truncated when generating points, so all coordinates
+    /// stay below 1.
+    ///
+    /// Panics if `precision` is not between 1 and 64, if the matrices don't have the
+    /// same number of columns, if there are more than 63 columns, or if any of the
+    /// columns doesn't fit into `precision` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// // two-dimensional Hammersley net with 4 points (identity and reversed identity)
+    /// let mut net = DigitalNet::new(2, &[vec![2, 1], vec![1, 2]]).with_buf();
+    /// assert_eq!(net.gen(), &[0.5, 0.25]);
+    /// ```
+    pub fn new(precision: u32, matrices: &[Vec<u64>]) -> Self {
+        if precision == 0 || precision > 64 {
+            panic!("invalid digital net precision: {}", precision);
+        }
+        let ndim = matrices.len();
+        let ncols = matrices.first().map_or(0, Vec::len);
+        if ncols > 63 {
+            panic!("invalid number of digital net matrix columns: {}", ncols);
+        }
+        let mut columns = vec![0; ndim * ncols];
+        for (j, m) in matrices.iter().enumerate() {
+            if m.len() != ncols {
+                panic!("invalid number of digital net matrix columns: {}", m.len());
+            }
+            for (k, &c) in m.iter().enumerate() {
+                if precision < 64 && c >> precision != 0 {
+                    panic!("invalid digital net matrix column: {}", c);
+                }
+                columns[k * ndim + j] = c;
+            }
+        }
+        let shift = precision.saturating_sub(53);
+        let scale = 0.5f64.powi((precision - shift) as _);
+        let value = vec![0; ndim];
+        Self { ndim, ncols, precision, shift, scale, columns, value, index: 0 }
+    }
+
+    /// Returns the digital net formed by the first `2^53` points of the Sobol sequence
+    /// with dimensionality `ndim`, with `53`-bit precision.
+    ///
+    /// Panics if `ndim` is greater than 1111 (or 21201 if `sobol-high-dim` feature
+    /// is enabled).
+    #[inline]
+    pub fn sobol(ndim: usize) -> Self {
+        Self::new(53, &SobolSeq::new(ndim).matrices())
+    }
+
+    /// Returns the interlaced digital net with interlacing factor `alpha`.
+    ///
+    /// Each group of `alpha` consecutive dimensions is combined into a single one by
+    /// interleaving their digits: the first digits of all of the coordinates in the
+    /// group come first, then the second digits, and so on. Interlacing an
+    /// `ndim * alpha`-dimensional net yields an `ndim`-dimensional higher-order
+    /// digital net, which achieves convergence rates of up to `O(n^(-alpha))` for
+    /// sufficiently smooth integrands ("Walsh Spaces Containing Smooth Functions and
+    /// Quasi-Monte Carlo Rules of Arbitrary High Order", Dick, 2008).
+    ///
+    /// The precision of the resulting net is `alpha` times the original one, capped
+    /// at 64 bits; the least significant digits are discarded if necessary.
+    ///
+    /// Panics if `alpha` is zero or if the dimensionality is not divisible by `alpha`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// // three-dimensional order-2 net obtained from six-dimensional Sobol net
+    /// let mut net = DigitalNet::sobol(6).interlace(2).with_buf();
+    /// assert_eq!(net.gen(), &[0.75, 0.75, 0.75]);
+    /// ```
+    pub fn interlace(&self, alpha: usize) -> Self {
+        if alpha == 0 || !self.ndim.is_multiple_of(alpha) {
+            panic!("invalid digital net interlacing factor: {}", alpha);
+        }
+        let precision = (alpha as u32 * self.precision).min(64);
+        let matrices: Vec<_> = (0..self.ndim / alpha)
+            .map(|j| {
+                (0..self.ncols)
+                    .map(|k| {
+                        let group = &self.columns[k * self.ndim + j * alpha..][..alpha];
+                        let mut c = 0u64;
+                        for p in 0..precision {
+                            // p-th output digit is the (p / alpha)-th digit of the (p % alpha)-th column
+                            let (r, i) = (p / alpha as u32, p as usize % alpha);
+                            let bit = (group[i] >> (self.precision - 1 - r)) & 1;
+                            c |= bit << (precision - 1 - p);
+                        }
+                        c
+                    })
+                    .collect()
+            })
+            .collect();
+        Self::new(precision, &matrices)
+    }
+
+    /// Returns the digital net with generating matrices randomized via random linear
+    /// matrix scrambling with the given seed.
+    ///
+    /// See [`SobolSeq::linear_scramble`](struct.SobolSeq.html#method.linear_scramble)
+    /// for details. The returned net starts from its first point.
+    pub fn linear_scramble(&self, seed: u64) -> Self {
+        let mut net = self.clone();
+        linear_scramble(&mut net.columns, self.ndim, self.precision, seed);
+        net.value.iter_mut().for_each(|x| *x = 0);
+        net.index = 0;
+        net
+    }
+
+    /// Returns the digital net with generating matrices randomized via Faure–Tezuka
+    /// scrambling with the given seed.
+    ///
+    /// See [`SobolSeq::faure_tezuka_scramble`](struct.SobolSeq.html#method.faure_tezuka_scramble)
+    /// for details. The returned net starts from its first point.
+    pub fn faure_tezuka_scramble(&self, seed: u64) -> Self {
+        let mut net = self.clone();
+        faure_tezuka_scramble(&mut net.columns, self.ndim, self.ncols, seed);
+        net.value.iter_mut().for_each(|x| *x = 0);
+        net.index = 0;
+        net
+    }
+
+    /// Returns the precision (number of rows) of the generating matrices.
+    #[inline]
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+
+    /// Returns the number of columns in each of the generating matrices.
+    ///
+    /// The net contains `2^ncols` points.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Returns the digital net formed by the given dimensions of this net, in the
+    /// given order.
+    ///
+    /// The returned net starts from its first point. Panics if any of the dimensions
+    /// is out of bounds.
+    pub fn select_dims(&self, dims: &[usize]) -> Self {
+        let matrices: Vec<Vec<u64>> = dims
+            .iter()
+            .map(|&j| {
+                if j >= self.ndim {
+                    panic!("invalid dimension: {}", j);
+                }
+                (0..self.ncols).map(|k| self.columns[k * self.ndim + j]).collect()
+            })
+            .collect();
+        Self::new(self.precision, &matrices)
+    }
+
+    /// Returns the quality parameter `t` of the first `2^m` points of the net.
+    ///
+    /// The first `2^m` points form a `(t, m, s)`-net in base 2, where `s` is the
+    /// dimensionality, if every elementary interval of volume `2^(t - m)` contains
+    /// exactly `2^t` points; the smaller `t` is, the better the net is distributed,
+    /// with `t = 0` being the best possible value. The exact value is computed from
+    /// the ranks of the generating matrices restricted to their first `m` columns
+    /// (Niederreiter, "Random Number Generation and Quasi-Monte Carlo Methods",
+    /// 1992, Theorem 4.28), which requires checking all ways of splitting up to
+    /// `m - t` rows between the dimensions; the cost therefore grows quickly with
+    /// both `m` and the dimensionality.
+    ///
+    /// Panics if `m` is greater than the number of columns of the matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let net = DigitalNet::sobol(3);
+    /// assert_eq!(net.select_dims(&[0, 1]).t_value(10), 0);
+    /// assert_eq!(net.t_value(10), 1);
+    /// ```
+    pub fn t_value(&self, m: usize) -> usize {
+        if m > self.ncols {
+            panic!("invalid number of digital net matrix columns: {}", m);
+        }
+        // rows[j][r] is the r-th row of the j-th matrix restricted to the first m columns
+        let nrows = (self.precision as usize).min(m);
+        let rows: Vec<Vec<u64>> = (0..self.ndim)
+            .map(|j| {
+                (0..nrows)
+                    .map(|r| {
+                        (0..m).fold(0, |row, k| {
+                            let c = self.columns[k * self.ndim + j];
+                            row | (((c >> (self.precision as usize - 1 - r)) & 1) << k)
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+        // linear independence of a set of rows implies it for all of its subsets, so
+        // the strength of the net is the largest q for which all splits are independent
+        let mut basis = [0u64; 64];
+        let mut q = 0;
+        while q < m && rows_independent(&rows, &mut basis, q + 1) {
+            q += 1;
+        }
+        m - q
+    }
+}
+
+/// Checks whether the first `d_j` rows of the `j`-th matrix taken together are
+/// linearly independent for all `d_0 + d_1 + ... <= budget`, given the echelon
+/// basis (indexed by the leading bit) of the rows of the preceding matrices.
+fn rows_independent(rows: &[Vec<u64>], basis: &mut [u64; 64], budget: usize) -> bool {
+    let (first, rest) = match rows.split_first() {
+        Some(split) if budget > 0 => split,
+        _ => return true,
+    };
+    if !rows_independent(rest, basis, budget) {
+        return false;
+    }
+    let saved = *basis;
+    let mut result = true;
+    for d in 1..=budget {
+        // a missing row (beyond the precision of the net) is treated as zero
+        let mut x = first.get(d - 1).copied().unwrap_or(0);
+        while x != 0 {
+            let bit = 63 - x.leading_zeros() as usize;
+            if basis[bit] == 0 {
+                basis[bit] = x;
+                break;
+            }
+            x ^= basis[bit];
+        }
+        if x == 0 || !rows_independent(rest, basis, budget - d) {
+            result = false;
+            break;
+        }
+    }
+    *basis = saved;
+    result
+}
+
+impl QRng for DigitalNet {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.index = 0;
+        self.value.iter_mut().for_each(|x| *x = 0);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        let c = (!self.index).trailing_zeros() as usize;
+        if c >= self.ncols {
+            // the last point of the net is the zero point
+            self.index = 0;
+            for j in 0..self.ndim {
+                *self.value.get_unchecked_mut(j) = 0;
+                *out.get_unchecked_mut(j) = 0.;
+            }
+            return;
+        }
+        let v = self.columns.get_unchecked(c * self.ndim..);
+        for j in 0..self.ndim {
+            let x = self.value.get_unchecked_mut(j);
+            *x ^= *v.get_unchecked(j);
+            *out.get_unchecked_mut(j) = ((*x >> self.shift) as f64) * self.scale;
+        }
+        self.index += 1;
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        // the period divides 2^64, so wrapping around is harmless
+        self.index = self.index.wrapping_add(n) & ((1 << self.ncols) - 1);
+        let gray = self.index ^ (self.index >> 1);
+        self.value.iter_mut().for_each(|x| *x = 0);
+        for k in (0..self.ncols).filter(|&k| (gray >> k) & 1 != 0) {
+            let v = &self.columns[k * self.ndim..(k + 1) * self.ndim];
+            self.value.iter_mut().zip(v).for_each(|(x, &v)| *x ^= v);
+        }
+    }
+}
+
+impl DigitalQRng for DigitalNet {
+    #[inline]
+    unsafe fn gen_fill_digits_unchecked(&mut self, out: &mut [u64]) {
+        let c = (!self.index).trailing_zeros() as usize;
+        if c >= self.ncols {
+            self.index = 0;
+            for j in 0..self.ndim {
+                *self.value.get_unchecked_mut(j) = 0;
+                *out.get_unchecked_mut(j) = 0;
+            }
+            return;
+        }
+        let shift = 64 - self.precision;
+        let v = self.columns.get_unchecked(c * self.ndim..);
+        for j in 0..self.ndim {
+            let x = self.value.get_unchecked_mut(j);
+            *x ^= *v.get_unchecked(j);
+            *out.get_unchecked_mut(j) = *x << shift;
+        }
+        self.index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DigitalNet;
+    use crate::{QRng, SobolSeq};
+
+    #[test]
+    fn test_digital_net_sobol() {
+        // first three dimensions of the Sobol sequence, with 8 columns each
+        let matrices = vec![
+            vec![128, 64, 32, 16, 8, 4, 2, 1],
+            vec![128, 192, 160, 240, 136, 204, 170, 255],
+            vec![128, 192, 96, 144, 232, 92, 142, 197],
+        ];
+        let mut net = DigitalNet::new(8, &matrices).with_buf();
+        let mut seq = SobolSeq::new(3).with_buf();
+        for _ in 0..255 {
+            assert_eq!(net.gen(), seq.gen());
+        }
+        assert_eq!(net.gen(), &[0., 0., 0.]);
+        let mut seq = SobolSeq::new(3).with_buf();
+        for _ in 0..255 {
+            assert_eq!(net.gen(), seq.gen());
+        }
+    }
+
+    #[test]
+    fn test_digital_net_full_precision() {
+        let net = DigitalNet::new(64, &[vec![1 << 63, 1 << 62]]);
+        assert_eq!(net.ncols(), 2);
+        let mut net = net.with_buf();
+        let points: Vec<_> = (0..4).map(|_| net.gen()[0]).collect();
+        assert_eq!(points, vec![0.5, 0.75, 0.25, 0.]);
+        // the digits beyond the resolution of f64 are truncated, not rounded up to 1
+        let mut net = DigitalNet::new(64, &[vec![u64::MAX]]);
+        assert_eq!(net.gen_points(1), [1. - f64::EPSILON / 2.]);
+        let mut net = DigitalNet::new(54, &[vec![(1 << 54) - 1]]);
+        assert_eq!(net.gen_points(1), [1. - f64::EPSILON / 2.]);
+    }
+
+    fn interlace_point(x: &[f64], alpha: usize) -> Vec<f64> {
+        // reference implementation operating directly on the binary digits of the points
+        x.chunks(alpha)
+            .map(|g| {
+                let (mut y, mut f) = (0., 0.5);
+                for r in 1..=(64 / alpha) {
+                    for &z in g {
+                        y += f * (((z * 2f64.powi(r as _)) as u64) & 1) as f64;
+                        f /= 2.;
+                    }
+                }
+                y
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_digital_net_interlace() {
+        let sobol = DigitalNet::sobol(6);
+        assert_eq!(sobol.precision(), 53);
+        for &alpha in &[1, 2, 3, 6] {
+            let net = sobol.interlace(alpha);
+            assert_eq!(net.ndim(), 6 / alpha);
+            assert_eq!(net.precision(), (53 * alpha as u32).min(64));
+            let mut net = net.with_buf();
+            let mut seq = SobolSeq::new(6).with_buf();
+            for _ in 0..1000 {
+                let x = interlace_point(seq.gen(), alpha);
+                for (a, b) in net.gen().iter().zip(&x) {
+                    assert!((a - b).abs() < 1e-15);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_digital_net_interlace_panic() {
+        DigitalNet::sobol(5).interlace(2);
+    }
+
+    #[test]
+    fn test_digital_net_t_value() {
+        // the Hammersley net and any pair of Sobol dimensions are (0, m, 2)-nets, while
+        // a repeated dimension only has strength 1
+        let hammersley = DigitalNet::new(3, &[vec![4, 2, 1], vec![1, 2, 4]]);
+        assert_eq!(hammersley.t_value(3), 0);
+        let sobol = DigitalNet::sobol(8);
+        for m in 0..16 {
+            assert_eq!(sobol.select_dims(&[0]).t_value(m), 0);
+            assert_eq!(sobol.select_dims(&[0, 1]).t_value(m), 0);
+            assert_eq!(sobol.select_dims(&[0, 0]).t_value(m), m.saturating_sub(1));
+        }
+        // the t-value never decreases with the dimensionality
+        let t: Vec<_> =
+            (1..=8).map(|s| sobol.select_dims(&(0..s).collect::<Vec<_>>()).t_value(8)).collect();
+        assert_eq!(t[..3], [0, 0, 1]);
+        assert!(t.windows(2).all(|w| w[0] <= w[1]));
+        // with fewer rows than m, the net can't be better than the precision allows
+        assert_eq!(DigitalNet::new(2, &[vec![2, 1, 0]]).t_value(3), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_digital_net_invalid_column() {
+        DigitalNet::new(4, &[vec![8, 16]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_digital_net_invalid_shape() {
+        DigitalNet::new(4, &[vec![8, 4], vec![8]]);
+    }
+}
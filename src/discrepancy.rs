@@ -0,0 +1,217 @@
+/// Checks the layout of the point set and returns the number of points.
+pub(crate) fn check_points(points: &[f64], ndim: usize) -> usize {
+    if ndim == 0 {
+        panic!("invalid dimension: {}", ndim);
+    }
+    if points.is_empty() || !points.len().is_multiple_of(ndim) {
+        panic!("invalid number of coordinates: {}", points.len());
+    }
+    points.len() / ndim
+}
+
+/// Returns the squared L2-star discrepancy of the point set, computed via
+/// Warnock's formula ("Computational investigations of low-discrepancy point sets",
+/// Warnock, 1972).
+///
+/// The points are given in row-major order, i.e. each `ndim` consecutive values
+/// form a point, and must lie in the unit hypercube. The L2-star discrepancy is the
+/// root mean square of the difference between the fraction of points in the box
+/// `[0, t)` and its volume, over all `t` in the unit hypercube. The computation
+/// takes `O(n^2 ndim)` time.
+///
+/// Like `scipy.stats.qmc.discrepancy`, the squared value is returned.
+///
+/// Panics if `ndim` is zero, or if the number of values is zero or not a multiple
+/// of `ndim`.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let points = SobolSeq::new(2).gen_points(256);
+/// let random: Vec<_> = (0..512).map(|i| ((i * 7919) % 1021) as f64 / 1021.).collect();
+/// assert!(l2_star_discrepancy(&points, 2) < l2_star_discrepancy(&random, 2));
+/// ```
+pub fn l2_star_discrepancy(points: &[f64], ndim: usize) -> f64 {
+    let n = check_points(points, ndim);
+    let nf = n as f64;
+    let single: f64 =
+        points.chunks_exact(ndim).map(|x| x.iter().map(|x| 1. - x * x).product::<f64>()).sum();
+    let mut pairs = 0.;
+    for (i, x) in points.chunks_exact(ndim).enumerate() {
+        // the sum is symmetric, so only the pairs with j < i are computed explicitly
+        pairs += x.iter().map(|x| 1. - x).product::<f64>();
+        for y in points.chunks_exact(ndim).take(i) {
+            pairs += 2. * x.iter().zip(y).map(|(x, y)| 1. - x.max(*y)).product::<f64>();
+        }
+    }
+    3f64.powi(-(ndim as i32)) - 2f64.powi(1 - ndim as i32) / nf * single + pairs / (nf * nf)
+}
+
+/// Returns the squared centered L2 discrepancy of the point set ("A generalized
+/// discrepancy and quadrature error bound", Hickernell, 1998).
+///
+/// The points are given in row-major order, i.e. each `ndim` consecutive values
+/// form a point, and must lie in the unit hypercube. Unlike the L2-star
+/// discrepancy, the centered discrepancy is invariant under reflections of the
+/// coordinates around `1/2`, i.e. it doesn't single out the origin. The computation
+/// takes `O(n^2 ndim)` time.
+///
+/// Like `scipy.stats.qmc.discrepancy` (with `method="CD"`), the squared value is
+/// returned.
+///
+/// Panics if `ndim` is zero, or if the number of values is zero or not a multiple
+/// of `ndim`.
+pub fn centered_l2_discrepancy(points: &[f64], ndim: usize) -> f64 {
+    let n = check_points(points, ndim);
+    let nf = n as f64;
+    let single: f64 = points
+        .chunks_exact(ndim)
+        .map(|x| {
+            x.iter()
+                .map(|x| {
+                    let d = (x - 0.5).abs();
+                    1. + 0.5 * d - 0.5 * d * d
+                })
+                .product::<f64>()
+        })
+        .sum();
+    let mut pairs = 0.;
+    for (i, x) in points.chunks_exact(ndim).enumerate() {
+        for (j, y) in points.chunks_exact(ndim).take(i + 1).enumerate() {
+            let p: f64 = x
+                .iter()
+                .zip(y)
+                .map(|(x, y)| {
+                    1. + 0.5 * (x - 0.5).abs() + 0.5 * (y - 0.5).abs() - 0.5 * (x - y).abs()
+                })
+                .product();
+            pairs += if i == j { p } else { 2. * p };
+        }
+    }
+    (13. / 12f64).powi(ndim as i32) - 2. / nf * single + pairs / (nf * nf)
+}
+
+/// Centered L2 discrepancy of a two-dimensional projection of a point set.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PairDiscrepancy {
+    /// Indices of the two dimensions of the projection, in increasing order.
+    pub dims: (usize, usize),
+    /// Squared centered L2 discrepancy of the projection.
+    pub discrepancy: f64,
+}
+
+/// Returns the squared centered L2 discrepancies of all two-dimensional projections
+/// of the point set, sorted from the worst to the best.
+///
+/// The points are given in row-major order, i.e. each `ndim` consecutive values
+/// form a point. Low-discrepancy sequences may have badly distributed projections
+/// onto some pairs of dimensions for small numbers of points (e.g. Halton sequences
+/// with large neighbouring prime bases), which can ruin the accuracy of integration
+/// of functions depending on these dimensions; the first few entries of the result
+/// point out such pairs. The computation takes `O(n^2 ndim^2)` time.
+///
+/// Panics if `ndim` is less than 2, or if the number of values is zero or not a
+/// multiple of `ndim`.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let points = HaltonSeq::new(18).gen_points(64);
+/// let worst = &pairwise_discrepancies(&points, 18)[0];
+/// assert!(worst.dims.0 >= 10);
+/// ```
+pub fn pairwise_discrepancies(points: &[f64], ndim: usize) -> Vec<PairDiscrepancy> {
+    if ndim < 2 {
+        panic!("invalid dimension: {}", ndim);
+    }
+    let n = check_points(points, ndim);
+    let mut proj = vec![0.; 2 * n];
+    let mut result = Vec::with_capacity(ndim * (ndim - 1) / 2);
+    for i in 0..ndim {
+        for j in i + 1..ndim {
+            for (p, x) in proj.chunks_exact_mut(2).zip(points.chunks_exact(ndim)) {
+                p[0] = x[i];
+                p[1] = x[j];
+            }
+            let discrepancy = centered_l2_discrepancy(&proj, 2);
+            result.push(PairDiscrepancy { dims: (i, j), discrepancy });
+        }
+    }
+    result.sort_by(|a, b| b.discrepancy.total_cmp(&a.discrepancy));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{centered_l2_discrepancy, l2_star_discrepancy, pairwise_discrepancies};
+    use crate::{QRng, SobolSeq};
+
+    #[test]
+    fn test_discrepancy_single_point() {
+        for &x in &[0., 0.25, 0.5, 0.9] {
+            let expected = x * x - x + 1. / 3.;
+            assert!((l2_star_discrepancy(&[x], 1) - expected).abs() < 1e-15);
+        }
+        assert!((centered_l2_discrepancy(&[0.5], 1) - 1. / 12.).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_l2_star_discrepancy_brute_force() {
+        let points = [0.1, 0.7, 0.4, 0.2, 0.8, 0.55, 0.3, 0.95];
+        // midpoint rule over the local discrepancy function
+        let m = 400;
+        let mut sum = 0.;
+        for a in 0..m {
+            for b in 0..m {
+                let t = [(a as f64 + 0.5) / m as f64, (b as f64 + 0.5) / m as f64];
+                let count = points.chunks(2).filter(|x| x[0] < t[0] && x[1] < t[1]).count();
+                let local = count as f64 / 4. - t[0] * t[1];
+                sum += local * local;
+            }
+        }
+        let expected = sum / (m * m) as f64;
+        assert!((l2_star_discrepancy(&points, 2) - expected).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_centered_l2_discrepancy() {
+        // example from the documentation of scipy.stats.qmc.discrepancy
+        let space = [1., 3., 2., 6., 3., 2., 4., 5., 5., 1., 6., 4.];
+        let points: Vec<_> = space.iter().map(|x| (x - 0.5) / 6.).collect();
+        assert!((centered_l2_discrepancy(&points, 2) - 0.008142039609053464).abs() < 1e-15);
+        // reflection invariance
+        let reflected: Vec<_> = points.iter().map(|x| 1. - x).collect();
+        let (a, b) = (centered_l2_discrepancy(&points, 2), centered_l2_discrepancy(&reflected, 2));
+        assert!((a - b).abs() < 1e-15);
+        let sobol = SobolSeq::new(3).gen_points(1024);
+        assert!(centered_l2_discrepancy(&sobol, 3) < centered_l2_discrepancy(&sobol[..384], 3));
+    }
+
+    #[test]
+    fn test_pairwise_discrepancies() {
+        // the first and the last dimensions are identical
+        let points: Vec<_> = SobolSeq::new(3)
+            .gen_points(128)
+            .chunks(3)
+            .flat_map(|x| vec![x[0], x[1], x[0]])
+            .collect();
+        let pairs = pairwise_discrepancies(&points, 3);
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs[0].dims, (0, 2));
+        assert!(pairs[0].discrepancy > 10. * pairs[1].discrepancy);
+        let mut proj = Vec::new();
+        for x in points.chunks(3) {
+            proj.extend_from_slice(&[x[1], x[2]]);
+        }
+        let expected = centered_l2_discrepancy(&proj, 2);
+        assert!(pairs.iter().any(|p| p.dims == (1, 2) && p.discrepancy == expected));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_discrepancy_panic() {
+        l2_star_discrepancy(&[0.5; 5], 2);
+    }
+}
@@ -0,0 +1,61 @@
+use core::fmt;
+
+/// Error returned by the fallible generator constructors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QrngError {
+    /// The requested dimensionality exceeds the maximum supported by the generator.
+    InvalidDimension {
+        /// Kind of the generator, e.g. `"Sobol sequence"`.
+        kind: &'static str,
+        /// Requested dimensionality.
+        ndim: usize,
+        /// Maximum supported dimensionality.
+        max_dim: usize,
+    },
+    /// One of the construction parameters is out of its valid range.
+    InvalidParameter {
+        /// Description of the parameter, e.g. `"Halton sequence leap"`.
+        name: &'static str,
+        /// Value of the parameter.
+        value: u64,
+    },
+}
+
+impl fmt::Display for QrngError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            QrngError::InvalidDimension { kind, ndim, max_dim } => {
+                write!(f, "invalid {} dimension: {} (maximum is {})", kind, ndim, max_dim)
+            }
+            QrngError::InvalidParameter { name, value } => write!(f, "invalid {}: {}", name, value),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for QrngError {}
+
+/// Checks that the dimensionality doesn't exceed the maximum.
+#[cfg(any(feature = "std", feature = "heapless", test))]
+#[inline]
+pub(crate) fn check_ndim(kind: &'static str, ndim: usize, max_dim: usize) -> Result<(), QrngError> {
+    if ndim > max_dim {
+        Err(QrngError::InvalidDimension { kind, ndim, max_dim })
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_ndim, QrngError};
+
+    #[test]
+    fn test_qrng_error() {
+        let err = check_ndim("Sobol sequence", 2000, 1111).unwrap_err();
+        assert_eq!(err.to_string(), "invalid Sobol sequence dimension: 2000 (maximum is 1111)");
+        assert!(check_ndim("Sobol sequence", 1111, 1111).is_ok());
+        let err = QrngError::InvalidParameter { name: "Halton sequence leap", value: 0 };
+        assert_eq!(err.to_string(), "invalid Halton sequence leap: 0");
+    }
+}
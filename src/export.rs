@@ -0,0 +1,193 @@
+//! Streaming export of generated points to files, pipes and other writers.
+//!
+//! The points are generated and written in chunks of bounded size, so arbitrarily
+//! large point sets can be exported without materializing them in memory. Since
+//! each chunk is written with a single call, wrapping the writer into a
+//! `BufWriter` is not necessary. All functions advance the generator by `n` points
+//! (or less, if writing fails midway).
+
+use std::io::{self, Write};
+
+use crate::traits::QRng;
+
+/// Maximum number of values generated and written at once.
+const CHUNK_LEN: usize = 1 << 14;
+
+/// Returns the number of points generated at once.
+#[inline]
+fn chunk_len(ndim: usize) -> usize {
+    (CHUNK_LEN / ndim.max(1)).max(1)
+}
+
+fn write_chunks<R: QRng, W: Write>(
+    seq: &mut R, n: u64, mut out: W, mut write_chunk: impl FnMut(&[f64], usize, &mut Vec<u8>),
+) -> io::Result<()> {
+    let ndim = seq.ndim();
+    let chunk = chunk_len(ndim);
+    let mut points = vec![0.; chunk * ndim];
+    let mut bytes = Vec::new();
+    let mut left = n;
+    while left != 0 {
+        let count = left.min(chunk as u64) as usize;
+        let points = &mut points[..count * ndim];
+        seq.gen_points_into(count, points);
+        bytes.clear();
+        write_chunk(points, count, &mut bytes);
+        out.write_all(&bytes)?;
+        left -= count as u64;
+    }
+    out.flush()
+}
+
+fn write_delimited<R: QRng, W: Write>(seq: &mut R, n: u64, out: W, sep: u8) -> io::Result<()> {
+    let ndim = seq.ndim();
+    write_chunks(seq, n, out, |points, count, bytes| {
+        // zero-dimensional points are written as empty lines
+        for point in (0..count).map(|i| &points[i * ndim..(i + 1) * ndim]) {
+            for (j, x) in point.iter().enumerate() {
+                if j != 0 {
+                    bytes.push(sep);
+                }
+                // writing to a vector never fails
+                let _ = write!(bytes, "{}", x);
+            }
+            bytes.push(b'\n');
+        }
+    })
+}
+
+/// Writes the next `n` points of the sequence as CSV, one point per line, with
+/// comma-separated coordinates and no header.
+///
+/// The values are written in the shortest form that parses back into the same
+/// `f64`, so no precision is lost.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut out = Vec::new();
+/// export::write_csv(&mut SobolSeq::new(2), 2, &mut out).unwrap();
+/// assert_eq!(out, b"0.5,0.5\n0.75,0.25\n");
+/// ```
+pub fn write_csv<R: QRng, W: Write>(seq: &mut R, n: u64, out: W) -> io::Result<()> {
+    write_delimited(seq, n, out, b',')
+}
+
+/// Same as [`write_csv`], but the coordinates are separated by tabs.
+pub fn write_tsv<R: QRng, W: Write>(seq: &mut R, n: u64, out: W) -> io::Result<()> {
+    write_delimited(seq, n, out, b'\t')
+}
+
+/// Writes the next `n` points of the sequence as raw little-endian `f64` values in
+/// row-major order, i.e. `8 * n * ndim` bytes in total.
+///
+/// The output can be loaded e.g. with `numpy.fromfile(path, "<f8").reshape(-1, ndim)`.
+pub fn write_f64_le<R: QRng, W: Write>(seq: &mut R, n: u64, out: W) -> io::Result<()> {
+    write_chunks(seq, n, out, |points, _, bytes| {
+        bytes.extend(points.iter().flat_map(|x| x.to_le_bytes()));
+    })
+}
+
+/// Writes the next `n` points of the sequence in Parquet format, with one
+/// non-nullable `DOUBLE` column per dimension, named `x0`, `x1`, and so on (the
+/// same as in [`QRng::gen_record_batch`]).
+///
+/// The points are generated and handed over to the Parquet writer in batches of
+/// bounded size; the file footer is written before returning.
+///
+/// This function is only available if the `parquet` feature is enabled.
+#[cfg(feature = "parquet")]
+pub fn write_parquet<R: QRng, W: Write + Send>(
+    seq: &mut R, n: u64, out: W,
+) -> parquet::errors::Result<()> {
+    let ndim = seq.ndim();
+    let schema = crate::arrow::points_schema(ndim);
+    let mut writer = parquet::arrow::ArrowWriter::try_new(out, schema, None)?;
+    let chunk = chunk_len(ndim);
+    let mut left = n;
+    while left != 0 {
+        let count = left.min(chunk as u64) as usize;
+        writer.write(&seq.gen_record_batch(count))?;
+        left -= count as u64;
+    }
+    writer.close()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::TryInto, io};
+
+    use super::{write_csv, write_f64_le, write_tsv};
+    use crate::{HaltonSeq, QRng, SobolSeq};
+
+    #[test]
+    fn test_write_delimited() {
+        let mut seq = HaltonSeq::new(3);
+        let mut out = Vec::new();
+        write_csv(&mut seq, 5000, &mut out).unwrap();
+        assert_eq!(seq.index(), 5000);
+        let text = String::from_utf8(out).unwrap();
+        let values: Vec<f64> =
+            text.lines().flat_map(|line| line.split(',')).map(|x| x.parse().unwrap()).collect();
+        assert_eq!(values, HaltonSeq::new(3).gen_points(5000));
+        let mut out = Vec::new();
+        write_tsv(&mut SobolSeq::new(3), 2, &mut out).unwrap();
+        assert_eq!(out, b"0.5\t0.5\t0.5\n0.75\t0.25\t0.25\n");
+        let mut seq = HaltonSeq::new(0);
+        let mut out = Vec::new();
+        write_csv(&mut seq, 3, &mut out).unwrap();
+        assert_eq!(out, b"\n\n\n");
+        assert_eq!(seq.index(), 3);
+    }
+
+    #[test]
+    fn test_write_f64_le() {
+        let mut out = Vec::new();
+        write_f64_le(&mut SobolSeq::new(1000), 100, &mut out).unwrap();
+        assert_eq!(out.len(), 8 * 100 * 1000);
+        let values: Vec<_> =
+            out.chunks(8).map(|b| f64::from_le_bytes(b.try_into().unwrap())).collect();
+        assert_eq!(values, SobolSeq::new(1000).gen_points(100));
+    }
+
+    #[test]
+    fn test_write_error() {
+        struct Failing;
+        impl io::Write for Failing {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::ErrorKind::BrokenPipe.into())
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let err = write_csv(&mut SobolSeq::new(2), 10, Failing).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_write_parquet() {
+        use arrow_array::{cast::AsArray, types::Float64Type};
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let path = std::env::temp_dir().join("qrng-test-export.parquet");
+        let file = std::fs::File::create(&path).unwrap();
+        super::write_parquet(&mut SobolSeq::new(3), 10000, file).unwrap();
+        let file = std::fs::File::open(&path).unwrap();
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file).unwrap().build().unwrap();
+        let mut columns = vec![Vec::new(); 3];
+        for batch in reader {
+            let batch = batch.unwrap();
+            for (j, column) in columns.iter_mut().enumerate() {
+                let name = format!("x{}", j);
+                let array = batch.column_by_name(&name).unwrap().as_primitive::<Float64Type>();
+                column.extend_from_slice(array.values());
+            }
+        }
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(columns.concat(), SobolSeq::new(3).gen_points_col_major(10000));
+    }
+}
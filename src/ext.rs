@@ -0,0 +1,303 @@
+use std::iter::Take;
+
+#[cfg(feature = "rand")]
+use crate::rng::SampleIter;
+use crate::{
+    combine::{Project, Stacked},
+    iter::QRngIter,
+    traits::QRng,
+    transform::{Scaled, Transformed},
+};
+
+/// Extension trait with combinators for composing sequence generators.
+///
+/// This trait is implemented for all generators.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let batches: Vec<_> = SobolSeq::new(2)
+///     .skip_points(16)
+///     .map_points(|x: &mut [f64]| x.iter_mut().for_each(|x| *x = 2. * *x - 1.))
+///     .chunks(8)
+///     .take(4)
+///     .collect();
+/// assert_eq!(batches.len(), 4);
+/// assert_eq!(batches[0].len(), 16);
+/// ```
+pub trait QRngExt: QRng {
+    /// Returns an iterator over the first `n` points of the sequence.
+    #[inline]
+    fn take_points(self, n: usize) -> Take<QRngIter<Self>> {
+        self.iter_points().take(n)
+    }
+
+    /// Returns a generator skipping the first `n` points of the sequence.
+    ///
+    /// Resetting the returned generator moves it back to the point with index `n`.
+    #[inline]
+    fn skip_points(self, n: u64) -> SkipPoints<Self> {
+        SkipPoints::new(self, n)
+    }
+
+    /// Returns a generator applying `f` in place to each generated point.
+    ///
+    /// The function must not change the length of the point.
+    #[inline]
+    fn map_points<F: FnMut(&mut [f64]) + Clone>(self, f: F) -> MapPoints<Self, F> {
+        MapPoints::new(self, f)
+    }
+
+    /// Returns an infinite iterator over batches of `k` points, each batch returned
+    /// as a flat vector of `k * ndim` values in row-major order.
+    ///
+    /// Panics if `k` is zero.
+    #[inline]
+    fn chunks(self, k: usize) -> PointChunks<Self> {
+        PointChunks::new(self, k)
+    }
+
+    /// Returns a generator concatenating the points of this generator with those of
+    /// another one (see [`Stacked`](struct.Stacked.html)).
+    #[inline]
+    fn stack<B: QRng>(self, other: B) -> Stacked<Self, B> {
+        Stacked::new(self, other)
+    }
+
+    /// Returns a generator exposing the coordinates of this generator with the given
+    /// indices (see [`Project`](struct.Project.html)).
+    #[inline]
+    fn project(self, dims: &[usize]) -> Project<Self> {
+        Project::new(self, dims)
+    }
+
+    /// Returns a generator mapping the points of this generator onto the
+    /// hyperrectangle with the given bounds (see [`Scaled`](struct.Scaled.html)).
+    #[inline]
+    fn scale(self, lows: &[f64], highs: &[f64]) -> Scaled<Self> {
+        Scaled::new(self, lows, highs)
+    }
+
+    /// Returns a generator mapping the `j`-th coordinate `x` of each point to
+    /// `f(j, x)`, e.g. a per-dimension quantile function (see
+    /// [`Transformed`](struct.Transformed.html)).
+    #[inline]
+    fn transform<F: Fn(usize, f64) -> f64 + Clone>(self, f: F) -> Transformed<Self, F> {
+        Transformed::new(self, f)
+    }
+
+    /// Returns an iterator over the points of the sequence with each coordinate
+    /// mapped through the given distribution (see [`SampleIter`](struct.SampleIter.html);
+    /// the iterator is infinite unless the distribution consumes more than one value
+    /// per draw).
+    ///
+    /// This method is only available if the `rand` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// use rand::distributions::Uniform;
+    ///
+    /// let points: Vec<_> = SobolSeq::new(2).sample_iter(Uniform::new(0., 10.)).take(2).collect();
+    /// assert_eq!(points, vec![vec![5., 5.], vec![7.5, 2.5]]);
+    /// ```
+    #[cfg(feature = "rand")]
+    #[inline]
+    fn sample_iter<D: rand::distributions::Distribution<T>, T>(
+        self, dist: D,
+    ) -> SampleIter<Self, D, T> {
+        SampleIter::new(self, dist)
+    }
+}
+
+impl<R: QRng> QRngExt for R {}
+
+/// Sequence generator skipping a number of initial points.
+///
+/// This struct is created by the [`skip_points`](trait.QRngExt.html#method.skip_points)
+/// method on generators. The index of the skipped generator is relative to the
+/// first non-skipped point.
+#[derive(Clone)]
+pub struct SkipPoints<R: QRng> {
+    seq: R,
+    n: u64,
+}
+
+impl<R: QRng> SkipPoints<R> {
+    #[inline]
+    fn new(mut seq: R, n: u64) -> Self {
+        seq.discard(n);
+        Self { seq, n }
+    }
+
+    /// Returns a reference to the underlying sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng> QRng for SkipPoints<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+        self.seq.discard(self.n);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index().wrapping_sub(self.n)
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(out);
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+/// Sequence generator applying a function in place to each point.
+///
+/// This struct is created by the [`map_points`](trait.QRngExt.html#method.map_points)
+/// method on generators.
+#[derive(Clone)]
+pub struct MapPoints<R: QRng, F> {
+    seq: R,
+    f: F,
+}
+
+impl<R: QRng, F: FnMut(&mut [f64]) + Clone> MapPoints<R, F> {
+    #[inline]
+    fn new(seq: R, f: F) -> Self {
+        Self { seq, f }
+    }
+
+    /// Returns a reference to the underlying (unmapped) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng, F: FnMut(&mut [f64]) + Clone> QRng for MapPoints<R, F> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(out);
+        (self.f)(out.get_unchecked_mut(..self.seq.ndim()));
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+/// Infinite iterator over batches of points of a sequence.
+///
+/// This struct is created by the [`chunks`](trait.QRngExt.html#method.chunks)
+/// method on generators.
+#[derive(Clone)]
+pub struct PointChunks<R: QRng> {
+    seq: R,
+    k: usize,
+}
+
+impl<R: QRng> PointChunks<R> {
+    #[inline]
+    fn new(seq: R, k: usize) -> Self {
+        if k == 0 {
+            panic!("invalid chunk size: {}", k);
+        }
+        Self { seq, k }
+    }
+
+    /// Returns the underlying sequence.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.seq
+    }
+}
+
+impl<R: QRng> Iterator for PointChunks<R> {
+    type Item = Vec<f64>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let ndim = self.seq.ndim();
+        let mut out = vec![0.; self.k * ndim];
+        if ndim != 0 {
+            for x in out.chunks_exact_mut(ndim) {
+                unsafe { self.seq.gen_fill_unchecked(x) };
+            }
+        }
+        Some(out)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QRngExt;
+    use crate::{HaltonSeq, QRng};
+
+    #[test]
+    fn test_skip_points() {
+        let mut seq = HaltonSeq::new(2).skip_points(10).with_buf();
+        let mut halton = HaltonSeq::new(2).with_buf();
+        halton.discard(10);
+        assert_eq!(seq.index(), 0);
+        let first = seq.gen().to_vec();
+        assert_eq!(first, halton.gen());
+        seq.gen();
+        seq.reset();
+        assert_eq!(seq.gen(), first.as_slice());
+    }
+
+    #[test]
+    fn test_map_points_chunks() {
+        let points: Vec<_> = HaltonSeq::new(3).take_points(6).collect();
+        let chunks: Vec<_> =
+            HaltonSeq::new(3).map_points(|x: &mut [f64]| x.reverse()).chunks(2).take(3).collect();
+        for (i, point) in points.iter().enumerate() {
+            let mut mapped = chunks[i / 2][(i % 2) * 3..(i % 2 + 1) * 3].to_vec();
+            mapped.reverse();
+            assert_eq!(&mapped, point);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chunks_panic() {
+        HaltonSeq::new(2).chunks(0);
+    }
+}
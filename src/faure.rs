@@ -0,0 +1,246 @@
+use crate::{
+    error::{check_ndim, QrngError},
+    traits::QRng,
+    util::primes,
+};
+
+const MAX_LOG_N: usize = 48;
+// largest prime below 2^16, so that the base always fits in 16 bits
+const MAX_DIM: usize = 65521;
+
+/// Faure low-discrepancy sequence generator.
+///
+/// The base `b` is the smallest prime such that `b >= ndim`. The first coordinate
+/// is the van der Corput sequence in base `b`; each subsequent coordinate is
+/// obtained by applying the Pascal matrix (modulo `b`) to the base-`b` digits of
+/// the previous one ("Discrépance de suites associées à un système de numération
+/// (en dimension s)", Faure, 1982).
+///
+/// As with [`HaltonSeq`](struct.HaltonSeq.html), the zero point is skipped.
+#[derive(Clone)]
+pub struct FaureSeq {
+    ndim: usize,
+    base: u32,
+    index: u64,
+    digits: Vec<u32>,
+    binom: Vec<Vec<u32>>,
+    work: Vec<u32>,
+}
+
+impl FaureSeq {
+    /// Returns a new Faure sequence generator with dimensionality `ndim`.
+    ///
+    /// Panics if `ndim` is greater than 65521.
+    #[inline]
+    pub fn new(ndim: usize) -> Self {
+        Self::try_new(ndim).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as [`new`](#method.new), but returns an error instead of panicking.
+    #[inline]
+    pub fn try_new(ndim: usize) -> Result<Self, QrngError> {
+        check_ndim("Faure sequence", ndim, MAX_DIM)?;
+        let base = primes().find(|&p| p >= ndim as u64).unwrap() as u32;
+        Ok(Self { ndim, base, index: 0, digits: vec![], binom: vec![], work: vec![] })
+    }
+
+    /// Returns the prime base of the sequence.
+    #[inline]
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+
+    /// Appends a zero digit to the index, growing the binomial table when needed.
+    #[inline]
+    fn push_digit(&mut self) {
+        let i = self.digits.len();
+        self.digits.push(0);
+        self.work.push(0);
+        if self.binom.len() <= i {
+            // binomial coefficients modulo base, binom[i][j] = C(i, j) mod b
+            let (binom, base) = (&self.binom, self.base);
+            let row = (0..=i)
+                .map(|j| match j {
+                    0 => 1,
+                    j if j == i => 1,
+                    j => (binom[i - 1][j - 1] + binom[i - 1][j]) % base,
+                })
+                .collect();
+            self.binom.push(row);
+        }
+    }
+
+    /// Increments the base-`b` digits of the index, growing tables when needed.
+    #[inline]
+    fn increment(&mut self) {
+        let mut i = 0;
+        loop {
+            if i == self.digits.len() {
+                self.push_digit();
+            }
+            self.digits[i] += 1;
+            if self.digits[i] < self.base {
+                break;
+            }
+            self.digits[i] = 0;
+            i += 1;
+        }
+    }
+
+    #[inline]
+    fn radical_inverse(&self) -> f64 {
+        let base_f = f64::from(self.base);
+        self.work.iter().rev().fold(0., |r, &y| (r + f64::from(y)) / base_f)
+    }
+}
+
+impl QRng for FaureSeq {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.index = 0;
+        self.digits.clear();
+        self.work.clear();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        if self.index >= (1 << MAX_LOG_N) {
+            self.reset();
+        }
+        self.index += 1;
+        self.increment();
+
+        let base = u64::from(self.base);
+        let m = self.digits.len();
+        self.work.copy_from_slice(&self.digits);
+        for d in 0..self.ndim {
+            if d != 0 {
+                // apply the Pascal matrix in place: y_j = sum_{i >= j} C(i, j) * x_i (mod b)
+                for j in 0..m {
+                    let mut y = 0;
+                    for i in j..m {
+                        y += u64::from(self.binom[i][j]) * u64::from(self.work[i]);
+                    }
+                    self.work[j] = (y % base) as u32;
+                }
+            }
+            *out.get_unchecked_mut(d) = self.radical_inverse();
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        let index = (u128::from(self.index) + u128::from(n)) % (1 << MAX_LOG_N);
+        self.reset();
+        self.index = index as u64;
+        let base = u64::from(self.base);
+        let mut rest = self.index;
+        while rest != 0 {
+            self.push_digit();
+            *self.digits.last_mut().unwrap() = (rest % base) as u32;
+            rest /= base;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FaureSeq;
+    use crate::{QRng, QrngError};
+
+    const TOL: f64 = 1e-15;
+
+    #[test]
+    fn test_faure_seq_base() {
+        assert_eq!(FaureSeq::new(0).base(), 2);
+        assert_eq!(FaureSeq::new(2).base(), 2);
+        assert_eq!(FaureSeq::new(3).base(), 3);
+        assert_eq!(FaureSeq::new(4).base(), 5);
+        assert_eq!(FaureSeq::new(14).base(), 17);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid Faure sequence dimension: 65522 (maximum is 65521)")]
+    fn test_faure_seq_invalid_ndim() {
+        assert!(FaureSeq::try_new(65521).is_ok());
+        let err = FaureSeq::try_new(65522).err().unwrap();
+        assert_eq!(
+            err,
+            QrngError::InvalidDimension { kind: "Faure sequence", ndim: 65522, max_dim: 65521 }
+        );
+        FaureSeq::new(65522);
+    }
+
+    #[test]
+    fn test_faure_seq_discard() {
+        for &ndim in &[1, 3, 4] {
+            let mut seq = FaureSeq::new(ndim);
+            let b = u64::from(seq.base());
+            let mut expected = seq.clone();
+            let mut pos = 0;
+            for &n in &[0, 1, b - 2, 1, b * b - 1, 0, b * b * b, 7] {
+                seq.discard(n);
+                expected.gen_points(n as usize);
+                pos += n;
+                assert_eq!(seq.index(), pos);
+                assert_eq!(seq.clone().gen_points(2), expected.clone().gen_points(2));
+            }
+            seq.reset();
+            expected.reset();
+            assert_eq!(seq.gen_points(2), expected.gen_points(2));
+        }
+    }
+
+    #[test]
+    fn test_faure_seq_base3() {
+        let expected = vec![
+            [1. / 3., 1. / 3., 1. / 3.],
+            [2. / 3., 2. / 3., 2. / 3.],
+            [1. / 9., 4. / 9., 7. / 9.],
+            [4. / 9., 7. / 9., 1. / 9.],
+            [7. / 9., 1. / 9., 4. / 9.],
+            [2. / 9., 8. / 9., 5. / 9.],
+            [5. / 9., 2. / 9., 8. / 9.],
+            [8. / 9., 5. / 9., 2. / 9.],
+            [1. / 27., 16. / 27., 13. / 27.],
+        ];
+        let mut seq = FaureSeq::new(3).with_buf();
+        for e in &expected {
+            let x = seq.gen();
+            for (a, b) in x.iter().zip(e.iter()) {
+                assert!((a - b).abs() < TOL);
+            }
+        }
+    }
+
+    #[test]
+    fn test_faure_seq_mean() {
+        for &ndim in &[2, 3, 5, 7] {
+            let seq = FaureSeq::new(ndim);
+            let b = seq.base() as usize;
+            let mut seq = seq.with_buf();
+            // b^4 - 1 points (zero excluded) form a full net, so every coordinate
+            // is a permutation of k / b^4 and the mean is exactly one half
+            let len = b.pow(4) - 1;
+            let mut sum = vec![0.; ndim];
+            for _ in 0..len {
+                for (i, &x) in seq.gen().iter().enumerate() {
+                    sum[i] += x;
+                }
+            }
+            for s in sum {
+                assert!((s / (len as f64) - 0.5).abs() < 1e-12);
+            }
+        }
+    }
+}
@@ -0,0 +1,386 @@
+//! C-compatible interface to the most commonly used generators.
+//!
+//! Each generator is exposed as an opaque pointer, with a set of `extern "C"`
+//! functions to create it, destroy it and generate points; the corresponding C
+//! declarations are in `include/qrng.h` (which can be regenerated with `cbindgen`
+//! using the `cbindgen.toml` config in the root of the repository). Constructors
+//! return a null pointer instead of panicking if the arguments are invalid, and all
+//! other functions accept null pointers, treating them as no-ops.
+//!
+//! Points are always generated in bulk, into caller-provided buffers in row-major
+//! order, so there's no overhead per point (which matters e.g. when called from
+//! Python via `ctypes`).
+//!
+//! The shared library can be built with `cargo rustc --release --features ffi
+//! --crate-type cdylib`; the crate type isn't declared in the manifest, since
+//! dependents would then have to link it too, which fails in `no_std` builds.
+//!
+//! This module is only available if the `ffi` feature is enabled.
+
+use std::slice;
+
+use crate::{
+    halton::HaltonSeq, niederreiter::NiederreiterSeq, scramble::OwenScrambled, sobol::SobolSeq,
+    traits::QRng,
+};
+
+/// Owen-scrambled Sobol sequence generator.
+pub type ScrambledSobolSeq = OwenScrambled<SobolSeq>;
+
+#[inline]
+fn into_raw<R>(seq: Option<R>) -> *mut R {
+    seq.map_or(std::ptr::null_mut(), |seq| Box::into_raw(Box::new(seq)))
+}
+
+#[inline]
+unsafe fn free<R>(seq: *mut R) {
+    if !seq.is_null() {
+        drop(Box::from_raw(seq));
+    }
+}
+
+#[inline]
+unsafe fn ndim<R: QRng>(seq: *const R) -> usize {
+    seq.as_ref().map_or(0, |seq| seq.ndim())
+}
+
+#[inline]
+unsafe fn reset<R: QRng>(seq: *mut R) {
+    if let Some(seq) = seq.as_mut() {
+        seq.reset();
+    }
+}
+
+#[inline]
+unsafe fn discard<R: QRng>(seq: *mut R, n: u64) {
+    if let Some(seq) = seq.as_mut() {
+        seq.discard(n);
+    }
+}
+
+#[inline]
+unsafe fn gen_fill<R: QRng>(seq: *mut R, out: *mut f64, len: usize) -> usize {
+    match seq.as_mut() {
+        Some(seq) if !out.is_null() => seq.gen_fill_points(slice::from_raw_parts_mut(out, len)),
+        _ => 0,
+    }
+}
+
+/// Returns a new Sobol sequence generator with dimensionality `ndim`, or null if the
+/// dimensionality is not supported.
+///
+/// The generator must be destroyed with [`qrng_sobol_free`].
+#[no_mangle]
+pub extern "C" fn qrng_sobol_new(ndim: usize) -> *mut SobolSeq {
+    into_raw(SobolSeq::try_new(ndim).ok())
+}
+
+/// Destroys a Sobol sequence generator.
+///
+/// # Safety
+///
+/// The pointer must be null or returned by [`qrng_sobol_new`], and must not be used
+/// afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_sobol_free(seq: *mut SobolSeq) {
+    free(seq)
+}
+
+/// Returns the dimensionality of a Sobol sequence generator.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a live generator.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_sobol_ndim(seq: *const SobolSeq) -> usize {
+    ndim(seq)
+}
+
+/// Moves a Sobol sequence generator back to the first point.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a live generator.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_sobol_reset(seq: *mut SobolSeq) {
+    reset(seq)
+}
+
+/// Skips the next `n` points of a Sobol sequence.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a live generator.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_sobol_discard(seq: *mut SobolSeq, n: u64) {
+    discard(seq, n)
+}
+
+/// Fills the buffer of length `len` with as many consecutive points of a Sobol
+/// sequence as fit in it, in row-major order, and returns the number of points.
+///
+/// # Safety
+///
+/// The generator pointer must be null or point to a live generator, and `out` must
+/// be null or valid for writing `len` values.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_sobol_gen_fill(
+    seq: *mut SobolSeq, out: *mut f64, len: usize,
+) -> usize {
+    gen_fill(seq, out, len)
+}
+
+/// Returns a new Owen-scrambled Sobol sequence generator with dimensionality `ndim`,
+/// randomized with the given seed, or null if the dimensionality is not supported.
+///
+/// The generator must be destroyed with [`qrng_scrambled_sobol_free`].
+#[no_mangle]
+pub extern "C" fn qrng_scrambled_sobol_new(ndim: usize, seed: u64) -> *mut ScrambledSobolSeq {
+    into_raw(SobolSeq::try_new(ndim).ok().map(|seq| OwenScrambled::new(seq, seed)))
+}
+
+/// Destroys an Owen-scrambled Sobol sequence generator.
+///
+/// # Safety
+///
+/// The pointer must be null or returned by [`qrng_scrambled_sobol_new`], and must
+/// not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_scrambled_sobol_free(seq: *mut ScrambledSobolSeq) {
+    free(seq)
+}
+
+/// Returns the dimensionality of an Owen-scrambled Sobol sequence generator.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a live generator.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_scrambled_sobol_ndim(seq: *const ScrambledSobolSeq) -> usize {
+    ndim(seq)
+}
+
+/// Moves an Owen-scrambled Sobol sequence generator back to the first point.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a live generator.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_scrambled_sobol_reset(seq: *mut ScrambledSobolSeq) {
+    reset(seq)
+}
+
+/// Skips the next `n` points of an Owen-scrambled Sobol sequence.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a live generator.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_scrambled_sobol_discard(seq: *mut ScrambledSobolSeq, n: u64) {
+    discard(seq, n)
+}
+
+/// Fills the buffer of length `len` with as many consecutive points of an
+/// Owen-scrambled Sobol sequence as fit in it, in row-major order, and returns the
+/// number of points.
+///
+/// # Safety
+///
+/// The generator pointer must be null or point to a live generator, and `out` must
+/// be null or valid for writing `len` values.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_scrambled_sobol_gen_fill(
+    seq: *mut ScrambledSobolSeq, out: *mut f64, len: usize,
+) -> usize {
+    gen_fill(seq, out, len)
+}
+
+/// Returns a new Halton sequence generator with dimensionality `ndim`.
+///
+/// The generator must be destroyed with [`qrng_halton_free`].
+#[no_mangle]
+pub extern "C" fn qrng_halton_new(ndim: usize) -> *mut HaltonSeq {
+    into_raw(Some(HaltonSeq::new(ndim)))
+}
+
+/// Destroys a Halton sequence generator.
+///
+/// # Safety
+///
+/// The pointer must be null or returned by [`qrng_halton_new`], and must not be
+/// used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_halton_free(seq: *mut HaltonSeq) {
+    free(seq)
+}
+
+/// Returns the dimensionality of a Halton sequence generator.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a live generator.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_halton_ndim(seq: *const HaltonSeq) -> usize {
+    ndim(seq)
+}
+
+/// Moves a Halton sequence generator back to the first point.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a live generator.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_halton_reset(seq: *mut HaltonSeq) {
+    reset(seq)
+}
+
+/// Skips the next `n` points of a Halton sequence.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a live generator.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_halton_discard(seq: *mut HaltonSeq, n: u64) {
+    discard(seq, n)
+}
+
+/// Fills the buffer of length `len` with as many consecutive points of a Halton
+/// sequence as fit in it, in row-major order, and returns the number of points.
+///
+/// # Safety
+///
+/// The generator pointer must be null or point to a live generator, and `out` must
+/// be null or valid for writing `len` values.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_halton_gen_fill(
+    seq: *mut HaltonSeq, out: *mut f64, len: usize,
+) -> usize {
+    gen_fill(seq, out, len)
+}
+
+/// Returns a new Niederreiter sequence generator with dimensionality `ndim`, or null
+/// if the dimensionality is not supported.
+///
+/// The generator must be destroyed with [`qrng_niederreiter_free`].
+#[no_mangle]
+pub extern "C" fn qrng_niederreiter_new(ndim: usize) -> *mut NiederreiterSeq {
+    into_raw(NiederreiterSeq::try_new(ndim).ok())
+}
+
+/// Destroys a Niederreiter sequence generator.
+///
+/// # Safety
+///
+/// The pointer must be null or returned by [`qrng_niederreiter_new`], and must not
+/// be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_niederreiter_free(seq: *mut NiederreiterSeq) {
+    free(seq)
+}
+
+/// Returns the dimensionality of a Niederreiter sequence generator.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a live generator.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_niederreiter_ndim(seq: *const NiederreiterSeq) -> usize {
+    ndim(seq)
+}
+
+/// Moves a Niederreiter sequence generator back to the first point.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a live generator.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_niederreiter_reset(seq: *mut NiederreiterSeq) {
+    reset(seq)
+}
+
+/// Skips the next `n` points of a Niederreiter sequence.
+///
+/// # Safety
+///
+/// The pointer must be null or point to a live generator.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_niederreiter_discard(seq: *mut NiederreiterSeq, n: u64) {
+    discard(seq, n)
+}
+
+/// Fills the buffer of length `len` with as many consecutive points of a
+/// Niederreiter sequence as fit in it, in row-major order, and returns the number of
+/// points.
+///
+/// # Safety
+///
+/// The generator pointer must be null or point to a live generator, and `out` must
+/// be null or valid for writing `len` values.
+#[no_mangle]
+pub unsafe extern "C" fn qrng_niederreiter_gen_fill(
+    seq: *mut NiederreiterSeq, out: *mut f64, len: usize,
+) -> usize {
+    gen_fill(seq, out, len)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::*;
+
+    #[test]
+    fn test_ffi_sobol() {
+        let seq = qrng_sobol_new(3);
+        assert!(!seq.is_null());
+        unsafe {
+            assert_eq!(qrng_sobol_ndim(seq), 3);
+            let mut buf = [0.; 10];
+            assert_eq!(qrng_sobol_gen_fill(seq, buf.as_mut_ptr(), buf.len()), 3);
+            assert_eq!(buf[..9], SobolSeq::new(3).gen_points(3)[..]);
+            assert_eq!(buf[9], 0.);
+            qrng_sobol_discard(seq, 5);
+            assert_eq!(qrng_sobol_gen_fill(seq, buf.as_mut_ptr(), 3), 1);
+            assert_eq!(buf[..3], SobolSeq::new_with_skip(3, 8).gen_points(1)[..]);
+            qrng_sobol_reset(seq);
+            assert_eq!(qrng_sobol_gen_fill(seq, buf.as_mut_ptr(), 3), 1);
+            assert_eq!(buf[..3], [0.5; 3]);
+            qrng_sobol_free(seq);
+        }
+        assert!(qrng_sobol_new(1_000_000).is_null());
+        assert!(qrng_niederreiter_new(100).is_null());
+    }
+
+    #[test]
+    fn test_ffi_generators() {
+        let mut buf = vec![0.; 40];
+        unsafe {
+            let seq = qrng_scrambled_sobol_new(4, 42);
+            assert_eq!(qrng_scrambled_sobol_gen_fill(seq, buf.as_mut_ptr(), 40), 10);
+            assert_eq!(buf, OwenScrambled::new(SobolSeq::new(4), 42).gen_points(10));
+            qrng_scrambled_sobol_free(seq);
+            let seq = qrng_halton_new(4);
+            assert_eq!(qrng_halton_gen_fill(seq, buf.as_mut_ptr(), 40), 10);
+            assert_eq!(buf, HaltonSeq::new(4).gen_points(10));
+            qrng_halton_free(seq);
+            let seq = qrng_niederreiter_new(4);
+            assert_eq!(qrng_niederreiter_gen_fill(seq, buf.as_mut_ptr(), 40), 10);
+            assert_eq!(buf, NiederreiterSeq::new(4).gen_points(10));
+            qrng_niederreiter_free(seq);
+        }
+    }
+
+    #[test]
+    fn test_ffi_null() {
+        unsafe {
+            assert_eq!(qrng_halton_ndim(ptr::null()), 0);
+            assert_eq!(qrng_halton_gen_fill(ptr::null_mut(), ptr::null_mut(), 10), 0);
+            let seq = qrng_halton_new(2);
+            assert_eq!(qrng_halton_gen_fill(seq, ptr::null_mut(), 10), 0);
+            qrng_halton_reset(ptr::null_mut());
+            qrng_halton_discard(ptr::null_mut(), 1);
+            qrng_halton_free(ptr::null_mut());
+            qrng_halton_free(seq);
+        }
+    }
+}
@@ -0,0 +1,597 @@
+use std::f64::consts::PI;
+
+use crate::{
+    traits::QRng,
+    util::{inverse_gamma_cdf, inverse_normal_cdf},
+};
+
+/// Returns the number of input coordinates needed to generate a point on the unit
+/// sphere in `ndim` dimensions.
+#[inline]
+fn sphere_input_ndim(ndim: usize) -> usize {
+    if ndim <= 3 {
+        ndim - 1
+    } else {
+        ndim
+    }
+}
+
+/// Maps the uniform coordinates `u` to a point on the unit sphere `out`.
+#[inline]
+fn sphere_point(u: &[f64], out: &mut [f64]) {
+    match out.len() {
+        2 => {
+            let phi = 2. * PI * u[0];
+            out[0] = phi.cos();
+            out[1] = phi.sin();
+        }
+        3 => {
+            // Archimedes' hat-box theorem: z is uniform on [-1, 1]
+            let (z, phi) = (1. - 2. * u[0], 2. * PI * u[1]);
+            let r = (1. - z * z).max(0.).sqrt();
+            out[0] = r * phi.cos();
+            out[1] = r * phi.sin();
+            out[2] = z;
+        }
+        _ => {
+            for (x, &u) in out.iter_mut().zip(u) {
+                *x = inverse_normal_cdf(u);
+            }
+            let norm = out.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm == 0. {
+                // all coordinates are 1/2 (e.g. the first Sobol point), pick a pole
+                out.iter_mut().for_each(|x| *x = 0.);
+                out[0] = 1.;
+            } else {
+                out.iter_mut().for_each(|x| *x /= norm);
+            }
+        }
+    }
+}
+
+/// Sequence generator producing uniformly distributed points on the unit sphere.
+///
+/// The points lie on the surface of the unit sphere `S^(ndim - 1)` in `ndim`
+/// dimensions. For the circle and the 2-sphere, the points are generated via exact
+/// area-preserving maps from `ndim - 1` coordinates of the underlying sequence:
+/// `(cos(2 pi u), sin(2 pi u))` and `(r cos(2 pi v), r sin(2 pi v), 1 - 2u)` with
+/// `r = sqrt(1 - (1 - 2u)^2)` respectively. For higher dimensions, `ndim`
+/// coordinates are mapped to independent standard normal samples and the resulting
+/// vector is normalized; coordinates equal to zero yield NaNs in this case, and
+/// points with all coordinates equal to `1/2` are mapped to `(1, 0, ..., 0)`.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = UnitSphere::new(SobolSeq::new(2), 3).with_buf();
+/// assert_eq!(seq.ndim(), 3);
+/// let x = seq.gen();
+/// assert!((x.iter().map(|x| x * x).sum::<f64>() - 1.).abs() < 1e-15);
+/// ```
+#[derive(Clone)]
+pub struct UnitSphere<R: QRng> {
+    seq: R,
+    ndim: usize,
+    buf: Vec<f64>,
+}
+
+impl<R: QRng> UnitSphere<R> {
+    /// Returns a new generator mapping the points of the given sequence onto the
+    /// unit sphere in `ndim` dimensions.
+    ///
+    /// Panics if `ndim` is less than 2, or if the dimensionality of the sequence is
+    /// not equal to [`input_ndim(ndim)`](#method.input_ndim).
+    pub fn new(seq: R, ndim: usize) -> Self {
+        if ndim < 2 {
+            panic!("invalid sphere dimension: {}", ndim);
+        }
+        let input_ndim = Self::input_ndim(ndim);
+        if seq.ndim() != input_ndim {
+            panic!("invalid sphere input dimension: {}", seq.ndim());
+        }
+        Self { seq, ndim, buf: vec![0.; input_ndim] }
+    }
+
+    /// Returns the dimensionality of the underlying sequence required to generate
+    /// points on the unit sphere in `ndim` dimensions: `ndim - 1` if `ndim` is at
+    /// most 3, and `ndim` otherwise.
+    #[inline]
+    pub fn input_ndim(ndim: usize) -> usize {
+        sphere_input_ndim(ndim)
+    }
+
+    /// Returns a reference to the underlying (uniform) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng> QRng for UnitSphere<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(&mut self.buf);
+        sphere_point(&self.buf, out.get_unchecked_mut(..self.ndim));
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+/// Maps the uniform coordinates `(u, v)` to a point in the unit disk via the
+/// concentric map ("A Low Distortion Map Between Disk and Square", Shirley and
+/// Chiu, 1997).
+#[inline]
+fn concentric_disk_point(u: f64, v: f64) -> (f64, f64) {
+    let (a, b) = (2. * u - 1., 2. * v - 1.);
+    if a == 0. && b == 0. {
+        return (0., 0.);
+    }
+    let (r, phi) =
+        if a.abs() > b.abs() { (a, PI / 4. * (b / a)) } else { (b, PI / 2. - PI / 4. * (a / b)) };
+    (r * phi.cos(), r * phi.sin())
+}
+
+/// Sequence generator producing uniformly distributed points in the unit disk.
+///
+/// The points of the underlying two-dimensional sequence are mapped via the
+/// concentric map of Shirley and Chiu, which maps concentric squares to concentric
+/// circles. Unlike the polar map `(sqrt(u) cos(2 pi v), sqrt(u) sin(2 pi v))`, it
+/// preserves areas with low distortion, so the stratification of the sequence
+/// carries over to the disk.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = UnitDisk::new(SobolSeq::new(2)).with_buf();
+/// assert_eq!(seq.gen(), &[0., 0.]);
+/// ```
+#[derive(Clone)]
+pub struct UnitDisk<R: QRng> {
+    seq: R,
+}
+
+impl<R: QRng> UnitDisk<R> {
+    /// Returns a new generator mapping the points of the given sequence onto the
+    /// unit disk.
+    ///
+    /// Panics if the sequence is not two-dimensional.
+    pub fn new(seq: R) -> Self {
+        if seq.ndim() != 2 {
+            panic!("invalid disk input dimension: {}", seq.ndim());
+        }
+        Self { seq }
+    }
+
+    /// Returns a reference to the underlying (uniform) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng> QRng for UnitDisk<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        2
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(out);
+        let (x, y) = concentric_disk_point(*out.get_unchecked(0), *out.get_unchecked(1));
+        *out.get_unchecked_mut(0) = x;
+        *out.get_unchecked_mut(1) = y;
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+/// Sequence generator producing uniformly distributed points in the unit ball.
+///
+/// In two dimensions, the points are generated via the concentric map (see
+/// [`UnitDisk`](struct.UnitDisk.html)). Otherwise, the first coordinate `u` of the
+/// underlying sequence determines the radius `u^(1 / ndim)`, and the remaining ones
+/// the direction, as in [`UnitSphere`](struct.UnitSphere.html); in particular, the
+/// map is volume-preserving in three dimensions.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = UnitBall::new(SobolSeq::new(3), 3).with_buf();
+/// let x = seq.gen();
+/// assert!(x.iter().map(|x| x * x).sum::<f64>() <= 1.);
+/// ```
+#[derive(Clone)]
+pub struct UnitBall<R: QRng> {
+    seq: R,
+    ndim: usize,
+    buf: Vec<f64>,
+}
+
+impl<R: QRng> UnitBall<R> {
+    /// Returns a new generator mapping the points of the given sequence into the
+    /// unit ball in `ndim` dimensions.
+    ///
+    /// Panics if `ndim` is less than 2, or if the dimensionality of the sequence is
+    /// not equal to [`input_ndim(ndim)`](#method.input_ndim).
+    pub fn new(seq: R, ndim: usize) -> Self {
+        if ndim < 2 {
+            panic!("invalid ball dimension: {}", ndim);
+        }
+        let input_ndim = Self::input_ndim(ndim);
+        if seq.ndim() != input_ndim {
+            panic!("invalid ball input dimension: {}", seq.ndim());
+        }
+        Self { seq, ndim, buf: vec![0.; input_ndim] }
+    }
+
+    /// Returns the dimensionality of the underlying sequence required to generate
+    /// points in the unit ball in `ndim` dimensions: `ndim` if `ndim` is at most 3,
+    /// and `ndim + 1` otherwise.
+    #[inline]
+    pub fn input_ndim(ndim: usize) -> usize {
+        sphere_input_ndim(ndim) + 1
+    }
+
+    /// Returns a reference to the underlying (uniform) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng> QRng for UnitBall<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(&mut self.buf);
+        let out = out.get_unchecked_mut(..self.ndim);
+        if self.ndim == 2 {
+            let (x, y) = concentric_disk_point(self.buf[0], self.buf[1]);
+            out[0] = x;
+            out[1] = y;
+        } else {
+            sphere_point(&self.buf[1..], out);
+            let r = self.buf[0].powf(1. / self.ndim as f64);
+            out.iter_mut().for_each(|x| *x *= r);
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+/// Sequence generator producing uniformly distributed points on the probability
+/// simplex.
+///
+/// The points have `ndim` non-negative coordinates summing up to one, and are
+/// generated from `ndim - 1` coordinates of the underlying sequence via sequential
+/// stick-breaking: the `i`-th coordinate `u_i` breaks off the fraction
+/// `1 - (1 - u_i)^(1 / (ndim - 1 - i))` of the remaining stick, which is the
+/// inverse CDF of the corresponding beta marginal. Unlike the ordered-uniform
+/// (sorted spacings) construction, the map is smooth and monotone in each
+/// coordinate, and unlike the exponential spacings construction it doesn't need an
+/// extra dimension.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = Simplex::new(HaltonSeq::new(2)).with_buf();
+/// let x = seq.gen();
+/// assert_eq!(x.len(), 3);
+/// assert!((x.iter().sum::<f64>() - 1.).abs() < 1e-15);
+/// ```
+#[derive(Clone)]
+pub struct Simplex<R: QRng> {
+    seq: R,
+}
+
+impl<R: QRng> Simplex<R> {
+    /// Returns a new generator mapping the points of the given sequence onto the
+    /// probability simplex with one more dimension than the sequence.
+    #[inline]
+    pub fn new(seq: R) -> Self {
+        Self { seq }
+    }
+
+    /// Returns a reference to the underlying (uniform) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng> QRng for Simplex<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim() + 1
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        let k = self.seq.ndim();
+        self.seq.gen_fill_unchecked(out);
+        let out = out.get_unchecked_mut(..=k);
+        let mut rest = 1.;
+        for (i, x) in out[..k].iter_mut().enumerate() {
+            let b = (k - i) as f64;
+            let part = rest * (1. - (1. - *x).powf(1. / b));
+            rest -= part;
+            *x = part;
+        }
+        out[k] = rest.max(0.);
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+/// Sequence generator producing Dirichlet-distributed quasi-random samples.
+///
+/// Each coordinate `u_i` of the underlying sequence is mapped through the inverse
+/// CDF of the gamma distribution with shape `alpha_i` (see
+/// [`util::inverse_gamma_cdf`](util/fn.inverse_gamma_cdf.html)), and the resulting
+/// vector is normalized to sum up to one. The construction is rejection-free, so
+/// the low-discrepancy structure of the sequence is preserved. For the uniform
+/// distribution on the simplex, [`Simplex`](struct.Simplex.html) requires one
+/// dimension less and is cheaper.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = Dirichlet::new(HaltonSeq::new(3), &[0.5, 1., 2.]).with_buf();
+/// let x = seq.gen();
+/// assert!((x.iter().sum::<f64>() - 1.).abs() < 1e-15);
+/// ```
+#[derive(Clone)]
+pub struct Dirichlet<R: QRng> {
+    seq: R,
+    alphas: Vec<f64>,
+}
+
+impl<R: QRng> Dirichlet<R> {
+    /// Returns a new generator mapping the points of the given sequence to samples
+    /// from the Dirichlet distribution with the given concentration parameters.
+    ///
+    /// Panics if the number of parameters differs from the dimensionality of the
+    /// sequence, or if any of the parameters is not positive.
+    pub fn new(seq: R, alphas: &[f64]) -> Self {
+        if alphas.len() != seq.ndim() {
+            panic!("invalid number of concentration parameters: {}", alphas.len());
+        }
+        if let Some(&alpha) = alphas.iter().find(|&&alpha| !(alpha > 0. && alpha.is_finite())) {
+            panic!("invalid concentration parameter: {}", alpha);
+        }
+        Self { seq, alphas: alphas.to_vec() }
+    }
+
+    /// Returns the concentration parameters of the distribution.
+    #[inline]
+    pub fn alphas(&self) -> &[f64] {
+        &self.alphas
+    }
+
+    /// Returns a reference to the underlying (uniform) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng> QRng for Dirichlet<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(out);
+        let out = out.get_unchecked_mut(..self.alphas.len());
+        for (x, &alpha) in out.iter_mut().zip(&self.alphas) {
+            *x = inverse_gamma_cdf(alpha, *x);
+        }
+        let sum: f64 = out.iter().sum();
+        if sum > 0. {
+            out.iter_mut().for_each(|x| *x /= sum);
+        } else {
+            // all of the gamma samples underflowed, fall back to the mean
+            let total: f64 = self.alphas.iter().sum();
+            out.iter_mut().zip(&self.alphas).for_each(|(x, alpha)| *x = alpha / total);
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dirichlet, Simplex, UnitBall, UnitDisk, UnitSphere};
+    use crate::{HaltonSeq, QRng, SobolSeq};
+
+    #[test]
+    fn test_unit_sphere() {
+        let n = 1 << 12;
+        for ndim in 2..=6 {
+            let seq = SobolSeq::new(UnitSphere::<SobolSeq>::input_ndim(ndim));
+            let points = UnitSphere::new(seq, ndim).gen_points(n);
+            let mut mean = vec![0.; ndim];
+            let mut cap = 0;
+            for x in points.chunks(ndim) {
+                assert!((x.iter().map(|x| x * x).sum::<f64>() - 1.).abs() < 1e-12);
+                mean.iter_mut().zip(x).for_each(|(m, x)| *m += x / n as f64);
+                cap += (x[ndim - 1] > 0.5) as usize;
+            }
+            assert!(mean.iter().all(|m| m.abs() < 1e-2));
+            if ndim == 3 {
+                // the cap z > 1/2 covers a quarter of the area of the sphere
+                assert!((cap as f64 / n as f64 - 0.25).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unit_sphere_panic() {
+        UnitSphere::new(HaltonSeq::new(3), 3);
+    }
+
+    #[test]
+    fn test_unit_disk() {
+        let n = 1 << 12;
+        let points = UnitDisk::new(SobolSeq::new(2)).gen_points(n);
+        let mut inner = 0;
+        let mut quadrants = [0; 4];
+        for x in points.chunks(2) {
+            let r2 = x[0] * x[0] + x[1] * x[1];
+            assert!(r2 <= 1. + 1e-15);
+            inner += (r2 < 0.25) as usize;
+            quadrants[(x[0] >= 0.) as usize * 2 + (x[1] >= 0.) as usize] += 1;
+        }
+        assert!((inner as f64 / n as f64 - 0.25).abs() < 1e-3);
+        assert!(quadrants.iter().all(|&q| q == n / 4));
+    }
+
+    #[test]
+    fn test_unit_ball() {
+        let n = 1 << 12;
+        for ndim in 2..=5 {
+            let seq = SobolSeq::new(UnitBall::<SobolSeq>::input_ndim(ndim));
+            let points = UnitBall::new(seq, ndim).gen_points(n);
+            let mut inner = 0;
+            for x in points.chunks(ndim) {
+                let r2 = x.iter().map(|x| x * x).sum::<f64>();
+                assert!(r2 <= 1. + 1e-12);
+                inner += (r2 < 0.25) as usize;
+            }
+            // the ball of radius 1/2 takes up 2^(-ndim) of the volume
+            assert!((inner as f64 / n as f64 - 0.5f64.powi(ndim as _)).abs() < 2e-3);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unit_ball_panic() {
+        UnitBall::new(HaltonSeq::new(4), 4);
+    }
+
+    #[test]
+    fn test_simplex() {
+        let n = 1 << 12;
+        for ndim in 1..=5 {
+            let points = Simplex::new(SobolSeq::new(ndim)).gen_points(n);
+            let mut mean = vec![0.; ndim + 1];
+            for x in points.chunks(ndim + 1) {
+                assert!((x.iter().sum::<f64>() - 1.).abs() < 1e-12);
+                assert!(x.iter().all(|&x| x >= 0.));
+                mean.iter_mut().zip(x).for_each(|(m, x)| *m += x / n as f64);
+            }
+            assert!(mean.iter().all(|m| (m - 1. / (ndim + 1) as f64).abs() < 1e-3));
+        }
+    }
+
+    #[test]
+    fn test_dirichlet() {
+        let n = 1 << 14;
+        let alphas = [0.3, 1., 2.5, 6.];
+        let total: f64 = alphas.iter().sum();
+        let points = Dirichlet::new(SobolSeq::new(4), &alphas).gen_points(n);
+        let (mut mean, mut var) = ([0.; 4], [0.; 4]);
+        for x in points.chunks(4) {
+            assert!((x.iter().sum::<f64>() - 1.).abs() < 1e-12);
+            for j in 0..4 {
+                mean[j] += x[j] / n as f64;
+                var[j] += x[j] * x[j] / n as f64;
+            }
+        }
+        for j in 0..4 {
+            let m = alphas[j] / total;
+            assert!((mean[j] - m).abs() < 1e-3);
+            let v = m * (1. - m) / (total + 1.);
+            assert!((var[j] - mean[j] * mean[j] - v).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dirichlet_panic() {
+        Dirichlet::new(HaltonSeq::new(2), &[1., 0.]);
+    }
+}
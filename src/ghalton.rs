@@ -0,0 +1,494 @@
+use crate::{
+    error::{check_ndim, QrngError},
+    halton::HaltonSeq,
+    shift::RandomShift,
+    traits::{QRng, Randomize},
+    util::{mix64, primes, SplitMix64},
+};
+
+const MAX_LOG_N: usize = 48;
+
+/// Maximum dimensionality of the Faure-Lemieux generalized Halton sequence.
+const FL_MAX_DIM: usize = 360;
+
+/// Multiplicative digit scrambling factors from "Generalized Halton sequences in
+/// 2008: A comparative study" (Faure and Lemieux, 2009), one per prime base.
+const FL_FACTORS: [u32; FL_MAX_DIM] = [
+    1, 1, 3, 3, 4, 9, 7, 5, 9, 18, 18, 8, 13, 31, 9, 19, 36, 33, 21, 44, 43, 61, 60, 56, 26, 71,
+    32, 77, 26, 95, 92, 47, 29, 61, 57, 69, 115, 63, 92, 31, 104, 126, 50, 80, 55, 152, 114, 80,
+    83, 97, 95, 150, 148, 55, 80, 192, 71, 76, 82, 109, 105, 173, 58, 143, 56, 177, 203, 239, 196,
+    143, 278, 227, 87, 274, 264, 84, 226, 163, 231, 177, 95, 116, 165, 131, 156, 105, 188, 142,
+    105, 125, 269, 292, 215, 182, 294, 152, 148, 144, 382, 194, 346, 323, 220, 174, 133, 324, 215,
+    246, 159, 337, 254, 423, 484, 239, 440, 362, 464, 376, 398, 174, 149, 418, 306, 282, 434, 196,
+    458, 313, 512, 450, 161, 315, 441, 549, 555, 431, 295, 557, 172, 343, 472, 604, 297, 524, 251,
+    514, 385, 531, 663, 674, 255, 519, 324, 391, 394, 533, 253, 717, 651, 399, 596, 676, 425, 261,
+    404, 691, 604, 274, 627, 777, 269, 217, 599, 447, 581, 640, 666, 595, 669, 686, 305, 460, 599,
+    335, 258, 649, 771, 619, 666, 669, 707, 737, 854, 925, 818, 424, 493, 463, 535, 782, 476, 451,
+    520, 886, 340, 793, 390, 381, 274, 500, 581, 345, 363, 1024, 514, 773, 932, 556, 954, 793, 294,
+    863, 393, 827, 527, 1007, 622, 549, 613, 799, 408, 856, 601, 1072, 938, 322, 1142, 873, 629,
+    1071, 1063, 1205, 596, 973, 984, 875, 918, 1133, 1223, 933, 1110, 1228, 1017, 701, 480, 678,
+    1172, 689, 1138, 1022, 682, 613, 635, 984, 526, 1311, 459, 1348, 477, 716, 1075, 682, 1245,
+    401, 774, 1026, 499, 1314, 743, 693, 1282, 1003, 1181, 1079, 765, 815, 1350, 1144, 1449, 718,
+    805, 1203, 1173, 737, 562, 579, 701, 1104, 1105, 1379, 827, 1256, 759, 540, 1284, 1188, 776,
+    853, 1140, 445, 1265, 802, 932, 632, 1504, 856, 1229, 1619, 774, 1229, 1300, 1563, 1551, 1265,
+    905, 1333, 493, 913, 1397, 1250, 612, 1251, 1765, 1303, 595, 981, 671, 1403, 820, 1404, 1661,
+    973, 1340, 1015, 1649, 855, 1834, 1621, 1704, 893, 1033, 721, 1737, 1507, 1851, 1006, 994, 923,
+    872, 1860,
+];
+
+/// Returns the digit permutation for the given base from "Good Permutations for
+/// Extreme Discrepancy" (Faure, 1992).
+///
+/// The permutations are defined recursively: for an even base `2c`, the values of
+/// the permutation for base `c` are doubled, followed by the doubled values plus
+/// one; for an odd base `2c + 1`, the values of the permutation for base `2c` not
+/// less than `c` are incremented, and `c` is inserted in the middle.
+fn faure_permutation(base: u32) -> Vec<u32> {
+    match base {
+        0 | 1 => vec![0; base as usize],
+        2 => vec![0, 1],
+        b if b.is_multiple_of(2) => {
+            let half = faure_permutation(b / 2);
+            half.iter().map(|&x| 2 * x).chain(half.iter().map(|&x| 2 * x + 1)).collect()
+        }
+        b => {
+            let c = b / 2;
+            let mut perm: Vec<_> =
+                faure_permutation(b - 1).into_iter().map(|x| x + (x >= c) as u32).collect();
+            perm.insert(c as usize, c);
+            perm
+        }
+    }
+}
+
+/// Draws a uniformly random permutation of the digits in the given base that maps
+/// zero to zero.
+fn random_permutation(base: u32, rng: &mut SplitMix64) -> Vec<u32> {
+    let mut perm: Vec<_> = (0..base).collect();
+    for i in (2..base as usize).rev() {
+        perm.swap(i, 1 + rng.next_below(i as u64) as usize);
+    }
+    perm
+}
+
+/// One-dimensional generalized van der Corput sequence with a digit permutation.
+#[derive(Clone)]
+struct PermutedSeq1D {
+    base: u32,
+    perm: Vec<u32>,
+    digits: Vec<u32>,
+}
+
+impl PermutedSeq1D {
+    #[inline]
+    fn new(perm: Vec<u32>) -> Self {
+        Self { base: perm.len() as _, perm, digits: vec![] }
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.digits.clear();
+    }
+
+    /// Sets the digits to the base-`b` expansion of the index.
+    #[inline]
+    fn set_index(&mut self, mut index: u64) {
+        let base = u64::from(self.base);
+        self.digits.clear();
+        while index != 0 {
+            self.digits.push((index % base) as u32);
+            index /= base;
+        }
+    }
+
+    /// Computes the element with the given index directly.
+    #[inline]
+    fn get(&self, mut index: u64) -> f64 {
+        let base = u64::from(self.base);
+        let (mut digits, mut m) = ([0; 64], 0);
+        while index != 0 {
+            digits[m] = (index % base) as usize;
+            index /= base;
+            m += 1;
+        }
+        let base_f = f64::from(self.base);
+        digits[..m].iter().rev().fold(0., |r, &d| (r + f64::from(self.perm[d])) / base_f)
+    }
+
+    #[inline]
+    unsafe fn next(&mut self) -> f64 {
+        let mut i = 0;
+        loop {
+            if i == self.digits.len() {
+                self.digits.push(0);
+            }
+            let digit = self.digits.get_unchecked_mut(i);
+            *digit += 1;
+            if *digit < self.base {
+                break;
+            }
+            *digit = 0;
+            i += 1;
+        }
+        let base_f = f64::from(self.base);
+        let perm = &self.perm;
+        self.digits
+            .iter()
+            .rev()
+            .fold(0., |r, &d| (r + f64::from(*perm.get_unchecked(d as usize))) / base_f)
+    }
+}
+
+/// Generalized Halton low-discrepancy sequence generator.
+///
+/// This is the Halton sequence where the base-`b` digits of the index are
+/// permuted before being mirrored around the radix point, with a separate
+/// permutation for each dimension. Scrambling the digits fixes the strong
+/// correlations between the coordinates with large bases that make the plain
+/// Halton sequence poorly behaved in higher dimensions.
+///
+/// As with [`HaltonSeq`](struct.HaltonSeq.html), the zero point is skipped.
+///
+/// Randomizing a Halton or generalized Halton sequence via
+/// [`Randomize`](trait.Randomize.html) composes its digit permutations with
+/// uniformly random permutations of the nonzero digits, one per dimension, and then
+/// applies a [`RandomShift`](struct.RandomShift.html), so that each point is
+/// uniformly distributed over the unit cube.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = HaltonSeq::new(5).randomize(42);
+/// let perms = GeneralizedHaltonSeq::with_random_permutations(5, 42);
+/// assert_eq!(seq.inner().clone().gen_points(10), perms.clone().gen_points(10));
+/// ```
+#[derive(Clone)]
+pub struct GeneralizedHaltonSeq {
+    index: u64,
+    leap: u64,
+    seqs: Vec<PermutedSeq1D>,
+}
+
+impl GeneralizedHaltonSeq {
+    /// Returns a new generalized Halton sequence generator with dimensionality `ndim`,
+    /// using the Faure-Lemieux digit permutations.
+    ///
+    /// For the `j`-th dimension with prime base `b_j`, each digit `d` is mapped to
+    /// `f_j * d mod b_j`, where the factors `f_j` were chosen to optimize the quality
+    /// of two-dimensional projections ("Generalized Halton sequences in 2008: A
+    /// comparative study", Faure and Lemieux, 2009).
+    ///
+    /// Panics if `ndim` is greater than 360.
+    #[inline]
+    pub fn new(ndim: usize) -> Self {
+        Self::try_new(ndim).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as [`new`](#method.new), but returns an error instead of panicking.
+    #[inline]
+    pub fn try_new(ndim: usize) -> Result<Self, QrngError> {
+        check_ndim("generalized Halton sequence", ndim, FL_MAX_DIM)?;
+        let perms = primes()
+            .zip(FL_FACTORS.iter())
+            .take(ndim)
+            .map(|(b, &f)| (0..b).map(|d| ((u64::from(f) * d) % b) as u32).collect())
+            .collect();
+        Ok(Self::from_permutations(perms))
+    }
+
+    /// Returns a new generalized Halton sequence generator with dimensionality `ndim`,
+    /// using the deterministic digit permutations by Faure.
+    ///
+    /// The same permutation is used for all digits in a given base ("Good
+    /// Permutations for Extreme Discrepancy", Faure, 1992); the permutations are
+    /// computed recursively and are available for any dimensionality. Like the
+    /// Braaten–Weller permutations, they remove the striping artifacts of the plain
+    /// Halton sequence at no runtime cost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = GeneralizedHaltonSeq::with_faure_permutations(5).with_buf();
+    /// assert_eq!(seq.gen()[2], 0.6);
+    /// ```
+    #[inline]
+    pub fn with_faure_permutations(ndim: usize) -> Self {
+        let perms = primes().take(ndim).map(|b| faure_permutation(b as u32)).collect();
+        Self::from_permutations(perms)
+    }
+
+    /// Returns a new generalized Halton sequence generator with dimensionality `ndim`,
+    /// using random digit permutations drawn with the given seed.
+    ///
+    /// For each base, a uniformly random permutation of the nonzero digits is used
+    /// for all of the digits, so that the generator is a random member of the family
+    /// of generalized Halton sequences. This is the unshifted part of
+    /// `HaltonSeq::new(ndim).randomize(seed)`, which should be preferred for
+    /// randomized quasi-Monte Carlo error estimates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = GeneralizedHaltonSeq::with_random_permutations(5, 42).with_buf();
+    /// assert_eq!(seq.gen()[0], 0.5);
+    /// ```
+    #[inline]
+    pub fn with_random_permutations(ndim: usize, seed: u64) -> Self {
+        Self::from(HaltonSeq::new(ndim)).permute_digits(seed)
+    }
+
+    /// Returns a new generalized Halton sequence generator with the given digit
+    /// permutations, one per dimension.
+    ///
+    /// The base for each dimension is equal to the length of its permutation and
+    /// is not required to be prime, although the sequence only has good properties
+    /// if the bases are pairwise coprime. Each permutation must map zero to zero.
+    ///
+    /// Panics if any of the permutations is invalid.
+    #[inline]
+    pub fn from_permutations(perms: Vec<Vec<u32>>) -> Self {
+        for perm in &perms {
+            let mut seen = vec![false; perm.len()];
+            for &d in perm {
+                if d as usize >= perm.len() || seen[d as usize] {
+                    panic!("invalid digit permutation: {:?}", perm);
+                }
+                seen[d as usize] = true;
+            }
+            if perm.len() < 2 || perm[0] != 0 {
+                panic!("invalid digit permutation: {:?}", perm);
+            }
+        }
+        Self { index: 0, leap: 1, seqs: perms.into_iter().map(PermutedSeq1D::new).collect() }
+    }
+
+    /// Composes each digit permutation with a uniformly random permutation of the
+    /// nonzero digits drawn with the given seed.
+    fn permute_digits(mut self, seed: u64) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        for s in &mut self.seqs {
+            let random = random_permutation(s.base, &mut rng);
+            s.perm.iter_mut().for_each(|d| *d = random[*d as usize]);
+        }
+        self
+    }
+}
+
+impl From<HaltonSeq> for GeneralizedHaltonSeq {
+    /// Converts a Halton sequence into a generalized Halton sequence with identity
+    /// digit permutations, preserving its leap and current position.
+    #[inline]
+    fn from(halton: HaltonSeq) -> Self {
+        let perms = primes().take(halton.ndim()).map(|b| (0..b as u32).collect()).collect();
+        let mut seq = Self::from_permutations(perms);
+        let (index, leap) = (halton.index(), halton.leap());
+        seq.index = index * leap;
+        seq.leap = leap;
+        if leap == 1 {
+            seq.seqs.iter_mut().for_each(|s| s.set_index(index));
+        }
+        seq
+    }
+}
+
+impl Randomize for HaltonSeq {
+    type Randomized = RandomShift<GeneralizedHaltonSeq>;
+
+    #[inline]
+    fn randomize(self, seed: u64) -> Self::Randomized {
+        GeneralizedHaltonSeq::from(self).randomize(seed)
+    }
+}
+
+impl Randomize for GeneralizedHaltonSeq {
+    type Randomized = RandomShift<GeneralizedHaltonSeq>;
+
+    #[inline]
+    fn randomize(self, seed: u64) -> Self::Randomized {
+        RandomShift::new(self.permute_digits(seed), mix64(seed))
+    }
+}
+
+impl QRng for GeneralizedHaltonSeq {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seqs.len()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.index = 0;
+        self.seqs.iter_mut().for_each(PermutedSeq1D::reset);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index / self.leap
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        if self.leap != 1 {
+            // leaped sequences (only obtained by randomizing leaped Halton sequences)
+            // compute the permuted radical inverses directly
+            if self.index + self.leap > (1 << MAX_LOG_N) {
+                self.index = 0;
+            }
+            self.index += self.leap;
+            for (i, s) in self.seqs.iter().enumerate() {
+                *out.get_unchecked_mut(i) = s.get(self.index);
+            }
+            return;
+        }
+        if self.index >= (1 << MAX_LOG_N) {
+            self.index = 0;
+            self.seqs.iter_mut().for_each(PermutedSeq1D::reset);
+        }
+        self.index += 1;
+        for (i, s) in self.seqs.iter_mut().enumerate() {
+            *out.get_unchecked_mut(i) = s.next();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{faure_permutation, GeneralizedHaltonSeq, FL_FACTORS};
+    use crate::{util::primes, HaltonSeq, QRng, Randomize};
+
+    const TOL: f64 = 1e-15;
+
+    #[test]
+    fn test_fl_factors() {
+        for (&f, p) in FL_FACTORS.iter().zip(primes()) {
+            assert!(f > 0 && u64::from(f) < p);
+        }
+    }
+
+    #[test]
+    fn test_generalized_halton_seq() {
+        // base 5 with factor 3: digits 1, 2, 3, 4 map to 3, 1, 4, 2
+        let expected = [0.6, 0.2, 0.8, 0.4, 0.12, 0.72, 0.32, 0.92, 0.52];
+        let mut seq = GeneralizedHaltonSeq::new(3).with_buf();
+        for &e in &expected {
+            assert!((seq.gen()[2] - e).abs() < TOL);
+        }
+    }
+
+    #[test]
+    fn test_faure_permutation() {
+        assert_eq!(faure_permutation(4), vec![0, 2, 1, 3]);
+        assert_eq!(faure_permutation(5), vec![0, 3, 2, 1, 4]);
+        assert_eq!(faure_permutation(7), vec![0, 2, 5, 3, 1, 4, 6]);
+        for b in 2..100 {
+            let mut perm = faure_permutation(b);
+            perm.sort_unstable();
+            assert_eq!(perm, (0..b).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_generalized_halton_seq_faure() {
+        // base 7: digits 1, ..., 6 map to 2, 5, 3, 1, 4, 6
+        let expected = [2, 5, 3, 1, 4, 6];
+        let mut seq = GeneralizedHaltonSeq::with_faure_permutations(100).with_buf();
+        for &e in &expected {
+            assert!((seq.gen()[3] - f64::from(e) / 7.).abs() < TOL);
+        }
+    }
+
+    #[test]
+    fn test_generalized_halton_seq_random() {
+        let mut a = GeneralizedHaltonSeq::with_random_permutations(50, 1).with_buf();
+        let mut b = GeneralizedHaltonSeq::with_random_permutations(50, 1).with_buf();
+        let mut c = GeneralizedHaltonSeq::with_random_permutations(50, 2).with_buf();
+        let (mut x, mut z) = (vec![], vec![]);
+        for _ in 0..100 {
+            x.push(a.gen().to_vec());
+            assert_eq!(x.last().unwrap().as_slice(), b.gen());
+            z.push(c.gen().to_vec());
+        }
+        assert_ne!(x, z);
+        // the first b - 1 points in base b are a permutation of 1/b, ..., (b-1)/b
+        let mut col: Vec<_> = x[..96].iter().map(|x| (x[24] * 97.).round() as u32).collect();
+        col.sort_unstable();
+        assert_eq!(col, (1..97).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_randomize() {
+        let mut halton = HaltonSeq::new(5);
+        halton.discard(10);
+        let mut seq = halton.randomize(3);
+        assert_eq!(seq.index(), 10);
+        let mut perms = GeneralizedHaltonSeq::with_random_permutations(5, 3);
+        perms.discard(10);
+        assert_eq!(seq.inner().clone().gen_points(100), perms.gen_points(100));
+        assert!(seq.gen_points(1000).iter().all(|&x| (0. ..1.).contains(&x)));
+        let shift = HaltonSeq::new(5).randomize(3).shift().to_vec();
+        assert_ne!(shift, HaltonSeq::new(5).randomize(4).shift());
+        assert_eq!(shift, GeneralizedHaltonSeq::new(5).randomize(3).shift());
+    }
+
+    #[test]
+    fn test_randomize_leaped() {
+        let mut seq = HaltonSeq::leaped(3, 7).randomize(5).inner().clone();
+        let all = GeneralizedHaltonSeq::with_random_permutations(3, 5).gen_points(70);
+        let points = seq.gen_points(10);
+        assert_eq!(seq.index(), 10);
+        for (i, x) in points.chunks(3).enumerate() {
+            assert_eq!(x, &all[(7 * i + 6) * 3..(7 * i + 7) * 3]);
+        }
+        seq.reset();
+        assert_eq!(seq.gen_points(10), points);
+    }
+
+    #[test]
+    fn test_generalized_halton_seq_identity() {
+        let perms = primes().take(5).map(|b| (0..b as u32).collect()).collect();
+        let mut seq = GeneralizedHaltonSeq::from_permutations(perms).with_buf();
+        let mut halton = HaltonSeq::new(5).with_buf();
+        for _ in 0..1000 {
+            for (a, b) in seq.gen().iter().zip(halton.gen()) {
+                assert!((a - b).abs() < TOL);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generalized_halton_seq_mean() {
+        const LEN: usize = 100_000;
+        const NDIM: usize = 20;
+        let mut seq = GeneralizedHaltonSeq::new(NDIM).with_buf();
+        let mut sum = [0.; NDIM];
+        for _ in 0..LEN {
+            for (i, &x) in seq.gen().iter().enumerate() {
+                sum[i] += x;
+            }
+        }
+        for s in &sum {
+            assert!(((s / (LEN as f64)) - 0.5).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_generalized_halton_seq_try_new() {
+        assert!(GeneralizedHaltonSeq::try_new(360).is_ok());
+        assert!(GeneralizedHaltonSeq::try_new(361).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_generalized_halton_seq_panic() {
+        GeneralizedHaltonSeq::new(361);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_generalized_halton_seq_invalid_perm() {
+        GeneralizedHaltonSeq::from_permutations(vec![vec![1, 0, 2]]);
+    }
+}
@@ -1,6 +1,11 @@
-use crate::{traits::QRng, utils::primes};
+use std::convert::TryFrom;
 
-const MAX_LOG_N: usize = 48;
+use crate::{
+    error::QrngError,
+    halton_core::{halton_digits, halton_step, MAX_LOG_N},
+    traits::{scatter_col_major, QRng, SkipTo},
+    util::{primes, radical_inverse},
+};
 
 /// One-dimensional Halton sequence generator with a given base.
 #[derive(Clone)]
@@ -26,43 +31,121 @@ impl HaltonSeq1D {
         self.next_power = 1;
     }
 
+    /// Adds a new most significant (zero) digit.
+    #[inline]
+    fn push_digit(&mut self) {
+        self.digits.push(0);
+        self.remainders.push(0.);
+        self.next_power = self.next_power.saturating_mul(u64::from(self.base));
+    }
+
+    /// Reconstructs the state after the element with the given index directly from
+    /// the base-`base` expansion of the index.
+    #[inline]
+    fn set_index(&mut self, index: u64) {
+        self.reset();
+        while index >= self.next_power {
+            self.push_digit();
+        }
+        halton_digits(self.base, index, &mut self.digits, &mut self.remainders);
+    }
+
     #[inline]
     unsafe fn next(&mut self, index: u64) -> f64 {
         // In order to avoid pre-allocating too much memory for digits and remainders,
         // we only extend digit/remainders vectors when the new bit appears. For a given
         // base, this happens on indices base^0, base^1, base^2, ...
         if index == self.next_power {
-            self.digits.push(0);
-            self.remainders.push(0.);
-            self.next_power *= u64::from(self.base);
-        }
-
-        let base_f = f64::from(self.base);
-        let mut digit = self.digits.as_mut_ptr();
-        let rem = self.remainders.as_mut_ptr();
-
-        // increase the least significant bit and see what happens
-        *digit += 1;
-        let h = if *digit == self.base {
-            // handle carry over if it occurs
-            let mut k = 0;
-            while {
-                k += 1;
-                *digit = 0;
-                digit = digit.add(1);
-                *digit += 1;
-                *digit == self.base
-            } {}
-            *rem.add(k - 1) = (f64::from(*digit) + *rem.add(k)) / base_f;
-            for i in (1..k).rev() {
-                *rem.add(i - 1) = *rem.add(i) / base_f;
-            }
-            *rem
-        } else {
-            // simple case, no carry
-            f64::from(*digit) + *rem
-        };
-        h / base_f
+            self.push_digit();
+        }
+
+        halton_step(self.base, &mut self.digits, &mut self.remainders)
+    }
+}
+
+/// Van der Corput one-dimensional low-discrepancy sequence generator.
+///
+/// The `i`-th element of the sequence is the radical inverse of `i` in the given
+/// base (see [`util::radical_inverse`](util/fn.radical_inverse.html)), computed
+/// incrementally in the same way as in [`HaltonSeq`](struct.HaltonSeq.html).
+///
+/// As with the Halton sequence, the zero point is skipped.
+#[derive(Clone)]
+pub struct VanDerCorput {
+    index: u64,
+    seq: HaltonSeq1D,
+}
+
+impl VanDerCorput {
+    /// Returns a new van der Corput sequence generator with the given base.
+    ///
+    /// Panics if `base` is less than 2.
+    #[inline]
+    pub fn new(base: u32) -> Self {
+        Self::try_new(base).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as [`new`](#method.new), but returns an error instead of panicking.
+    #[inline]
+    pub fn try_new(base: u32) -> Result<Self, QrngError> {
+        if base < 2 {
+            let value = u64::from(base);
+            return Err(QrngError::InvalidParameter {
+                name: "van der Corput sequence base",
+                value,
+            });
+        }
+        Ok(Self { index: 0, seq: HaltonSeq1D::new(base) })
+    }
+
+    /// Returns the base of the sequence.
+    #[inline]
+    pub fn base(&self) -> u32 {
+        self.seq.base
+    }
+
+    /// Moves the generator so that the next generated point is the one with the
+    /// given (zero-based) index, as if `index` points were generated since the
+    /// beginning of the sequence.
+    ///
+    /// The index is taken modulo `2^48`.
+    #[inline]
+    pub fn set_index(&mut self, index: u64) {
+        self.index = index % (1 << MAX_LOG_N);
+        self.seq.set_index(self.index);
+    }
+}
+
+impl QRng for VanDerCorput {
+    #[inline]
+    fn ndim(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.set_index(0);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        if self.index >= (1 << MAX_LOG_N) {
+            self.index = 0;
+            self.seq.reset();
+        }
+        self.index += 1;
+        *out.get_unchecked_mut(0) = self.seq.next(self.index);
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        let index = (u128::from(self.index) + u128::from(n)) % (1 << MAX_LOG_N);
+        self.set_index(index as u64);
     }
 }
 
@@ -75,8 +158,11 @@ impl HaltonSeq1D {
 ///
 /// The first `ndim` prime numbers are used as bases.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "HaltonState", into = "HaltonState"))]
 pub struct HaltonSeq {
     index: u64,
+    leap: u64,
     seqs: Vec<HaltonSeq1D>,
 }
 
@@ -84,7 +170,360 @@ impl HaltonSeq {
     /// Returns a new Halton sequence generator with dimensionality `ndim`.
     #[inline]
     pub fn new(ndim: usize) -> Self {
-        Self { index: 0, seqs: primes().take(ndim).map(|x| HaltonSeq1D::new(x as _)).collect() }
+        Self::leaped(ndim, 1)
+    }
+
+    /// Returns a new Halton sequence generator with dimensionality `ndim`, skipping
+    /// the first `n` points ("burn-in").
+    ///
+    /// Skipping the initial points of the Halton sequence reduces the correlation
+    /// between its coordinates for high dimensions. Skipping is performed in
+    /// `O(ndim * log(n))` time; note that resetting the generator moves it back to
+    /// the first point.
+    #[inline]
+    pub fn new_with_skip(ndim: usize, n: u64) -> Self {
+        Self::builder().ndim(ndim).skip(n).build()
+    }
+
+    /// Returns a new leaped Halton sequence generator with dimensionality `ndim`.
+    ///
+    /// Only every `leap`-th point of the Halton sequence is generated, i.e. the
+    /// points with indices `leap`, `2 * leap`, `3 * leap`, and so on. Leaping
+    /// breaks up the correlations between the coordinates with large bases
+    /// ("Computational Investigations of Low-Discrepancy Sequences", Kocis and
+    /// Whiten, 1997). The leap should be a prime that is not used as one of the
+    /// bases, e.g. the `(ndim + 1)`-th prime; Kocis and Whiten found 409 to work
+    /// well for up to 400 dimensions.
+    ///
+    /// Unlike the plain sequence, the leaped one is generated by computing the
+    /// radical inverses directly, which is somewhat slower.
+    ///
+    /// Panics if `leap` is zero or not less than `2^48` (the period of the sequence).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = HaltonSeq::leaped(2, 3).with_buf();
+    /// assert_eq!(seq.gen()[0], 0.75); // radical inverse of 3 in base 2
+    /// ```
+    #[inline]
+    pub fn leaped(ndim: usize, leap: u64) -> Self {
+        Self::try_leaped(ndim, leap).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as [`leaped`](#method.leaped), but returns an error instead of panicking.
+    #[inline]
+    pub fn try_leaped(ndim: usize, leap: u64) -> Result<Self, QrngError> {
+        if leap == 0 || leap >= 1 << MAX_LOG_N {
+            return Err(QrngError::InvalidParameter { name: "Halton sequence leap", value: leap });
+        }
+        let seqs = primes().take(ndim).map(|x| HaltonSeq1D::new(x as _)).collect();
+        Ok(Self { index: 0, leap, seqs })
+    }
+
+    /// Returns the leap of the sequence (equal to 1 unless the sequence is leaped).
+    #[inline]
+    pub fn leap(&self) -> u64 {
+        self.leap
+    }
+
+    /// Moves the generator so that the next generated point is the one with the
+    /// given (zero-based) index, as if `index` points were generated since the
+    /// beginning of the sequence.
+    ///
+    /// Instead of replaying the sequence, the internal state is reconstructed
+    /// directly from the digit expansions of the index in `O(ndim * log(index))`
+    /// time. The index is taken modulo the period of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = HaltonSeq::new(2);
+    /// seq.set_index(5);
+    /// assert_eq!(seq.with_buf().gen()[0], 0.375); // radical inverse of 6 in base 2
+    /// ```
+    #[inline]
+    pub fn set_index(&mut self, index: u64) {
+        let period = (1 << MAX_LOG_N) / self.leap;
+        self.index = (index % period) * self.leap;
+        if self.leap == 1 {
+            for s in &mut self.seqs {
+                s.set_index(self.index);
+            }
+        }
+    }
+}
+
+/// Halton sequence generator with dimensionality fixed at compile time.
+///
+/// This is the same sequence as [`HaltonSeq`](struct.HaltonSeq.html), but the
+/// per-dimension generators are stored in an array and [`gen`](#method.gen) returns
+/// the points by value, which is convenient and efficient for low-dimensional
+/// problems.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = HaltonSeqN::<2>::new();
+/// let [x, y] = seq.gen();
+/// assert_eq!((x, y), (0.5, 1. / 3.));
+/// ```
+#[derive(Clone)]
+pub struct HaltonSeqN<const D: usize> {
+    index: u64,
+    seqs: [HaltonSeq1D; D],
+}
+
+impl<const D: usize> HaltonSeqN<D> {
+    /// Returns a new Halton sequence generator with dimensionality `D`.
+    #[inline]
+    pub fn new() -> Self {
+        let mut bases = primes();
+        let seqs = std::array::from_fn(|_| HaltonSeq1D::new(bases.next().unwrap() as _));
+        Self { index: 0, seqs }
+    }
+
+    /// Generates the next point of the sequence.
+    #[inline]
+    pub fn gen(&mut self) -> [f64; D] {
+        if self.index >= (1 << MAX_LOG_N) {
+            self.index = 0;
+            self.seqs.iter_mut().for_each(HaltonSeq1D::reset);
+        }
+        self.index += 1;
+        let index = self.index;
+        let mut out = [0.; D];
+        for (x, s) in out.iter_mut().zip(&mut self.seqs) {
+            *x = unsafe { s.next(index) };
+        }
+        out
+    }
+
+    /// Moves the generator so that the next generated point is the one with the
+    /// given (zero-based) index (see [`HaltonSeq::set_index`](struct.HaltonSeq.html#method.set_index)).
+    #[inline]
+    pub fn set_index(&mut self, index: u64) {
+        self.index = index % (1 << MAX_LOG_N);
+        for s in &mut self.seqs {
+            s.set_index(self.index);
+        }
+    }
+}
+
+impl<const D: usize> Default for HaltonSeqN<D> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const D: usize> QRng for HaltonSeqN<D> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        D
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.set_index(0);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        out.get_unchecked_mut(..D).copy_from_slice(&self.gen());
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        let index = (u128::from(self.index) + u128::from(n)) % (1 << MAX_LOG_N);
+        self.set_index(index as u64);
+    }
+}
+
+impl<const D: usize> SkipTo for HaltonSeqN<D> {
+    #[inline]
+    fn skip_to(&mut self, index: u64) {
+        self.set_index(index);
+    }
+}
+
+/// Version of the [`HaltonState`](struct.HaltonState.html) layout.
+const HALTON_STATE_VERSION: u32 = 1;
+
+/// Opaque snapshot of the state of a [`HaltonSeq`](struct.HaltonSeq.html) generator.
+///
+/// The snapshot includes the digits and remainders of the incremental algorithm,
+/// so the generator can be restored exactly via
+/// [`HaltonSeq::from_state`](struct.HaltonSeq.html#method.from_state).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HaltonState {
+    version: u32,
+    index: u64,
+    leap: u64,
+    bases: Vec<u32>,
+    digits: Vec<Vec<u32>>,
+    remainders: Vec<Vec<f64>>,
+    next_powers: Vec<u64>,
+}
+
+impl HaltonSeq {
+    /// Returns a snapshot of the generator state for checkpointing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = HaltonSeq::new(3);
+    /// seq.discard(100);
+    /// let state = seq.state();
+    /// let mut restored = HaltonSeq::from_state(state).unwrap().with_buf();
+    /// assert_eq!(restored.gen(), seq.with_buf().gen());
+    /// ```
+    pub fn state(&self) -> HaltonState {
+        HaltonState {
+            version: HALTON_STATE_VERSION,
+            index: self.index,
+            leap: self.leap,
+            bases: self.seqs.iter().map(|s| s.base).collect(),
+            digits: self.seqs.iter().map(|s| s.digits.clone()).collect(),
+            remainders: self.seqs.iter().map(|s| s.remainders.clone()).collect(),
+            next_powers: self.seqs.iter().map(|s| s.next_power).collect(),
+        }
+    }
+
+    /// Restores a generator from a snapshot of its state.
+    ///
+    /// The digits of each dimension are checked against the expansion of the index,
+    /// since the incremental algorithm relies on them being consistent.
+    ///
+    /// Returns an error if the snapshot has an unsupported version or is malformed.
+    pub fn from_state(state: HaltonState) -> Result<Self, QrngError> {
+        if state.version != HALTON_STATE_VERSION {
+            let value = u64::from(state.version);
+            return Err(QrngError::InvalidParameter { name: "Halton state version", value });
+        }
+        let HaltonState { index, leap, bases, digits, remainders, next_powers, .. } = state;
+        let ndim = bases.len();
+        let valid = leap != 0
+            && leap < 1 << MAX_LOG_N
+            && index <= 1 << MAX_LOG_N
+            && index % leap == 0
+            && digits.len() == ndim
+            && remainders.len() == ndim
+            && next_powers.len() == ndim
+            && bases.iter().all(|&base| base >= 2);
+        if !valid {
+            return Err(QrngError::InvalidParameter { name: "Halton state", value: index });
+        }
+        let mut seqs = Vec::with_capacity(ndim);
+        for (((base, digits), remainders), next_power) in
+            bases.into_iter().zip(digits).zip(remainders).zip(next_powers)
+        {
+            // leaped sequences compute the radical inverses directly and never
+            // advance the incremental state
+            let mut seq = HaltonSeq1D::new(base);
+            if leap == 1 {
+                seq.set_index(index);
+            }
+            if digits != seq.digits
+                || next_power != seq.next_power
+                || remainders.len() != seq.remainders.len()
+            {
+                return Err(QrngError::InvalidParameter { name: "Halton state", value: index });
+            }
+            seq.remainders = remainders;
+            seqs.push(seq);
+        }
+        Ok(Self { index, leap, seqs })
+    }
+}
+
+impl From<HaltonSeq> for HaltonState {
+    #[inline]
+    fn from(seq: HaltonSeq) -> Self {
+        seq.state()
+    }
+}
+
+impl TryFrom<HaltonState> for HaltonSeq {
+    type Error = QrngError;
+
+    #[inline]
+    fn try_from(state: HaltonState) -> Result<Self, QrngError> {
+        Self::from_state(state)
+    }
+}
+
+/// Builder for [`HaltonSeq`](struct.HaltonSeq.html) generators.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let seq = HaltonSeq::builder().ndim(10).leap(31).skip(100).build();
+/// assert_eq!(seq.leap(), 31);
+/// ```
+#[derive(Clone, Debug)]
+pub struct HaltonSeqBuilder {
+    ndim: usize,
+    leap: u64,
+    skip: u64,
+}
+
+impl HaltonSeqBuilder {
+    /// Sets the dimensionality of the sequence (1 by default).
+    #[inline]
+    pub fn ndim(mut self, ndim: usize) -> Self {
+        self.ndim = ndim;
+        self
+    }
+
+    /// Sets the leap of the sequence (1 by default, see
+    /// [`HaltonSeq::leaped`](struct.HaltonSeq.html#method.leaped)).
+    #[inline]
+    pub fn leap(mut self, leap: u64) -> Self {
+        self.leap = leap;
+        self
+    }
+
+    /// Sets the number of initial points to skip (none by default).
+    #[inline]
+    pub fn skip(mut self, n: u64) -> Self {
+        self.skip = n;
+        self
+    }
+
+    /// Builds the generator.
+    ///
+    /// Panics if the leap is zero or not less than `2^48`.
+    #[inline]
+    pub fn build(self) -> HaltonSeq {
+        self.try_build().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as [`build`](#method.build), but returns an error instead of panicking.
+    #[inline]
+    pub fn try_build(self) -> Result<HaltonSeq, QrngError> {
+        let mut seq = HaltonSeq::try_leaped(self.ndim, self.leap)?;
+        seq.set_index(self.skip);
+        Ok(seq)
+    }
+}
+
+impl HaltonSeq {
+    /// Returns a builder for configuring the generator.
+    #[inline]
+    pub fn builder() -> HaltonSeqBuilder {
+        HaltonSeqBuilder { ndim: 1, leap: 1, skip: 0 }
     }
 }
 
@@ -94,8 +533,28 @@ impl QRng for HaltonSeq {
         self.seqs.len()
     }
 
+    #[inline]
+    fn reset(&mut self) {
+        self.set_index(0);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index / self.leap
+    }
+
     #[inline]
     unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        if self.leap != 1 {
+            if self.index + self.leap > (1 << MAX_LOG_N) {
+                self.index = 0;
+            }
+            self.index += self.leap;
+            for (i, s) in self.seqs.iter().enumerate() {
+                *out.get_unchecked_mut(i) = radical_inverse(s.base, self.index);
+            }
+            return;
+        }
         if self.index >= (1 << MAX_LOG_N) {
             self.index = 0;
             self.seqs.iter_mut().for_each(HaltonSeq1D::reset);
@@ -105,15 +564,129 @@ impl QRng for HaltonSeq {
             *out.get_unchecked_mut(i) = s.next(self.index);
         }
     }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        let period = (1 << MAX_LOG_N) / self.leap;
+        let index = (u128::from(self.index / self.leap) + u128::from(n)) % u128::from(period);
+        self.set_index(index as u64);
+    }
+
+    unsafe fn gen_points_col_major_unchecked(&mut self, n: usize, out: &mut [f64]) {
+        if self.leap != 1 || self.index + n as u64 > (1 << MAX_LOG_N) {
+            scatter_col_major(self, n, out);
+            return;
+        }
+        // the dimensions are independent, so each of them is generated in one go
+        for (j, s) in self.seqs.iter_mut().enumerate() {
+            let col = out.get_unchecked_mut(j * n..(j + 1) * n);
+            for (i, y) in col.iter_mut().enumerate() {
+                *y = s.next(self.index + 1 + i as u64);
+            }
+        }
+        self.index += n as u64;
+    }
+}
+
+impl SkipTo for VanDerCorput {
+    #[inline]
+    fn skip_to(&mut self, index: u64) {
+        self.set_index(index);
+    }
+}
+
+impl SkipTo for HaltonSeq {
+    #[inline]
+    fn skip_to(&mut self, index: u64) {
+        self.set_index(index);
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::HaltonSeq;
-    use crate::{utils::primes, QRng};
+    use super::{HaltonSeq, HaltonSeqN, HaltonState, VanDerCorput};
+    use crate::{
+        util::{primes, radical_inverse},
+        QRng, QrngError,
+    };
 
     const TOL: f64 = 1e-15;
 
+    #[test]
+    fn test_halton_seq_exact() {
+        // bit-exact values of the original implementation (dividing by the base)
+        let expected: [&[f64]; 4] = [
+            &[0.2, 0.4, 0.6, 0.8, 0.04, 0.24, 0.44000000000000006, 0.64],
+            &[
+                0.14285714285714285,
+                0.2857142857142857,
+                0.42857142857142855,
+                0.5714285714285714,
+                0.7142857142857143,
+                0.8571428571428571,
+                0.02040816326530612,
+                0.16326530612244897,
+            ],
+            &[
+                0.09090909090909091,
+                0.18181818181818182,
+                0.2727272727272727,
+                0.36363636363636365,
+                0.45454545454545453,
+                0.5454545454545454,
+                0.6363636363636364,
+                0.7272727272727273,
+            ],
+            &[
+                0.07692307692307693,
+                0.15384615384615385,
+                0.23076923076923078,
+                0.3076923076923077,
+                0.38461538461538464,
+                0.46153846153846156,
+                0.5384615384615384,
+                0.6153846153846154,
+            ],
+        ];
+        let points = HaltonSeq::new(6).gen_points(8);
+        for (j, expected) in expected.iter().enumerate() {
+            let x: Vec<_> = points.chunks(6).map(|p| p[j + 2]).collect();
+            assert_eq!(x, *expected);
+        }
+        // carries cascading through several digits
+        let cases: [(usize, [f64; 4]); 3] = [
+            (
+                1,
+                [
+                    0.9876543209876543,
+                    0.004115226337448559,
+                    0.9958847736625515,
+                    0.0013717421124828531,
+                ],
+            ),
+            (2, [0.96, 0.008, 0.992, 0.0016]),
+            (
+                3,
+                [
+                    0.9795918367346939,
+                    0.0029154518950437317,
+                    0.9970845481049563,
+                    0.00041649312786339027,
+                ],
+            ),
+        ];
+        let indices = [[80, 81, 242, 243], [24, 25, 124, 125], [48, 49, 342, 343]];
+        let points = HaltonSeq::new(4).gen_points(343);
+        for ((j, expected), indices) in cases.iter().zip(&indices) {
+            for (&i, &x) in indices.iter().zip(expected) {
+                assert_eq!(points[(i - 1) * 4 + j], x);
+                let mut seq = HaltonSeq::new(4);
+                seq.set_index(i as u64 - 1);
+                assert_eq!(seq.gen_points(1)[*j], x);
+            }
+        }
+    }
+
     #[test]
     fn test_halton_seq_mod2() {
         let halton2 = vec![
@@ -191,4 +764,198 @@ mod tests {
             assert!((mean - 0.5).abs() < TOL);
         }
     }
+
+    #[test]
+    fn test_halton_seq_leaped() {
+        let mut seq = HaltonSeq::new(10).with_buf();
+        let leaped = HaltonSeq::leaped(10, 31);
+        assert_eq!(leaped.leap(), 31);
+        let mut leaped = leaped.with_buf();
+        let points: Vec<_> = (0..31 * 50).map(|_| seq.gen().to_vec()).collect();
+        for x in points.iter().skip(30).step_by(31) {
+            for (a, b) in leaped.gen().iter().zip(x) {
+                assert!((a - b).abs() < TOL);
+            }
+        }
+    }
+
+    #[test]
+    fn test_halton_seq_set_index() {
+        let mut seq = HaltonSeq::new(6).with_buf();
+        let points: Vec<_> = (0..3000).map(|_| seq.gen().to_vec()).collect();
+        for &i in &[0, 1, 2, 4, 8, 24, 25, 26, 242, 2000, 2998] {
+            let mut seq = HaltonSeq::new(6);
+            seq.set_index(i as u64);
+            let mut seq = seq.with_buf();
+            assert_eq!(seq.gen(), points[i].as_slice());
+            assert_eq!(seq.gen(), points[i + 1].as_slice());
+        }
+        let mut seq = HaltonSeq::leaped(6, 17);
+        seq.set_index(100);
+        let mut seq = seq.with_buf();
+        for (a, b) in seq.gen().iter().zip(&points[101 * 17 - 1]) {
+            assert!((a - b).abs() < TOL);
+        }
+        let mut seq = VanDerCorput::new(3);
+        seq.set_index(80);
+        assert!((seq.with_buf().gen()[0] - radical_inverse(3, 81)).abs() < TOL);
+    }
+
+    #[test]
+    fn test_halton_seq_builder() {
+        let mut seq = HaltonSeq::builder().ndim(3).leap(7).skip(10).build().with_buf();
+        let mut leaped = HaltonSeq::leaped(3, 7).with_buf();
+        for _ in 0..10 {
+            leaped.gen();
+        }
+        for _ in 0..10 {
+            assert_eq!(seq.gen(), leaped.gen());
+        }
+        let mut seq = HaltonSeq::new_with_skip(3, 20).with_buf();
+        let mut halton = HaltonSeq::new(3).with_buf();
+        halton.discard(20);
+        assert_eq!(seq.index(), 20);
+        assert_eq!(seq.gen(), halton.gen());
+        let err = HaltonSeq::builder().leap(0).try_build().err().unwrap();
+        assert_eq!(err, QrngError::InvalidParameter { name: "Halton sequence leap", value: 0 });
+    }
+
+    #[test]
+    fn test_halton_seq_try_new() {
+        assert!(HaltonSeq::try_leaped(3, 1).is_ok());
+        assert!(HaltonSeq::try_leaped(3, 0).is_err());
+        assert!(VanDerCorput::try_new(2).is_ok());
+        assert!(VanDerCorput::try_new(1).is_err());
+    }
+
+    #[test]
+    fn test_halton_seq_n() {
+        let mut seq = HaltonSeq::new(6).with_buf();
+        let mut fixed = HaltonSeqN::<6>::new();
+        for _ in 0..1000 {
+            assert_eq!(fixed.gen(), seq.gen());
+        }
+        fixed.discard(77);
+        seq.discard(77);
+        assert_eq!(fixed.index(), seq.index());
+        assert_eq!(fixed.gen(), seq.gen());
+    }
+
+    #[test]
+    fn test_halton_seq_state() {
+        let mut seq = HaltonSeq::new(5);
+        seq.discard(1234);
+        let restored = HaltonSeq::from_state(seq.state()).unwrap();
+        assert_eq!(restored.index(), 1234);
+        assert_eq!(restored.state(), seq.state());
+        let (mut a, mut b) = (restored.with_buf(), seq.with_buf());
+        for _ in 0..100 {
+            assert_eq!(a.gen().to_vec(), b.gen());
+        }
+        let mut state = HaltonSeq::new(2).state();
+        state.version = 0;
+        assert!(HaltonSeq::from_state(state).is_err());
+        let mut seq = HaltonSeq::leaped(3, 7);
+        seq.discard(100);
+        let restored = HaltonSeq::from_state(seq.state()).unwrap();
+        assert_eq!(restored.with_buf().gen(), seq.with_buf().gen());
+    }
+
+    #[test]
+    fn test_halton_seq_state_inconsistent() {
+        let mut seq = HaltonSeq::new(2);
+        seq.discard(10);
+        let state = seq.state();
+        let check = |f: &dyn Fn(&mut HaltonState)| {
+            let mut state = state.clone();
+            f(&mut state);
+            assert!(HaltonSeq::from_state(state).is_err());
+        };
+        // a digit count that doesn't match the next power of the base
+        check(&|s| {
+            s.next_powers[0] = 1_000_000_000;
+            s.digits[0] = vec![0];
+            s.remainders[0] = vec![0.];
+        });
+        check(&|s| s.next_powers[1] = 81);
+        // digits of a different index
+        check(&|s| s.digits[0] = vec![0, 0, 0, 1]);
+        check(&|s| s.index = 11);
+        check(&|s| s.remainders[1].push(0.));
+        check(&|s| s.index = (1 << 48) + 1);
+        check(&|s| s.leap = 0);
+        check(&|s| s.leap = 1 << 48);
+        let mut state = HaltonSeq::leaped(2, 3).state();
+        state.index = 4;
+        assert!(HaltonSeq::from_state(state).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_halton_seq_serde() {
+        let mut seq = HaltonSeq::leaped(4, 3);
+        seq.discard(321);
+        let json = serde_json::to_string(&seq).unwrap();
+        let restored: HaltonSeq = serde_json::from_str(&json).unwrap();
+        let (mut a, mut b) = (restored.with_buf(), seq.with_buf());
+        for _ in 0..10 {
+            assert_eq!(a.gen().to_vec(), b.gen());
+        }
+        // inconsistent snapshots are rejected instead of being trusted
+        let json = r#"{"version":1,"index":0,"leap":1,"bases":[2],"digits":[[0]],
+            "remainders":[[0.0]],"next_powers":[1000000000]}"#;
+        assert!(serde_json::from_str::<HaltonSeq>(json).is_err());
+        assert!(serde_json::from_str::<HaltonSeq>(&json.replace("1000000000", "1")).is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_halton_seq_leaped_panic() {
+        HaltonSeq::leaped(2, 0);
+    }
+
+    #[test]
+    fn test_halton_seq_leap_bounds() {
+        assert!(HaltonSeq::try_leaped(2, (1 << 48) + 1).is_err());
+        assert!(HaltonSeq::try_leaped(2, 1 << 48).is_err());
+        let mut seq = HaltonSeq::try_leaped(2, (1 << 48) - 1).unwrap();
+        seq.discard(3);
+        seq.reset();
+        assert_eq!(seq.index(), 0);
+        let x = seq.with_buf().gen().to_vec();
+        assert_eq!(x[0], radical_inverse(2, (1 << 48) - 1));
+    }
+
+    #[test]
+    fn test_van_der_corput() {
+        for &base in &[2, 3, 10, 29] {
+            let mut seq = VanDerCorput::new(base).with_buf();
+            for i in 1..1000 {
+                assert!((seq.gen()[0] - radical_inverse(base, i)).abs() < TOL);
+            }
+        }
+    }
+
+    #[test]
+    fn test_van_der_corput_carries() {
+        // long carry chains use the cached negative powers of the base
+        for &base in &[2, 3] {
+            let mut seq = VanDerCorput::new(base).with_buf();
+            let points: Vec<_> = (0..1 << 16).map(|_| seq.gen()[0]).collect();
+            for (i, &x) in points.iter().enumerate() {
+                assert!((x - radical_inverse(base, i as u64 + 1)).abs() < 1e-15);
+            }
+            for &i in &[(1 << 15) - 1, 1 << 15, 59_048, 59_049, (1 << 16) - 2] {
+                let mut seq = VanDerCorput::new(base);
+                seq.set_index(i as u64);
+                assert_eq!(seq.with_buf().gen()[0], points[i]);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_van_der_corput_panic() {
+        VanDerCorput::new(1);
+    }
 }
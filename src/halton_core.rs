@@ -0,0 +1,64 @@
+//! Allocation-free parts of the Halton sequence generators: the digit expansions
+//! of the indices shared by `HaltonSeq` and `StaticHaltonSeq`.
+//!
+//! Unlike the rest of the crate, this module doesn't depend on `std`.
+
+pub(crate) const MAX_LOG_N: usize = 48;
+
+/// Sets the digits of the base-`base` expansion of `index` and the corresponding
+/// remainders, in exactly the same way as they would be computed by [`halton_step`];
+/// `digits` must be long enough to hold all of the digits.
+pub(crate) fn halton_digits(base: u32, mut index: u64, digits: &mut [u32], remainders: &mut [f64]) {
+    for digit in digits.iter_mut() {
+        *digit = (index % u64::from(base)) as u32;
+        index /= u64::from(base);
+    }
+    // each remainder is obtained from the digit and the remainder right above it, in
+    // the same order of operations as in halton_step (adding a zero digit is exact)
+    let base_f = f64::from(base);
+    let top = digits.len() - 1;
+    remainders[top] = 0.;
+    for k in (1..digits.len()).rev() {
+        remainders[k - 1] = (f64::from(digits[k]) + remainders[k]) / base_f;
+    }
+}
+
+/// Increments the base-`base` expansion of the index stored in `digits` and updates
+/// the remainders, returning the radical inverse of the new index.
+///
+/// This divides by the base rather than multiplying by cached reciprocals, since
+/// the latter would round differently and change the generated values.
+///
+/// # Safety
+///
+/// The most significant digit must not overflow, i.e. there must be enough digits to
+/// hold the new index.
+#[inline]
+pub(crate) unsafe fn halton_step(base: u32, digits: &mut [u32], remainders: &mut [f64]) -> f64 {
+    let base_f = f64::from(base);
+    let mut digit = digits.as_mut_ptr();
+    let rem = remainders.as_mut_ptr();
+
+    // increase the least significant bit and see what happens
+    *digit += 1;
+    let h = if *digit == base {
+        // handle carry over if it occurs
+        let mut k = 0;
+        while {
+            k += 1;
+            *digit = 0;
+            digit = digit.add(1);
+            *digit += 1;
+            *digit == base
+        } {}
+        *rem.add(k - 1) = (f64::from(*digit) + *rem.add(k)) / base_f;
+        for i in (1..k).rev() {
+            *rem.add(i - 1) = *rem.add(i) / base_f;
+        }
+        *rem
+    } else {
+        // simple case, no carry
+        f64::from(*digit) + *rem
+    };
+    h / base_f
+}
@@ -0,0 +1,102 @@
+use crate::{
+    traits::PointSet,
+    util::{primes, radical_inverse},
+};
+
+/// Hammersley finite point set.
+///
+/// The `i`-th point of an `n`-point Hammersley set is `i / n` in the first
+/// coordinate, while the remaining coordinates are radical inverses of `i` in
+/// bases of the first `ndim - 1` prime numbers (same as in
+/// [`HaltonSeq`](struct.HaltonSeq.html)).
+///
+/// Unlike the Halton sequence, the set includes the zero point (`i = 0`).
+#[derive(Clone)]
+pub struct HammersleySet {
+    ndim: usize,
+    len: usize,
+    bases: Vec<u32>,
+}
+
+impl HammersleySet {
+    /// Returns a new Hammersley set with dimensionality `ndim` containing `n` points.
+    #[inline]
+    pub fn new(ndim: usize, n: usize) -> Self {
+        let bases = primes().take(ndim.saturating_sub(1)).map(|x| x as _).collect();
+        Self { ndim, len: n, bases }
+    }
+}
+
+impl PointSet for HammersleySet {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    unsafe fn get_fill_unchecked(&self, index: usize, out: &mut [f64]) {
+        if self.ndim == 0 {
+            return;
+        }
+        *out.get_unchecked_mut(0) = (index as f64) / (self.len as f64);
+        for (i, &base) in self.bases.iter().enumerate() {
+            *out.get_unchecked_mut(i + 1) = radical_inverse(base, index as _);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HammersleySet;
+    use crate::{HaltonSeq, PointSet, QRng};
+
+    const TOL: f64 = 1e-15;
+
+    #[test]
+    fn test_hammersley_set() {
+        let expected = [
+            [0., 0., 0.],
+            [0.125, 0.5, 1. / 3.],
+            [0.25, 0.25, 2. / 3.],
+            [0.375, 0.75, 1. / 9.],
+            [0.5, 0.125, 4. / 9.],
+            [0.625, 0.625, 7. / 9.],
+            [0.75, 0.375, 2. / 9.],
+            [0.875, 0.875, 5. / 9.],
+        ];
+        let set = HammersleySet::new(3, 8);
+        assert_eq!(set.len(), 8);
+        assert_eq!(set.iter().len(), 8);
+        for (x, e) in set.iter().zip(expected.iter()) {
+            for (a, b) in x.iter().zip(e.iter()) {
+                assert!((a - b).abs() < TOL);
+            }
+        }
+        assert!(set.get(8).is_none());
+    }
+
+    #[test]
+    fn test_hammersley_set_halton() {
+        let set = HammersleySet::new(6, 100);
+        let mut seq = HaltonSeq::new(5).with_buf();
+        for i in 1..100 {
+            let x = set.get(i).unwrap();
+            assert_eq!(x[0], (i as f64) / 100.);
+            for (a, b) in x[1..].iter().zip(seq.gen()) {
+                assert!((a - b).abs() < TOL);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hammersley_set_panic() {
+        let mut buf = vec![0.; 2];
+        HammersleySet::new(2, 4).get_fill(4, &mut buf);
+    }
+}
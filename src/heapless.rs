@@ -0,0 +1,346 @@
+#[cfg(feature = "std")]
+use crate::traits::{QRng, SkipTo};
+use crate::{
+    error::{check_ndim, QrngError},
+    halton_core::{halton_digits, halton_step, MAX_LOG_N as HALTON_LOG_N},
+    sobol_core::{
+        column, embedded_dirnums, to_unit_f64, DirectionNumbers, MAX_LOG_N as SOBOL_LOG_N,
+    },
+};
+
+/// Sobol sequence generator that never allocates, with dimensionality fixed at
+/// compile time.
+///
+/// This is the same sequence as [`SobolSeqN`](struct.SobolSeqN.html), but the
+/// direction numbers are decoded from the embedded tables directly into a
+/// fixed-size array, so neither construction nor generation touches the heap. This
+/// makes it suitable for firmware and other environments without an allocator; the
+/// state takes `8 * 54 * D` bytes.
+///
+/// This type is only available if the `heapless` feature is enabled.
+/// It doesn't need `std` either, so it can be used with default features disabled
+/// (although it then doesn't implement [`QRng`](trait.QRng.html)).
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = StaticSobolSeq::<2>::new();
+/// assert_eq!(seq.gen(), [0.5, 0.5]);
+/// assert_eq!(seq.gen(), [0.75, 0.25]);
+/// ```
+#[derive(Clone)]
+pub struct StaticSobolSeq<const D: usize> {
+    dirnums: [[u64; D]; SOBOL_LOG_N],
+    value: [u64; D],
+    index: u64,
+}
+
+impl<const D: usize> StaticSobolSeq<D> {
+    /// Returns a new Sobol sequence generator with dimensionality `D`.
+    ///
+    /// Panics if `D` is greater than 1111 (or 21201 if `sobol-high-dim` feature
+    /// is enabled).
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_direction_numbers(DirectionNumbers::JoeKuo)
+    }
+
+    /// Returns a new Sobol sequence generator with dimensionality `D`, using the
+    /// given set of direction numbers.
+    ///
+    /// Panics if `D` is greater than `dnums.max_dim()`.
+    #[inline]
+    pub fn with_direction_numbers(dnums: DirectionNumbers) -> Self {
+        Self::try_with_direction_numbers(dnums).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as [`with_direction_numbers`](#method.with_direction_numbers), but returns
+    /// an error instead of panicking.
+    pub fn try_with_direction_numbers(dnums: DirectionNumbers) -> Result<Self, QrngError> {
+        check_ndim("Sobol sequence", D, dnums.max_dim())?;
+        let mut dirnums = [[0; D]; SOBOL_LOG_N];
+        for j in 0..D {
+            let v = embedded_dirnums(dnums, j);
+            dirnums.iter_mut().zip(&v).for_each(|(row, &v)| row[j] = v);
+        }
+        Ok(Self { dirnums, value: [0; D], index: 0 })
+    }
+
+    /// Generates the next point of the sequence.
+    #[inline]
+    pub fn gen(&mut self) -> [f64; D] {
+        // the index is below 2^53, so c is always a valid row
+        let c = column(self.index, SOBOL_LOG_N);
+        let v = &self.dirnums[c];
+        let mut out = [0.; D];
+        for j in 0..D {
+            self.value[j] ^= v[j];
+            out[j] = to_unit_f64(self.value[j]);
+        }
+        self.index = (self.index + 1) % (1 << SOBOL_LOG_N);
+        out
+    }
+
+    /// Moves the generator so that the next generated point is the one with the
+    /// given (zero-based) index (see [`SobolSeq::skip_to`](struct.SobolSeq.html#method.skip_to)).
+    pub fn skip_to(&mut self, index: u64) {
+        let index = index % (1 << SOBOL_LOG_N);
+        let gray = index ^ (index >> 1);
+        self.value = [0; D];
+        for k in (0..SOBOL_LOG_N).filter(|&k| (gray >> k) & 1 != 0) {
+            self.value.iter_mut().zip(&self.dirnums[k]).for_each(|(x, &v)| *x ^= v);
+        }
+        self.index = index;
+    }
+}
+
+impl<const D: usize> Default for StaticSobolSeq<D> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const D: usize> QRng for StaticSobolSeq<D> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        D
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.skip_to(0);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        out.get_unchecked_mut(..D).copy_from_slice(&self.gen());
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.skip_to(self.index.wrapping_add(n) % (1 << SOBOL_LOG_N));
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const D: usize> SkipTo for StaticSobolSeq<D> {
+    #[inline]
+    fn skip_to(&mut self, index: u64) {
+        StaticSobolSeq::skip_to(self, index);
+    }
+}
+
+/// Maximum number of digits stored for the indices of Halton sequences (reached in
+/// base 2 at the end of the period; like in `HaltonSeq`, there's always one extra
+/// zero digit on top).
+const MAX_DIGITS: usize = HALTON_LOG_N + 2;
+
+/// Digit expansion of the index in one of the bases of a
+/// [`StaticHaltonSeq`](struct.StaticHaltonSeq.html), stored in fixed-size arrays.
+#[derive(Clone)]
+struct StaticHalton1D {
+    base: u32,
+    len: usize,
+    next_power: u64,
+    digits: [u32; MAX_DIGITS],
+    remainders: [f64; MAX_DIGITS],
+}
+
+impl StaticHalton1D {
+    #[inline]
+    fn new(base: u32) -> Self {
+        let (digits, remainders) = ([0; MAX_DIGITS], [0.; MAX_DIGITS]);
+        Self { base, len: 1, next_power: 1, digits, remainders }
+    }
+
+    #[inline]
+    fn set_index(&mut self, index: u64) {
+        self.digits = [0; MAX_DIGITS];
+        self.remainders = [0.; MAX_DIGITS];
+        self.len = 1;
+        self.next_power = 1;
+        while index >= self.next_power {
+            self.len += 1;
+            self.next_power = self.next_power.saturating_mul(u64::from(self.base));
+        }
+        let (digits, remainders) = (&mut self.digits[..self.len], &mut self.remainders[..self.len]);
+        halton_digits(self.base, index, digits, remainders);
+    }
+
+    #[inline]
+    fn next(&mut self, index: u64) -> f64 {
+        // same as in HaltonSeq, the number of digits grows at the powers of the base
+        if index == self.next_power {
+            self.len += 1;
+            self.next_power = self.next_power.saturating_mul(u64::from(self.base));
+        }
+        let (digits, remainders) = (&mut self.digits[..self.len], &mut self.remainders[..self.len]);
+        // the index is at most 2^48, so the digits never overflow
+        unsafe { halton_step(self.base, digits, remainders) }
+    }
+}
+
+/// Returns the `n`-th prime (zero-based) via trial division, without allocating.
+fn nth_prime(n: usize) -> u32 {
+    let is_prime = |x: u32| (2..).take_while(|p| p * p <= x).all(|p| !x.is_multiple_of(p));
+    (2..).filter(|&x| is_prime(x)).nth(n).unwrap()
+}
+
+/// Halton sequence generator that never allocates, with dimensionality fixed at
+/// compile time.
+///
+/// This is the same sequence as [`HaltonSeq`](struct.HaltonSeq.html), but the
+/// digit expansions of the index are stored in fixed-size arrays large enough for
+/// the whole period of the sequence, so neither construction nor generation touches
+/// the heap. The state takes about 600 bytes per dimension.
+///
+/// This type is only available if the `heapless` feature is enabled.
+/// It doesn't need `std` either, so it can be used with default features disabled
+/// (although it then doesn't implement [`QRng`](trait.QRng.html)).
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = StaticHaltonSeq::<2>::new();
+/// assert_eq!(seq.gen(), [0.5, 1. / 3.]);
+/// ```
+#[derive(Clone)]
+pub struct StaticHaltonSeq<const D: usize> {
+    index: u64,
+    seqs: [StaticHalton1D; D],
+}
+
+impl<const D: usize> StaticHaltonSeq<D> {
+    /// Returns a new Halton sequence generator with dimensionality `D`.
+    #[inline]
+    pub fn new() -> Self {
+        let seqs = core::array::from_fn(|j| StaticHalton1D::new(nth_prime(j)));
+        Self { index: 0, seqs }
+    }
+
+    /// Generates the next point of the sequence.
+    #[inline]
+    pub fn gen(&mut self) -> [f64; D] {
+        if self.index >= (1 << HALTON_LOG_N) {
+            self.set_index(0);
+        }
+        self.index += 1;
+        let index = self.index;
+        let mut out = [0.; D];
+        for (x, s) in out.iter_mut().zip(&mut self.seqs) {
+            *x = s.next(index);
+        }
+        out
+    }
+
+    /// Moves the generator so that the next generated point is the one with the
+    /// given (zero-based) index (see [`HaltonSeq::set_index`](struct.HaltonSeq.html#method.set_index)).
+    #[inline]
+    pub fn set_index(&mut self, index: u64) {
+        self.index = index % (1 << HALTON_LOG_N);
+        for s in &mut self.seqs {
+            s.set_index(self.index);
+        }
+    }
+}
+
+impl<const D: usize> Default for StaticHaltonSeq<D> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const D: usize> QRng for StaticHaltonSeq<D> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        D
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.set_index(0);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        out.get_unchecked_mut(..D).copy_from_slice(&self.gen());
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        let index = (u128::from(self.index) + u128::from(n)) % (1 << HALTON_LOG_N);
+        self.set_index(index as u64);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const D: usize> SkipTo for StaticHaltonSeq<D> {
+    #[inline]
+    fn skip_to(&mut self, index: u64) {
+        self.set_index(index);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{nth_prime, StaticHaltonSeq, StaticSobolSeq};
+    use crate::{DirectionNumbers, HaltonSeqN, QRng, SobolSeq, SobolSeqN};
+
+    #[test]
+    fn test_static_sobol_seq() {
+        let mut seq = StaticSobolSeq::<9>::new();
+        let mut expected = SobolSeqN::<9>::new();
+        for _ in 0..1000 {
+            assert_eq!(seq.gen(), expected.gen());
+        }
+        seq.skip_to(123_456_789);
+        expected.skip_to(123_456_789);
+        assert_eq!(seq.gen(), expected.gen());
+        let mut seq = StaticSobolSeq::<40>::with_direction_numbers(DirectionNumbers::BratleyFox);
+        let mut expected =
+            SobolSeq::builder().ndim(40).direction_numbers(DirectionNumbers::BratleyFox).build();
+        let mut x = [0.; 40];
+        for _ in 0..100 {
+            expected.gen_fill(&mut x);
+            assert_eq!(seq.gen(), x);
+        }
+        assert!(
+            StaticSobolSeq::<41>::try_with_direction_numbers(DirectionNumbers::BratleyFox).is_err()
+        );
+    }
+
+    #[test]
+    fn test_static_halton_seq() {
+        assert_eq!((0..8).map(nth_prime).collect::<Vec<_>>(), [2, 3, 5, 7, 11, 13, 17, 19]);
+        let mut seq = StaticHaltonSeq::<6>::new();
+        let mut expected = HaltonSeqN::<6>::new();
+        for _ in 0..3000 {
+            assert_eq!(seq.gen(), expected.gen());
+        }
+        for &i in &[0, 1, 242, 243, 1 << 20, (1 << 48) - 1] {
+            seq.set_index(i);
+            expected.set_index(i);
+            for _ in 0..3 {
+                assert_eq!(seq.gen(), expected.gen());
+            }
+        }
+        // wraps around the period
+        assert_eq!(seq.index(), 2);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::traits::{PointSet, QRng};
+
+/// Iterator over the points of a [`PointSet`](trait.PointSet.html).
+///
+/// This struct is created by the [`iter`](trait.PointSet.html#method.iter) method
+/// on point sets.
+#[derive(Clone)]
+pub struct PointSetIter<'a, S: PointSet> {
+    set: &'a S,
+    index: usize,
+}
+
+impl<'a, S: PointSet> PointSetIter<'a, S> {
+    #[inline]
+    pub(crate) fn new(set: &'a S) -> Self {
+        Self { set, index: 0 }
+    }
+}
+
+impl<'a, S: PointSet> Iterator for PointSetIter<'a, S> {
+    type Item = Vec<f64>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.set.get(self.index)?;
+        self.index += 1;
+        Some(point)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.set.len().saturating_sub(self.index);
+        (len, Some(len))
+    }
+}
+
+impl<'a, S: PointSet> ExactSizeIterator for PointSetIter<'a, S> {}
+
+/// Infinite owning iterator over the points of a [`QRng`](trait.QRng.html).
+///
+/// This struct is created by the [`iter_points`](trait.QRng.html#method.iter_points)
+/// method on generators.
+#[derive(Clone)]
+pub struct QRngIter<R: QRng> {
+    qrng: R,
+}
+
+impl<R: QRng> QRngIter<R> {
+    #[inline]
+    pub(crate) fn new(qrng: R) -> Self {
+        Self { qrng }
+    }
+
+    /// Returns the underlying generator.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.qrng
+    }
+}
+
+impl<R: QRng> Iterator for QRngIter<R> {
+    type Item = Vec<f64>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut point = vec![0.; self.qrng.ndim()];
+        unsafe { self.qrng.gen_fill_unchecked(&mut point) };
+        Some(point)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.qrng.discard(n as u64);
+        self.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{HaltonSeq, QRng};
+
+    #[test]
+    fn test_qrng_iter() {
+        let mut seq = HaltonSeq::new(3).with_buf();
+        let mut iter = HaltonSeq::new(3).iter_points();
+        for _ in 0..10 {
+            assert_eq!(iter.next().unwrap(), seq.gen());
+        }
+        seq.discard(5);
+        assert_eq!(iter.nth(5).unwrap(), seq.gen());
+        assert_eq!(iter.into_inner().index(), 16);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::traits::{QRng, SkipTo};
+
+/// Converts a fractional number to a 64-bit fixed-point representation.
+#[inline]
+fn to_fixed(x: f64) -> u64 {
+    let frac = x - x.floor();
+    // 2^64 * frac may round up to 2^64, which saturates to u64::MAX
+    (frac * 18_446_744_073_709_551_616.) as u64
+}
+
+/// Returns the generalized golden ratio: the unique positive root of `x^(d + 1) = x + 1`.
+#[inline]
+fn golden_ratio(d: usize) -> f64 {
+    let mut x = 2f64;
+    for _ in 0..64 {
+        x = (1. + x).powf(1. / ((d + 1) as f64));
+    }
+    x
+}
+
+/// Kronecker (additive recurrence) low-discrepancy sequence generator.
+///
+/// The `i`-th point of the sequence is `{i * alpha}`, where `alpha` is a vector of
+/// irrational numbers which, together with 1, are linearly independent over the
+/// rationals, and `{x}` denotes the fractional part of `x` taken component-wise.
+///
+/// The recurrence is computed exactly in 64-bit fixed-point arithmetic, so there
+/// is no loss of precision for large indices.
+///
+/// As with [`HaltonSeq`](struct.HaltonSeq.html), the zero point is skipped.
+#[derive(Clone)]
+pub struct KroneckerSeq {
+    alpha: Vec<u64>,
+    value: Vec<u64>,
+    index: u64,
+}
+
+impl KroneckerSeq {
+    /// Returns a new Kronecker sequence generator with the given `alpha` vector.
+    ///
+    /// Only the fractional parts of `alpha` are used. The dimensionality of the
+    /// sequence is equal to the length of `alpha`.
+    #[inline]
+    pub fn new(alpha: &[f64]) -> Self {
+        let alpha: Vec<_> = alpha.iter().map(|&x| to_fixed(x)).collect();
+        Self { value: vec![0; alpha.len()], alpha, index: 0 }
+    }
+
+    /// Returns a new `R_d` sequence generator with dimensionality `ndim`.
+    ///
+    /// This follows "The Unreasonable Effectiveness of Quasirandom Sequences"
+    /// (Roberts, 2018), where `alpha_j = 1 / phi^j` for `j = 1, ..., ndim` and `phi`
+    /// is the generalized golden ratio, i.e. the unique positive root of
+    /// `x^(ndim + 1) = x + 1`. In one dimension, this is the golden ratio sequence.
+    #[inline]
+    pub fn roberts(ndim: usize) -> Self {
+        let phi = golden_ratio(ndim);
+        let alpha: Vec<_> = (1..=ndim).map(|j| phi.powi(-(j as i32))).collect();
+        Self::new(&alpha)
+    }
+}
+
+impl QRng for KroneckerSeq {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.alpha.len()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.skip_to(0);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        const DENUM: f64 = (1u64 << 53) as f64;
+        for j in 0..self.alpha.len() {
+            let x = self.value.get_unchecked_mut(j);
+            *x = x.wrapping_add(*self.alpha.get_unchecked(j));
+            *out.get_unchecked_mut(j) = ((*x >> 11) as f64) / DENUM;
+        }
+        self.index = self.index.wrapping_add(1);
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.skip_to(self.index.wrapping_add(n));
+    }
+}
+
+impl SkipTo for KroneckerSeq {
+    #[inline]
+    fn skip_to(&mut self, index: u64) {
+        self.index = index;
+        for (x, &a) in self.value.iter_mut().zip(&self.alpha) {
+            *x = a.wrapping_mul(index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{golden_ratio, KroneckerSeq};
+    use crate::QRng;
+
+    const TOL: f64 = 1e-15;
+
+    #[test]
+    fn test_golden_ratio() {
+        assert!((golden_ratio(1) - (1. + 5f64.sqrt()) / 2.).abs() < TOL);
+        assert!((golden_ratio(2) - 1.324_717_957_244_746).abs() < TOL);
+    }
+
+    #[test]
+    fn test_kronecker_seq() {
+        let alpha = [2f64.sqrt(), 3f64.sqrt() - 1.];
+        let mut seq = KroneckerSeq::new(&alpha).with_buf();
+        for i in 1..=100 {
+            let x = seq.gen();
+            for (a, b) in x.iter().zip(alpha.iter()) {
+                let e = (i as f64) * b;
+                assert!((a - (e - e.floor())).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_kronecker_seq_roberts() {
+        let mut seq = KroneckerSeq::roberts(2).with_buf();
+        let x = seq.gen().to_vec();
+        assert!((x[0] - 0.754_877_666_246_692_7).abs() < TOL);
+        assert!((x[1] - 0.569_840_290_998_053_2).abs() < TOL);
+    }
+
+    #[test]
+    fn test_kronecker_seq_mean() {
+        const LEN: usize = 100_000;
+        const NDIM: usize = 10;
+        let mut seq = KroneckerSeq::roberts(NDIM).with_buf();
+        let mut sum = [0.; NDIM];
+        for _ in 0..LEN {
+            for (i, &x) in seq.gen().iter().enumerate() {
+                sum[i] += x;
+            }
+        }
+        for s in &sum {
+            assert!(((s / (LEN as f64)) - 0.5).abs() < 1e-4);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::traits::{QRng, SkipTo};
+
+/// Rank-1 lattice rule generator.
+///
+/// The `i`-th point of the lattice is `{i * z / n}`, where `z` is the generating
+/// vector, `n` is the number of points and `{x}` denotes the fractional part of
+/// `x` taken component-wise.
+///
+/// Unlike the digital sequences, the lattice includes the zero point (`i = 0`),
+/// which is generated first. After `n` points the lattice repeats itself.
+#[derive(Clone)]
+pub struct LatticeRule {
+    n: u64,
+    z: Vec<u64>,
+    value: Vec<u64>,
+    index: u64,
+}
+
+impl LatticeRule {
+    /// Returns a new lattice rule generator with `n` points and generating vector `z`.
+    ///
+    /// The dimensionality of the lattice is equal to the length of `z`.
+    ///
+    /// Panics if `n` is zero.
+    #[inline]
+    pub fn new(n: u64, z: &[u64]) -> Self {
+        if n == 0 {
+            panic!("invalid lattice rule size: {}", n);
+        }
+        Self { n, z: z.iter().map(|&x| x % n).collect(), value: vec![0; z.len()], index: 0 }
+    }
+
+    /// Returns a new Korobov lattice rule generator with `n` points.
+    ///
+    /// The generating vector is `(1, a, a^2, ..., a^(ndim - 1))` modulo `n`.
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let seq = LatticeRule::korobov(1021, 76, 4);
+    /// assert_eq!(seq.gen_vector(), &[1, 76, 671, 967]);
+    /// ```
+    #[inline]
+    pub fn korobov(n: u64, a: u64, ndim: usize) -> Self {
+        if n == 0 {
+            panic!("invalid lattice rule size: {}", n);
+        }
+        let mut z = Vec::with_capacity(ndim);
+        let mut x = 1 % n;
+        for _ in 0..ndim {
+            z.push(x);
+            x = ((u128::from(x) * u128::from(a)) % u128::from(n)) as u64;
+        }
+        Self::new(n, &z)
+    }
+
+    /// Returns the number of points in the lattice.
+    #[inline]
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the generating vector of the lattice (reduced modulo `n`).
+    #[inline]
+    pub fn gen_vector(&self) -> &[u64] {
+        &self.z
+    }
+}
+
+impl QRng for LatticeRule {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.z.len()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.index = 0;
+        self.value.iter_mut().for_each(|x| *x = 0);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        let n_f = self.n as f64;
+        for j in 0..self.z.len() {
+            let x = self.value.get_unchecked_mut(j);
+            *out.get_unchecked_mut(j) = (*x as f64) / n_f;
+            // both terms are below n, so subtracting n once is enough to reduce the sum
+            let (y, overflow) = x.overflowing_add(*self.z.get_unchecked(j));
+            *x = if overflow || y >= self.n { y.wrapping_sub(self.n) } else { y };
+        }
+        self.index = if self.index + 1 == self.n { 0 } else { self.index + 1 };
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.skip_to(((u128::from(self.index) + u128::from(n)) % u128::from(self.n)) as u64);
+    }
+}
+
+impl SkipTo for LatticeRule {
+    #[inline]
+    fn skip_to(&mut self, index: u64) {
+        self.index = index % self.n;
+        let (n, k) = (u128::from(self.n), u128::from(self.index));
+        for (x, &z) in self.value.iter_mut().zip(&self.z) {
+            *x = ((k * u128::from(z)) % n) as u64;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LatticeRule;
+    use crate::QRng;
+
+    #[test]
+    fn test_lattice_rule() {
+        let expected = [
+            [0., 0., 0.],
+            [0.125, 0.375, 0.625],
+            [0.25, 0.75, 0.25],
+            [0.375, 0.125, 0.875],
+            [0.5, 0.5, 0.5],
+            [0.625, 0.875, 0.125],
+            [0.75, 0.25, 0.75],
+            [0.875, 0.625, 0.375],
+            [0., 0., 0.],
+        ];
+        let mut seq = LatticeRule::new(8, &[1, 3, 13]).with_buf();
+        assert_eq!(seq.ndim(), 3);
+        for e in &expected {
+            assert_eq!(seq.gen(), e.as_ref());
+        }
+    }
+
+    #[test]
+    fn test_lattice_rule_large_n() {
+        let n = u64::MAX - 58; // largest prime below 2^64
+        let mut seq = LatticeRule::new(n, &[n - 1]).with_buf();
+        seq.gen();
+        assert_eq!(seq.gen()[0], ((n - 1) as f64) / (n as f64));
+        assert_eq!(seq.gen()[0], ((n - 2) as f64) / (n as f64));
+    }
+
+    #[test]
+    fn test_lattice_rule_korobov() {
+        assert_eq!(LatticeRule::korobov(8, 3, 4).gen_vector(), &[1, 3, 1, 3]);
+        assert_eq!(LatticeRule::korobov(1, 3, 2).gen_vector(), &[0, 0]);
+        assert!(LatticeRule::korobov(8, 3, 0).gen_vector().is_empty());
+        let n = u64::MAX - 58;
+        assert_eq!(LatticeRule::korobov(n, n - 1, 3).gen_vector(), &[1, n - 1, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lattice_rule_panic() {
+        LatticeRule::new(0, &[1, 2]);
+    }
+}
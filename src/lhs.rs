@@ -0,0 +1,241 @@
+use crate::{traits::PointSet, util::SplitMix64};
+
+/// Returns a random permutation of `0..n`.
+fn permutation(n: usize, rng: &mut SplitMix64) -> Vec<usize> {
+    let mut perm: Vec<_> = (0..n).collect();
+    for i in (1..n).rev() {
+        perm.swap(i, rng.next_below(i as u64 + 1) as usize);
+    }
+    perm
+}
+
+/// Returns true if `p` is a prime number.
+fn is_prime(p: usize) -> bool {
+    p >= 2 && (2..).take_while(|q| q * q <= p).all(|q| !p.is_multiple_of(q))
+}
+
+/// Latin hypercube sample (LHS) finite point set.
+///
+/// Each coordinate axis is split into `n` equal strata, and each stratum of each
+/// axis contains exactly one of the `n` points; the points are jittered randomly
+/// within their strata. The set is randomized with the given seed.
+///
+/// Plain Latin hypercubes only guarantee good one-dimensional projections; the
+/// [`orthogonal`](#method.orthogonal) and [`optimized`](#method.optimized)
+/// constructors also improve the two-dimensional ones.
+#[derive(Clone)]
+pub struct LatinHypercube {
+    ndim: usize,
+    len: usize,
+    points: Vec<f64>,
+}
+
+impl LatinHypercube {
+    /// Returns a new random Latin hypercube sample with dimensionality `ndim`
+    /// containing `n` points.
+    pub fn new(ndim: usize, n: usize, seed: u64) -> Self {
+        Self::random(ndim, n, &mut SplitMix64::new(seed))
+    }
+
+    fn random(ndim: usize, n: usize, rng: &mut SplitMix64) -> Self {
+        let mut points = vec![0.; n * ndim];
+        for j in 0..ndim {
+            for (i, k) in permutation(n, rng).into_iter().enumerate() {
+                points[i * ndim + j] = (k as f64 + rng.next_f64()) / (n as f64);
+            }
+        }
+        Self { ndim, len: n, points }
+    }
+
+    /// Returns a new orthogonal-array-based Latin hypercube sample with
+    /// dimensionality `ndim` containing `p^2` points.
+    ///
+    /// The sample is built from a randomized orthogonal array of strength 2 with
+    /// `p` levels ("Orthogonal Array-Based Latin Hypercubes", Tang, 1993), so that,
+    /// in addition to the Latin hypercube property, every two-dimensional projection
+    /// is stratified on the `p x p` grid. This is equivalent to SciPy's
+    /// `LatinHypercube(strength=2)`.
+    ///
+    /// Panics if `p` is not a prime number or if `ndim` is greater than `p + 1`.
+    pub fn orthogonal(ndim: usize, p: usize, seed: u64) -> Self {
+        if !is_prime(p) {
+            panic!("invalid orthogonal array size: {}", p);
+        }
+        if ndim > p + 1 {
+            panic!("invalid orthogonal array dimension: {}", ndim);
+        }
+        let mut rng = SplitMix64::new(seed);
+        let n = p * p;
+        let mut points = vec![0.; n * ndim];
+        for j in 0..ndim {
+            // the j-th column of the Bush orthogonal array OA(p^2, p + 1, p, 2) with
+            // randomly permuted levels; each level appears exactly p times
+            let perm = permutation(p, &mut rng);
+            let levels: Vec<_> = (0..n)
+                .map(|i| {
+                    let (a, b) = (i / p, i % p);
+                    perm[match j {
+                        0 => a,
+                        1 => b,
+                        _ => (a + (j - 1) * b) % p,
+                    }]
+                })
+                .collect();
+            // within each level, the p points form a one-dimensional Latin hypercube
+            let strata: Vec<_> = (0..p).map(|_| permutation(p, &mut rng)).collect();
+            let mut counts = vec![0; p];
+            for (i, &level) in levels.iter().enumerate() {
+                let k = strata[level][counts[level]];
+                counts[level] += 1;
+                points[i * ndim + j] = ((level * p + k) as f64 + rng.next_f64()) / (n as f64);
+            }
+        }
+        Self { ndim, len: n, points }
+    }
+
+    /// Returns a new Latin hypercube sample with dimensionality `ndim` containing
+    /// `n` points, optimized to minimize the correlations between the coordinates.
+    ///
+    /// Starting from a random Latin hypercube, values of randomly chosen pairs of
+    /// points are swapped within a randomly chosen column, and the swap is kept
+    /// if it decreases the sum of squared pairwise correlation coefficients of the
+    /// columns. This is repeated `iterations` times. Swaps preserve the Latin
+    /// hypercube property.
+    pub fn optimized(ndim: usize, n: usize, seed: u64, iterations: usize) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        let mut lhs = Self::random(ndim, n, &mut rng);
+        if n < 2 || ndim < 2 {
+            return lhs;
+        }
+        let x = &mut lhs.points;
+        // all columns have the same mean and variance since they are stratified
+        let mean = 0.5;
+        let mut dot = vec![0.; ndim * ndim];
+        for i in 0..n {
+            for j in 0..ndim {
+                for k in 0..ndim {
+                    dot[j * ndim + k] += (x[i * ndim + j] - mean) * (x[i * ndim + k] - mean);
+                }
+            }
+        }
+        for _ in 0..iterations {
+            let j = rng.next_below(ndim as u64) as usize;
+            let a = rng.next_below(n as u64) as usize;
+            let b = rng.next_below(n as u64) as usize;
+            let d = x[a * ndim + j] - x[b * ndim + j];
+            // swapping values a and b in column j changes dot(j, k) by delta(k)
+            let delta = |k: usize| d * (x[b * ndim + k] - x[a * ndim + k]);
+            let gain: f64 = (0..ndim)
+                .filter(|&k| k != j)
+                .map(|k| {
+                    let (old, new) = (dot[j * ndim + k], dot[j * ndim + k] + delta(k));
+                    new * new - old * old
+                })
+                .sum();
+            if gain < 0. {
+                for k in (0..ndim).filter(|&k| k != j) {
+                    let dk = delta(k);
+                    dot[j * ndim + k] += dk;
+                    dot[k * ndim + j] += dk;
+                }
+                x.swap(a * ndim + j, b * ndim + j);
+            }
+        }
+        lhs
+    }
+}
+
+impl PointSet for LatinHypercube {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    unsafe fn get_fill_unchecked(&self, index: usize, out: &mut [f64]) {
+        let point = self.points.get_unchecked(index * self.ndim..(index + 1) * self.ndim);
+        out.get_unchecked_mut(..self.ndim).copy_from_slice(point);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_prime, LatinHypercube};
+    use crate::PointSet;
+
+    fn is_latin(set: &LatinHypercube) -> bool {
+        let n = set.len();
+        (0..set.ndim()).all(|j| {
+            let mut seen = vec![false; n];
+            set.iter().for_each(|x| seen[(x[j] * n as f64) as usize] = true);
+            seen.iter().all(|&s| s)
+        })
+    }
+
+    fn sum_sq_corr(set: &LatinHypercube) -> f64 {
+        let points: Vec<_> = set.iter().collect();
+        let mut sum = 0.;
+        for j in 0..set.ndim() {
+            for k in 0..j {
+                let dot = |a: usize, b: usize| -> f64 {
+                    points.iter().map(|x| (x[a] - 0.5) * (x[b] - 0.5)).sum()
+                };
+                sum += dot(j, k).powi(2) / (dot(j, j) * dot(k, k));
+            }
+        }
+        sum
+    }
+
+    #[test]
+    fn test_is_prime() {
+        let primes: Vec<_> = (0..30).filter(|&p| is_prime(p)).collect();
+        assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+
+    #[test]
+    fn test_latin_hypercube() {
+        let set = LatinHypercube::new(5, 100, 42);
+        assert_eq!(set.len(), 100);
+        assert!(is_latin(&set));
+        assert_eq!(set.get(10), LatinHypercube::new(5, 100, 42).get(10));
+        assert_ne!(set.get(10), LatinHypercube::new(5, 100, 43).get(10));
+    }
+
+    #[test]
+    fn test_latin_hypercube_orthogonal() {
+        let p = 7;
+        let set = LatinHypercube::orthogonal(p + 1, p, 1);
+        assert_eq!(set.len(), p * p);
+        assert!(is_latin(&set));
+        let points: Vec<_> = set.iter().collect();
+        for j in 0..=p {
+            for k in 0..j {
+                let mut seen = vec![false; p * p];
+                for x in &points {
+                    let (a, b) = ((x[j] * p as f64) as usize, (x[k] * p as f64) as usize);
+                    seen[a * p + b] = true;
+                }
+                assert!(seen.iter().all(|&s| s));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_latin_hypercube_orthogonal_panic() {
+        LatinHypercube::orthogonal(3, 6, 1);
+    }
+
+    #[test]
+    fn test_latin_hypercube_optimized() {
+        let set = LatinHypercube::optimized(6, 50, 7, 5000);
+        assert!(is_latin(&set));
+        let (plain, opt) = (sum_sq_corr(&LatinHypercube::new(6, 50, 7)), sum_sq_corr(&set));
+        assert!(opt < plain / 10.);
+    }
+}
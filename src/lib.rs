@@ -1,7 +1,142 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "std")]
+mod combine;
+#[cfg(feature = "std")]
+mod digital_net;
+#[cfg(feature = "std")]
+mod discrepancy;
+mod error;
+#[cfg(feature = "std")]
+pub mod export;
+#[cfg(feature = "std")]
+mod ext;
+#[cfg(feature = "std")]
+mod faure;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "std")]
+mod geometry;
+#[cfg(feature = "std")]
+mod ghalton;
+#[cfg(feature = "std")]
 mod halton;
+#[cfg(any(feature = "std", feature = "heapless"))]
+mod halton_core;
+#[cfg(feature = "std")]
+mod hammersley;
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "std")]
+mod iter;
+#[cfg(feature = "std")]
+mod kronecker;
+#[cfg(feature = "std")]
+mod lattice;
+#[cfg(feature = "std")]
+mod lhs;
+#[cfg(feature = "std")]
+mod niederreiter;
+#[cfg(feature = "rand")]
+mod padded;
+#[cfg(feature = "std")]
+mod partition;
+#[cfg(feature = "std")]
+mod path;
+#[cfg(feature = "std")]
+mod pmj;
+#[cfg(feature = "std")]
+mod poly_lattice;
+#[cfg(feature = "std")]
+mod replicate;
+#[cfg(feature = "rand")]
+mod rng;
+#[cfg(feature = "std")]
+mod scramble;
+#[cfg(feature = "std")]
+mod sensitivity;
+#[cfg(feature = "std")]
+mod shift;
+#[cfg(feature = "std")]
 mod sobol;
+#[cfg(any(feature = "std", feature = "heapless"))]
+mod sobol_core;
+#[cfg(feature = "std")]
+mod space_filling;
+#[cfg(feature = "rand")]
+mod stratified;
+#[cfg(feature = "std")]
+mod supercube;
+#[cfg(feature = "std")]
 mod traits;
-mod utils;
+#[cfg(feature = "std")]
+mod transform;
+#[cfg(feature = "std")]
+mod uniformity;
+#[cfg(feature = "std")]
+pub mod util;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "std")]
 mod with_buf;
 
-pub use crate::{halton::HaltonSeq, sobol::SobolSeq, traits::QRng, with_buf::QRngWithBuf};
+#[cfg(feature = "std")]
+pub use crate::{
+    combine::{Project, Stacked},
+    digital_net::DigitalNet,
+    discrepancy::{
+        centered_l2_discrepancy, l2_star_discrepancy, pairwise_discrepancies, PairDiscrepancy,
+    },
+    ext::{MapPoints, PointChunks, QRngExt, SkipPoints},
+    faure::FaureSeq,
+    geometry::{Dirichlet, Simplex, UnitBall, UnitDisk, UnitSphere},
+    ghalton::GeneralizedHaltonSeq,
+    halton::{HaltonSeq, HaltonSeqBuilder, HaltonSeqN, HaltonState, VanDerCorput},
+    hammersley::HammersleySet,
+    iter::{PointSetIter, QRngIter},
+    kronecker::KroneckerSeq,
+    lattice::LatticeRule,
+    lhs::LatinHypercube,
+    niederreiter::NiederreiterSeq,
+    partition::Leapfrog,
+    path::{BrownianBridge, BrownianPaths, Incremental, PathConstruction, Pca},
+    pmj::Pmj02Seq,
+    poly_lattice::PolyLatticeRule,
+    replicate::{
+        integrate, integrate_median, integrate_median_with, integrate_with, replicate,
+        replicate_map, RqmcEstimate,
+    },
+    scramble::{FastOwenScrambled, OwenScrambled},
+    sensitivity::{MorrisDesign, MorrisIndices, SaltelliDesign, SobolIndices},
+    shift::{DigitalShift, RandomShift},
+    sobol::{
+        CompactSobolSeq, DirectionNumberSource, DirectionTable, SobolNet, SobolSeq,
+        SobolSeqBuilder, SobolSeqN, SobolState,
+    },
+    space_filling::{correlation_matrix, maximin_criterion, min_distance},
+    supercube::LatinSupercube,
+    traits::{DigitalQRng, DynQRng, PointSet, QRng, Randomize, Randomized, SkipTo},
+    transform::{IntMapped, MultivariateNormal, Normalized, Scaled, TentTransformed, Transformed},
+    uniformity::{chi_square_test, dimension_correlations, ks_test, TestResult},
+    with_buf::QRngWithBuf,
+};
+
+pub use crate::error::QrngError;
+
+#[cfg(any(feature = "std", feature = "heapless"))]
+pub use crate::sobol_core::DirectionNumbers;
+
+#[cfg(feature = "heapless")]
+pub use crate::heapless::{StaticHaltonSeq, StaticSobolSeq};
+
+#[cfg(feature = "rand")]
+pub use crate::{
+    padded::PaddedQRng,
+    rng::{QRngAsRng, SampleIter},
+    stratified::StratifiedSet,
+};
+
+#[cfg(feature = "wasm")]
+pub use crate::wasm::{JsHaltonSeq, JsSobolSeq};
@@ -0,0 +1,229 @@
+use crate::{
+    error::{check_ndim, QrngError},
+    traits::{DigitalQRng, QRng},
+};
+
+const MAX_DIM: usize = 12;
+const MAX_PRIM_DEGREE: usize = 5;
+const NBITS: usize = 31;
+const MAXV: usize = NBITS + MAX_PRIM_DEGREE;
+
+/// Irreducible polynomials over GF(2), lowest-order coefficient first; the first
+/// entry (the constant polynomial) is never used.
+const POLYS: [[u8; MAX_PRIM_DEGREE + 1]; MAX_DIM + 1] = [
+    [1, 0, 0, 0, 0, 0], // 1
+    [0, 1, 0, 0, 0, 0], // x
+    [1, 1, 0, 0, 0, 0], // 1 + x
+    [1, 1, 1, 0, 0, 0], // 1 + x + x^2
+    [1, 1, 0, 1, 0, 0], // 1 + x + x^3
+    [1, 0, 1, 1, 0, 0], // 1 + x^2 + x^3
+    [1, 1, 0, 0, 1, 0], // 1 + x + x^4
+    [1, 0, 0, 1, 1, 0], // 1 + x^3 + x^4
+    [1, 1, 1, 1, 1, 0], // 1 + x + x^2 + x^3 + x^4
+    [1, 0, 1, 0, 0, 1], // 1 + x^2 + x^5
+    [1, 0, 0, 1, 0, 1], // 1 + x^3 + x^5
+    [1, 1, 1, 1, 0, 1], // 1 + x + x^2 + x^3 + x^5
+    [1, 1, 1, 0, 1, 1], // 1 + x + x^2 + x^4 + x^5
+];
+
+const POLY_DEGREES: [usize; MAX_DIM + 1] = [0, 1, 1, 2, 3, 3, 4, 4, 4, 5, 5, 5, 5];
+
+/// Multiplies two polynomials over GF(2), returning the product.
+fn poly_mul(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut c = vec![0; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            c[i + j] ^= x & y;
+        }
+    }
+    c
+}
+
+/// Computes the packed generating matrix columns for the given axis, such that
+/// `out[r * stride]` holds the bits of row `r` for all `NBITS` digits.
+fn get_cj(axis: usize, out: &mut [u32], stride: usize) {
+    let px = &POLYS[axis + 1][..=POLY_DEGREES[axis + 1]];
+    let e = px.len() - 1;
+    let mut pb = vec![1];
+    let mut v = [0u8; MAXV + 1];
+    let mut ci = [[0u8; NBITS]; NBITS];
+    let mut u = 0;
+    for j in 0..NBITS {
+        if u == 0 {
+            // advance b to the next power of px and recompute v ("Low-discrepancy
+            // and low-dispersion sequences", Niederreiter, 1988, section 3.3)
+            let k = pb.len() - 1;
+            pb = poly_mul(px, &pb);
+            let m = pb.len() - 1;
+            for (r, x) in v.iter_mut().enumerate().take(m) {
+                *x = (r >= k) as u8;
+            }
+            for r in 0..=(MAXV - m) {
+                v[r + m] = (0..m).fold(0, |t, i| t ^ (pb[i] & v[r + i]));
+            }
+        }
+        for (r, row) in ci.iter_mut().enumerate() {
+            row[j] = v[r + u];
+        }
+        u = (u + 1) % e;
+    }
+    for (r, row) in ci.iter().enumerate() {
+        out[r * stride] = row.iter().fold(0, |t, &x| (t << 1) | u32::from(x));
+    }
+}
+
+/// Niederreiter low-discrepancy sequence generator in base 2.
+///
+/// The implementation follows "Implementation and Tests of Low-Discrepancy
+/// Sequences" (Bratley, Fox and Niederreiter, 1992), with irreducible polynomials
+/// over GF(2) used for each dimension and the Gray code ordering of points.
+///
+/// As with [`SobolSeq`](struct.SobolSeq.html), the zero point is skipped.
+#[derive(Clone)]
+pub struct NiederreiterSeq {
+    ndim: usize,
+    cj: Vec<u32>,
+    value: Vec<u32>,
+    index: u64,
+}
+
+impl NiederreiterSeq {
+    /// Returns a new Niederreiter sequence generator with dimensionality `ndim`.
+    ///
+    /// Panics if `ndim` is greater than 12.
+    #[inline]
+    pub fn new(ndim: usize) -> Self {
+        Self::try_new(ndim).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as [`new`](#method.new), but returns an error instead of panicking.
+    #[inline]
+    pub fn try_new(ndim: usize) -> Result<Self, QrngError> {
+        check_ndim("Niederreiter sequence", ndim, MAX_DIM)?;
+        let mut cj = vec![0; ndim * NBITS];
+        for i in 0..ndim {
+            get_cj(i, &mut cj[i..], ndim);
+        }
+        Ok(Self { ndim, cj, value: vec![0; ndim], index: 0 })
+    }
+}
+
+impl QRng for NiederreiterSeq {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.index = 0;
+        self.value.iter_mut().for_each(|x| *x = 0);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        const MAX_N: u64 = 1u64 << NBITS;
+        const DENUM: f64 = MAX_N as f64;
+        if self.index == MAX_N - 1 {
+            self.index = 0;
+            self.value.iter_mut().for_each(|x| *x = 0);
+        }
+        let c = (!self.index).trailing_zeros() as usize;
+        let v = self.cj.get_unchecked(c * self.ndim..);
+        for j in 0..self.ndim {
+            let x = self.value.get_unchecked_mut(j);
+            *x ^= *v.get_unchecked(j);
+            *out.get_unchecked_mut(j) = f64::from(*x) / DENUM;
+        }
+        self.index += 1;
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        // the sequence restarts after 2^NBITS - 1 points
+        let period = (1u64 << NBITS) - 1;
+        self.index = (self.index % period + n % period) % period;
+        let gray = self.index ^ (self.index >> 1);
+        self.value.iter_mut().for_each(|x| *x = 0);
+        for k in (0..NBITS).filter(|&k| (gray >> k) & 1 != 0) {
+            let v = &self.cj[k * self.ndim..(k + 1) * self.ndim];
+            self.value.iter_mut().zip(v).for_each(|(x, &v)| *x ^= v);
+        }
+    }
+}
+
+impl DigitalQRng for NiederreiterSeq {
+    #[inline]
+    unsafe fn gen_fill_digits_unchecked(&mut self, out: &mut [u64]) {
+        const MAX_N: u64 = 1u64 << NBITS;
+        if self.index == MAX_N - 1 {
+            self.index = 0;
+            self.value.iter_mut().for_each(|x| *x = 0);
+        }
+        let c = (!self.index).trailing_zeros() as usize;
+        let v = self.cj.get_unchecked(c * self.ndim..);
+        for j in 0..self.ndim {
+            let x = self.value.get_unchecked_mut(j);
+            *x ^= *v.get_unchecked(j);
+            *out.get_unchecked_mut(j) = u64::from(*x) << (64 - NBITS);
+        }
+        self.index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NiederreiterSeq;
+    use crate::QRng;
+
+    #[test]
+    fn test_niederreiter_seq() {
+        let expected = vec![
+            [0.5, 0.5, 0.75],
+            [0.75, 0.25, 0.3125],
+            [0.25, 0.75, 0.5625],
+            [0.375, 0.375, 0.875],
+            [0.875, 0.875, 0.125],
+            [0.625, 0.125, 0.6875],
+            [0.125, 0.625, 0.4375],
+            [0.1875, 0.3125, 0.515625],
+        ];
+        let mut seq = NiederreiterSeq::new(3).with_buf();
+        for e in &expected {
+            assert_eq!(seq.gen(), e.as_ref());
+        }
+    }
+
+    #[test]
+    fn test_niederreiter_seq_mean() {
+        const LEN: usize = (1 << 16) - 1;
+        const NDIM: usize = 12;
+        let mut seq = NiederreiterSeq::new(NDIM).with_buf();
+        let mut sum = [0.; NDIM];
+        for _ in 0..LEN {
+            for (i, &x) in seq.gen().iter().enumerate() {
+                sum[i] += x;
+            }
+        }
+        for s in &sum {
+            assert!(((s / (LEN as f64)) - 0.5).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_niederreiter_seq_try_new() {
+        assert!(NiederreiterSeq::try_new(12).is_ok());
+        assert!(NiederreiterSeq::try_new(13).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_niederreiter_seq_panic() {
+        NiederreiterSeq::new(13);
+    }
+}
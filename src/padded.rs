@@ -0,0 +1,134 @@
+use rand::Rng;
+
+use crate::traits::QRng;
+
+/// Sequence generator padding the points of a quasi-random sequence with
+/// pseudo-random coordinates.
+///
+/// The first coordinates of each point are taken from the quasi-random sequence,
+/// and the remaining ones are drawn uniformly from the given PRNG ("QMC padding").
+/// This is the standard approach when the nominal dimensionality exceeds what the
+/// quasi-random generator supports, or when only the leading coordinates matter.
+///
+/// Resetting the generator also restores the PRNG to its initial state, so the
+/// padded sequence is reproducible. Skipping points via
+/// [`discard`](trait.QRng.html#method.discard) advances the PRNG one draw at a
+/// time, so it takes `O(n * (ndim - seq.ndim()))` time regardless of how fast the
+/// underlying sequence can be skipped.
+///
+/// This type is only available if the `rand` feature is enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut seq = PaddedQRng::new(SobolSeq::new(2), 100, StdRng::seed_from_u64(42)).with_buf();
+/// let x = seq.gen();
+/// assert_eq!(x.len(), 100);
+/// assert_eq!(&x[..2], &[0.5, 0.5]);
+/// ```
+#[derive(Clone)]
+pub struct PaddedQRng<R: QRng, P: Rng + Clone> {
+    seq: R,
+    ndim: usize,
+    rng: P,
+    init: P,
+}
+
+impl<R: QRng, P: Rng + Clone> PaddedQRng<R, P> {
+    /// Returns a new generator with dimensionality `ndim`, padding the points of the
+    /// given sequence with values drawn from the given PRNG.
+    ///
+    /// Panics if `ndim` is less than the dimensionality of the sequence.
+    pub fn new(seq: R, ndim: usize, rng: P) -> Self {
+        if ndim < seq.ndim() {
+            panic!("invalid padded dimension: {}", ndim);
+        }
+        let init = rng.clone();
+        Self { seq, ndim, rng, init }
+    }
+
+    /// Returns a reference to the underlying quasi-random sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng, P: Rng + Clone> QRng for PaddedQRng<R, P> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+        self.rng = self.init.clone();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(out);
+        for x in out.get_unchecked_mut(self.seq.ndim()..self.ndim) {
+            *x = self.rng.gen();
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        // the PRNG is advanced as well, so that the points don't depend on how
+        // the sequence was skipped
+        let pad = (self.ndim - self.seq.ndim()) as u64;
+        let draws = n.checked_mul(pad).unwrap_or_else(|| panic!("invalid padded discard: {}", n));
+        self.seq.discard(n);
+        for _ in 0..draws {
+            self.rng.gen::<f64>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::PaddedQRng;
+    use crate::{HaltonSeq, QRng};
+
+    #[test]
+    fn test_padded_qrng() {
+        let padded = PaddedQRng::new(HaltonSeq::new(3), 10, StdRng::seed_from_u64(1));
+        let mut seq = padded.clone().with_buf();
+        let mut halton = HaltonSeq::new(3).with_buf();
+        let points: Vec<_> = (0..20).map(|_| seq.gen().to_vec()).collect();
+        for x in &points {
+            assert_eq!(&x[..3], halton.gen());
+            assert!(x[3..].iter().all(|&x| (0. ..1.).contains(&x)));
+        }
+        assert_ne!(points[0][3..], points[1][3..]);
+        seq.reset();
+        assert_eq!(seq.gen(), points[0].as_slice());
+        let mut skipped = padded.with_buf();
+        skipped.discard(7);
+        assert_eq!(skipped.gen(), points[7].as_slice());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_padded_qrng_panic() {
+        PaddedQRng::new(HaltonSeq::new(3), 2, StdRng::seed_from_u64(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid padded discard: 18446744073709551615")]
+    fn test_padded_qrng_discard_overflow() {
+        PaddedQRng::new(HaltonSeq::new(3), 5, StdRng::seed_from_u64(1)).discard(u64::MAX);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::traits::{QRng, SkipTo};
+
+/// Leapfrogged sequence generator.
+///
+/// Yields the points `offset`, `offset + k`, `offset + 2k`, and so on of the
+/// underlying sequence, jumping over the intermediate points instead of
+/// generating them. Using `k` generators with offsets `0, ..., k - 1` partitions
+/// a single sequence into `k` disjoint interleaved streams, which is the classic
+/// way of parallelizing a quasi-Monte Carlo computation.
+///
+/// Note that leapfrogging may destroy the uniformity of some of the sequences for
+/// particular values of `k`, e.g. powers of 2 for base-2 sequences in some of the
+/// coordinates.
+#[derive(Clone)]
+pub struct Leapfrog<R: SkipTo> {
+    seq: R,
+    k: u64,
+    offset: u64,
+    next: u64,
+}
+
+impl<R: SkipTo> Leapfrog<R> {
+    /// Returns a new leapfrogged generator yielding every `k`-th point of the given
+    /// sequence starting from the point with index `offset`.
+    ///
+    /// Panics if `k` is zero or if `offset` is not less than `k`.
+    #[inline]
+    pub fn new(seq: R, k: u64, offset: u64) -> Self {
+        if k == 0 {
+            panic!("invalid leapfrog stride: {}", k);
+        }
+        if offset >= k {
+            panic!("invalid leapfrog offset: {}", offset);
+        }
+        let mut seq = seq;
+        seq.skip_to(offset);
+        Self { seq, k, offset, next: offset }
+    }
+
+    /// Returns the leapfrog stride.
+    #[inline]
+    pub fn stride(&self) -> u64 {
+        self.k
+    }
+
+    /// Returns a reference to the underlying sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: SkipTo> QRng for Leapfrog<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.next = self.offset;
+        self.seq.skip_to(self.offset);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.next.wrapping_sub(self.offset) / self.k
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(out);
+        self.next = self.next.wrapping_add(self.k);
+        if self.k != 1 {
+            self.seq.skip_to(self.next);
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.next = self.next.wrapping_add(n.wrapping_mul(self.k));
+        self.seq.skip_to(self.next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Leapfrog;
+    use crate::{HaltonSeq, KroneckerSeq, LatticeRule, QRng, SkipTo, SobolSeq};
+
+    fn check_leapfrog<R: SkipTo>(seq: R) {
+        const K: usize = 5;
+        let mut plain = seq.clone().with_buf();
+        let points: Vec<_> = (0..K * 100).map(|_| plain.gen().to_vec()).collect();
+        for offset in 0..K {
+            let mut seq = seq.clone().leapfrog(K as _, offset as _).with_buf();
+            for x in points.iter().skip(offset).step_by(K) {
+                for (a, b) in seq.gen().iter().zip(x) {
+                    assert!((a - b).abs() < 1e-15);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_leapfrog() {
+        check_leapfrog(SobolSeq::new(4));
+        check_leapfrog(HaltonSeq::new(4));
+        check_leapfrog(HaltonSeq::leaped(4, 7));
+        check_leapfrog(LatticeRule::korobov(1021, 76, 4));
+        check_leapfrog(KroneckerSeq::roberts(4));
+    }
+
+    #[test]
+    fn test_split_blocks() {
+        let mut seq = SobolSeq::new(3).with_buf();
+        let points: Vec<_> = (0..4 * 50).map(|_| seq.gen().to_vec()).collect();
+        let blocks = SobolSeq::new(3).split_blocks(4, 50);
+        assert_eq!(blocks.len(), 4);
+        let mut block_points = vec![];
+        for block in blocks {
+            let mut block = block.with_buf();
+            block_points.extend((0..50).map(|_| block.gen().to_vec()));
+        }
+        assert_eq!(block_points, points);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_leapfrog_panic() {
+        Leapfrog::new(SobolSeq::new(2), 4, 4);
+    }
+}
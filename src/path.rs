@@ -0,0 +1,443 @@
+use crate::{traits::QRng, util::inverse_normal_cdf};
+
+/// Construction of discretely sampled Brownian motion paths from independent
+/// standard normal samples.
+///
+/// All constructions produce paths with the same distribution, but they differ in
+/// how the variance of the path is distributed across the inputs. With
+/// quasi-random inputs it pays off to concentrate as much of the variance as
+/// possible in the first few inputs, since the leading coordinates of
+/// low-discrepancy sequences are the most uniform ones.
+pub trait PathConstruction {
+    /// Returns the number of time steps (and the number of normal samples consumed
+    /// per path).
+    fn nsteps(&self) -> usize;
+
+    /// Writes the values of the path at each of the time steps to `path`, given the
+    /// independent standard normal samples `z`.
+    ///
+    /// Panics if the length of either slice differs from the number of steps.
+    fn build_path(&self, z: &[f64], path: &mut [f64]);
+}
+
+/// Checks that the time grid is valid and returns its length.
+fn check_times(times: &[f64]) -> usize {
+    let mut prev = 0.;
+    for &t in times {
+        if t <= prev || !t.is_finite() {
+            panic!("invalid time step: {}", t);
+        }
+        prev = t;
+    }
+    times.len()
+}
+
+/// Checks the lengths of the input and output slices.
+#[inline]
+fn check_lens(nsteps: usize, z: &[f64], path: &[f64]) {
+    if z.len() != nsteps {
+        panic!("invalid number of normal samples: {}", z.len());
+    }
+    if path.len() != nsteps {
+        panic!("invalid path length: {}", path.len());
+    }
+}
+
+/// Incremental (forward) path construction.
+///
+/// The `i`-th input determines the increment of the path over the `i`-th time
+/// step, so the variance is spread evenly across the inputs.
+#[derive(Clone, Debug)]
+pub struct Incremental {
+    stddevs: Vec<f64>,
+}
+
+impl Incremental {
+    /// Returns the incremental construction for the given time grid.
+    ///
+    /// Panics if the times are not positive and strictly increasing.
+    pub fn new(times: &[f64]) -> Self {
+        check_times(times);
+        let mut prev = 0.;
+        let stddevs = times
+            .iter()
+            .map(|&t| {
+                let dt = t - prev;
+                prev = t;
+                dt.sqrt()
+            })
+            .collect();
+        Self { stddevs }
+    }
+}
+
+impl PathConstruction for Incremental {
+    #[inline]
+    fn nsteps(&self) -> usize {
+        self.stddevs.len()
+    }
+
+    fn build_path(&self, z: &[f64], path: &mut [f64]) {
+        check_lens(self.nsteps(), z, path);
+        let mut w = 0.;
+        for ((x, z), s) in path.iter_mut().zip(z).zip(&self.stddevs) {
+            w += s * z;
+            *x = w;
+        }
+    }
+}
+
+/// Brownian bridge path construction.
+///
+/// The first input determines the value of the path at the last time step, the
+/// second one the value in the middle (conditionally on the endpoints), and so on
+/// by recursive bisection. The implementation follows "Monte Carlo Methods in
+/// Finance" (Jäckel, 2002) and supports arbitrary numbers of time steps.
+#[derive(Clone, Debug)]
+pub struct BrownianBridge {
+    // for the i-th input: the index of the point being filled, the indices of the
+    // points on both sides (with the left one offset by one, zero being the origin),
+    // the weights of their values and the conditional standard deviation
+    bridge: Vec<usize>,
+    left: Vec<usize>,
+    right: Vec<usize>,
+    left_weights: Vec<f64>,
+    right_weights: Vec<f64>,
+    stddevs: Vec<f64>,
+}
+
+impl BrownianBridge {
+    /// Returns the Brownian bridge construction for the given time grid.
+    ///
+    /// Panics if the times are not positive and strictly increasing.
+    pub fn new(times: &[f64]) -> Self {
+        let n = check_times(times);
+        let t = times;
+        let mut bridge = vec![0; n];
+        let mut left = vec![0; n];
+        let mut right = vec![0; n];
+        let mut left_weights = vec![0.; n];
+        let mut right_weights = vec![0.; n];
+        let mut stddevs = vec![0.; n];
+        if n != 0 {
+            // map[k] is the (one-based) index of the input filling the k-th point
+            let mut map = vec![0; n];
+            map[n - 1] = 1;
+            bridge[0] = n - 1;
+            stddevs[0] = t[n - 1].sqrt();
+            let mut j = 0;
+            for i in 1..n {
+                while map[j] != 0 {
+                    j += 1;
+                }
+                let mut k = j;
+                while map[k] == 0 {
+                    k += 1;
+                }
+                let l = j + ((k - 1 - j) >> 1);
+                map[l] = i + 1;
+                bridge[i] = l;
+                left[i] = j;
+                right[i] = k;
+                let t0 = if j == 0 { 0. } else { t[j - 1] };
+                left_weights[i] = (t[k] - t[l]) / (t[k] - t0);
+                right_weights[i] = (t[l] - t0) / (t[k] - t0);
+                stddevs[i] = ((t[l] - t0) * (t[k] - t[l]) / (t[k] - t0)).sqrt();
+                j = k + 1;
+                if j >= n {
+                    j = 0;
+                }
+            }
+        }
+        Self { bridge, left, right, left_weights, right_weights, stddevs }
+    }
+}
+
+impl PathConstruction for BrownianBridge {
+    #[inline]
+    fn nsteps(&self) -> usize {
+        self.bridge.len()
+    }
+
+    fn build_path(&self, z: &[f64], path: &mut [f64]) {
+        let n = self.nsteps();
+        check_lens(n, z, path);
+        if n == 0 {
+            return;
+        }
+        path[n - 1] = self.stddevs[0] * z[0];
+        for (i, z) in z.iter().enumerate().skip(1) {
+            let (j, k, l) = (self.left[i], self.right[i], self.bridge[i]);
+            let w0 = if j == 0 { 0. } else { path[j - 1] };
+            path[l] =
+                self.left_weights[i] * w0 + self.right_weights[i] * path[k] + self.stddevs[i] * z;
+        }
+    }
+}
+
+/// Principal component analysis (PCA) path construction.
+///
+/// The path is built as `sum(sqrt(lambda_k) v_k z_k)`, where `lambda_k` and `v_k`
+/// are the eigenvalues and eigenvectors of the covariance matrix `min(t_i, t_j)` of
+/// the path, sorted by decreasing eigenvalue. This maximizes the variance explained
+/// by each leading input, at the cost of `O(n^2)` operations per path (as opposed
+/// to `O(n)` for the other constructions) and an `O(n^3)` setup.
+#[derive(Clone, Debug)]
+pub struct Pca {
+    n: usize,
+    // row-major n x n matrix mapping the inputs to the path
+    matrix: Vec<f64>,
+    eigenvalues: Vec<f64>,
+}
+
+impl Pca {
+    /// Returns the PCA construction for the given time grid.
+    ///
+    /// Panics if the times are not positive and strictly increasing.
+    pub fn new(times: &[f64]) -> Self {
+        let n = check_times(times);
+        let mut cov = vec![0.; n * n];
+        for i in 0..n {
+            for j in 0..n {
+                cov[i * n + j] = times[i.min(j)];
+            }
+        }
+        let (values, vectors) = symmetric_eigen(cov, n);
+        let mut order: Vec<_> = (0..n).collect();
+        order.sort_by(|&a, &b| values[b].total_cmp(&values[a]));
+        let eigenvalues: Vec<_> = order.iter().map(|&k| values[k].max(0.)).collect();
+        let mut matrix = vec![0.; n * n];
+        for (c, &k) in order.iter().enumerate() {
+            let s = eigenvalues[c].sqrt();
+            for i in 0..n {
+                matrix[i * n + c] = s * vectors[i * n + k];
+            }
+        }
+        Self { n, matrix, eigenvalues }
+    }
+
+    /// Returns the eigenvalues of the covariance matrix in decreasing order, i.e.
+    /// the variances explained by each of the inputs.
+    #[inline]
+    pub fn eigenvalues(&self) -> &[f64] {
+        &self.eigenvalues
+    }
+}
+
+/// Computes the eigenvalues and eigenvectors of the symmetric matrix `a` of size
+/// `n x n` (in row-major order) via the cyclic Jacobi method.
+///
+/// Returns the eigenvalues and the matrix with the eigenvectors as columns.
+fn symmetric_eigen(mut a: Vec<f64>, n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut v = vec![0.; n * n];
+    for i in 0..n {
+        v[i * n + i] = 1.;
+    }
+    let norm: f64 = a.iter().map(|x| x * x).sum();
+    for _ in 0..100 {
+        let off: f64 = (0..n)
+            .flat_map(|p| (p + 1..n).map(move |q| (p, q)))
+            .map(|(p, q)| a[p * n + q] * a[p * n + q])
+            .sum();
+        if off <= 1e-32 * norm {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[p * n + q];
+                if apq == 0. {
+                    continue;
+                }
+                let theta = (a[q * n + q] - a[p * n + p]) / (2. * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+                let c = 1. / (t * t + 1.).sqrt();
+                let s = t * c;
+                for k in 0..n {
+                    let (akp, akq) = (a[k * n + p], a[k * n + q]);
+                    a[k * n + p] = c * akp - s * akq;
+                    a[k * n + q] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+                    a[p * n + k] = c * apk - s * aqk;
+                    a[q * n + k] = s * apk + c * aqk;
+                }
+                for k in 0..n {
+                    let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+                    v[k * n + p] = c * vkp - s * vkq;
+                    v[k * n + q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    ((0..n).map(|i| a[i * n + i]).collect(), v)
+}
+
+impl PathConstruction for Pca {
+    #[inline]
+    fn nsteps(&self) -> usize {
+        self.n
+    }
+
+    fn build_path(&self, z: &[f64], path: &mut [f64]) {
+        check_lens(self.n, z, path);
+        for (x, row) in path.iter_mut().zip(self.matrix.chunks_exact(self.n.max(1))) {
+            *x = row.iter().zip(z).map(|(a, z)| a * z).sum();
+        }
+    }
+}
+
+/// Sequence generator producing discretely sampled Brownian motion paths.
+///
+/// Each point of the underlying sequence is mapped to standard normal samples (as
+/// in [`Normalized`](struct.Normalized.html)), which are then mapped to the values
+/// of the path at each of the time steps via the given path construction. The
+/// dimensionality of the sequence must be equal to the number of time steps.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let times: Vec<_> = (1..=16).map(|i| i as f64 / 16.).collect();
+/// let seq = SobolSeq::new(16).skip_points(1);
+/// let mut paths = BrownianPaths::new(seq, BrownianBridge::new(&times)).with_buf();
+/// let path = paths.gen();
+/// assert_eq!(path.len(), 16);
+/// ```
+#[derive(Clone)]
+pub struct BrownianPaths<R: QRng, P: PathConstruction + Clone> {
+    seq: R,
+    construction: P,
+    buf: Vec<f64>,
+}
+
+impl<R: QRng, P: PathConstruction + Clone> BrownianPaths<R, P> {
+    /// Returns a new generator mapping the points of the given sequence to Brownian
+    /// paths via the given construction.
+    ///
+    /// Panics if the dimensionality of the sequence differs from the number of time
+    /// steps.
+    pub fn new(seq: R, construction: P) -> Self {
+        let ndim = seq.ndim();
+        if ndim != construction.nsteps() {
+            panic!("invalid path dimension: {}", ndim);
+        }
+        Self { seq, construction, buf: vec![0.; ndim] }
+    }
+
+    /// Returns a reference to the path construction.
+    #[inline]
+    pub fn construction(&self) -> &P {
+        &self.construction
+    }
+
+    /// Returns a reference to the underlying (uniform) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng, P: PathConstruction + Clone> QRng for BrownianPaths<R, P> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(&mut self.buf);
+        for z in &mut self.buf {
+            *z = inverse_normal_cdf(*z);
+        }
+        let n = self.buf.len();
+        self.construction.build_path(&self.buf, out.get_unchecked_mut(..n));
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BrownianBridge, BrownianPaths, Incremental, PathConstruction, Pca};
+    use crate::{QRng, QRngExt, SobolSeq};
+
+    /// Checks that the construction is linear with covariance `min(t_i, t_j)`.
+    fn check_covariance<P: PathConstruction>(p: &P, times: &[f64]) {
+        let n = times.len();
+        let mut cols = vec![vec![0.; n]; n];
+        for (k, col) in cols.iter_mut().enumerate() {
+            let mut z = vec![0.; n];
+            z[k] = 1.;
+            p.build_path(&z, col);
+        }
+        for i in 0..n {
+            for j in 0..n {
+                let c: f64 = cols.iter().map(|col| col[i] * col[j]).sum();
+                assert!((c - times[i.min(j)]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_path_constructions() {
+        for n in [1, 2, 3, 7, 16, 25] {
+            let times: Vec<_> = (1..=n).map(|i| (i as f64).powf(1.3) / 10.).collect();
+            check_covariance(&Incremental::new(&times), &times);
+            check_covariance(&BrownianBridge::new(&times), &times);
+            check_covariance(&Pca::new(&times), &times);
+        }
+    }
+
+    #[test]
+    fn test_path_construction_order() {
+        let times: Vec<_> = (1..=16).map(|i| i as f64 / 16.).collect();
+        let (mut a, mut b) = (vec![0.; 16], vec![0.; 16]);
+        let mut z = vec![0.; 16];
+        z[0] = 1.;
+        BrownianBridge::new(&times).build_path(&z, &mut a);
+        assert_eq!(a[15], 1.);
+        assert!((a[7] - 0.5).abs() < 1e-15);
+        let pca = Pca::new(&times);
+        let ev = pca.eigenvalues();
+        assert!(ev.windows(2).all(|w| w[0] >= w[1]));
+        assert!(ev[0] / ev.iter().sum::<f64>() > 0.8);
+        Incremental::new(&times).build_path(&z, &mut b);
+        assert!(b.iter().all(|&x| x == 0.25));
+    }
+
+    #[test]
+    fn test_brownian_paths() {
+        let times: Vec<_> = (1..=8).map(|i| i as f64 / 8.).collect();
+        let n = 1 << 12;
+        let seq = SobolSeq::new(8).skip_points(1);
+        let points = BrownianPaths::new(seq, Pca::new(&times)).gen_points(n);
+        let var = points.chunks(8).map(|x| x[7] * x[7]).sum::<f64>() / n as f64;
+        assert!((var - 1.).abs() < 1e-2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_brownian_paths_panic() {
+        BrownianPaths::new(SobolSeq::new(3), Incremental::new(&[1., 2.]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_path_times_panic() {
+        Incremental::new(&[1., 1.]);
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{traits::QRng, util::SplitMix64};
+
+/// Searches for a fine cell `(x, y)` inside the given subsquare such that all of
+/// the elementary intervals containing it are unoccupied.
+///
+/// The subsquare is `[sx, sx + 1) x [sy, sy + 1)` in units of `2^(-depth)`, and the
+/// fine cells are in units of `2^(-l)`.
+fn find_cell(
+    occupied: &[Vec<bool>], l: usize, depth: usize, sx: usize, sy: usize, rng: &mut SplitMix64,
+) -> Option<(usize, usize)> {
+    let shift = l - depth;
+    let mut xs: Vec<_> =
+        ((sx << shift)..((sx + 1) << shift)).filter(|&x| !occupied[l][x]).collect();
+    for i in (1..xs.len()).rev() {
+        xs.swap(i, rng.next_below(i as u64 + 1) as usize);
+    }
+    for x in xs {
+        // the top `depth` bits of y are fixed by the subsquare
+        let valid = (0..=depth).all(|d| !is_occupied(occupied, l, x, sy >> (depth - d), d));
+        if valid {
+            if let Some(y) = find_y(occupied, l, x, sy, depth, rng) {
+                return Some((x, y));
+            }
+        }
+    }
+    None
+}
+
+/// Returns true if the elementary interval of shape `2^(l - d) x 2^d` containing
+/// the fine cell with x-coordinate `x` and the top `d` bits of y-coordinate `yp`
+/// is occupied.
+#[inline]
+fn is_occupied(occupied: &[Vec<bool>], l: usize, x: usize, yp: usize, d: usize) -> bool {
+    occupied[l - d][((x >> d) << d) | yp]
+}
+
+/// Depth-first search over the remaining bits of y in random order.
+fn find_y(
+    occupied: &[Vec<bool>], l: usize, x: usize, yp: usize, d: usize, rng: &mut SplitMix64,
+) -> Option<usize> {
+    if d == l {
+        return Some(yp);
+    }
+    let first = rng.next_below(2) as usize;
+    for &b in &[first, first ^ 1] {
+        let y = (yp << 1) | b;
+        if !is_occupied(occupied, l, x, y, d + 1) {
+            if let Some(y) = find_y(occupied, l, x, y, d + 1, rng) {
+                return Some(y);
+            }
+        }
+    }
+    None
+}
+
+/// Progressive multi-jittered (0,2)-sequence sampler.
+///
+/// The implementation follows "Progressive Multi-Jittered Sample Sequences"
+/// (Christensen, Kensler and Kilpatrick, 2018), with the valid sample positions
+/// found via a depth-first search over elementary intervals as in "Efficient
+/// Generation of Points that Satisfy Two-Dimensional Elementary Intervals"
+/// (Pharr, 2019). Every prefix of length `2^m` of the sequence is a (0,m,2)-net
+/// in base 2, i.e. each elementary interval of area `2^(-m)` contains exactly one
+/// point, and the points are jittered within their strata.
+///
+/// The sequence is two-dimensional and is randomized with the given seed. Points
+/// are generated in batches doubling the sequence length, and all of the points
+/// generated so far are stored by the generator.
+#[derive(Clone)]
+pub struct Pmj02Seq {
+    rng: SplitMix64,
+    points: Vec<[f64; 2]>,
+    index: usize,
+}
+
+impl Pmj02Seq {
+    /// Returns a new pmj02 sequence generator randomized with the given seed.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        let points = vec![[rng.next_f64(), rng.next_f64()]];
+        Self { rng, points, index: 0 }
+    }
+
+    /// Doubles the number of generated points.
+    fn extend(&mut self) {
+        let n = self.points.len();
+        let m = n.trailing_zeros() as usize;
+        let l = m + 1;
+        let res = (1u64 << l) as f64;
+        // occupied[a] is the 2^a x 2^(l - a) grid of elementary intervals (column-major)
+        let mut occupied = vec![vec![false; 1 << l]; l + 1];
+        let mut cells = Vec::with_capacity(n);
+        let mark = |occupied: &mut Vec<Vec<bool>>, x: usize, y: usize| {
+            for (a, occ) in occupied.iter_mut().enumerate() {
+                occ[((x >> (l - a)) << (l - a)) | (y >> a)] = true;
+            }
+        };
+        for p in &self.points {
+            let (x, y) = ((p[0] * res) as usize, (p[1] * res) as usize);
+            mark(&mut occupied, x, y);
+            cells.push((x, y));
+        }
+        // new points go into the unoccupied subsquares of a 2^depth x 2^depth grid,
+        // each of which contains exactly one of the existing points
+        let depth = m / 2 + 1;
+        let shift = l - depth;
+        let mut flips = vec![(1, 1); n];
+        for i in 0..n {
+            let (sx, sy) = (cells[i].0 >> shift, cells[i].1 >> shift);
+            let (fx, fy) = if m.is_multiple_of(2) {
+                // diagonally opposite subsquare
+                (1, 1)
+            } else if i < n / 2 {
+                // one of the two remaining subsquares, chosen randomly
+                flips[i] = if self.rng.next_below(2) == 0 { (1, 0) } else { (0, 1) };
+                flips[i]
+            } else {
+                // the last remaining subsquare; the pair is diagonal, so the flip is the same
+                flips[i - n / 2]
+            };
+            let (sx, sy) = (sx ^ fx, sy ^ fy);
+            let (x, y) = find_cell(&occupied, l, depth, sx, sy, &mut self.rng)
+                .or_else(|| find_cell(&occupied, l, 0, 0, 0, &mut self.rng))
+                .unwrap_or_else(|| {
+                    let w = 1 << shift;
+                    let (rx, ry) = (self.rng.next_below(w), self.rng.next_below(w));
+                    ((sx << shift) | rx as usize, (sy << shift) | ry as usize)
+                });
+            mark(&mut occupied, x, y);
+            let (u, v) = (self.rng.next_f64(), self.rng.next_f64());
+            self.points.push([(x as f64 + u) / res, (y as f64 + v) / res]);
+        }
+    }
+}
+
+impl QRng for Pmj02Seq {
+    #[inline]
+    fn ndim(&self) -> usize {
+        2
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.index = 0;
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index as u64
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        if self.index == self.points.len() {
+            self.extend();
+        }
+        let p = self.points.get_unchecked(self.index);
+        *out.get_unchecked_mut(0) = p[0];
+        *out.get_unchecked_mut(1) = p[1];
+        self.index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Pmj02Seq;
+    use crate::QRng;
+
+    fn is_net(points: &[Vec<f64>]) -> bool {
+        let n = points.len();
+        let m = n.trailing_zeros();
+        (0..=m).all(|a| {
+            let (nx, ny) = (1usize << a, 1usize << (m - a));
+            let mut seen = vec![false; n];
+            for p in points {
+                let (x, y) = ((p[0] * nx as f64) as usize, (p[1] * ny as f64) as usize);
+                seen[x * ny + y] = true;
+            }
+            seen.iter().all(|&s| s)
+        })
+    }
+
+    #[test]
+    fn test_pmj02_seq_net() {
+        for &seed in &[0, 1, 42, 12345] {
+            let mut seq = Pmj02Seq::new(seed).with_buf();
+            let points: Vec<_> = (0..1 << 12).map(|_| seq.gen().to_vec()).collect();
+            for m in 0..=12 {
+                assert!(is_net(&points[..1 << m]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_pmj02_seq_seed() {
+        let mut a = Pmj02Seq::new(1).with_buf();
+        let mut b = Pmj02Seq::new(1).with_buf();
+        let mut c = Pmj02Seq::new(2).with_buf();
+        for _ in 0..100 {
+            let x = a.gen().to_vec();
+            assert_eq!(x, b.gen());
+            assert_ne!(x, c.gen());
+            assert!(x.iter().all(|&x| (0. ..1.).contains(&x)));
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    digital_net::DigitalNet,
+    traits::{DigitalQRng, QRng},
+};
+
+/// Returns the coefficients `u_1, ..., u_n` of the Laurent series expansion
+/// `q(x) / p(x) = sum_l u_l x^(-l)` over GF(2), where `deg q < deg p = m`.
+fn laurent_coefs(p: u64, q: u64, m: usize, n: usize) -> Vec<u64> {
+    let mut u = vec![0; n + 1];
+    for k in 1..=n {
+        // match the coefficients of x^(m - k) on both sides of q(x) = p(x) * (u(x))
+        let mut x = if k <= m { (q >> (m - k)) & 1 } else { 0 };
+        for (l, &ul) in u.iter().enumerate().take(k).skip(k.saturating_sub(m).max(1)) {
+            x ^= (p >> (m + l - k)) & ul;
+        }
+        u[k] = x;
+    }
+    u.remove(0);
+    u
+}
+
+/// Returns the remainder of the division of `a(x)` by `b(x)` over GF(2).
+#[inline]
+fn poly_rem(mut a: u64, b: u64) -> u64 {
+    let db = 63 - b.leading_zeros();
+    while a != 0 && 63 - a.leading_zeros() >= db {
+        a ^= b << (63 - a.leading_zeros() - db);
+    }
+    a
+}
+
+/// Polynomial lattice rule generator over GF(2).
+///
+/// For a modulus `p(x)` of degree `m` and generating polynomials `q_j(x)`, the
+/// `i`-th point has coordinates `v(i(x) * q_j(x) / p(x))`, where `i(x)` is the
+/// polynomial whose coefficients are the binary digits of `i`, and `v` maps the
+/// Laurent series `sum_l u_l x^(-l)` to `sum_{l = 1..m} u_l 2^(-l)`.
+///
+/// Polynomial lattice rules are digital nets with Hankel generating matrices and
+/// are generated in the same way as [`DigitalNet`](struct.DigitalNet.html): the
+/// rule contains `2^m` points, the first of which are the nonzero ones, followed
+/// by the zero point.
+#[derive(Clone)]
+pub struct PolyLatticeRule {
+    net: DigitalNet,
+}
+
+impl PolyLatticeRule {
+    /// Returns a new polynomial lattice rule generator with the given modulus and
+    /// generating polynomials.
+    ///
+    /// Polynomials are represented as bit masks, where the `k`-th bit is the
+    /// coefficient of `x^k`; e.g. `0b1011` is `x^3 + x + 1`. The generating
+    /// polynomials are reduced modulo `p(x)`; the dimensionality of the rule is
+    /// equal to the number of generating polynomials.
+    ///
+    /// Panics if the degree of the modulus is not between 1 and 63.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// // polynomial lattice rule with 8 points and modulus x^3 + x + 1
+    /// let mut seq = PolyLatticeRule::new(0b1011, &[0b1, 0b110]).with_buf();
+    /// assert_eq!(seq.gen(), &[0.125, 0.875]);
+    /// ```
+    pub fn new(modulus: u64, gen: &[u64]) -> Self {
+        let m = 63 - modulus.leading_zeros() as usize;
+        if modulus == 0 || m == 0 {
+            panic!("invalid polynomial lattice rule modulus: {:#b}", modulus);
+        }
+        let matrices: Vec<_> = gen
+            .iter()
+            .map(|&q| {
+                let u = laurent_coefs(modulus, poly_rem(q, modulus), m, 2 * m - 1);
+                // the generating matrix is the Hankel matrix C[r][k] = u[r + k]
+                (0..m).map(|k| (0..m).fold(0, |c, r| (c << 1) | u[r + k])).collect()
+            })
+            .collect();
+        Self { net: DigitalNet::new(m as _, &matrices) }
+    }
+
+    /// Returns the degree of the modulus; the rule contains `2^m` points.
+    #[inline]
+    pub fn m(&self) -> usize {
+        self.net.ncols()
+    }
+}
+
+impl QRng for PolyLatticeRule {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.net.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.net.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.net.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.net.gen_fill_unchecked(out);
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.net.discard(n);
+    }
+}
+
+impl DigitalQRng for PolyLatticeRule {
+    #[inline]
+    unsafe fn gen_fill_digits_unchecked(&mut self, out: &mut [u64]) {
+        self.net.gen_fill_digits_unchecked(out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{laurent_coefs, poly_rem, PolyLatticeRule};
+    use crate::QRng;
+
+    #[test]
+    fn test_laurent_coefs() {
+        // 1 / (x^2 + x + 1) = x^-2 + x^-3 + x^-5 + x^-6 + ...
+        assert_eq!(laurent_coefs(0b111, 0b1, 2, 6), vec![0, 1, 1, 0, 1, 1]);
+        // x / x^3 = x^-2
+        assert_eq!(laurent_coefs(0b1000, 0b10, 3, 5), vec![0, 1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_poly_rem() {
+        assert_eq!(poly_rem(0b110, 0b111), 0b1);
+        assert_eq!(poly_rem(0b1011, 0b1011), 0);
+        assert_eq!(poly_rem(0b11, 0b1011), 0b11);
+    }
+
+    #[test]
+    fn test_poly_lattice_rule() {
+        let mut seq = PolyLatticeRule::new(0b111, &[0b1]).with_buf();
+        let points: Vec<_> = (0..8).map(|_| seq.gen()[0]).collect();
+        assert_eq!(points, vec![0.25, 0.5, 0.75, 0., 0.25, 0.5, 0.75, 0.]);
+    }
+
+    #[test]
+    fn test_poly_lattice_rule_projections() {
+        // every one-dimensional projection is the full grid k / 2^m
+        let seq = PolyLatticeRule::new(0b1000011, &[0b1, 0b101101, 0b11001, 0b111]);
+        assert_eq!(seq.m(), 6);
+        let mut seq = seq.with_buf();
+        let mut seen = vec![[false; 64]; 4];
+        for _ in 0..64 {
+            for (j, &x) in seq.gen().iter().enumerate() {
+                seen[j][(x * 64.) as usize] = true;
+            }
+        }
+        assert!(seen.iter().all(|s| s.iter().all(|&x| x)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_poly_lattice_rule_panic() {
+        PolyLatticeRule::new(0b1, &[0b1]);
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{
+    sobol::SobolSeq,
+    traits::{QRng, Randomize, Randomized},
+    util::SplitMix64,
+};
+
+/// Returns `m` independently randomized copies of the generator.
+///
+/// The seeds for the individual replicates are derived from the given seed, so
+/// the result is reproducible. Averaging an estimate over the replicates yields
+/// an unbiased randomized quasi-Monte Carlo estimate, and the spread of the
+/// per-replicate estimates can be used to construct confidence intervals.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let reps = replicate(SobolSeq::new(3), 10, 42);
+/// assert_eq!(reps.len(), 10);
+/// ```
+pub fn replicate<R: Randomize>(gen: R, m: usize, seed: u64) -> Vec<Randomized<R>> {
+    let mut rng = SplitMix64::new(seed);
+    (0..m).map(|_| gen.clone().randomize(rng.next_u64())).collect()
+}
+
+/// Runs the closure over `m` independently randomized copies of the generator,
+/// returning the per-replicate results.
+///
+/// See [`replicate`](fn.replicate.html) for details; the same seed yields the same
+/// replicates in the same order.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// // RQMC estimates of the integral of x * y over the unit square
+/// let estimates = replicate_map(SobolSeq::new(2), 16, 42, |seq| {
+///     let mut seq = seq.with_buf();
+///     (0..1024).map(|_| seq.gen().iter().product::<f64>()).sum::<f64>() / 1024.
+/// });
+/// let mean = estimates.iter().sum::<f64>() / 16.;
+/// assert!((mean - 0.25).abs() < 1e-3);
+/// ```
+pub fn replicate_map<R, F, T>(gen: R, m: usize, seed: u64, f: F) -> Vec<T>
+where
+    R: Randomize,
+    F: FnMut(Randomized<R>) -> T,
+{
+    replicate(gen, m, seed).into_iter().map(f).collect()
+}
+
+/// Randomized quasi-Monte Carlo estimate of an integral.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RqmcEstimate {
+    /// The estimate, i.e. the mean (or the median) of the per-replicate estimates.
+    pub value: f64,
+    /// The standard error of the estimate, based on the spread of the per-replicate
+    /// estimates.
+    pub std_error: f64,
+    /// The per-replicate estimates.
+    pub replicates: Vec<f64>,
+}
+
+/// Returns a Sobol sequence generator whose first point is the origin.
+///
+/// `SobolSeq` skips the origin, so its first `2^m` points don't form a (t,m,s)-net
+/// ("On dropping the first Sobol' point", Owen, 2022); since the origin is the last
+/// point of the period, moving there first yields the net and the rest of the
+/// sequence after it.
+fn sobol_with_origin(ndim: usize) -> SobolSeq {
+    let mut seq = SobolSeq::new(ndim);
+    seq.skip_to((1 << seq.bits()) - 1);
+    seq
+}
+
+/// Estimates the integral of `f` over the unit hypercube in `ndim` dimensions via
+/// randomized quasi-Monte Carlo.
+///
+/// The integrand is evaluated at the first `n` points of each of `m` independently
+/// scrambled Sobol sequences (see [`integrate_with`](fn.integrate_with.html)), for
+/// `n * m` evaluations in total. Unlike [`SobolSeq`](struct.SobolSeq.html), the
+/// sequences start at the origin, so that for `n` a power of two the points of each
+/// replicate form a scrambled (t,m,s)-net, whose variance decreases faster than
+/// `1 / n^2` for smooth integrands. For best results, `n` should be a power of two.
+///
+/// Panics if `n` is zero or `m` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let est = integrate(|x| x[0] * x[1] * x[2], 3, 1024, 16, 42);
+/// assert!((est.value - 0.125).abs() < 4. * est.std_error);
+/// assert!(est.std_error < 1e-4);
+/// ```
+pub fn integrate<F: FnMut(&[f64]) -> f64>(
+    f: F, ndim: usize, n: usize, m: usize, seed: u64,
+) -> RqmcEstimate {
+    integrate_with(sobol_with_origin(ndim), f, n, m, seed)
+}
+
+/// Estimates the integral of `f` over the unit hypercube via randomized
+/// quasi-Monte Carlo, using `m` randomized replicates of the given generator.
+///
+/// The integrand is evaluated at the first `n` points of each replicate (see
+/// [`replicate`](fn.replicate.html)). The per-replicate estimates are independent
+/// and unbiased, so their mean is an unbiased estimate of the integral, and their
+/// sample standard deviation divided by `sqrt(m)` is its standard error.
+///
+/// Panics if `n` is zero or `m` is less than 2.
+pub fn integrate_with<R, F>(gen: R, f: F, n: usize, m: usize, seed: u64) -> RqmcEstimate
+where
+    R: Randomize,
+    F: FnMut(&[f64]) -> f64,
+{
+    if m < 2 {
+        panic!("invalid number of replicates: {}", m);
+    }
+    let replicates = run_replicates(gen, f, n, m, seed);
+    let mf = m as f64;
+    let value = replicates.iter().sum::<f64>() / mf;
+    let var = replicates.iter().map(|y| (y - value) * (y - value)).sum::<f64>() / (mf - 1.);
+    RqmcEstimate { value, std_error: (var / mf).sqrt(), replicates }
+}
+
+/// Estimates the integral of `f` over the unit hypercube in `ndim` dimensions via
+/// the median-of-means randomized quasi-Monte Carlo estimator.
+///
+/// See [`integrate_median_with`](fn.integrate_median_with.html) for details; the
+/// replicates are scrambled Sobol nets as in [`integrate`](fn.integrate.html).
+///
+/// Panics if `n` is zero, or if `m` is even or less than 3.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let est = integrate_median(|x| x[0] * x[1] * x[2], 3, 1024, 15, 42);
+/// assert!((est.value - 0.125).abs() < 1e-4);
+/// ```
+pub fn integrate_median<F: FnMut(&[f64]) -> f64>(
+    f: F, ndim: usize, n: usize, m: usize, seed: u64,
+) -> RqmcEstimate {
+    integrate_median_with(sobol_with_origin(ndim), f, n, m, seed)
+}
+
+/// Estimates the integral of `f` over the unit hypercube via the median-of-means
+/// randomized quasi-Monte Carlo estimator, using `m` randomized replicates of the
+/// given generator.
+///
+/// The integrand is evaluated at the first `n` points of each replicate, and the
+/// median of the per-replicate estimates is returned. Since the errors of
+/// scrambled nets are occasionally much larger than typical, the median is far
+/// more robust than the mean and converges at a faster rate for smooth integrands
+/// ("Super-polynomial accuracy of one dimensional randomized nets using the median
+/// of means", Pan and Owen, 2023). The number of replicates must be odd, so the
+/// median is one of the estimates.
+///
+/// The reported standard error is the asymptotic standard error of the median of
+/// normally distributed estimates, `sqrt(pi / 2) * s / sqrt(m)`, where `s` is the
+/// sample standard deviation of the per-replicate estimates; it is typically
+/// conservative.
+///
+/// Panics if `n` is zero, or if `m` is even or less than 3.
+pub fn integrate_median_with<R, F>(gen: R, f: F, n: usize, m: usize, seed: u64) -> RqmcEstimate
+where
+    R: Randomize,
+    F: FnMut(&[f64]) -> f64,
+{
+    if m < 3 || m.is_multiple_of(2) {
+        panic!("invalid number of replicates: {}", m);
+    }
+    let replicates = run_replicates(gen, f, n, m, seed);
+    let mut sorted = replicates.clone();
+    sorted.sort_by(f64::total_cmp);
+    let value = sorted[m / 2];
+    let mf = m as f64;
+    let mean = replicates.iter().sum::<f64>() / mf;
+    let var = replicates.iter().map(|y| (y - mean) * (y - mean)).sum::<f64>() / (mf - 1.);
+    let std_error = (std::f64::consts::FRAC_PI_2 * var / mf).sqrt();
+    RqmcEstimate { value, std_error, replicates }
+}
+
+/// Returns the per-replicate estimates of the integral of `f` over the first `n`
+/// points of `m` randomized replicates of the generator.
+fn run_replicates<R, F>(gen: R, mut f: F, n: usize, m: usize, seed: u64) -> Vec<f64>
+where
+    R: Randomize,
+    F: FnMut(&[f64]) -> f64,
+{
+    if n == 0 {
+        panic!("invalid number of points: {}", n);
+    }
+    let mut x = vec![0.; gen.ndim()];
+    replicate_map(gen, m, seed, |mut seq| {
+        let mut sum = 0.;
+        for _ in 0..n {
+            unsafe { seq.gen_fill_unchecked(&mut x) };
+            sum += f(&x);
+        }
+        sum / n as f64
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        integrate, integrate_median, integrate_with, replicate, replicate_map, sobol_with_origin,
+    };
+    use crate::{LatticeRule, PointSet, QRng, RandomShift, SobolNet, SobolSeq};
+
+    #[test]
+    fn test_replicate() {
+        let seq = LatticeRule::korobov(1021, 76, 3);
+        let first = |seq: RandomShift<LatticeRule>| seq.with_buf().gen().to_vec();
+        let a = replicate_map(seq.clone(), 8, 1, first);
+        let b: Vec<_> = replicate(seq.clone(), 8, 1).into_iter().map(first).collect();
+        let c = replicate_map(seq, 8, 2, first);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        for i in 0..8 {
+            for j in 0..i {
+                assert_ne!(a[i], a[j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_integrate() {
+        let f = |x: &[f64]| x.iter().map(|x| 1.5 * x.sqrt()).product::<f64>();
+        let est = integrate(f, 4, 1 << 10, 8, 1);
+        assert_eq!(est.replicates.len(), 8);
+        assert!((est.value - 1.).abs() < 5. * est.std_error);
+        assert!(est.std_error > 0. && est.std_error < 1e-3);
+        assert_eq!(integrate(f, 4, 1 << 10, 8, 1), est);
+        let lattice = LatticeRule::korobov(1021, 76, 2);
+        let est = integrate_with(lattice, |x| x[0] * x[1], 1021, 10, 5);
+        assert!((est.value - 0.25).abs() < 5. * est.std_error);
+    }
+
+    #[test]
+    fn test_integrate_nets() {
+        let mut seq = sobol_with_origin(3);
+        let net = SobolNet::new(3, 4);
+        let mut points = seq.gen_points(16);
+        let mut expected: Vec<_> = (0..16).flat_map(|i| net.get(i).unwrap()).collect();
+        let key = |x: &[f64]| x.iter().fold(0., |k, &x| 16. * k + x);
+        for p in [&mut points, &mut expected] {
+            let mut rows: Vec<_> = p.chunks(3).map(|x| x.to_vec()).collect();
+            rows.sort_by(|a, b| key(a).total_cmp(&key(b)));
+            *p = rows.concat();
+        }
+        assert_eq!(points, expected);
+        assert_eq!(seq.gen_points(3), SobolSeq::new_with_skip(3, 15).gen_points(3));
+
+        // the variance of scrambled nets decreases as n^-3 for smooth integrands, so
+        // the standard error should drop by much more than the Monte Carlo rate
+        let f = |x: &[f64]| x.iter().map(|x| x.exp()).product::<f64>();
+        let small = integrate(f, 3, 1 << 6, 16, 1);
+        let large = integrate(f, 3, 1 << 12, 16, 1);
+        assert!(small.std_error > 100. * large.std_error);
+        assert!((large.value - (1f64.exp() - 1.).powi(3)).abs() < 5. * large.std_error);
+    }
+
+    #[test]
+    fn test_integrate_median() {
+        let f = |x: &[f64]| x.iter().map(|x| 1.5 * x.sqrt()).product::<f64>();
+        let mean = integrate(f, 4, 1 << 10, 9, 3);
+        let median = integrate_median(f, 4, 1 << 10, 9, 3);
+        assert_eq!(mean.replicates, median.replicates);
+        assert!(median.replicates.contains(&median.value));
+        let below = median.replicates.iter().filter(|&&y| y < median.value).count();
+        assert_eq!(below, 4);
+        assert!((median.value - 1.).abs() < 5. * median.std_error);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_integrate_median_panic() {
+        integrate_median(|x| x[0], 1, 16, 4, 0);
+    }
+}
@@ -0,0 +1,300 @@
+use std::marker::PhantomData;
+
+use rand::{distributions::Distribution, Error, RngCore};
+
+use crate::{traits::QRng, util::SplitMix64};
+
+/// Adaptor exposing a quasi-random sequence as a [`rand::RngCore`] source.
+///
+/// The coordinates of the points of the sequence are consumed one by one, in order:
+/// the first value returned is the first coordinate of the first point, then the
+/// second coordinate and so on. Each coordinate `x` is converted to an integer as
+/// `floor(x * 2^32)` or `floor(x * 2^64)`; since the coordinates only have a finite
+/// number of significant bits (e.g. 53 for Sobol sequences), the lowest bits of
+/// 64-bit outputs may always be zero.
+///
+/// Note that low-discrepancy properties only carry over if each draw of the
+/// consumer uses exactly one value per dimension, and all draws for one sample
+/// use the same number of values (which should be equal to the dimensionality
+/// of the sequence); otherwise the sequence effectively gets reshuffled across
+/// dimensions and the result may be much worse than with a pseudo-random source.
+/// The values returned are also far from independent, so this should never be
+/// used where statistical randomness is expected.
+///
+/// This type is only available if the `rand` feature is enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// use rand::Rng;
+///
+/// let mut rng = QRngAsRng::new(SobolSeq::new(2));
+/// let (x, y): (f64, f64) = (rng.gen(), rng.gen());
+/// assert_eq!((x, y), (0.5, 0.5));
+/// ```
+#[derive(Clone)]
+pub struct QRngAsRng<R: QRng> {
+    seq: R,
+    buf: Vec<f64>,
+    pos: usize,
+}
+
+impl<R: QRng> QRngAsRng<R> {
+    /// Returns a new adaptor consuming the coordinates of the given sequence.
+    ///
+    /// Panics if the sequence is zero-dimensional.
+    pub fn new(seq: R) -> Self {
+        let ndim = seq.ndim();
+        if ndim == 0 {
+            panic!("invalid dimension: {}", ndim);
+        }
+        Self { seq, buf: vec![0.; ndim], pos: ndim }
+    }
+
+    /// Returns a reference to the underlying sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+
+    /// Returns the next coordinate of the sequence.
+    #[inline]
+    fn next_f64(&mut self) -> f64 {
+        if self.pos == self.buf.len() {
+            unsafe { self.seq.gen_fill_unchecked(&mut self.buf) };
+            self.pos = 0;
+        }
+        let x = self.buf[self.pos];
+        self.pos += 1;
+        x
+    }
+}
+
+impl<R: QRng> RngCore for QRngAsRng<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_f64() * 4294967296.) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (self.next_f64() * 18446744073709551616.) as u64
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        // big-endian, so that the most significant bytes of each value come first
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_be_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Random source serving a single coordinate of a point to a distribution.
+///
+/// If the distribution asks for more than one value, the extra values are taken
+/// from a pseudo-random stream (so that e.g. rejection loops still terminate), and
+/// the draw is marked as invalid.
+struct SingleDraw {
+    value: f64,
+    draws: u32,
+    extra: SplitMix64,
+}
+
+impl SingleDraw {
+    #[inline]
+    fn new(value: f64) -> Self {
+        Self { value, draws: 0, extra: SplitMix64::new(value.to_bits()) }
+    }
+
+    /// Returns true if no more than one value has been consumed.
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.draws <= 1
+    }
+
+    /// Returns the next value as a 64-bit integer, `floor(x * 2^64)` for the
+    /// coordinate `x` on the first call.
+    #[inline]
+    fn next_bits(&mut self) -> u64 {
+        self.draws = self.draws.saturating_add(1);
+        if self.draws == 1 {
+            (self.value * 18446744073709551616.) as u64
+        } else {
+            self.extra.next_u64()
+        }
+    }
+}
+
+impl RngCore for SingleDraw {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        // same as floor(x * 2^32), since scaling by powers of two is exact
+        (self.next_bits() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.next_bits()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_bits().to_be_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Iterator mapping each coordinate of the points of a sequence through a
+/// distribution.
+///
+/// This struct is created by the [`sample_iter`](trait.QRngExt.html#method.sample_iter)
+/// method on generators.
+///
+/// Each coordinate of each point is used for exactly one draw from the distribution,
+/// so that the low-discrepancy structure of the sequence is preserved. Therefore,
+/// only distributions consuming a single random value per draw (e.g. by inverting
+/// their CDF, like `rand::distributions::Uniform<f64>` or `Bernoulli`) are
+/// supported, and the iterator is infinite for those.
+///
+/// Distributions using rejection sampling or consuming several values (e.g.
+/// integer `Uniform`, or ziggurat-based normal samplers) can't be detected up front,
+/// since they may only ask for another value for some inputs. Instead, the iterator
+/// ends (returns `None` from then on) at the first draw consuming more than one
+/// value, discarding the point, so with such distributions the failure may only
+/// show up after arbitrarily many points.
+///
+/// This type is only available if the `rand` feature is enabled.
+#[derive(Clone)]
+pub struct SampleIter<R: QRng, D, T> {
+    seq: R,
+    dist: D,
+    buf: Vec<f64>,
+    done: bool,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<R: QRng, D: Distribution<T>, T> SampleIter<R, D, T> {
+    #[inline]
+    pub(crate) fn new(seq: R, dist: D) -> Self {
+        let buf = vec![0.; seq.ndim()];
+        Self { seq, dist, buf, done: false, phantom: PhantomData }
+    }
+}
+
+impl<R: QRng, D: Distribution<T>, T> Iterator for SampleIter<R, D, T> {
+    type Item = Vec<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        unsafe { self.seq.gen_fill_unchecked(&mut self.buf) };
+        let mut point = Vec::with_capacity(self.buf.len());
+        for &value in &self.buf {
+            let mut rng = SingleDraw::new(value);
+            point.push(self.dist.sample(&mut rng));
+            if !rng.is_valid() {
+                self.done = true;
+                return None;
+            }
+        }
+        Some(point)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{
+        distributions::{Bernoulli, Distribution, Uniform},
+        Rng, RngCore,
+    };
+
+    use super::QRngAsRng;
+    use crate::{HaltonSeq, QRng, QRngExt};
+
+    #[test]
+    fn test_qrng_as_rng() {
+        let mut rng = QRngAsRng::new(HaltonSeq::new(3));
+        let mut halton = HaltonSeq::new(3).with_buf();
+        for _ in 0..10 {
+            let x = halton.gen().to_vec();
+            assert_eq!(rng.next_u64(), (x[0] * 2f64.powi(64)) as u64);
+            assert_eq!(rng.next_u32(), (x[1] * 2f64.powi(32)) as u32);
+            assert!((rng.gen::<f64>() - x[2]).abs() < 1e-15);
+        }
+        let mut bytes = [0; 12];
+        rng.fill_bytes(&mut bytes);
+        let x = halton.gen().to_vec();
+        assert_eq!(bytes[..8], ((x[0] * 2f64.powi(64)) as u64).to_be_bytes());
+        assert_eq!(bytes[8..], ((x[1] * 2f64.powi(64)) as u64).to_be_bytes()[..4]);
+    }
+
+    #[test]
+    fn test_sample_iter() {
+        let mut halton = HaltonSeq::new(2).with_buf();
+        let mut iter = HaltonSeq::new(2).sample_iter(Uniform::new(-1., 1.));
+        for _ in 0..10 {
+            let (x, y) = (iter.next().unwrap(), halton.gen());
+            for j in 0..2 {
+                assert!((x[j] - (2. * y[j] - 1.)).abs() < 1e-12);
+            }
+        }
+        let coins: Vec<_> =
+            HaltonSeq::new(1).sample_iter(Bernoulli::new(0.25).unwrap()).take(8).collect();
+        assert_eq!(coins.iter().filter(|x| x[0]).count(), 2);
+    }
+
+    #[test]
+    fn test_sample_iter_multiple_draws() {
+        struct Pair;
+        impl Distribution<f64> for Pair {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+                rng.gen::<f64>() + rng.gen::<f64>()
+            }
+        }
+        let mut iter = HaltonSeq::new(2).sample_iter(Pair);
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+        struct Rejection;
+        impl Distribution<f64> for Rejection {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+                loop {
+                    let x = rng.gen::<f64>();
+                    if x < 0.9 {
+                        return x;
+                    }
+                }
+            }
+        }
+        // the 15th point of the van der Corput sequence is the first one above 0.9
+        let points: Vec<_> = HaltonSeq::new(1).sample_iter(Rejection).collect();
+        assert_eq!(points, HaltonSeq::new(1).gen_points(14).chunks(1).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,406 @@
+use crate::{
+    digital_net::DigitalNet,
+    niederreiter::NiederreiterSeq,
+    poly_lattice::PolyLatticeRule,
+    sobol::SobolSeq,
+    traits::{DigitalQRng, QRng, Randomize},
+    util::{mix64, SplitMix64},
+};
+
+/// Number of scrambled binary digits (the precision of an `f64` mantissa).
+const NBITS: usize = 53;
+
+/// Applies nested uniform scrambling to the digits of `x` (most significant bit
+/// first), returning the scrambled digits in the top `NBITS` bits.
+///
+/// The `k`-th digit is flipped depending on a random bit attached to the node of
+/// the binary tree identified by the first `k` digits of `x`; instead of storing
+/// the tree, the random bits are derived by hashing the node with the given seed.
+#[inline]
+fn owen_scramble(x: u64, seed: u64) -> u64 {
+    let mut flips = 0;
+    for k in 0..NBITS {
+        let prefix = if k == 0 { 0 } else { x >> (64 - k) };
+        let node = ((k as u64) << 58) | prefix;
+        flips |= (mix64(seed ^ node) >> 63) << (63 - k);
+    }
+    (x ^ flips) & !((1 << (64 - NBITS)) - 1)
+}
+
+/// Applies the hash-based approximation of nested uniform scrambling to the top 32
+/// digits of `x`, returning the scrambled digits in the top 32 bits.
+///
+/// The digits are reversed so that the Laine–Karras permutation, in which each bit
+/// only depends on the lower bits, affects each digit based on the preceding ones.
+#[inline]
+fn lk_scramble(x: u64, seed: u32) -> u64 {
+    let mut x = ((x >> 32) as u32).reverse_bits();
+    x = x.wrapping_add(seed);
+    x ^= x.wrapping_mul(0x6c50_b47c);
+    x ^= x.wrapping_mul(0xb82f_1e52);
+    x ^= x.wrapping_mul(0xc7af_e638);
+    x ^= x.wrapping_mul(0x8d22_f6e6);
+    u64::from(x.reverse_bits()) << 32
+}
+
+/// Applies random linear matrix scrambling to the generating matrices, with the
+/// columns stored as `precision`-bit integers (most significant bit being the
+/// first row) in `columns[k * ndim + j]`.
+///
+/// Each matrix is left-multiplied by a random lower-triangular matrix with unit
+/// diagonal, drawn independently for each dimension.
+pub(crate) fn linear_scramble(columns: &mut [u64], ndim: usize, precision: u32, seed: u64) {
+    let mut rng = SplitMix64::new(seed);
+    let p = precision as usize;
+    for j in 0..ndim {
+        // the r-th row of the scrambling matrix, with the s-th column at bit p - 1 - s
+        let rows: Vec<u64> = (0..p)
+            .map(|r| {
+                let below = if r == 0 { 0 } else { (rng.next_u64() >> (64 - r)) << (p - r) };
+                below | (1 << (p - 1 - r))
+            })
+            .collect();
+        for c in columns.iter_mut().skip(j).step_by(ndim) {
+            *c = rows
+                .iter()
+                .enumerate()
+                .fold(0, |x, (r, &row)| x | u64::from((row & *c).count_ones() & 1) << (p - 1 - r));
+        }
+    }
+}
+
+/// Applies Faure–Tezuka scrambling to the generating matrices, with the columns
+/// stored in `columns[k * ndim + j]` for `ncols` columns.
+///
+/// All matrices are right-multiplied by the same random upper-triangular matrix
+/// with unit diagonal, i.e. each column is replaced by the sum of itself and a
+/// random subset of the preceding columns.
+pub(crate) fn faure_tezuka_scramble(columns: &mut [u64], ndim: usize, ncols: usize, seed: u64) {
+    let mut rng = SplitMix64::new(seed);
+    let coefs: Vec<u64> = (0..ncols).map(|_| rng.next_u64()).collect();
+    // iterating backwards so that the preceding columns are still unscrambled
+    for k in (1..ncols).rev() {
+        for i in (0..k).filter(|&i| (coefs[k] >> i) & 1 != 0) {
+            for j in 0..ndim {
+                columns[k * ndim + j] ^= columns[i * ndim + j];
+            }
+        }
+    }
+}
+
+/// Owen-scrambled digital sequence generator.
+///
+/// Wraps a base-2 digital sequence and applies nested uniform scrambling to each
+/// coordinate of each point ("Randomly Permuted (t,m,s)-Nets and (t,s)-Sequences",
+/// Owen, 1995): each binary digit is flipped randomly depending on all of the
+/// preceding digits. The scrambled sequence retains the net properties of the
+/// original one, while each point is uniformly distributed over the unit cube,
+/// so that averages over independent scramblings yield unbiased estimates with
+/// error estimates (randomized quasi-Monte Carlo).
+///
+/// All 53 digits of the `f64` mantissa are scrambled, including the digits past
+/// the precision of the base sequence. The scrambling is randomized with the
+/// given seed.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = OwenScrambled::new(SobolSeq::new(3), 42).with_buf();
+/// let x = seq.gen();
+/// assert!(x.iter().all(|&x| 0. <= x && x < 1.));
+/// ```
+#[derive(Clone)]
+pub struct OwenScrambled<R: DigitalQRng> {
+    seq: R,
+    seeds: Vec<u64>,
+    digits: Vec<u64>,
+}
+
+impl<R: DigitalQRng> OwenScrambled<R> {
+    /// Returns a new scrambled generator wrapping the given digital sequence and
+    /// randomized with the given seed.
+    #[inline]
+    pub fn new(seq: R, seed: u64) -> Self {
+        let ndim = seq.ndim();
+        let mut rng = SplitMix64::new(seed);
+        let seeds = (0..ndim).map(|_| rng.next_u64()).collect();
+        Self { seq, seeds, digits: vec![0; ndim] }
+    }
+
+    /// Returns a reference to the underlying (unscrambled) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: DigitalQRng> QRng for OwenScrambled<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        const DENUM: f64 = (1u64 << NBITS) as f64;
+        self.seq.gen_fill_digits_unchecked(&mut self.digits);
+        for (j, &seed) in self.seeds.iter().enumerate() {
+            let x = owen_scramble(*self.digits.get_unchecked(j), seed);
+            *out.get_unchecked_mut(j) = ((x >> (64 - NBITS)) as f64) / DENUM;
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+/// Hash-based Owen-scrambled digital sequence generator.
+///
+/// A fast approximation of [`OwenScrambled`](struct.OwenScrambled.html) based on
+/// the Laine–Karras permutation, as described in "Practical Hash-based Owen
+/// Scrambling" (Burley, 2020): the nested uniform scrambling of each coordinate is
+/// performed by a handful of integer multiplications instead of hashing each of
+/// the tree nodes. As a result, only the first 32 binary digits of each
+/// coordinate are scrambled, and the output has 32-bit precision.
+///
+/// Like the full Owen scrambling, it preserves the net properties of the base
+/// sequence. The scrambling is randomized with the given seed.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = FastOwenScrambled::new(SobolSeq::new(3), 42).with_buf();
+/// let x = seq.gen();
+/// assert!(x.iter().all(|&x| 0. <= x && x < 1.));
+/// ```
+#[derive(Clone)]
+pub struct FastOwenScrambled<R: DigitalQRng> {
+    seq: R,
+    seeds: Vec<u32>,
+    digits: Vec<u64>,
+}
+
+impl<R: DigitalQRng> FastOwenScrambled<R> {
+    /// Returns a new scrambled generator wrapping the given digital sequence and
+    /// randomized with the given seed.
+    #[inline]
+    pub fn new(seq: R, seed: u64) -> Self {
+        let ndim = seq.ndim();
+        let mut rng = SplitMix64::new(seed);
+        let seeds = (0..ndim).map(|_| (rng.next_u64() >> 32) as u32).collect();
+        Self { seq, seeds, digits: vec![0; ndim] }
+    }
+
+    /// Returns a reference to the underlying (unscrambled) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: DigitalQRng> QRng for FastOwenScrambled<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        const DENUM: f64 = (1u64 << 32) as f64;
+        self.seq.gen_fill_digits_unchecked(&mut self.digits);
+        for (j, &seed) in self.seeds.iter().enumerate() {
+            let x = lk_scramble(*self.digits.get_unchecked(j), seed);
+            *out.get_unchecked_mut(j) = ((x >> 32) as f64) / DENUM;
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+macro_rules! impl_randomize_scrambled {
+    ($($ty:ty),*) => {
+        $(
+            impl Randomize for $ty {
+                type Randomized = OwenScrambled<$ty>;
+
+                #[inline]
+                fn randomize(self, seed: u64) -> Self::Randomized {
+                    OwenScrambled::new(self, seed)
+                }
+            }
+        )*
+    };
+}
+
+impl_randomize_scrambled!(DigitalNet, NiederreiterSeq, PolyLatticeRule, SobolSeq);
+
+#[cfg(test)]
+mod tests {
+    use super::{lk_scramble, owen_scramble, FastOwenScrambled, OwenScrambled};
+    use crate::{DigitalNet, NiederreiterSeq, QRng, QRngWithBuf, Randomize, SobolSeq};
+
+    #[test]
+    fn test_owen_scramble() {
+        // numbers sharing the first k digits share the first k scrambled digits
+        let xs = [0u64, 1 << 40, 1 << 60, 3 << 62, u64::MAX];
+        for &a in &xs {
+            for &b in &xs {
+                let k = (a ^ b).leading_zeros().min(53);
+                let (sa, sb) = (owen_scramble(a, 7), owen_scramble(b, 7));
+                assert!((sa ^ sb).leading_zeros() >= k);
+                assert_eq!((sa ^ sb).leading_zeros() == k, k < 53);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lk_scramble() {
+        let xs = [0u64, 1 << 40, 1 << 60, 3 << 62, u64::MAX];
+        for &a in &xs {
+            for &b in &xs {
+                let k = (a ^ b).leading_zeros().min(32);
+                let (sa, sb) = (lk_scramble(a, 7), lk_scramble(b, 7));
+                assert!((sa ^ sb).leading_zeros() >= k);
+            }
+        }
+        assert_ne!(lk_scramble(0, 7), lk_scramble(0, 8));
+    }
+
+    fn is_net_minus_one(points: &[Vec<f64>], m: u32) -> bool {
+        let n = 1usize << m;
+        (0..=m).all(|a| {
+            let (nx, ny) = (1usize << a, 1usize << (m - a));
+            let mut seen = vec![false; n];
+            points.iter().all(|p| {
+                let (x, y) = ((p[0] * nx as f64) as usize, (p[1] * ny as f64) as usize);
+                !std::mem::replace(&mut seen[x * ny + y], true)
+            })
+        })
+    }
+
+    #[test]
+    fn test_owen_scrambled_net() {
+        // the first 2^m - 1 points of the sequence (excluding the zero point) are
+        // a (0,m,2)-net with one point missing, which scrambling preserves
+        const M: u32 = 10;
+        let mut seq = OwenScrambled::new(SobolSeq::new(2), 1).with_buf();
+        let points: Vec<_> = (0..(1 << M) - 1).map(|_| seq.gen().to_vec()).collect();
+        assert!(is_net_minus_one(&points, M));
+        let mut seq = FastOwenScrambled::new(SobolSeq::new(2), 1).with_buf();
+        let points: Vec<_> = (0..(1 << M) - 1).map(|_| seq.gen().to_vec()).collect();
+        assert!(is_net_minus_one(&points, M));
+    }
+
+    #[test]
+    fn test_linear_scramble() {
+        const M: u32 = 10;
+        let mut seq = SobolSeq::new(2).linear_scramble(1).with_buf();
+        let points: Vec<_> = (0..(1 << M) - 1).map(|_| seq.gen().to_vec()).collect();
+        assert!(is_net_minus_one(&points, M));
+        let mut seq = DigitalNet::sobol(2).linear_scramble(1).with_buf();
+        let net_points: Vec<_> = (0..(1 << M) - 1).map(|_| seq.gen().to_vec()).collect();
+        assert_eq!(points, net_points);
+        let mut sobol = SobolSeq::new(2).with_buf();
+        let mut other = SobolSeq::new(2).linear_scramble(2).with_buf();
+        assert!(points.iter().any(|p| p.as_slice() != sobol.gen()));
+        assert!(points.iter().any(|p| p.as_slice() != other.gen()));
+    }
+
+    #[test]
+    fn test_faure_tezuka_scramble() {
+        // the first 2^m - 1 points are the same, but in a different order
+        const N: usize = (1 << 8) - 1;
+        let sorted = |mut seq: QRngWithBuf<SobolSeq>| {
+            let mut points: Vec<_> = (0..N).map(|_| seq.gen().to_vec()).collect();
+            let original = points.clone();
+            points.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            (original, points)
+        };
+        let (a, sorted_a) = sorted(SobolSeq::new(3).with_buf());
+        let (b, sorted_b) = sorted(SobolSeq::new(3).faure_tezuka_scramble(1).with_buf());
+        assert_eq!(sorted_a, sorted_b);
+        assert_ne!(a, b);
+        let mut net = DigitalNet::sobol(3).faure_tezuka_scramble(1).with_buf();
+        assert!(b.iter().all(|x| x.as_slice() == net.gen()));
+    }
+
+    #[test]
+    fn test_owen_scrambled_seed() {
+        let mut a = OwenScrambled::new(NiederreiterSeq::new(4), 1).with_buf();
+        let mut b = OwenScrambled::new(NiederreiterSeq::new(4), 1).with_buf();
+        let mut c = OwenScrambled::new(NiederreiterSeq::new(4), 2).with_buf();
+        let mut seq = NiederreiterSeq::new(4).with_buf();
+        for _ in 0..100 {
+            let x = a.gen().to_vec();
+            assert_eq!(x, b.gen());
+            assert_ne!(x, c.gen());
+            assert_ne!(x, seq.gen());
+            assert!(x.iter().all(|&x| (0. ..1.).contains(&x)));
+        }
+    }
+
+    #[test]
+    fn test_fast_owen_scrambled_seed() {
+        let mut a = FastOwenScrambled::new(SobolSeq::new(4), 1).with_buf();
+        let mut b = FastOwenScrambled::new(SobolSeq::new(4), 1).with_buf();
+        let mut c = FastOwenScrambled::new(SobolSeq::new(4), 2).with_buf();
+        for _ in 0..100 {
+            let x = a.gen().to_vec();
+            assert_eq!(x, b.gen());
+            assert_ne!(x, c.gen());
+            assert!(x.iter().all(|&x| (0. ..1.).contains(&x)));
+        }
+    }
+
+    #[test]
+    fn test_randomize_scrambled() {
+        let mut a = SobolSeq::new(3).randomize(9).with_buf();
+        let mut b = OwenScrambled::new(SobolSeq::new(3), 9).with_buf();
+        for _ in 0..100 {
+            assert_eq!(a.gen().to_vec(), b.gen());
+        }
+    }
+
+    #[test]
+    fn test_owen_scrambled_mean() {
+        const LEN: usize = 1 << 16;
+        const NDIM: usize = 16;
+        let mut seq = OwenScrambled::new(SobolSeq::new(NDIM), 42).with_buf();
+        let mut sum = [0.; NDIM];
+        for _ in 0..LEN {
+            for (i, &x) in seq.gen().iter().enumerate() {
+                sum[i] += x;
+            }
+        }
+        for s in &sum {
+            assert!(((s / (LEN as f64)) - 0.5).abs() < 1e-4);
+        }
+    }
+}
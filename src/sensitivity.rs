@@ -0,0 +1,398 @@
+use crate::{traits::QRng, util::SplitMix64};
+
+/// First-order and total Sobol' sensitivity indices of a function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SobolIndices {
+    /// First-order indices, i.e. the fractions of the variance of the function
+    /// explained by each of the inputs alone.
+    pub first_order: Vec<f64>,
+    /// Total indices, i.e. the fractions of the variance of the function explained
+    /// by each of the inputs, including all of its interactions with other inputs.
+    pub total: Vec<f64>,
+}
+
+/// Saltelli sampling scheme for estimating Sobol' sensitivity indices.
+///
+/// The design consists of two `n x k` sample matrices `A` and `B`, taken from the
+/// first and the last `k` coordinates of the first `n` points of a
+/// `2k`-dimensional sequence, and `k` matrices `AB_i`, equal to `A` with the `i`-th
+/// column replaced by that of `B` ("Variance based sensitivity analysis of model
+/// output. Design and estimator for the total sensitivity index", Saltelli et al.,
+/// 2010). The function is evaluated at `n * (k + 2)` points in total.
+///
+/// All matrices are stored in row-major order, i.e. each row is a point.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// // the second input has twice the effect of the first one, and the third has none
+/// let design = SaltelliDesign::new(SobolSeq::new(6), 1 << 12);
+/// let indices = design.estimate(|x| x[0] + 2. * x[1]);
+/// assert!((indices.first_order[0] - 0.2).abs() < 1e-2);
+/// assert!((indices.total[1] - 0.8).abs() < 1e-2);
+/// assert!(indices.total[2].abs() < 1e-2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SaltelliDesign {
+    ndim: usize,
+    n: usize,
+    a: Vec<f64>,
+    b: Vec<f64>,
+}
+
+impl SaltelliDesign {
+    /// Returns the design built from the first `n` points of the given sequence,
+    /// with the number of inputs equal to half of its dimensionality.
+    ///
+    /// Panics if the dimensionality of the sequence is zero or odd, or if `n` is
+    /// less than 2.
+    pub fn new<R: QRng>(mut seq: R, n: usize) -> Self {
+        let seq_ndim = seq.ndim();
+        if seq_ndim == 0 || !seq_ndim.is_multiple_of(2) {
+            panic!("invalid Saltelli base dimension: {}", seq_ndim);
+        }
+        if n < 2 {
+            panic!("invalid Saltelli sample size: {}", n);
+        }
+        let ndim = seq_ndim / 2;
+        let (mut a, mut b) = (Vec::with_capacity(n * ndim), Vec::with_capacity(n * ndim));
+        let mut x = vec![0.; seq_ndim];
+        for _ in 0..n {
+            unsafe { seq.gen_fill_unchecked(&mut x) };
+            a.extend_from_slice(&x[..ndim]);
+            b.extend_from_slice(&x[ndim..]);
+        }
+        Self { ndim, n, a, b }
+    }
+
+    /// Returns the number of inputs of the function.
+    #[inline]
+    pub fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    /// Returns the number of rows in each of the sample matrices.
+    #[inline]
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the sample matrix `A`.
+    #[inline]
+    pub fn a(&self) -> &[f64] {
+        &self.a
+    }
+
+    /// Returns the sample matrix `B`.
+    #[inline]
+    pub fn b(&self) -> &[f64] {
+        &self.b
+    }
+
+    /// Returns the sample matrix `AB_i`, i.e. `A` with the `i`-th column taken from
+    /// `B`.
+    ///
+    /// Panics if `i` is not less than the number of inputs.
+    pub fn ab(&self, i: usize) -> Vec<f64> {
+        if i >= self.ndim {
+            panic!("invalid Saltelli input index: {}", i);
+        }
+        let mut ab = self.a.clone();
+        for (x, y) in ab.chunks_exact_mut(self.ndim).zip(self.b.chunks_exact(self.ndim)) {
+            x[i] = y[i];
+        }
+        ab
+    }
+
+    /// Estimates the sensitivity indices from the values of the function at the
+    /// rows of `A`, `B` and each of the `AB_i`.
+    ///
+    /// The first-order indices are estimated as in Saltelli et al. (2010), and the
+    /// total ones via Jansen's estimator.
+    ///
+    /// Panics if the numbers of values don't match the design.
+    pub fn indices(&self, f_a: &[f64], f_b: &[f64], f_ab: &[Vec<f64>]) -> SobolIndices {
+        let n = self.n;
+        if f_a.len() != n || f_b.len() != n {
+            panic!("invalid number of function values: {}", f_a.len().min(f_b.len()));
+        }
+        if f_ab.len() != self.ndim {
+            panic!("invalid number of AB_i matrices: {}", f_ab.len());
+        }
+        let count = (2 * n) as f64;
+        let mean = f_a.iter().chain(f_b).sum::<f64>() / count;
+        let var = f_a.iter().chain(f_b).map(|y| (y - mean) * (y - mean)).sum::<f64>() / count;
+        let (mut first_order, mut total) = (Vec::new(), Vec::new());
+        for f_abi in f_ab {
+            if f_abi.len() != n {
+                panic!("invalid number of function values: {}", f_abi.len());
+            }
+            let (mut s, mut t) = (0., 0.);
+            for ((ya, yb), yab) in f_a.iter().zip(f_b).zip(f_abi) {
+                s += yb * (yab - ya);
+                t += (ya - yab) * (ya - yab);
+            }
+            first_order.push(s / n as f64 / var);
+            total.push(t / (2 * n) as f64 / var);
+        }
+        SobolIndices { first_order, total }
+    }
+
+    /// Evaluates the function at all points of the design and estimates the
+    /// sensitivity indices (see [`indices`](#method.indices)).
+    pub fn estimate<F: FnMut(&[f64]) -> f64>(&self, mut f: F) -> SobolIndices {
+        let mut eval = |m: &[f64]| -> Vec<f64> { m.chunks_exact(self.ndim).map(&mut f).collect() };
+        let (f_a, f_b) = (eval(&self.a), eval(&self.b));
+        let f_ab: Vec<_> = (0..self.ndim).map(|i| eval(&self.ab(i))).collect();
+        self.indices(&f_a, &f_b, &f_ab)
+    }
+}
+
+/// Morris elementary effects of a function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MorrisIndices {
+    /// Means of the elementary effects of each of the inputs.
+    pub mu: Vec<f64>,
+    /// Means of the absolute values of the elementary effects of each of the inputs.
+    pub mu_star: Vec<f64>,
+    /// Standard deviations of the elementary effects of each of the inputs.
+    pub sigma: Vec<f64>,
+}
+
+/// Morris screening design ("Factorial Sampling Plans for Preliminary
+/// Computational Experiments", Morris, 1991).
+///
+/// The design consists of `r` trajectories of `k + 1` points each on the grid with
+/// `p` levels `0, 1/(p - 1), ..., 1` in each of the `k` dimensions. Consecutive
+/// points of a trajectory differ in a single coordinate by `delta = p / (2(p - 1))`,
+/// and each coordinate is changed exactly once per trajectory, in random order.
+/// The function is evaluated at `r * (k + 1)` points in total.
+///
+/// The starting points of the trajectories are either drawn pseudo-randomly, or
+/// taken from the points of a quasi-random sequence for better coverage of the
+/// input space.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let design = MorrisDesign::with_base_points(HaltonSeq::new(3), 4, 20, 42);
+/// let indices = design.estimate(|x| x[0] + 2. * x[1] * x[1]);
+/// assert!((indices.mu_star[0] - 1.).abs() < 1e-12);
+/// assert!(indices.mu_star[1] > 1.);
+/// assert_eq!(indices.mu_star[2], 0.);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MorrisDesign {
+    ndim: usize,
+    r: usize,
+    delta: f64,
+    points: Vec<f64>,
+    // the changed coordinate and the sign of the change for each step
+    steps: Vec<(usize, f64)>,
+}
+
+impl MorrisDesign {
+    /// Returns a design with `r` trajectories in `ndim` dimensions on the grid with
+    /// the given number of levels, with pseudo-random starting points drawn using
+    /// the given seed.
+    ///
+    /// Panics if `ndim` or `r` is zero, or if the number of levels is odd or less
+    /// than 2.
+    pub fn new(ndim: usize, levels: usize, r: usize, seed: u64) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        let base: Vec<_> = (0..r * ndim).map(|_| rng.next_f64()).collect();
+        Self::build(ndim, levels, r, &base, &mut rng)
+    }
+
+    /// Returns a design with `r` trajectories on the grid with the given number of
+    /// levels, with the starting points taken from the first `r` points of the given
+    /// sequence; the order of the coordinates is randomized using the given seed.
+    ///
+    /// Panics if the sequence is zero-dimensional, if `r` is zero, or if the number
+    /// of levels is odd or less than 2.
+    pub fn with_base_points<R: QRng>(mut seq: R, levels: usize, r: usize, seed: u64) -> Self {
+        let ndim = seq.ndim();
+        let mut base = vec![0.; r * ndim];
+        if ndim != 0 {
+            for x in base.chunks_exact_mut(ndim) {
+                unsafe { seq.gen_fill_unchecked(x) };
+            }
+        }
+        Self::build(ndim, levels, r, &base, &mut SplitMix64::new(seed))
+    }
+
+    fn build(ndim: usize, levels: usize, r: usize, base: &[f64], rng: &mut SplitMix64) -> Self {
+        if ndim == 0 {
+            panic!("invalid Morris dimension: {}", ndim);
+        }
+        if r == 0 {
+            panic!("invalid number of Morris trajectories: {}", r);
+        }
+        if levels < 2 || !levels.is_multiple_of(2) {
+            panic!("invalid number of Morris levels: {}", levels);
+        }
+        let p = levels as f64;
+        let delta = p / (2. * (p - 1.));
+        let mut points = Vec::with_capacity(r * (ndim + 1) * ndim);
+        let mut steps = Vec::with_capacity(r * ndim);
+        let mut perm: Vec<_> = (0..ndim).collect();
+        for u in base.chunks_exact(ndim) {
+            // the levels in the lower half move up by delta, the rest move down
+            let mut x: Vec<_> =
+                u.iter().map(|&u| ((u * p) as usize).min(levels - 1) as f64 / (p - 1.)).collect();
+            for i in (1..ndim).rev() {
+                perm.swap(i, rng.next_below(i as u64 + 1) as usize);
+            }
+            points.extend_from_slice(&x);
+            for &j in &perm {
+                let sign = if x[j] + delta <= 1. + 1e-12 { 1. } else { -1. };
+                x[j] = (x[j] + sign * delta).clamp(0., 1.);
+                steps.push((j, sign));
+                points.extend_from_slice(&x);
+            }
+        }
+        Self { ndim, r, delta, points, steps }
+    }
+
+    /// Returns the number of inputs of the function.
+    #[inline]
+    pub fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    /// Returns the number of trajectories.
+    #[inline]
+    pub fn r(&self) -> usize {
+        self.r
+    }
+
+    /// Returns the size of the steps along the trajectories.
+    #[inline]
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+
+    /// Returns the points of all trajectories in row-major order, i.e. each row is a
+    /// point and each trajectory takes up `ndim + 1` consecutive rows.
+    #[inline]
+    pub fn points(&self) -> &[f64] {
+        &self.points
+    }
+
+    /// Computes the elementary effects from the values of the function at the points
+    /// of the design (see [`points`](#method.points)).
+    ///
+    /// Panics if the number of values doesn't match the design.
+    pub fn indices(&self, values: &[f64]) -> MorrisIndices {
+        let k = self.ndim;
+        if values.len() != self.r * (k + 1) {
+            panic!("invalid number of function values: {}", values.len());
+        }
+        let (mut mu, mut mu_star, mut sq) = (vec![0.; k], vec![0.; k], vec![0.; k]);
+        for (t, ys) in values.chunks_exact(k + 1).enumerate() {
+            for (s, y) in ys.windows(2).enumerate() {
+                let (j, sign) = self.steps[t * k + s];
+                let effect = (y[1] - y[0]) / (sign * self.delta);
+                mu[j] += effect;
+                mu_star[j] += effect.abs();
+                sq[j] += effect * effect;
+            }
+        }
+        let r = self.r as f64;
+        mu.iter_mut().for_each(|m| *m /= r);
+        mu_star.iter_mut().for_each(|m| *m /= r);
+        let sigma = if self.r > 1 {
+            sq.iter().zip(&mu).map(|(s, m)| ((s - r * m * m) / (r - 1.)).max(0.).sqrt()).collect()
+        } else {
+            vec![0.; k]
+        };
+        MorrisIndices { mu, mu_star, sigma }
+    }
+
+    /// Evaluates the function at all points of the design and computes the
+    /// elementary effects (see [`indices`](#method.indices)).
+    pub fn estimate<F: FnMut(&[f64]) -> f64>(&self, f: F) -> MorrisIndices {
+        let values: Vec<_> = self.points.chunks_exact(self.ndim).map(f).collect();
+        self.indices(&values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::{MorrisDesign, SaltelliDesign};
+    use crate::SobolSeq;
+
+    #[test]
+    fn test_saltelli_design() {
+        let design = SaltelliDesign::new(SobolSeq::new(4), 8);
+        assert_eq!((design.ndim(), design.n()), (2, 8));
+        let ab = design.ab(1);
+        for i in 0..8 {
+            assert_eq!(ab[2 * i], design.a()[2 * i]);
+            assert_eq!(ab[2 * i + 1], design.b()[2 * i + 1]);
+        }
+    }
+
+    #[test]
+    fn test_saltelli_ishigami() {
+        let (a, b) = (7., 0.1);
+        let ishigami = |x: &[f64]| {
+            let x: Vec<_> = x.iter().map(|x| PI * (2. * x - 1.)).collect();
+            x[0].sin() + a * x[1].sin().powi(2) + b * x[2].powi(4) * x[0].sin()
+        };
+        let indices = SaltelliDesign::new(SobolSeq::new(6), 1 << 14).estimate(ishigami);
+        let first_order = [0.3139, 0.4424, 0.];
+        let total = [0.5576, 0.4424, 0.2437];
+        for i in 0..3 {
+            assert!((indices.first_order[i] - first_order[i]).abs() < 1e-2);
+            assert!((indices.total[i] - total[i]).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_saltelli_design_panic() {
+        SaltelliDesign::new(SobolSeq::new(3), 16);
+    }
+
+    #[test]
+    fn test_morris_design() {
+        let design = MorrisDesign::new(5, 6, 10, 1);
+        assert_eq!(design.points().len(), 10 * 6 * 5);
+        assert_eq!(design.delta(), 0.6);
+        for traj in design.points().chunks(6 * 5) {
+            let points: Vec<_> = traj.chunks(5).collect();
+            let mut changed = [false; 5];
+            for w in points.windows(2) {
+                let diff: Vec<_> = (0..5).filter(|&j| w[0][j] != w[1][j]).collect();
+                assert_eq!(diff.len(), 1);
+                assert!(((w[0][diff[0]] - w[1][diff[0]]).abs() - 0.6).abs() < 1e-12);
+                changed[diff[0]] = true;
+            }
+            assert!(changed.iter().all(|&c| c));
+            for &x in traj {
+                assert!((0. ..=1.).contains(&x));
+                assert!(((x * 5.).round() - x * 5.).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_morris_indices() {
+        let design = MorrisDesign::with_base_points(SobolSeq::new(4), 4, 50, 7);
+        let indices = design.estimate(|x| 3. * x[0] - x[1] + 5. * x[2] * x[3]);
+        assert!((indices.mu[0] - 3.).abs() < 1e-12 && indices.sigma[0] < 1e-12);
+        assert!((indices.mu[1] + 1.).abs() < 1e-12 && (indices.mu_star[1] - 1.).abs() < 1e-12);
+        assert!(indices.sigma[2] > 0. && indices.sigma[3] > 0.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_morris_design_panic() {
+        MorrisDesign::new(3, 5, 10, 0);
+    }
+}
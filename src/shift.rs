@@ -0,0 +1,248 @@
+use crate::{
+    faure::FaureSeq,
+    halton::VanDerCorput,
+    kronecker::KroneckerSeq,
+    lattice::LatticeRule,
+    traits::{DigitalQRng, QRng, Randomize},
+    util::SplitMix64,
+};
+
+/// Digitally shifted digital sequence generator.
+///
+/// Wraps a base-2 digital sequence and XORs the binary digits of each coordinate
+/// of each point with a fixed random shift, drawn independently for each
+/// dimension. This is the standard randomization of digital nets: it preserves
+/// their net properties while making each point uniformly distributed over the
+/// unit cube. When combined with
+/// [`SobolSeq::linear_scramble`](struct.SobolSeq.html#method.linear_scramble),
+/// it yields Matoušek's random linear scrambling with digital shift.
+///
+/// All 53 digits of the `f64` mantissa are shifted. The shift is randomized with
+/// the given seed.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = DigitalShift::new(SobolSeq::new(3).linear_scramble(1), 2).with_buf();
+/// let x = seq.gen();
+/// assert!(x.iter().all(|&x| 0. <= x && x < 1.));
+/// ```
+#[derive(Clone)]
+pub struct DigitalShift<R: DigitalQRng> {
+    seq: R,
+    shift: Vec<u64>,
+    digits: Vec<u64>,
+}
+
+impl<R: DigitalQRng> DigitalShift<R> {
+    /// Returns a new digitally shifted generator wrapping the given digital sequence
+    /// and randomized with the given seed.
+    #[inline]
+    pub fn new(seq: R, seed: u64) -> Self {
+        let ndim = seq.ndim();
+        let mut rng = SplitMix64::new(seed);
+        let shift = (0..ndim).map(|_| rng.next_u64() & !((1 << 11) - 1)).collect();
+        Self { seq, shift, digits: vec![0; ndim] }
+    }
+
+    /// Returns a reference to the underlying (unshifted) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: DigitalQRng> QRng for DigitalShift<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        const DENUM: f64 = (1u64 << 53) as f64;
+        self.seq.gen_fill_digits_unchecked(&mut self.digits);
+        for (j, &shift) in self.shift.iter().enumerate() {
+            let x = *self.digits.get_unchecked(j) ^ shift;
+            *out.get_unchecked_mut(j) = ((x >> 11) as f64) / DENUM;
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+/// Randomly shifted sequence generator (Cranley–Patterson rotation).
+///
+/// Wraps an arbitrary sequence and adds a fixed random vector to each point,
+/// modulo 1 ("Randomization of Number Theoretic Methods for Multiple
+/// Integration", Cranley and Patterson, 1976). This is the canonical randomization
+/// of lattice rules: it preserves their lattice structure while making each point
+/// uniformly distributed over the unit cube. It is also useful for decorrelating
+/// multiple streams of the same generator.
+///
+/// The shift is randomized with the given seed.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = RandomShift::new(LatticeRule::korobov(1021, 76, 3), 42).with_buf();
+/// let x = seq.gen();
+/// assert!(x.iter().all(|&x| 0. <= x && x < 1.));
+/// ```
+#[derive(Clone)]
+pub struct RandomShift<R: QRng> {
+    seq: R,
+    shift: Vec<f64>,
+}
+
+impl<R: QRng> RandomShift<R> {
+    /// Returns a new randomly shifted generator wrapping the given sequence and
+    /// randomized with the given seed.
+    #[inline]
+    pub fn new(seq: R, seed: u64) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        let shift = (0..seq.ndim()).map(|_| rng.next_f64()).collect();
+        Self { seq, shift }
+    }
+
+    /// Returns the random shift vector.
+    #[inline]
+    pub fn shift(&self) -> &[f64] {
+        &self.shift
+    }
+
+    /// Returns a reference to the underlying (unshifted) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng> QRng for RandomShift<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(out);
+        for (j, &shift) in self.shift.iter().enumerate() {
+            let x = out.get_unchecked_mut(j);
+            *x += shift;
+            if *x >= 1. {
+                *x -= 1.;
+            }
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+macro_rules! impl_randomize_shifted {
+    ($($ty:ty),*) => {
+        $(
+            impl Randomize for $ty {
+                type Randomized = RandomShift<$ty>;
+
+                #[inline]
+                fn randomize(self, seed: u64) -> Self::Randomized {
+                    RandomShift::new(self, seed)
+                }
+            }
+        )*
+    };
+}
+
+impl_randomize_shifted!(FaureSeq, KroneckerSeq, LatticeRule, VanDerCorput);
+
+#[cfg(test)]
+mod tests {
+    use super::{DigitalShift, RandomShift};
+    use crate::{LatticeRule, QRng, Randomize, SobolSeq};
+
+    #[test]
+    fn test_digital_shift() {
+        // shifting a net permutes its elementary intervals
+        const M: u32 = 8;
+        const N: usize = 1 << M;
+        let shifted = DigitalShift::new(SobolSeq::new(2), 7);
+        let shift: Vec<_> = shifted.shift.iter().map(|&s| (s >> (64 - M)) as usize).collect();
+        let mut seq = shifted.with_buf();
+        let mut sobol = SobolSeq::new(2).with_buf();
+        for _ in 0..N {
+            let (x, y) = (seq.gen().to_vec(), sobol.gen().to_vec());
+            for j in 0..2 {
+                let (a, b) = ((x[j] * N as f64) as usize, (y[j] * N as f64) as usize);
+                assert_eq!(a, b ^ shift[j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_digital_shift_seed() {
+        let mut a = DigitalShift::new(SobolSeq::new(4), 1).with_buf();
+        let mut b = DigitalShift::new(SobolSeq::new(4), 1).with_buf();
+        let mut c = DigitalShift::new(SobolSeq::new(4), 2).with_buf();
+        for _ in 0..100 {
+            let x = a.gen().to_vec();
+            assert_eq!(x, b.gen());
+            assert_ne!(x, c.gen());
+            assert!(x.iter().all(|&x| (0. ..1.).contains(&x)));
+        }
+    }
+
+    #[test]
+    fn test_random_shift() {
+        let shifted = RandomShift::new(LatticeRule::korobov(1021, 76, 3), 5);
+        let shift = shifted.shift().to_vec();
+        assert!(shift.iter().all(|&x| (0. ..1.).contains(&x)));
+        let mut seq = shifted.with_buf();
+        let mut lattice = LatticeRule::korobov(1021, 76, 3).with_buf();
+        for _ in 0..2000 {
+            let (x, y) = (seq.gen().to_vec(), lattice.gen().to_vec());
+            for j in 0..3 {
+                assert!((0. ..1.).contains(&x[j]));
+                let d = (x[j] - y[j] - shift[j]).rem_euclid(1.);
+                assert!(!(1e-12..=1. - 1e-12).contains(&d));
+            }
+        }
+    }
+
+    #[test]
+    fn test_randomize_shifted() {
+        let mut a = LatticeRule::korobov(1021, 76, 3).randomize(9).with_buf();
+        let mut b = RandomShift::new(LatticeRule::korobov(1021, 76, 3), 9).with_buf();
+        for _ in 0..100 {
+            assert_eq!(a.gen().to_vec(), b.gen());
+        }
+    }
+}
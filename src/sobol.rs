@@ -1,122 +1,1487 @@
-use crate::traits::QRng;
+use std::{
+    convert::TryFrom,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    ops::Range,
+    path::Path,
+    sync::Arc,
+};
 
-#[cfg(not(feature = "sobol-high-dim"))]
-mod assets {
-    pub type DirNum = u16;
-    pub const SOBOL_MAX_DIM: usize = 1111;
-    pub const SOBOL_LOCS: &[DirNum] = include!("assets/sobol-lim-locs.rs");
-    pub const SOBOL_COEF: &[DirNum] = include!("assets/sobol-lim-coef.rs");
-    pub const SOBOL_DNUM: &[DirNum] = include!("assets/sobol-lim-dnum.rs");
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+    error::{check_ndim, QrngError},
+    scramble::{faure_tezuka_scramble, linear_scramble},
+    sobol_core::{column, next_dirnum, to_unit_f64, DirectionNumbers, MAX_LOG_N},
+    traits::{DigitalQRng, PointSet, QRng, SkipTo, F32_BELOW_ONE},
+};
+
+/// Source of the primitive polynomials and initial direction numbers defining the
+/// dimensions of the Sobol sequence.
+///
+/// The first dimension is always the van der Corput sequence in base 2 and is not
+/// taken from the source; each of the following ones is defined by a primitive
+/// polynomial `x^s + a_1 x^(s - 1) + ... + a_(s - 1) x + 1` over GF(2) and `s`
+/// initial direction numbers `m_1, ..., m_s`, where each `m_k` is odd and less than
+/// `2^k`; the remaining direction numbers are derived via the recurrence defined by
+/// the polynomial. This is the convention used in the tables published by Joe and
+/// Kuo.
+///
+/// The crate provides the embedded tables ([`DirectionNumbers`](enum.DirectionNumbers.html))
+/// and tables loaded at runtime ([`DirectionTable`](struct.DirectionTable.html));
+/// custom constructions can be plugged in by implementing this trait and passing it
+/// to [`SobolSeq::with_source`](struct.SobolSeq.html#method.with_source).
+pub trait DirectionNumberSource {
+    /// Returns the maximum dimensionality supported by the source, including the
+    /// first dimension.
+    fn max_dim(&self) -> usize;
+
+    /// Returns the primitive polynomial and the initial direction numbers for the
+    /// dimension with the given zero-based index, between 1 and `max_dim() - 1`.
+    ///
+    /// The polynomial is given by its inner coefficients packed into an integer
+    /// `a_1 a_2 ... a_(s - 1)` in binary, with `a_1` being the most significant
+    /// bit, and its degree `s` is the number of initial direction numbers.
+    fn initial_numbers(&self, dim: usize) -> (u64, Vec<u64>);
+}
+
+impl DirectionNumberSource for DirectionNumbers {
+    #[inline]
+    fn max_dim(&self) -> usize {
+        DirectionNumbers::max_dim(*self)
+    }
+
+    fn initial_numbers(&self, dim: usize) -> (u64, Vec<u64>) {
+        // the embedded tables omit m_1 = 1 and store the other values as (m_k - 1) / 2
+        let mut m = [0; MAX_LOG_N];
+        let (coef, count) = self.table().raw_data_into(dim - 1, &mut m);
+        (coef, Some(1).into_iter().chain(m[..count].iter().map(|m| m * 2 + 1)).collect())
+    }
+}
+
+/// Table of primitive polynomials and initial direction numbers for the Sobol
+/// sequence, e.g. loaded from a file at runtime.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// // the first three dimensions of the Joe–Kuo table
+/// let table = DirectionTable::new(vec![(0, vec![1]), (1, vec![1, 3])]).unwrap();
+/// assert_eq!(table.max_dim(), 3);
+/// let mut seq = SobolSeq::with_source(3, &table);
+/// assert_eq!(seq.gen_points(10), SobolSeq::new(3).gen_points(10));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirectionTable {
+    entries: Vec<(u64, Vec<u64>)>,
+}
+
+impl DirectionTable {
+    /// Returns a new table from the given polynomials and initial direction numbers
+    /// for the dimensions starting from the second one, in the format described in
+    /// [`DirectionNumberSource::initial_numbers`](trait.DirectionNumberSource.html#tymethod.initial_numbers).
+    ///
+    /// Returns an error if any of the entries is invalid.
+    pub fn new(entries: Vec<(u64, Vec<u64>)>) -> Result<Self, QrngError> {
+        for (a, m) in &entries {
+            check_initial_numbers(*a, m)?;
+        }
+        Ok(Self { entries })
+    }
+
+    /// Loads the table from a file in the format of the tables published by Joe and
+    /// Kuo, e.g. `new-joe-kuo-6.21201`.
+    ///
+    /// The file consists of a header line, followed by one line per dimension
+    /// starting from the second one, each containing the dimension `d`, the degree
+    /// `s` of the primitive polynomial, its inner coefficients `a` and the initial
+    /// direction numbers `m_1, ..., m_s`, separated by whitespace.
+    ///
+    /// Returns an error if the file can't be read or if it is malformed.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Same as [`from_file`](#method.from_file), but reads the table from an arbitrary
+    /// buffered reader.
+    pub fn from_reader<B: BufRead>(reader: B) -> io::Result<Self> {
+        Ok(Self { entries: parse_direction_file(reader, usize::MAX)? })
+    }
+
+    /// Returns the maximum dimensionality supported by the table, including the
+    /// first dimension.
+    #[inline]
+    pub fn max_dim(&self) -> usize {
+        self.entries.len() + 1
+    }
+}
+
+impl DirectionNumberSource for DirectionTable {
+    #[inline]
+    fn max_dim(&self) -> usize {
+        DirectionTable::max_dim(self)
+    }
+
+    #[inline]
+    fn initial_numbers(&self, dim: usize) -> (u64, Vec<u64>) {
+        self.entries[dim - 1].clone()
+    }
+}
+
+/// Checks that the polynomial and initial direction numbers of a dimension are
+/// valid.
+fn check_initial_numbers(a: u64, m: &[u64]) -> Result<(), QrngError> {
+    let s = m.len();
+    if s == 0 || s >= 64 {
+        return Err(QrngError::InvalidParameter {
+            name: "Sobol primitive polynomial degree",
+            value: s as _,
+        });
+    }
+    if a >> (s - 1) != 0 {
+        return Err(QrngError::InvalidParameter { name: "Sobol primitive polynomial", value: a });
+    }
+    for (k, &m) in m.iter().enumerate() {
+        if m % 2 == 0 || m >> (k + 1) != 0 {
+            return Err(QrngError::InvalidParameter {
+                name: "Sobol initial direction number",
+                value: m,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Minimum number of dimensions expanded by each of the parallel tasks.
+#[cfg(feature = "rayon")]
+const PAR_MIN_DIMS: usize = 256;
+
+/// Returns the primitive polynomial and the initial direction numbers of the given
+/// dimension, checking that they are valid; the first dimension has no initial
+/// direction numbers.
+fn get_initial_numbers<S: DirectionNumberSource + ?Sized>(
+    source: &S, dim: usize,
+) -> Result<(u64, Vec<u64>), QrngError> {
+    if dim == 0 {
+        return Ok((0, Vec::new()));
+    }
+    let (a, m) = source.initial_numbers(dim);
+    check_initial_numbers(a, &m)?;
+    Ok((a, m))
+}
+
+/// Returns all direction numbers of the given dimension.
+fn get_dirnums<S: DirectionNumberSource + ?Sized>(
+    source: &S, dim: usize,
+) -> Result<[u64; MAX_LOG_N], QrngError> {
+    let (a, m) = get_initial_numbers(source, dim)?;
+    let mut v = [0; MAX_LOG_N];
+    for k in 0..MAX_LOG_N {
+        v[k] = next_dirnum(a, &m, k, |i| v[i]);
+    }
+    Ok(v)
+}
+
+/// Parses the primitive polynomials and initial direction numbers for up to `count`
+/// dimensions following the first one from a file in the format used by Joe and
+/// Kuo.
+fn parse_direction_file<B: BufRead>(reader: B, count: usize) -> io::Result<Vec<(u64, Vec<u64>)>> {
+    let invalid = |line: usize, msg: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid direction number file at line {}: {}", line, msg),
+        )
+    };
+    let mut entries = Vec::new();
+    // the first line is the header
+    for (i, line) in reader.lines().enumerate().skip(1) {
+        if entries.len() == count {
+            break;
+        }
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields = line
+            .split_whitespace()
+            .map(|f| f.parse::<u64>().map_err(|_| invalid(i + 1, "non-integer value")))
+            .collect::<io::Result<Vec<_>>>()?;
+        let (s, a) = match fields.get(1..3) {
+            Some(&[s, a]) => (s as usize, a),
+            _ => return Err(invalid(i + 1, "missing fields")),
+        };
+        let m = fields[3..].to_vec();
+        if m.len() != s {
+            return Err(invalid(i + 1, "wrong number of direction numbers"));
+        }
+        check_initial_numbers(a, &m).map_err(|e| invalid(i + 1, &e.to_string()))?;
+        entries.push((a, m));
+    }
+    Ok(entries)
+}
+
+/// Number of dimensions processed at once by [`xor_fill`].
+#[cfg(not(feature = "simd"))]
+const LANES: usize = 8;
+
+/// Applies the direction numbers `v` to the current point `value` and writes its
+/// coordinates to `out`; all three slices must have the same length.
+///
+/// The dimensions are processed in blocks of [`LANES`] without explicit SIMD
+/// intrinsics, so that the loop can be auto-vectorized; whether it actually is
+/// depends on the compiler and the enabled target features (e.g. AVX2 via
+/// `-C target-cpu=native`). The `simd` feature replaces it with an explicitly
+/// vectorized version. The `SobolSeq::gen_points_into` benchmarks cover the
+/// high-dimensional cases where this matters.
+#[cfg(not(feature = "simd"))]
+#[inline]
+fn xor_fill(value: &mut [u64], v: &[u64], out: &mut [f64]) {
+    let mut value = value.chunks_exact_mut(LANES);
+    let mut v = v.chunks_exact(LANES);
+    let mut out = out.chunks_exact_mut(LANES);
+    for ((x, v), y) in (&mut value).zip(&mut v).zip(&mut out) {
+        for i in 0..LANES {
+            x[i] ^= v[i];
+            y[i] = to_unit_f64(x[i]);
+        }
+    }
+    let rest = value.into_remainder().iter_mut().zip(v.remainder());
+    for ((x, &v), y) in rest.zip(out.into_remainder()) {
+        *x ^= v;
+        *y = to_unit_f64(*x);
+    }
+}
+
+/// Applies the direction numbers `v` to the current point `value` and writes its
+/// coordinates to `out`; all three slices must have the same length.
+///
+/// The dimensions are processed four at a time using the portable SIMD vectors
+/// from the `wide` crate, which are lowered to AVX2, SSE2, NEON or WebAssembly SIMD
+/// instructions depending on the enabled target features. The conversion is done
+/// in the same way as in [`to_unit_f64`], so the results are exactly the same as
+/// without the `simd` feature.
+#[cfg(feature = "simd")]
+#[inline]
+fn xor_fill(value: &mut [u64], v: &[u64], out: &mut [f64]) {
+    use wide::{f64x4, u64x4};
+
+    const MAGIC: f64 = (1u64 << 52) as f64;
+    const SCALE: f64 = 1. / (1u64 << MAX_LOG_N) as f64;
+    let (magic_bits, low_mask) = (u64x4::splat(MAGIC.to_bits()), u64x4::splat(0xffff_ffff));
+    let (magic, high, scale) =
+        (f64x4::splat(MAGIC), f64x4::splat(4294967296.), f64x4::splat(SCALE));
+
+    let mut value = value.chunks_exact_mut(4);
+    let mut v = v.chunks_exact(4);
+    let mut out = out.chunks_exact_mut(4);
+    for ((x, v), y) in (&mut value).zip(&mut v).zip(&mut out) {
+        let x4 = u64x4::new([x[0], x[1], x[2], x[3]]) ^ u64x4::new([v[0], v[1], v[2], v[3]]);
+        x.copy_from_slice(x4.as_array_ref());
+        let hi: f64x4 = bytemuck::cast((x4 >> 32) | magic_bits);
+        let lo: f64x4 = bytemuck::cast((x4 & low_mask) | magic_bits);
+        y.copy_from_slice(&(((hi - magic) * high + (lo - magic)) * scale).to_array());
+    }
+    let rest = value.into_remainder().iter_mut().zip(v.remainder());
+    for ((x, &v), y) in rest.zip(out.into_remainder()) {
+        *x ^= v;
+        *y = to_unit_f64(*x);
+    }
+}
+
+/// Number of consecutive points generated at once by [`xor_fill_block`].
+const BLOCK: usize = 8;
+
+/// Generates a block of [`BLOCK`] consecutive points starting from the current point
+/// `value` (which is updated to the last point of the block), writing them to `out`
+/// in row-major order; the index of the first point of the block must be a
+/// multiple of [`BLOCK`].
+///
+/// Within such a block, all points except for the last one use fixed columns of the
+/// direction numbers (the trailing ones of the indices `0, 1, ..., BLOCK - 2`), so
+/// only the last column (`last`) has to be computed. The points are generated one
+/// dimension at a time, so that the coordinates stay in registers across the block.
+///
+/// The bit depth of the sequence must be greater than `log2(BLOCK)`.
+#[inline]
+unsafe fn xor_fill_block(value: &mut [u64], dirnums: &[u64], last: usize, out: &mut [f64]) {
+    const COLS: [usize; BLOCK - 1] = [0, 1, 0, 2, 0, 1, 0];
+    let ndim = value.len();
+    for (j, x) in value.iter_mut().enumerate() {
+        for (p, &c) in COLS.iter().enumerate() {
+            *x ^= *dirnums.get_unchecked(c * ndim + j);
+            *out.get_unchecked_mut(p * ndim + j) = to_unit_f64(*x);
+        }
+        *x ^= *dirnums.get_unchecked(last * ndim + j);
+        *out.get_unchecked_mut((BLOCK - 1) * ndim + j) = to_unit_f64(*x);
+    }
+}
+
+/// Sobol low-discrepancy sequence generator.
+///
+/// The implementation relies on primitive polynomials module two suggested in
+/// "Constructing Sobol Sequences with Better Two-Dimensional Projections" (Joe and
+/// Kuo, 2008).
+///
+/// The direction numbers are expanded lazily, one column (i.e. one direction number
+/// for each of the dimensions) at a time: generating the first `n` points only
+/// requires `log2(n) + 1` columns out of 53, which makes construction of
+/// high-dimensional generators cheap. If the `rayon` feature is enabled, the
+/// columns are expanded in parallel across the dimensions.
+///
+/// The expanded direction numbers are shared between clones of a generator and only
+/// copied when one of the clones needs to modify them (e.g. to expand more columns),
+/// so that cloning only copies the current point, which takes `8 * ndim` bytes.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SobolState", into = "SobolState"))]
+pub struct SobolSeq {
+    ndim: usize,
+    bits: usize,
+    /// Number of columns of direction numbers expanded so far.
+    ncols: usize,
+    /// Primitive polynomials and initial direction numbers for expanding the rest of
+    /// the columns; empty once all of the columns are expanded.
+    polys: Arc<Vec<(u64, Vec<u64>)>>,
+    dirnums: Arc<Vec<u64>>,
+    value: Vec<u64>,
+    index: u64,
+}
+
+impl SobolSeq {
+    /// Returns a new Sobol sequence generator with dimensionality `ndim`.
+    ///
+    /// Panics if `ndim` is greater than 1111 (or 21201 if `sobol-high-dim` feature
+    /// is enabled).
+    #[inline]
+    pub fn new(ndim: usize) -> Self {
+        Self::with_direction_numbers(ndim, DirectionNumbers::JoeKuo)
+    }
+
+    /// Returns a new Sobol sequence generator with dimensionality `ndim`, skipping
+    /// the first `n` points ("burn-in").
+    ///
+    /// Skipping is performed in `O(ndim * log(n))` time; note that resetting the
+    /// generator moves it back to the first point. If a burn-in is used at all, it
+    /// is recommended to skip a power of two points so that the balance properties
+    /// of the sequence are preserved.
+    ///
+    /// Panics if `ndim` is greater than 1111 (or 21201 if `sobol-high-dim` feature
+    /// is enabled).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let seq = SobolSeq::new_with_skip(2, 1024);
+    /// assert_eq!(seq.index(), 1024);
+    /// ```
+    #[inline]
+    pub fn new_with_skip(ndim: usize, n: u64) -> Self {
+        Self::builder().ndim(ndim).skip(n).build()
+    }
+
+    /// Returns a new Sobol sequence generator with dimensionality `ndim`, using the
+    /// given set of direction numbers.
+    ///
+    /// Panics if `ndim` is greater than `dnums.max_dim()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = SobolSeq::with_direction_numbers(10, DirectionNumbers::BratleyFox);
+    /// ```
+    #[inline]
+    pub fn with_direction_numbers(ndim: usize, dnums: DirectionNumbers) -> Self {
+        Self::try_with_direction_numbers(ndim, dnums).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as [`new`](#method.new), but returns an error instead of panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// assert!(SobolSeq::try_new(100).is_ok());
+    /// assert!(SobolSeq::try_new(1_000_000).is_err());
+    /// ```
+    #[inline]
+    pub fn try_new(ndim: usize) -> Result<Self, QrngError> {
+        Self::try_with_direction_numbers(ndim, DirectionNumbers::JoeKuo)
+    }
+
+    /// Same as [`with_direction_numbers`](#method.with_direction_numbers), but returns
+    /// an error instead of panicking.
+    #[inline]
+    pub fn try_with_direction_numbers(
+        ndim: usize, dnums: DirectionNumbers,
+    ) -> Result<Self, QrngError> {
+        Self::try_with_source(ndim, &dnums)
+    }
+
+    /// Returns a new Sobol sequence generator with dimensionality `ndim`, using the
+    /// direction numbers from the given source.
+    ///
+    /// Panics if `ndim` is greater than `source.max_dim()`, or if the source provides
+    /// invalid direction numbers.
+    #[inline]
+    pub fn with_source<S: DirectionNumberSource + ?Sized>(ndim: usize, source: &S) -> Self {
+        Self::try_with_source(ndim, source).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as [`with_source`](#method.with_source), but returns an error instead of
+    /// panicking.
+    pub fn try_with_source<S: DirectionNumberSource + ?Sized>(
+        ndim: usize, source: &S,
+    ) -> Result<Self, QrngError> {
+        check_ndim("Sobol sequence", ndim, source.max_dim())?;
+        let polys = (0..ndim).map(|j| get_initial_numbers(source, j)).collect::<Result<_, _>>()?;
+        let (bits, value, polys) = (MAX_LOG_N, vec![0; ndim], Arc::new(polys));
+        Ok(Self { ndim, bits, ncols: 0, polys, dirnums: Arc::default(), value, index: 0 })
+    }
+
+    /// Returns a new Sobol sequence generator with dimensionality `ndim`, using the
+    /// direction numbers loaded from a file at runtime.
+    ///
+    /// The file must be in the format of the tables published by Joe and Kuo, e.g.
+    /// `new-joe-kuo-6.21201` (see
+    /// [`DirectionTable::from_file`](struct.DirectionTable.html#method.from_file));
+    /// only the first `ndim - 1` lines after the header are read. This allows using
+    /// updated or custom tables, or going beyond the embedded ones without enabling
+    /// the `sobol-high-dim` feature.
+    ///
+    /// Returns an error if the file can't be read, if it is malformed, or if it
+    /// contains fewer than `ndim` dimensions.
+    pub fn from_direction_file<P: AsRef<Path>>(path: P, ndim: usize) -> io::Result<Self> {
+        Self::from_direction_reader(BufReader::new(File::open(path)?), ndim)
+    }
+
+    /// Same as [`from_direction_file`](#method.from_direction_file), but reads the
+    /// direction numbers from an arbitrary buffered reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let table = "d s a m_i\n2 1 0 1\n3 2 1 1 3\n";
+    /// let mut seq = SobolSeq::from_direction_reader(table.as_bytes(), 3).unwrap();
+    /// assert_eq!(seq.gen_points(10), SobolSeq::new(3).gen_points(10));
+    /// ```
+    pub fn from_direction_reader<B: BufRead>(reader: B, ndim: usize) -> io::Result<Self> {
+        let entries = parse_direction_file(reader, ndim.saturating_sub(1))?;
+        if entries.len() + 1 < ndim {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("invalid direction number file: only {} dimensions", entries.len() + 1),
+            ));
+        }
+        Ok(Self::with_source(ndim, &DirectionTable { entries }))
+    }
+
+    /// Returns the bit depth of the sequence, i.e. the number of direction numbers
+    /// per dimension; the period of the sequence is `2^bits`.
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Makes sure that at least the first `ncols` columns of direction numbers are
+    /// expanded.
+    #[inline]
+    fn expand_to(&mut self, ncols: usize) {
+        if ncols > self.ncols {
+            self.expand_columns(ncols);
+        }
+    }
+
+    /// Expands the columns of direction numbers up to `ncols`; if the `rayon` feature
+    /// is enabled, each column is expanded in parallel across the dimensions.
+    #[cold]
+    fn expand_columns(&mut self, ncols: usize) {
+        let (ndim, polys) = (self.ndim, &self.polys);
+        let dirnums = Arc::make_mut(&mut self.dirnums);
+        dirnums.resize(ncols * ndim, 0);
+        for k in self.ncols..ncols {
+            // each column only depends on the preceding ones
+            let (prev, col) = dirnums[..(k + 1) * ndim].split_at_mut(k * ndim);
+            let expand = |(j, (x, (a, m))): (usize, (&mut u64, &(u64, Vec<u64>)))| {
+                *x = next_dirnum(*a, m, k, |i| prev[i * ndim + j]);
+            };
+            #[cfg(feature = "rayon")]
+            col.par_iter_mut()
+                .zip(&polys[..])
+                .enumerate()
+                .with_min_len(PAR_MIN_DIMS)
+                .for_each(expand);
+            #[cfg(not(feature = "rayon"))]
+            col.iter_mut().zip(polys.iter()).enumerate().for_each(expand);
+        }
+        self.ncols = ncols;
+        if ncols == self.bits {
+            self.polys = Arc::default();
+        }
+    }
+
+    /// Makes sure that all of the direction numbers are expanded.
+    #[inline]
+    fn expand_all(&mut self) {
+        self.expand_to(self.bits);
+    }
+
+    /// Makes sure that the direction numbers needed for generating the next `n`
+    /// points are expanded.
+    #[inline]
+    fn expand_for_next(&mut self, n: usize) {
+        // the column of a point never exceeds the bit length of its index plus one
+        let end = u128::from(self.index) + n as u128;
+        self.expand_to((128 - end.leading_zeros() as usize).min(self.bits));
+    }
+
+    /// Returns all of the direction numbers, expanding them if needed.
+    fn full_dirnums(&self) -> Vec<u64> {
+        let mut seq = self.clone();
+        seq.expand_all();
+        Arc::try_unwrap(seq.dirnums).unwrap_or_else(|dirnums| dirnums.to_vec())
+    }
+
+    /// Reduces the bit depth to the given number of bits, truncating the direction
+    /// numbers; the generator is moved to the beginning of the sequence.
+    fn truncate_bits(&mut self, bits: usize) -> Result<(), QrngError> {
+        if bits == 0 || bits > self.bits {
+            return Err(QrngError::InvalidParameter {
+                name: "Sobol sequence bit depth",
+                value: bits as _,
+            });
+        }
+        // the direction numbers stay aligned to MAX_LOG_N bits so that the scaling
+        // of the outputs doesn't depend on the bit depth
+        let mask = !((1u64 << (MAX_LOG_N - bits)) - 1);
+        self.expand_all();
+        self.ncols = bits;
+        let dirnums = Arc::make_mut(&mut self.dirnums);
+        dirnums.truncate(bits * self.ndim);
+        dirnums.iter_mut().for_each(|v| *v &= mask);
+        self.bits = bits;
+        self.skip_to(0);
+        Ok(())
+    }
+
+    /// Switches the generator to natural (non-Gray-code) order; the generator is
+    /// moved to the beginning of the sequence.
+    ///
+    /// In natural order, the point with index `i` is the sum of the direction
+    /// numbers for the bits set in `i + 1`. Since the bits of the Gray code of an
+    /// index telescope to the bits of the index itself, this amounts to replacing
+    /// each direction number with the prefix sum (XOR) of the direction numbers up
+    /// to it, after which the Gray code recurrence is used as is.
+    fn use_natural_order(&mut self) {
+        self.expand_all();
+        let (ndim, dirnums) = (self.ndim, Arc::make_mut(&mut self.dirnums));
+        for k in 1..self.bits {
+            for j in 0..ndim {
+                dirnums[k * ndim + j] ^= dirnums[(k - 1) * ndim + j];
+            }
+        }
+        self.skip_to(0);
+    }
+
+    /// Returns a new generator with the given fully expanded direction numbers,
+    /// starting from the beginning of the sequence.
+    #[inline]
+    fn from_dirnums(ndim: usize, bits: usize, dirnums: Vec<u64>) -> Self {
+        let (value, polys, dirnums) = (vec![0; ndim], Arc::default(), Arc::new(dirnums));
+        Self { ndim, bits, ncols: bits, polys, dirnums, value, index: 0 }
+    }
+
+    /// Returns the mask for reducing indices modulo the period of the sequence.
+    #[inline]
+    fn index_mask(&self) -> u64 {
+        (1u64 << self.bits) - 1
+    }
+
+    /// Returns a new Sobol sequence generator with the direction numbers randomized
+    /// via random linear matrix scrambling with the given seed.
+    ///
+    /// The generating matrix of each dimension is left-multiplied by a random
+    /// nonsingular lower-triangular matrix ("On the L2-Discrepancy for Anchored
+    /// Boxes", Matoušek, 1998). This preserves the net properties of the sequence
+    /// and has no per-point cost. Note that the zero point is a fixed point of any
+    /// linear scrambling; for the points to be uniformly distributed, it should be
+    /// combined with a digital shift.
+    ///
+    /// The returned generator starts from the beginning of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = SobolSeq::new(3).linear_scramble(42).with_buf();
+    /// assert_ne!(seq.gen(), &[0.5, 0.5, 0.5]);
+    /// ```
+    pub fn linear_scramble(&self, seed: u64) -> Self {
+        let mut dirnums = self.full_dirnums();
+        linear_scramble(&mut dirnums, self.ndim, MAX_LOG_N as _, seed);
+        Self::from_dirnums(self.ndim, self.bits, dirnums)
+    }
+
+    /// Returns a new Sobol sequence generator with the direction numbers randomized
+    /// via Faure–Tezuka scrambling with the given seed.
+    ///
+    /// The generating matrices of all dimensions are right-multiplied by the same
+    /// random nonsingular upper-triangular matrix ("Another Random Scrambling of
+    /// Digital (t,s)-Sequences", Faure and Tezuka, 2002). This reorders the points
+    /// within each block of `2^m` points of the sequence, and can be combined with
+    /// [`linear_scramble`](#method.linear_scramble) and digital shifts.
+    ///
+    /// The returned generator starts from the beginning of the sequence.
+    pub fn faure_tezuka_scramble(&self, seed: u64) -> Self {
+        let mut dirnums = self.full_dirnums();
+        faure_tezuka_scramble(&mut dirnums, self.ndim, self.bits, seed);
+        Self::from_dirnums(self.ndim, self.bits, dirnums)
+    }
+
+    /// Moves the generator so that the next generated point is the one with the
+    /// given (zero-based) index, as if `index` points were generated since the
+    /// beginning of the sequence.
+    ///
+    /// The state is computed directly from the Gray code of the index in
+    /// `O(ndim * log(index))` time. The index is taken modulo the period of the
+    /// sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = SobolSeq::new(2);
+    /// seq.skip_to(2);
+    /// assert_eq!(seq.with_buf().gen(), &[0.25, 0.75]);
+    /// ```
+    pub fn skip_to(&mut self, index: u64) {
+        let index = index & self.index_mask();
+        let gray = index ^ (index >> 1);
+        self.expand_to(64 - gray.leading_zeros() as usize);
+        self.value.iter_mut().for_each(|x| *x = 0);
+        for k in (0..self.bits).filter(|&k| (gray >> k) & 1 != 0) {
+            let v = &self.dirnums[k * self.ndim..(k + 1) * self.ndim];
+            self.value.iter_mut().zip(v).for_each(|(x, &v)| *x ^= v);
+        }
+        self.index = index;
+    }
+
+    /// Writes the next point of the sequence to `out` as raw fixed-point integers,
+    /// i.e. each coordinate `x` is written as the integer `x * 2^64`.
+    ///
+    /// Only the top 53 bits of the integers are significant (or fewer if the bit depth
+    /// is lower, unless the sequence is scrambled); the rest are zero.
+    ///
+    /// Panics if the length of `out` is less than the dimensionality.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = SobolSeq::new(2);
+    /// let mut buf = [0; 2];
+    /// seq.gen_fill_u64(&mut buf);
+    /// seq.gen_fill_u64(&mut buf);
+    /// assert_eq!(buf, [3 << 62, 1 << 62]);
+    /// ```
+    #[inline]
+    pub fn gen_fill_u64(&mut self, out: &mut [u64]) {
+        if out.len() < self.ndim {
+            panic!("index out of bounds: the len is {} but the index is {}", out.len(), self.ndim);
+        }
+        unsafe { self.gen_fill_digits_unchecked(out) };
+    }
+
+    /// Writes the next point of the sequence to `out` as raw fixed-point integers,
+    /// i.e. each coordinate `x` is written as the integer `floor(x * 2^32)`.
+    ///
+    /// Panics if the length of `out` is less than the dimensionality.
+    #[inline]
+    pub fn gen_fill_u32(&mut self, out: &mut [u32]) {
+        if out.len() < self.ndim {
+            panic!("index out of bounds: the len is {} but the index is {}", out.len(), self.ndim);
+        }
+        let c = column(self.index, self.bits);
+        self.expand_to(c + 1);
+        let v = &self.dirnums[c * self.ndim..(c + 1) * self.ndim];
+        for ((x, &v), y) in self.value.iter_mut().zip(v).zip(out) {
+            *x ^= v;
+            *y = (*x >> (MAX_LOG_N - 32)) as u32;
+        }
+        self.index = (self.index + 1) & self.index_mask();
+    }
+}
+
+impl SobolSeq {
+    /// Returns the generating matrices of the generator, one matrix per dimension.
+    ///
+    /// Each matrix consists of `bits()` columns (direction numbers), stored as
+    /// `53`-bit integers whose most significant bit corresponds to the first binary
+    /// digit after the radix point; the `i`-th point of the sequence (in Gray code
+    /// order) is the XOR of the columns corresponding to the bits set in the Gray
+    /// code of `i + 1`. Any scrambling or change of the bit depth or of the order
+    /// applied to the generator is reflected in the matrices. This is the format
+    /// expected by [`DigitalNet::new`](struct.DigitalNet.html#method.new) with
+    /// `53`-bit precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let matrices = SobolSeq::new(2).matrices();
+    /// assert_eq!(matrices[1][..3], [1 << 52, 3 << 51, 5 << 50]);
+    /// ```
+    pub fn matrices(&self) -> Vec<Vec<u64>> {
+        let dirnums = self.full_dirnums();
+        (0..self.ndim)
+            .map(|j| (0..self.bits).map(|k| dirnums[k * self.ndim + j]).collect())
+            .collect()
+    }
+
+    /// Returns the generating matrices of the default (Joe–Kuo) Sobol sequence for
+    /// the given range of (zero-based) dimensions, with all `53` columns per matrix.
+    ///
+    /// The format of the matrices is the same as in [`matrices`](#method.matrices).
+    /// This doesn't require constructing a generator for all of the preceding
+    /// dimensions, and can be used e.g. for uploading the matrices to a GPU or for
+    /// implementing custom scrambles.
+    ///
+    /// Panics if the end of the range is greater than 1111 (or 21201 if
+    /// `sobol-high-dim` feature is enabled).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let matrices = SobolSeq::generating_matrices(1..3);
+    /// assert_eq!(matrices.len(), 2);
+    /// assert_eq!(matrices[1][..], SobolSeq::new(3).matrices()[2][..]);
+    /// ```
+    pub fn generating_matrices(dims: Range<usize>) -> Vec<[u64; MAX_LOG_N]> {
+        let max_dim = DirectionNumbers::JoeKuo.max_dim();
+        check_ndim("Sobol sequence", dims.end, max_dim).unwrap_or_else(|e| panic!("{}", e));
+        dims.map(|j| get_dirnums(&DirectionNumbers::JoeKuo, j).unwrap_or_else(|e| panic!("{}", e)))
+            .collect()
+    }
+}
+
+/// Version of the [`SobolState`](struct.SobolState.html) layout.
+const SOBOL_STATE_VERSION: u32 = 3;
+
+/// Opaque snapshot of the state of a [`SobolSeq`](struct.SobolSeq.html) generator.
+///
+/// The snapshot includes the (possibly scrambled) direction numbers, so the
+/// generator can be restored exactly via
+/// [`SobolSeq::from_state`](struct.SobolSeq.html#method.from_state).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SobolState {
+    version: u32,
+    ndim: usize,
+    bits: usize,
+    dirnums: Vec<u64>,
+    value: Vec<u64>,
+    index: u64,
+}
+
+impl SobolSeq {
+    /// Returns a snapshot of the generator state for checkpointing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = SobolSeq::new(3);
+    /// seq.discard(100);
+    /// let state = seq.state();
+    /// let mut restored = SobolSeq::from_state(state).unwrap().with_buf();
+    /// assert_eq!(restored.gen(), seq.with_buf().gen());
+    /// ```
+    pub fn state(&self) -> SobolState {
+        SobolState {
+            version: SOBOL_STATE_VERSION,
+            ndim: self.ndim,
+            bits: self.bits,
+            dirnums: self.full_dirnums(),
+            value: self.value.clone(),
+            index: self.index,
+        }
+    }
+
+    /// Restores a generator from a snapshot of its state.
+    ///
+    /// Returns an error if the snapshot has an unsupported version or is malformed,
+    /// including when any of the direction numbers or current values doesn't fit in
+    /// 53 bits.
+    pub fn from_state(state: SobolState) -> Result<Self, QrngError> {
+        if state.version != SOBOL_STATE_VERSION {
+            let value = u64::from(state.version);
+            return Err(QrngError::InvalidParameter { name: "Sobol state version", value });
+        }
+        let SobolState { ndim, bits, dirnums, value, index, .. } = state;
+        let valid = bits != 0
+            && bits <= MAX_LOG_N
+            && dirnums.len() == ndim * bits
+            && value.len() == ndim
+            && index >> bits == 0
+            && dirnums.iter().chain(&value).all(|&x| x >> MAX_LOG_N == 0);
+        if !valid {
+            return Err(QrngError::InvalidParameter { name: "Sobol state", value: index });
+        }
+        let (polys, dirnums) = (Arc::default(), Arc::new(dirnums));
+        Ok(Self { ndim, bits, ncols: bits, polys, dirnums, value, index })
+    }
+}
+
+impl From<SobolSeq> for SobolState {
+    #[inline]
+    fn from(seq: SobolSeq) -> Self {
+        seq.state()
+    }
+}
+
+impl TryFrom<SobolState> for SobolSeq {
+    type Error = QrngError;
+
+    #[inline]
+    fn try_from(state: SobolState) -> Result<Self, QrngError> {
+        Self::from_state(state)
+    }
+}
+
+/// Builder for [`SobolSeq`](struct.SobolSeq.html) generators.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let seq = SobolSeq::builder()
+///     .ndim(10)
+///     .direction_numbers(DirectionNumbers::BratleyFox)
+///     .bits(32)
+///     .scramble(42)
+///     .skip(1024)
+///     .build();
+/// assert_eq!(seq.ndim(), 10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SobolSeqBuilder {
+    ndim: usize,
+    dnums: DirectionNumbers,
+    bits: usize,
+    natural: bool,
+    scramble: Option<u64>,
+    skip: u64,
+}
+
+impl SobolSeqBuilder {
+    /// Sets the dimensionality of the sequence (1 by default).
+    #[inline]
+    pub fn ndim(mut self, ndim: usize) -> Self {
+        self.ndim = ndim;
+        self
+    }
+
+    /// Sets the direction numbers (Joe–Kuo by default).
+    #[inline]
+    pub fn direction_numbers(mut self, dnums: DirectionNumbers) -> Self {
+        self.dnums = dnums;
+        self
+    }
+
+    /// Sets the bit depth, i.e. the number of direction numbers per dimension (53 by
+    /// default, which is also the maximum).
+    ///
+    /// The period of the sequence is `2^bits`, and the values of unscrambled
+    /// sequences have `bits` bits of precision. Lower bit depths reduce the size of
+    /// the table of direction numbers accessed on each step.
+    #[inline]
+    pub fn bits(mut self, bits: usize) -> Self {
+        self.bits = bits;
+        self
+    }
+
+    /// Sets whether the points are generated in natural order rather than in Gray
+    /// code order (the default).
+    ///
+    /// In natural order, the coordinates of each point are determined directly by the
+    /// bits of its index, which is e.g. needed to match the output of other
+    /// libraries. For each `m`, the first `2^m` points are the same in both orders,
+    /// up to a permutation. The generation speed doesn't depend on the order.
+    #[inline]
+    pub fn natural_order(mut self, natural: bool) -> Self {
+        self.natural = natural;
+        self
+    }
+
+    /// Enables random linear scrambling of the direction numbers with the given seed
+    /// (see [`SobolSeq::linear_scramble`](struct.SobolSeq.html#method.linear_scramble)).
+    #[inline]
+    pub fn scramble(mut self, seed: u64) -> Self {
+        self.scramble = Some(seed);
+        self
+    }
+
+    /// Sets the number of initial points to skip (none by default).
+    ///
+    /// Skipping is performed in `O(ndim * log(n))` time (see
+    /// [`SobolSeq::new_with_skip`](struct.SobolSeq.html#method.new_with_skip)).
+    #[inline]
+    pub fn skip(mut self, n: u64) -> Self {
+        self.skip = n;
+        self
+    }
+
+    /// Builds the generator.
+    ///
+    /// Panics if the dimensionality is greater than `max_dim()` of the direction
+    /// numbers, or if the bit depth is zero or greater than 53.
+    #[inline]
+    pub fn build(self) -> SobolSeq {
+        self.try_build().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as [`build`](#method.build), but returns an error instead of panicking.
+    #[inline]
+    pub fn try_build(self) -> Result<SobolSeq, QrngError> {
+        let mut seq = SobolSeq::try_with_direction_numbers(self.ndim, self.dnums)?;
+        seq.truncate_bits(self.bits)?;
+        if let Some(seed) = self.scramble {
+            seq = seq.linear_scramble(seed);
+        }
+        if self.natural {
+            seq.use_natural_order();
+        }
+        seq.skip_to(self.skip);
+        Ok(seq)
+    }
+}
+
+impl SobolSeq {
+    /// Returns a builder for configuring the generator.
+    #[inline]
+    pub fn builder() -> SobolSeqBuilder {
+        SobolSeqBuilder {
+            ndim: 1,
+            dnums: DirectionNumbers::JoeKuo,
+            bits: MAX_LOG_N,
+            natural: false,
+            scramble: None,
+            skip: 0,
+        }
+    }
+}
+
+impl QRng for SobolSeq {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.skip_to(0);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        let (c, ndim) = (column(self.index, self.bits), self.ndim);
+        self.expand_to(c + 1);
+        let v = self.dirnums.get_unchecked(c * ndim..(c + 1) * ndim);
+        xor_fill(&mut self.value, v, out.get_unchecked_mut(..ndim));
+        self.index = (self.index + 1) & self.index_mask();
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        // the period divides 2^64, so wrapping around is harmless
+        self.skip_to(self.index.wrapping_add(n));
+    }
+
+    #[inline]
+    unsafe fn gen_fill_f32_unchecked(&mut self, out: &mut [f32]) {
+        const SCALE: f32 = 1. / ((1u64 << MAX_LOG_N) as f32);
+        let c = column(self.index, self.bits);
+        self.expand_to(c + 1);
+        let v = self.dirnums.get_unchecked(c * self.ndim..);
+        for j in 0..self.ndim {
+            let x = self.value.get_unchecked_mut(j);
+            *x ^= *v.get_unchecked(j);
+            // the integer is rounded to the nearest f32, so only the top may round to 1
+            *out.get_unchecked_mut(j) = ((*x as f32) * SCALE).min(F32_BELOW_ONE);
+        }
+        self.index = (self.index + 1) & self.index_mask();
+    }
+
+    unsafe fn gen_points_unchecked(&mut self, n: usize, out: &mut [f64]) {
+        self.expand_for_next(n);
+        let (ndim, mask) = (self.ndim, self.index_mask());
+        let mut index = self.index;
+        let mut i = 0;
+        while i < n {
+            // full blocks are generated at once, and the rest one point at a time
+            if index.is_multiple_of(BLOCK as u64) && n - i >= BLOCK && self.bits > 3 {
+                let last = column(index + BLOCK as u64 - 1, self.bits);
+                let y = out.get_unchecked_mut(i * ndim..(i + BLOCK) * ndim);
+                xor_fill_block(&mut self.value, &self.dirnums, last, y);
+                index = (index + BLOCK as u64) & mask;
+                i += BLOCK;
+            } else {
+                let c = column(index, self.bits);
+                let v = self.dirnums.get_unchecked(c * ndim..(c + 1) * ndim);
+                xor_fill(&mut self.value, v, out.get_unchecked_mut(i * ndim..(i + 1) * ndim));
+                index = (index + 1) & mask;
+                i += 1;
+            }
+        }
+        self.index = index;
+    }
+
+    unsafe fn gen_points_col_major_unchecked(&mut self, n: usize, out: &mut [f64]) {
+        self.expand_for_next(n);
+        let mask = self.index_mask();
+        // offsets of the direction numbers used for each of the points
+        let rows: Vec<_> = (0..n as u64)
+            .map(|i| {
+                let index = self.index.wrapping_add(i) & mask;
+                column(index, self.bits) * self.ndim
+            })
+            .collect();
+        for j in 0..self.ndim {
+            let mut x = *self.value.get_unchecked(j);
+            let col = out.get_unchecked_mut(j * n..(j + 1) * n);
+            for (y, &row) in col.iter_mut().zip(&rows) {
+                x ^= *self.dirnums.get_unchecked(row + j);
+                *y = to_unit_f64(x);
+            }
+            *self.value.get_unchecked_mut(j) = x;
+        }
+        self.index = self.index.wrapping_add(n as u64) & mask;
+    }
+}
+
+impl SkipTo for SobolSeq {
+    #[inline]
+    fn skip_to(&mut self, index: u64) {
+        SobolSeq::skip_to(self, index);
+    }
+}
+
+impl DigitalQRng for SobolSeq {
+    #[inline]
+    unsafe fn gen_fill_digits_unchecked(&mut self, out: &mut [u64]) {
+        let c = column(self.index, self.bits);
+        self.expand_to(c + 1);
+        let v = self.dirnums.get_unchecked(c * self.ndim..);
+        for j in 0..self.ndim {
+            let x = self.value.get_unchecked_mut(j);
+            *x ^= *v.get_unchecked(j);
+            *out.get_unchecked_mut(j) = *x << (64 - MAX_LOG_N);
+        }
+        self.index = (self.index + 1) & self.index_mask();
+    }
+}
+
+/// Maximum bit depth of [`CompactSobolSeq`](struct.CompactSobolSeq.html) generators.
+const COMPACT_BITS: usize = 32;
+
+/// Sobol sequence generator with the direction numbers stored as 32-bit integers.
+///
+/// The direction number of column `k` only has `k + 1` significant bits, starting
+/// from the first binary digit after the radix point, so for bit depths of up to 32
+/// the direction numbers fit into `u32` with an implicit shift. Compared to
+/// [`SobolSeq`](struct.SobolSeq.html), this cuts the size of the table accessed on
+/// each step more than threefold (`4 * 32` bytes per dimension rather than
+/// `8 * 53`), which improves throughput once the table doesn't fit in cache, i.e.
+/// for thousands of dimensions. The generated points are the same as those of the
+/// `SobolSeq` it was created from; for scrambled sequences, the coordinates are
+/// truncated to 32 bits.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let seq = SobolSeq::builder().ndim(1000).bits(32).build();
+/// let mut compact = seq.clone().into_compact().unwrap().with_buf();
+/// assert_eq!(compact.gen(), seq.with_buf().gen());
+/// ```
+#[derive(Clone)]
+pub struct CompactSobolSeq {
+    ndim: usize,
+    bits: usize,
+    dirnums: Vec<u32>,
+    value: Vec<u32>,
+    index: u64,
 }
-#[cfg(feature = "sobol-high-dim")]
-mod assets {
-    pub type DirNum = u32;
-    pub const SOBOL_MAX_DIM: usize = 21201;
-    pub const SOBOL_LOCS: &[DirNum] = include!("assets/sobol-all-locs.rs");
-    pub const SOBOL_COEF: &[DirNum] = include!("assets/sobol-all-coef.rs");
-    pub const SOBOL_DNUM: &[DirNum] = include!("assets/sobol-all-dnum.rs");
+
+impl SobolSeq {
+    /// Converts the generator into a [`CompactSobolSeq`](struct.CompactSobolSeq.html)
+    /// generating the same points, keeping its position in the sequence.
+    ///
+    /// Returns an error if the bit depth is greater than 32.
+    pub fn into_compact(self) -> Result<CompactSobolSeq, QrngError> {
+        if self.bits > COMPACT_BITS {
+            let value = self.bits as _;
+            return Err(QrngError::InvalidParameter {
+                name: "compact Sobol sequence bit depth",
+                value,
+            });
+        }
+        const SHIFT: usize = MAX_LOG_N - COMPACT_BITS;
+        let dirnums = self.full_dirnums().iter().map(|&v| (v >> SHIFT) as u32).collect();
+        let value = self.value.iter().map(|&x| (x >> SHIFT) as u32).collect();
+        let (ndim, bits, index) = (self.ndim, self.bits, self.index);
+        Ok(CompactSobolSeq { ndim, bits, dirnums, value, index })
+    }
 }
-use self::assets::*;
 
-const MAX_LOG_N: usize = 48;
+impl CompactSobolSeq {
+    /// Returns the bit depth of the sequence (see
+    /// [`SobolSeqBuilder::bits`](struct.SobolSeqBuilder.html#method.bits)).
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
 
-#[inline]
-fn get_raw_data(index: usize) -> (DirNum, &'static [DirNum]) {
-    if index + 2 > SOBOL_MAX_DIM {
-        panic!("invalid Sobol sequence dimension: {}", index + 2);
-    }
-    let (start, end) = (SOBOL_LOCS[index] as usize, SOBOL_LOCS[index + 1] as usize);
-    (SOBOL_COEF[index], &SOBOL_DNUM[start..end])
-}
-
-unsafe fn get_dirnums(axis: usize, out: &mut [u64], stride: usize) {
-    const MAX: usize = MAX_LOG_N - 1;
-    let mut dirnums = [0; MAX_LOG_N];
-    if axis == 0 {
-        for (i, x) in dirnums.iter_mut().enumerate() {
-            *x = 1 << (MAX - i);
-        }
-    } else {
-        let (coef, m) = get_raw_data(axis - 1);
-        let coef = u64::from(coef);
-        let s = m.len() + 1;
-        *dirnums.get_unchecked_mut(0) = 1 << MAX;
-        for i in 1..s.min(MAX_LOG_N) {
-            *dirnums.get_unchecked_mut(i) =
-                (u64::from(*m.get_unchecked(i - 1)) * 2 + 1) << (MAX - i);
-        }
-        for i in s..MAX_LOG_N {
-            let mut k = i - s;
-            let dk = *dirnums.get_unchecked(k);
-            let mut x = dk ^ (dk >> s);
-            let mut coef_s = coef;
-            k += 1;
-            for _ in 1..s {
-                x ^= (coef_s & 1) * *dirnums.get_unchecked(k);
-                coef_s >>= 1;
-                k += 1;
-            }
-            *dirnums.get_unchecked_mut(i) = x;
+    /// Returns the mask for reducing indices modulo the period of the sequence.
+    #[inline]
+    fn index_mask(&self) -> u64 {
+        (1u64 << self.bits) - 1
+    }
+
+    /// Moves the generator so that the next generated point is the one with the
+    /// given (zero-based) index (see [`SobolSeq::skip_to`](struct.SobolSeq.html#method.skip_to)).
+    pub fn skip_to(&mut self, index: u64) {
+        let index = index & self.index_mask();
+        let gray = index ^ (index >> 1);
+        self.value.iter_mut().for_each(|x| *x = 0);
+        for k in (0..self.bits).filter(|&k| (gray >> k) & 1 != 0) {
+            let v = &self.dirnums[k * self.ndim..(k + 1) * self.ndim];
+            self.value.iter_mut().zip(v).for_each(|(x, &v)| *x ^= v);
+        }
+        self.index = index;
+    }
+}
+
+impl QRng for CompactSobolSeq {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.skip_to(0);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        const SCALE: f64 = 1. / (1u64 << COMPACT_BITS) as f64;
+        let c = column(self.index, self.bits);
+        let v = self.dirnums.get_unchecked(c * self.ndim..(c + 1) * self.ndim);
+        let out = out.get_unchecked_mut(..self.ndim);
+        for ((x, &v), y) in self.value.iter_mut().zip(v).zip(out) {
+            *x ^= v;
+            *y = f64::from(*x) * SCALE;
+        }
+        self.index = (self.index + 1) & self.index_mask();
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.skip_to(self.index.wrapping_add(n));
+    }
+
+    #[inline]
+    unsafe fn gen_fill_f32_unchecked(&mut self, out: &mut [f32]) {
+        const SCALE: f32 = 1. / ((1u64 << COMPACT_BITS) as f32);
+        let c = column(self.index, self.bits);
+        let v = self.dirnums.get_unchecked(c * self.ndim..(c + 1) * self.ndim);
+        let out = out.get_unchecked_mut(..self.ndim);
+        for ((x, &v), y) in self.value.iter_mut().zip(v).zip(out) {
+            *x ^= v;
+            *y = ((*x as f32) * SCALE).min(F32_BELOW_ONE);
         }
+        self.index = (self.index + 1) & self.index_mask();
     }
-    for (i, &x) in dirnums.iter().enumerate() {
-        *out.get_unchecked_mut(i * stride) = x;
+}
+
+impl SkipTo for CompactSobolSeq {
+    #[inline]
+    fn skip_to(&mut self, index: u64) {
+        CompactSobolSeq::skip_to(self, index);
     }
 }
 
-/// Sobol low-discrepancy sequence generator.
+impl DigitalQRng for CompactSobolSeq {
+    #[inline]
+    unsafe fn gen_fill_digits_unchecked(&mut self, out: &mut [u64]) {
+        let c = column(self.index, self.bits);
+        let v = self.dirnums.get_unchecked(c * self.ndim..(c + 1) * self.ndim);
+        let out = out.get_unchecked_mut(..self.ndim);
+        for ((x, &v), y) in self.value.iter_mut().zip(v).zip(out) {
+            *x ^= v;
+            *y = u64::from(*x) << (64 - COMPACT_BITS);
+        }
+        self.index = (self.index + 1) & self.index_mask();
+    }
+}
+
+/// Sobol sequence generator with dimensionality fixed at compile time.
 ///
-/// The implementation relies on primitive polynomials module two suggested in
-/// "Constructing Sobol Sequences with Better Two-Dimensional Projections" (Joe and
-/// Kuo, 2008).
+/// This is the same sequence as [`SobolSeq`](struct.SobolSeq.html), but the state is
+/// stored in arrays and [`gen`](#method.gen) returns the points by value, which is
+/// convenient and efficient for low-dimensional problems.
+///
+/// The direction numbers (`424 * D` bytes) are stored on the heap, so that large
+/// values of `D` don't overflow the stack; the current point and the generated
+/// points take `8 * D` bytes each and are passed around by value.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = SobolSeqN::<2>::new();
+/// let [x, y] = seq.gen();
+/// assert_eq!((x, y), (0.5, 0.5));
+/// ```
 #[derive(Clone)]
-pub struct SobolSeq {
+pub struct SobolSeqN<const D: usize> {
+    dirnums: Box<[[u64; D]; MAX_LOG_N]>,
+    value: [u64; D],
+    index: u64,
+}
+
+impl<const D: usize> SobolSeqN<D> {
+    /// Returns a new Sobol sequence generator with dimensionality `D`.
+    ///
+    /// Panics if `D` is greater than 1111 (or 21201 if `sobol-high-dim` feature
+    /// is enabled).
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_direction_numbers(DirectionNumbers::JoeKuo)
+    }
+
+    /// Returns a new Sobol sequence generator with dimensionality `D`, using the
+    /// given set of direction numbers.
+    ///
+    /// Panics if `D` is greater than `dnums.max_dim()`.
+    #[inline]
+    pub fn with_direction_numbers(dnums: DirectionNumbers) -> Self {
+        Self::try_with_direction_numbers(dnums).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as [`with_direction_numbers`](#method.with_direction_numbers), but returns
+    /// an error instead of panicking.
+    pub fn try_with_direction_numbers(dnums: DirectionNumbers) -> Result<Self, QrngError> {
+        let mut seq = SobolSeq::try_with_direction_numbers(D, dnums)?;
+        seq.expand_all();
+        // allocate the table directly on the heap rather than moving it there
+        let mut dirnums =
+            Box::<[[u64; D]; MAX_LOG_N]>::try_from(vec![[0; D]; MAX_LOG_N].into_boxed_slice())
+                .unwrap();
+        for (k, v) in dirnums.iter_mut().enumerate() {
+            v.copy_from_slice(&seq.dirnums[k * D..(k + 1) * D]);
+        }
+        Ok(Self { dirnums, value: [0; D], index: 0 })
+    }
+
+    /// Generates the next point of the sequence.
+    #[inline]
+    pub fn gen(&mut self) -> [f64; D] {
+        const MAX_N: u64 = 1u64 << MAX_LOG_N;
+        // the index is below 2^53, so c is always a valid row
+        let c = column(self.index, MAX_LOG_N);
+        let v = unsafe { self.dirnums.get_unchecked(c) };
+        let mut out = [0.; D];
+        for j in 0..D {
+            self.value[j] ^= v[j];
+            out[j] = to_unit_f64(self.value[j]);
+        }
+        self.index = (self.index + 1) % MAX_N;
+        out
+    }
+
+    /// Moves the generator so that the next generated point is the one with the
+    /// given (zero-based) index (see [`SobolSeq::skip_to`](struct.SobolSeq.html#method.skip_to)).
+    pub fn skip_to(&mut self, index: u64) {
+        let index = index % (1u64 << MAX_LOG_N);
+        let gray = index ^ (index >> 1);
+        self.value = [0; D];
+        for k in (0..MAX_LOG_N).filter(|&k| (gray >> k) & 1 != 0) {
+            self.value.iter_mut().zip(&self.dirnums[k]).for_each(|(x, &v)| *x ^= v);
+        }
+        self.index = index;
+    }
+}
+
+impl<const D: usize> Default for SobolSeqN<D> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const D: usize> QRng for SobolSeqN<D> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        D
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.skip_to(0);
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        out.get_unchecked_mut(..D).copy_from_slice(&self.gen());
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.skip_to(self.index.wrapping_add(n));
+    }
+}
+
+impl<const D: usize> SkipTo for SobolSeqN<D> {
+    #[inline]
+    fn skip_to(&mut self, index: u64) {
+        SobolSeqN::skip_to(self, index);
+    }
+}
+
+/// Finite Sobol digital net with `2^m` points.
+///
+/// The `i`-th point of the net is obtained by multiplying the Sobol generating
+/// matrices by the binary digits of `i`, so that the points are in the natural
+/// (rather than the Gray code) order and can be accessed in any order. The first
+/// point is the zero point. Since the Sobol sequence is a (t,s)-sequence in base 2,
+/// the set of points is a (t,m,s)-net, i.e. every elementary interval of volume
+/// `2^(t - m)` contains exactly `2^t` points.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let net = SobolNet::new(3, 4);
+/// assert_eq!(net.len(), 16);
+/// assert_eq!(net.get(3), Some(vec![0.75, 0.25, 0.25]));
+/// ```
+#[derive(Clone)]
+pub struct SobolNet {
     ndim: usize,
+    m: usize,
     dirnums: Vec<u64>,
-    value: Vec<u64>,
-    index: u64,
 }
 
-impl SobolSeq {
-    /// Returns a new Sobol sequence generator with dimensionality `ndim`.
+impl SobolNet {
+    /// Returns a new Sobol net with dimensionality `ndim` containing `2^m` points.
     ///
     /// Panics if `ndim` is greater than 1111 (or 21201 if `sobol-high-dim` feature
-    /// is enabled).
+    /// is enabled), or if `m` is greater than 53 (or 31 on 32-bit targets, where the
+    /// points couldn't be indexed).
     #[inline]
-    pub fn new(ndim: usize) -> Self {
-        let mut dirnums = vec![0; ndim * MAX_LOG_N];
-        for i in 0..ndim {
-            unsafe { get_dirnums(i, &mut dirnums[i..], ndim) };
+    pub fn new(ndim: usize, m: usize) -> Self {
+        Self::with_direction_numbers(ndim, m, DirectionNumbers::JoeKuo)
+    }
+
+    /// Returns a new Sobol net with dimensionality `ndim` containing `2^m` points,
+    /// using the given set of direction numbers.
+    ///
+    /// Panics if `ndim` is greater than `dnums.max_dim()`, or if `m` is greater than 53
+    /// (or 31 on 32-bit targets).
+    pub fn with_direction_numbers(ndim: usize, m: usize, dnums: DirectionNumbers) -> Self {
+        Self::try_with_direction_numbers(ndim, m, dnums).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Same as [`new`](#method.new), but returns an error instead of panicking.
+    #[inline]
+    pub fn try_new(ndim: usize, m: usize) -> Result<Self, QrngError> {
+        Self::try_with_direction_numbers(ndim, m, DirectionNumbers::JoeKuo)
+    }
+
+    /// Same as [`with_direction_numbers`](#method.with_direction_numbers), but returns
+    /// an error instead of panicking.
+    pub fn try_with_direction_numbers(
+        ndim: usize, m: usize, dnums: DirectionNumbers,
+    ) -> Result<Self, QrngError> {
+        if m > MAX_LOG_N || m >= usize::BITS as usize {
+            return Err(QrngError::InvalidParameter {
+                name: "Sobol net size exponent",
+                value: m as _,
+            });
         }
-        Self { ndim, dirnums, value: vec![0; ndim], index: 0 }
+        // only the first m columns are ever used
+        let mut seq = SobolSeq::try_with_direction_numbers(ndim, dnums)?;
+        seq.expand_to(m);
+        let dirnums = seq.dirnums[..m * ndim].to_vec();
+        Ok(Self { ndim, m, dirnums })
+    }
+
+    /// Returns the base-2 logarithm of the number of points in the net.
+    #[inline]
+    pub fn m(&self) -> usize {
+        self.m
     }
 }
 
-impl QRng for SobolSeq {
+impl PointSet for SobolNet {
     #[inline]
     fn ndim(&self) -> usize {
         self.ndim
     }
 
     #[inline]
-    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
-        const MAX_N: u64 = 1u64 << MAX_LOG_N;
-        const DENUM: f64 = MAX_N as f64;
-        let c = (!self.index).trailing_zeros() as usize;
-        let v = self.dirnums.get_unchecked(c * self.ndim..);
+    fn len(&self) -> usize {
+        1 << self.m
+    }
+
+    #[inline]
+    unsafe fn get_fill_unchecked(&self, index: usize, out: &mut [f64]) {
         for j in 0..self.ndim {
-            let x = self.value.get_unchecked_mut(j);
-            *x ^= *v.get_unchecked(j);
-            *out.get_unchecked_mut(j) = (*x as f64) / DENUM;
+            let (mut x, mut i, mut k) = (0, index, 0);
+            while i != 0 {
+                if i & 1 != 0 {
+                    x ^= *self.dirnums.get_unchecked(k * self.ndim + j);
+                }
+                i >>= 1;
+                k += 1;
+            }
+            *out.get_unchecked_mut(j) = to_unit_f64(x);
         }
-        self.index = (self.index + 1) % MAX_N;
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_range_loop, clippy::useless_vec)]
 mod tests {
-    use super::{get_raw_data, SobolSeq};
-    use crate::QRng;
+    use std::sync::Arc;
+
+    use super::{
+        to_unit_f64, xor_fill, DirectionNumberSource, DirectionNumbers, DirectionTable, SobolNet,
+        SobolSeq, SobolSeqN,
+    };
+    use crate::{DigitalQRng, PointSet, QRng, QrngError};
 
     #[test]
     fn test_sobol_seq() {
@@ -149,6 +1514,405 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sobol_seq_from_direction_file() {
+        // the beginning of new-joe-kuo-6.21201
+        let table = "d       s       a       m_i
+2       1       0       1
+3       2       1       1 3
+4       3       1       1 3 1
+5       3       2       1 1 1
+6       4       1       1 1 3 3
+7       4       4       1 3 5 13
+
+8       5       2       1 1 5 5 17
+9       5       4       1 1 5 5 5
+";
+        let mut seq = SobolSeq::from_direction_reader(table.as_bytes(), 9).unwrap();
+        assert_eq!(seq.gen_points(1000), SobolSeq::new(9).gen_points(1000));
+        // there's no filesystem on wasm targets
+        #[cfg(not(target_family = "wasm"))]
+        {
+            let path = std::env::temp_dir().join("qrng-test-joe-kuo.txt");
+            std::fs::write(&path, table).unwrap();
+            let mut seq = SobolSeq::from_direction_file(&path, 5).unwrap();
+            std::fs::remove_file(&path).unwrap();
+            assert_eq!(seq.gen_points(100), SobolSeq::new(5).gen_points(100));
+        }
+        let err = SobolSeq::from_direction_reader(table.as_bytes(), 10).err().unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+        for bad in &["d s a m\n2 1 0 2\n", "d s a m\n2 2 0 1\n", "d s a m\n2 1 x 1\n"] {
+            let err = SobolSeq::from_direction_reader(bad.as_bytes(), 2).err().unwrap();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        }
+        assert!(SobolSeq::from_direction_file("/nonexistent/qrng", 2).is_err());
+    }
+
+    #[test]
+    fn test_sobol_seq_source() {
+        struct Custom(u64);
+        impl DirectionNumberSource for Custom {
+            fn max_dim(&self) -> usize {
+                3
+            }
+            fn initial_numbers(&self, dim: usize) -> (u64, Vec<u64>) {
+                if dim == 1 {
+                    (0, vec![1])
+                } else {
+                    (1, vec![1, self.0])
+                }
+            }
+        }
+        let mut seq = SobolSeq::with_source(3, &Custom(3));
+        assert_eq!(seq.gen_points(100), SobolSeq::new(3).gen_points(100));
+        let mut seq = SobolSeq::with_source(2, &DirectionNumbers::BratleyFox);
+        assert_eq!(seq.gen_points(100), SobolSeq::new(2).gen_points(100));
+        assert!(SobolSeq::try_with_source(4, &Custom(3)).is_err());
+        let err = SobolSeq::try_with_source(3, &Custom(2)).err().unwrap();
+        assert_eq!(err.to_string(), "invalid Sobol initial direction number: 2");
+        assert_eq!(DirectionNumbers::JoeKuo.initial_numbers(6), (4, vec![1, 3, 5, 13]));
+        let table = DirectionTable::new(vec![(0, vec![1]), (1, vec![1, 3])]).unwrap();
+        assert_eq!(DirectionNumberSource::max_dim(&table), 3);
+        assert!(DirectionTable::new(vec![(2, vec![1, 3])]).is_err());
+        assert!(DirectionTable::new(vec![(0, vec![])]).is_err());
+    }
+
+    #[test]
+    fn test_sobol_seq_matrices() {
+        let seq = SobolSeq::new(20);
+        let matrices = SobolSeq::generating_matrices(0..20);
+        for (a, b) in matrices.iter().zip(seq.matrices()) {
+            assert_eq!(a[..], b[..]);
+        }
+        let seq = SobolSeq::new(1000);
+        let matrices = SobolSeq::generating_matrices(0..1000);
+        assert!(matrices.iter().zip(seq.matrices()).all(|(a, b)| a[..] == b[..]));
+        let seq = SobolSeq::builder().ndim(3).bits(10).build();
+        assert!(seq.matrices().iter().all(|m| m.len() == 10));
+        assert!(SobolSeq::generating_matrices(5..5).is_empty());
+    }
+
+    #[test]
+    fn test_sobol_seq_lazy_dirnums() {
+        let mut eager = SobolSeq::new(40);
+        eager.expand_all();
+        let mut lazy = SobolSeq::new(40);
+        assert_eq!(lazy.ncols, 0);
+        let mut x = vec![0.; 40];
+        for _ in 0..5 {
+            lazy.gen_fill(&mut x);
+        }
+        assert_eq!(lazy.ncols, 3);
+        lazy.skip_to(0);
+        let (mut a, mut b) = (eager.clone(), lazy.clone());
+        assert_eq!(a.gen_points(300), b.gen_points(300));
+        assert_eq!(b.ncols, 9);
+        assert_eq!(a.gen_points_col_major(1000), b.gen_points_col_major(1000));
+        let (mut a, mut b) = (eager.clone(), lazy.clone());
+        a.skip_to(123_456);
+        b.skip_to(123_456);
+        assert_eq!(b.ncols, 17);
+        assert_eq!(a.gen_points(10), b.gen_points(10));
+        assert_eq!(lazy.matrices(), eager.matrices());
+        assert!(lazy.polys.len() == 40 && eager.polys.is_empty());
+    }
+
+    #[test]
+    fn test_sobol_seq_clone_shares_dirnums() {
+        let mut seq = SobolSeq::new(100);
+        seq.skip_to(1 << 20);
+        let mut other = seq.clone();
+        assert!(Arc::ptr_eq(&seq.dirnums, &other.dirnums));
+        assert_eq!(seq.gen_points(100), other.gen_points(100));
+        assert!(Arc::ptr_eq(&seq.dirnums, &other.dirnums));
+        // copy on write
+        other.use_natural_order();
+        assert!(!Arc::ptr_eq(&seq.dirnums, &other.dirnums));
+        assert_eq!(seq.matrices(), SobolSeq::new(100).matrices());
+        assert_ne!(seq.matrices(), other.matrices());
+    }
+
+    #[test]
+    fn test_sobol_xor_fill() {
+        let mut x = 0x9e37_79b9_7f4a_7c15u64;
+        let mut values = vec![0, 1, 2, (1 << 32) - 1, 1 << 32, (1 << 52) + 1, (1 << 53) - 1];
+        for _ in 0..1000 {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            values.push(x >> 11);
+        }
+        for &x in &values {
+            assert_eq!(to_unit_f64(x).to_bits(), ((x as f64) / (1u64 << 53) as f64).to_bits());
+        }
+        for ndim in 1..20 {
+            let (mut value, v) = (values[..ndim].to_vec(), &values[100..100 + ndim]);
+            let mut out = vec![0.; ndim];
+            xor_fill(&mut value, v, &mut out);
+            for j in 0..ndim {
+                assert_eq!(value[j], values[j] ^ v[j]);
+                assert_eq!(out[j], to_unit_f64(value[j]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sobol_seq_outputs_exact() {
+        // the outputs are bit-identical to dividing the integer coordinates by 2^53
+        const DENUM: f64 = (1u64 << 53) as f64;
+        let (ndim, n) = (11, 300);
+        let mut digits = SobolSeq::new(ndim);
+        let mut x = vec![0; ndim];
+        let expected: Vec<_> = (0..n)
+            .flat_map(|_| {
+                digits.gen_fill_u64(&mut x);
+                x.iter().map(|&x| ((x >> 11) as f64) / DENUM).collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(SobolSeq::new(ndim).gen_points(n), expected);
+        let col_major = SobolSeq::new(ndim).gen_points_col_major(n);
+        for i in 0..n {
+            for j in 0..ndim {
+                assert_eq!(col_major[j * n + i].to_bits(), expected[i * ndim + j].to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn test_sobol_seq_gen_points_blocks() {
+        for &(ndim, bits) in &[(1, 53), (3, 53), (13, 53), (2, 3), (5, 4)] {
+            let seq = SobolSeq::builder().ndim(ndim).bits(bits).build();
+            for &start in &[0, 1, 5, 8, 13] {
+                let (mut a, mut b) = (seq.clone(), seq.clone());
+                a.skip_to(start);
+                b.skip_to(start);
+                // enough points to wrap around the period of low bit depths
+                let points = a.gen_points(37);
+                let mut x = vec![0.; ndim];
+                for p in points.chunks(ndim) {
+                    b.gen_fill(&mut x);
+                    assert_eq!(p, &x[..]);
+                }
+                assert_eq!(a.index(), b.index());
+            }
+        }
+    }
+
+    #[test]
+    fn test_sobol_seq_skip_to() {
+        let mut seq = SobolSeq::new(5).with_buf();
+        let points: Vec<_> = (0..1000).map(|_| seq.gen().to_vec()).collect();
+        for &i in &[0, 1, 2, 3, 127, 128, 500, 999] {
+            let mut seq = SobolSeq::new(5);
+            seq.skip_to(i as u64);
+            let mut seq = seq.with_buf();
+            assert_eq!(seq.gen(), points[i].as_slice());
+            if i < 999 {
+                assert_eq!(seq.gen(), points[i + 1].as_slice());
+            }
+        }
+    }
+
+    #[test]
+    fn test_sobol_seq_builder() {
+        let seq = SobolSeq::builder().ndim(3).scramble(5).skip(10).build();
+        let mut seq = seq.with_buf();
+        let mut expected = SobolSeq::new(3).linear_scramble(5).with_buf();
+        for _ in 0..10 {
+            expected.gen();
+        }
+        for _ in 0..10 {
+            assert_eq!(seq.gen(), expected.gen());
+        }
+        let seq = SobolSeq::builder().direction_numbers(DirectionNumbers::BratleyFox).build();
+        assert_eq!(seq.ndim(), 1);
+        let err = SobolSeq::builder().ndim(100_000).try_build().err().unwrap();
+        assert!(matches!(err, QrngError::InvalidDimension { ndim: 100_000, .. }));
+        let err = SobolSeq::builder().bits(0).try_build().err().unwrap();
+        assert_eq!(err, QrngError::InvalidParameter { name: "Sobol sequence bit depth", value: 0 });
+        let mut seq = SobolSeq::new_with_skip(3, 256).with_buf();
+        let mut sobol = SobolSeq::new(3).with_buf();
+        sobol.discard(256);
+        assert_eq!(seq.gen(), sobol.gen());
+    }
+
+    #[test]
+    fn test_sobol_seq_bits() {
+        let mut full = SobolSeq::new(4);
+        let mut seq = SobolSeq::builder().ndim(4).bits(32).build();
+        assert_eq!((full.bits(), seq.bits()), (53, 32));
+        let (mut x, mut y) = ([0; 4], [0; 4]);
+        for _ in 0..1000 {
+            full.gen_fill_u64(&mut x);
+            seq.gen_fill_u64(&mut y);
+            for j in 0..4 {
+                assert_eq!(y[j], x[j] & !((1 << 32) - 1));
+            }
+        }
+        // the origin is the last point of the period
+        seq.skip_to((1 << 32) - 1);
+        seq.gen_fill_u64(&mut y);
+        assert_eq!((y, seq.index()), ([0; 4], 0));
+        seq.gen_fill_u64(&mut y);
+        assert_eq!(y, [1 << 63; 4]);
+        let restored = SobolSeq::from_state(seq.state()).unwrap();
+        assert_eq!(restored.bits(), 32);
+        assert!(SobolSeq::new(1).truncate_bits(54).is_err());
+        assert!(SobolSeq::new(1).truncate_bits(0).is_err());
+    }
+
+    #[test]
+    fn test_sobol_seq_natural_order() {
+        let mut seq = SobolSeq::builder().ndim(4).bits(20).natural_order(true).build();
+        let matrices = SobolSeq::new(4).matrices();
+        let mut gray = SobolSeq::new(4).with_buf();
+        let mut x = [0; 4];
+        let (mut a, mut b) = (Vec::new(), Vec::new());
+        for i in 1..1024u64 {
+            seq.gen_fill_u64(&mut x);
+            for (j, m) in matrices.iter().enumerate() {
+                let expected = (0..20).filter(|k| (i >> k) & 1 != 0).fold(0, |y, k| y ^ m[k]);
+                assert_eq!(x[j], (expected & !((1 << 33) - 1)) << 11);
+            }
+            a.push(x.to_vec());
+            b.push(gray.gen().iter().map(|&y| (y * 2f64.powi(64)) as u64).collect::<Vec<_>>());
+        }
+        a.sort();
+        b.sort();
+        assert_eq!(a, b);
+        seq.skip_to(99);
+        seq.gen_fill_u64(&mut x);
+        assert_eq!(x[0], (matrices[0][2] ^ matrices[0][5] ^ matrices[0][6]) << 11);
+    }
+
+    #[test]
+    fn test_sobol_seq_try_new() {
+        let max_dim = DirectionNumbers::BratleyFox.max_dim();
+        assert!(SobolSeq::try_with_direction_numbers(max_dim, DirectionNumbers::BratleyFox).is_ok());
+        let err = SobolSeq::try_with_direction_numbers(max_dim + 1, DirectionNumbers::BratleyFox);
+        assert_eq!(
+            err.err(),
+            Some(QrngError::InvalidDimension {
+                kind: "Sobol sequence",
+                ndim: max_dim + 1,
+                max_dim
+            })
+        );
+        assert!(SobolNet::try_new(3, 54).is_err());
+        let max_m = super::MAX_LOG_N.min(usize::BITS as usize - 1);
+        assert!(SobolNet::try_new(3, max_m).is_ok());
+        assert!(SobolNet::try_new(3, max_m + 1).is_err());
+    }
+
+    #[test]
+    fn test_sobol_seq_ints() {
+        let mut seq = SobolSeq::new(5).with_buf();
+        let (mut a, mut b) = (SobolSeq::new(5), SobolSeq::new(5));
+        let (mut x, mut y) = ([0; 5], [0; 5]);
+        for _ in 0..1000 {
+            a.gen_fill_u64(&mut x);
+            b.gen_fill_u32(&mut y);
+            for (j, &z) in seq.gen().iter().enumerate() {
+                assert_eq!(x[j] as f64, z * 2f64.powi(64));
+                assert_eq!(y[j], (x[j] >> 32) as u32);
+            }
+        }
+        assert_eq!(a.index(), 1000);
+        assert_eq!(b.index(), 1000);
+    }
+
+    #[test]
+    fn test_compact_sobol_seq() {
+        let seq = SobolSeq::builder().ndim(7).bits(32).skip(1000).build();
+        let mut compact = seq.clone().into_compact().unwrap();
+        assert_eq!((compact.ndim(), compact.bits(), compact.index()), (7, 32, 1000));
+        assert_eq!(compact.dirnums.len(), 7 * 32);
+        let (mut a, mut b) = (seq.clone(), compact.clone());
+        assert_eq!(a.gen_points(500), b.gen_points(500));
+        let (mut x, mut y) = ([0; 7], [0; 7]);
+        a.gen_fill_u64(&mut x);
+        unsafe { b.gen_fill_digits_unchecked(&mut y) };
+        assert_eq!(x, y);
+        let (mut x, mut y) = ([0f32; 7], [0f32; 7]);
+        a.gen_fill_f32(&mut x);
+        b.gen_fill_f32(&mut y);
+        assert_eq!(x, y);
+        compact.skip_to((1 << 32) - 3);
+        let mut seq = seq;
+        seq.skip_to((1 << 32) - 3);
+        // wraps around the period
+        assert_eq!(compact.gen_points(5), seq.gen_points(5));
+        assert_eq!(compact.index(), 2);
+        let mut scrambled = SobolSeq::builder().ndim(3).bits(20).scramble(1).build();
+        let mut compact = scrambled.clone().into_compact().unwrap();
+        let (mut x, mut y) = ([0; 3], [0; 3]);
+        for _ in 0..100 {
+            scrambled.gen_fill_u64(&mut x);
+            unsafe { compact.gen_fill_digits_unchecked(&mut y) };
+            assert!(x.iter().zip(&y).all(|(x, y)| x >> 32 << 32 == *y));
+        }
+        assert!(SobolSeq::new(2).into_compact().is_err());
+    }
+
+    #[test]
+    fn test_sobol_seq_n() {
+        let mut seq = SobolSeq::new(5).with_buf();
+        let mut fixed = SobolSeqN::<5>::new();
+        for _ in 0..1000 {
+            assert_eq!(fixed.gen(), seq.gen());
+        }
+        fixed.discard(123);
+        seq.discard(123);
+        assert_eq!(fixed.index(), seq.index());
+        assert_eq!(fixed.gen(), seq.gen());
+    }
+
+    #[test]
+    fn test_sobol_seq_n_stack() {
+        // the direction numbers for 1111 dimensions take over 450 KiB
+        let thread = std::thread::Builder::new().stack_size(256 * 1024);
+        let handle = thread.spawn(|| SobolSeqN::<1111>::new().gen()[1110]).unwrap();
+        assert_eq!(handle.join().unwrap(), 0.5);
+    }
+
+    #[test]
+    fn test_sobol_seq_state() {
+        let mut seq = SobolSeq::new(4).linear_scramble(3);
+        seq.discard(777);
+        let restored = SobolSeq::from_state(seq.state()).unwrap();
+        assert_eq!(restored.state(), seq.state());
+        let (mut a, mut b) = (restored.with_buf(), seq.with_buf());
+        for _ in 0..100 {
+            assert_eq!(a.gen(), b.gen());
+        }
+        let mut state = SobolSeq::new(2).state();
+        state.value.pop();
+        assert!(SobolSeq::from_state(state).is_err());
+        let mut state = SobolSeq::new(2).state();
+        state.value[1] = 1 << 53;
+        assert!(SobolSeq::from_state(state).is_err());
+        let mut state = SobolSeq::new(2).state();
+        state.dirnums[0] |= 1 << 60;
+        assert!(SobolSeq::from_state(state).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_sobol_seq_serde() {
+        let mut seq = SobolSeq::new(3).with_buf();
+        seq.discard(100);
+        let json = serde_json::to_string(&seq).unwrap();
+        let mut restored: crate::QRngWithBuf<SobolSeq> = serde_json::from_str(&json).unwrap();
+        for _ in 0..10 {
+            assert_eq!(restored.gen(), seq.gen());
+        }
+        let json = json.replace("\"version\":3", "\"version\":2");
+        assert!(serde_json::from_str::<SobolSeq>(&json).is_err());
+        let mut value = serde_json::to_value(SobolSeq::new(2)).unwrap();
+        value["value"][0] = serde_json::Value::from(u64::MAX);
+        assert!(serde_json::from_value::<SobolSeq>(value).is_err());
+    }
+
     #[test]
     fn test_sobol_seq_mean() {
         const LEN: usize = 100_000;
@@ -166,60 +1930,46 @@ mod tests {
     }
 
     #[test]
-    fn test_raw_data() {
-        assert_eq!(get_raw_data(0), (0, vec![].as_slice()));
-        assert_eq!(get_raw_data(1), (1, vec![1].as_slice()));
-        assert_eq!(get_raw_data(2), (1, vec![1, 0].as_slice()));
-        assert_eq!(get_raw_data(3), (2, vec![0, 0].as_slice()));
-        assert_eq!(get_raw_data(4), (1, vec![0, 1, 1].as_slice()));
-        assert_eq!(get_raw_data(5), (4, vec![1, 2, 6].as_slice()));
-        assert_eq!(get_raw_data(6), (2, vec![0, 2, 2, 8].as_slice()));
-        assert_eq!(get_raw_data(7), (4, vec![0, 2, 2, 2].as_slice()));
-        assert_eq!(get_raw_data(100), (4, vec![1, 0, 7, 2, 2, 18, 113, 111, 229].as_slice()));
-        assert_eq!(
-            get_raw_data(1108),
-            (4091, vec![1, 0, 1, 13, 1, 1, 86, 195, 106, 401, 1640, 1603].as_slice())
-        );
-        assert_eq!(
-            get_raw_data(1109),
-            (4094, vec![0, 2, 7, 9, 0, 3, 105, 78, 301, 201, 693, 791].as_slice())
-        );
+    fn test_sobol_seq_projections() {
+        // each one-dimensional projection of the first 2^k points is the full grid
+        for &dnums in &[DirectionNumbers::JoeKuo, DirectionNumbers::BratleyFox] {
+            let mut seq = SobolSeq::with_direction_numbers(40, dnums).with_buf();
+            let mut seen = vec![[false; 1024]; 40];
+            for _ in 0..1023 {
+                for (j, &x) in seq.gen().iter().enumerate() {
+                    seen[j][(x * 1024.) as usize] = true;
+                }
+            }
+            assert!(seen.iter().all(|s| s[1..].iter().all(|&x| x)));
+        }
     }
 
-    #[cfg(feature = "sobol-high-dim")]
-    fn test_raw_data_high_dim() {
-        assert_eq!(
-            get_raw_data(1110),
-            (21, vec![1, 2, 6, 8, 26, 62, 6, 169, 361, 260, 206, 2900, 5225].as_slice())
-        );
-        assert_eq!(
-            get_raw_data(21198),
-            (
-                131020,
-                vec![
-                    0, 2, 0, 9, 0, 41, 1, 212, 436, 971, 1967, 2128, 7293, 5914, 27608, 10981,
-                    19841
-                ]
-                .as_slice()
-            )
-        );
-        assert_eq!(
-            get_raw_data(21199),
-            (
-                131059,
-                vec![
-                    0, 3, 5, 7, 3, 18, 119, 168, 122, 778, 1840, 3678, 4819, 13683, 13434, 57301,
-                    43158
-                ]
-                .as_slice()
-            )
-        );
+    #[test]
+    fn test_sobol_net() {
+        // the net contains the same points as the first 2^m points of the sequence
+        let net = SobolNet::new(10, 8);
+        assert_eq!(net.m(), 8);
+        assert_eq!(net.len(), 256);
+        let mut points: Vec<_> = net.iter().collect();
+        assert_eq!(points[0], vec![0.; 10]);
+        let mut seq = SobolSeq::new(10).with_buf();
+        let mut expected: Vec<_> = (0..255).map(|_| seq.gen().to_vec()).collect();
+        expected.push(vec![0.; 10]);
+        points.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(points, expected);
+        assert!(net.get(256).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sobol_net_panic() {
+        SobolNet::new(2, 54);
     }
 
     #[test]
     #[should_panic]
-    fn test_raw_data_panic() {
-        let index = if cfg!(feature = "sobol-high-dim") { 21200 } else { 1110 };
-        get_raw_data(index);
+    fn test_raw_data_bratley_fox_panic() {
+        SobolSeq::with_direction_numbers(41, DirectionNumbers::BratleyFox);
     }
 }
@@ -0,0 +1,283 @@
+//! Allocation-free parts of the Sobol sequence generators: the embedded tables of
+//! direction numbers and the helpers shared by `SobolSeq` and `StaticSobolSeq`.
+//!
+//! Unlike the rest of the crate, this module doesn't depend on `std`.
+
+/// Table of primitive polynomials and initial direction numbers embedded into the
+/// binary in packed form.
+///
+/// `locs` and `coef` are arrays of little-endian `u32` values: the bit offsets of
+/// the data of each dimension in `dnum` (plus the end offset), and the inner
+/// coefficients of the polynomials. The initial direction numbers of a dimension
+/// are stored as `(m_k - 1) / 2` for `k >= 2` (`m_1` is always 1), packed into
+/// `dnum` least significant bit first, with `k - 1` bits for the `k`-th value since
+/// `m_k < 2^k`. This takes a fraction of the space of plain integer arrays, and
+/// `include_bytes!` is much cheaper to compile than large array literals.
+pub(crate) struct PackedTable {
+    max_dim: usize,
+    locs: &'static [u8],
+    coef: &'static [u8],
+    dnum: &'static [u8],
+}
+
+impl PackedTable {
+    #[inline]
+    fn read_u32(bytes: &[u8], index: usize) -> u32 {
+        let mut buf = [0; 4];
+        buf.copy_from_slice(&bytes[index * 4..(index + 1) * 4]);
+        u32::from_le_bytes(buf)
+    }
+
+    /// Same as [`raw_data_into`](#method.raw_data_into), but returns the initial
+    /// direction numbers in a vector.
+    #[cfg(test)]
+    fn raw_data(&self, index: usize) -> (u64, Vec<u64>) {
+        let mut m = [0; MAX_LOG_N];
+        let (coef, count) = self.raw_data_into(index, &mut m);
+        (coef, m[..count].to_vec())
+    }
+
+    /// Returns the coefficients of the polynomial for the dimension `index + 1`,
+    /// writing its packed initial direction numbers (`(m_k - 1) / 2` for `k >= 2`)
+    /// to `m` and returning their count.
+    pub(crate) fn raw_data_into(&self, index: usize, m: &mut [u64]) -> (u64, usize) {
+        if index + 2 > self.max_dim {
+            panic!("invalid Sobol sequence dimension: {}", index + 2);
+        }
+        let start = Self::read_u32(self.locs, index) as usize;
+        let end = Self::read_u32(self.locs, index + 1) as usize;
+        let (mut count, mut pos) = (0, start);
+        while pos < end {
+            let width = count + 1;
+            m[count] = (0..width).fold(0, |x, b| {
+                let bit = (self.dnum[(pos + b) / 8] >> ((pos + b) % 8)) & 1;
+                x | (u64::from(bit) << b)
+            });
+            count += 1;
+            pos += width;
+        }
+        (u64::from(Self::read_u32(self.coef, index)), count)
+    }
+}
+
+#[cfg(not(feature = "sobol-high-dim"))]
+const JOE_KUO: PackedTable = PackedTable {
+    max_dim: 1111,
+    locs: include_bytes!("assets/sobol-lim-locs.bin"),
+    coef: include_bytes!("assets/sobol-lim-coef.bin"),
+    dnum: include_bytes!("assets/sobol-lim-dnum.bin"),
+};
+#[cfg(feature = "sobol-high-dim")]
+const JOE_KUO: PackedTable = PackedTable {
+    max_dim: 21201,
+    locs: include_bytes!("assets/sobol-all-locs.bin"),
+    coef: include_bytes!("assets/sobol-all-coef.bin"),
+    dnum: include_bytes!("assets/sobol-all-dnum.bin"),
+};
+
+const BRATLEY_FOX: PackedTable = PackedTable {
+    max_dim: 40,
+    locs: include_bytes!("assets/sobol-bf-locs.bin"),
+    coef: include_bytes!("assets/sobol-bf-coef.bin"),
+    dnum: include_bytes!("assets/sobol-bf-dnum.bin"),
+};
+
+/// Number of bits of the direction numbers (and the maximum period is `2^MAX_LOG_N`).
+///
+/// 53 bits is the full resolution of `f64` values in `[0.5, 1)`, so the points are
+/// converted to floats exactly.
+pub(crate) const MAX_LOG_N: usize = 53;
+
+/// Published sets of direction numbers for the Sobol sequence.
+///
+/// All sets share the first dimension (the van der Corput sequence in base 2),
+/// but differ in the primitive polynomials and initial direction numbers used for
+/// the remaining ones.
+///
+/// Only the Joe–Kuo and Bratley–Fox sets are embedded so far; other published sets
+/// (the original Sobol–Levitan numbers and their extension from Lemieux's RandQMC
+/// library) are planned as additional variants, so the enum is non-exhaustive.
+/// Tables not embedded in the crate can be loaded at runtime via
+/// [`DirectionTable`](struct.DirectionTable.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DirectionNumbers {
+    /// "Constructing Sobol Sequences with Better Two-Dimensional Projections" (Joe
+    /// and Kuo, 2008), the `new-joe-kuo-6.21201` set.
+    ///
+    /// Supports up to 1111 dimensions (or 21201 if `sobol-high-dim` feature is
+    /// enabled). This is the default.
+    JoeKuo,
+    /// "Algorithm 659: Implementing Sobol's Quasirandom Sequence Generator"
+    /// (Bratley and Fox, 1988), as used in ACM TOMS 659 and the codes derived from it.
+    ///
+    /// Supports up to 40 dimensions.
+    BratleyFox,
+}
+
+impl Default for DirectionNumbers {
+    #[inline]
+    fn default() -> Self {
+        DirectionNumbers::JoeKuo
+    }
+}
+
+impl DirectionNumbers {
+    /// Returns the maximum dimensionality supported by this set of direction numbers.
+    #[inline]
+    pub fn max_dim(self) -> usize {
+        self.table().max_dim
+    }
+
+    #[inline]
+    pub(crate) fn table(self) -> &'static PackedTable {
+        match self {
+            DirectionNumbers::JoeKuo => &JOE_KUO,
+            DirectionNumbers::BratleyFox => &BRATLEY_FOX,
+        }
+    }
+}
+
+/// Returns the `k`-th direction number of a dimension given its primitive polynomial,
+/// its initial direction numbers and the preceding direction numbers `v(i)`.
+///
+/// The direction numbers past the initial ones are obtained via the recurrence
+/// defined by the polynomial; the first dimension (with no initial direction
+/// numbers) is the van der Corput sequence.
+#[inline]
+pub(crate) fn next_dirnum(a: u64, m: &[u64], k: usize, v: impl Fn(usize) -> u64) -> u64 {
+    const MAX: usize = MAX_LOG_N - 1;
+    let s = m.len();
+    if s == 0 {
+        return 1 << (MAX - k);
+    } else if k < s {
+        return m[k] << (MAX - k);
+    }
+    let mut x = v(k - s) ^ (v(k - s) >> s);
+    for i in 1..s {
+        x ^= ((a >> (s - 1 - i)) & 1) * v(k - i);
+    }
+    x
+}
+
+/// Returns all direction numbers of the given dimension of an embedded table,
+/// without allocating.
+///
+/// Panics if the dimension is not less than `dnums.max_dim()`.
+#[cfg(feature = "heapless")]
+pub(crate) fn embedded_dirnums(dnums: DirectionNumbers, dim: usize) -> [u64; MAX_LOG_N] {
+    // the embedded tables omit m_1 = 1 and store the other values as (m_k - 1) / 2
+    let mut m = [1; MAX_LOG_N];
+    let (a, s) = if dim == 0 {
+        (0, 0)
+    } else {
+        let (a, count) = dnums.table().raw_data_into(dim - 1, &mut m[1..]);
+        m[1..=count].iter_mut().for_each(|m| *m = *m * 2 + 1);
+        (a, count + 1)
+    };
+    let mut v = [0; MAX_LOG_N];
+    for k in 0..MAX_LOG_N {
+        v[k] = next_dirnum(a, &m[..s], k, |i| v[i]);
+    }
+    v
+}
+
+/// Returns the index of the direction number to be applied when moving from point
+/// `index` to the next one in Gray code order.
+///
+/// The last point of the period (with all of the `bits` lowest bits set) has the
+/// Gray code `2^(bits - 1)`, so applying the last direction number wraps the
+/// sequence around to zero.
+#[inline]
+pub(crate) fn column(index: u64, bits: usize) -> usize {
+    ((!index).trailing_zeros() as usize).min(bits - 1)
+}
+
+/// Converts an integer below `2^53` to the float `x / 2^53` exactly.
+///
+/// Unlike `as` casts (which have no SIMD counterparts for 64-bit integers on most
+/// instruction sets), this only uses bitwise operations and floating-point
+/// arithmetic: each of the 32-bit halves is placed into the mantissa of `2^52`, and
+/// the results are exact since all of the intermediate values fit into 53 bits.
+#[inline(always)]
+pub(crate) fn to_unit_f64(x: u64) -> f64 {
+    const MAGIC: f64 = (1u64 << 52) as f64;
+    const SCALE: f64 = 1. / (1u64 << MAX_LOG_N) as f64;
+    let hi = f64::from_bits(MAGIC.to_bits() | (x >> 32)) - MAGIC;
+    let lo = f64::from_bits(MAGIC.to_bits() | (x & 0xffff_ffff)) - MAGIC;
+    (hi * 4294967296. + lo) * SCALE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DirectionNumbers, JOE_KUO};
+
+    #[test]
+    fn test_raw_data_bratley_fox() {
+        assert_eq!(DirectionNumbers::BratleyFox.max_dim(), 40);
+        let bf = DirectionNumbers::BratleyFox.table();
+        assert_eq!(bf.raw_data(0), (0, vec![]));
+        assert_eq!(bf.raw_data(1), (1, vec![0]));
+        assert_eq!(bf.raw_data(2), (1, vec![1, 3]));
+        assert_eq!(bf.raw_data(6), (2, vec![1, 1, 4, 4]));
+        assert_eq!(bf.raw_data(38), (21, vec![1, 1, 4, 4, 12, 53, 19]));
+        assert_eq!(bf.raw_data(0), JOE_KUO.raw_data(0));
+    }
+
+    #[test]
+    fn test_raw_data() {
+        assert_eq!(JOE_KUO.raw_data(0), (0, vec![]));
+        assert_eq!(JOE_KUO.raw_data(1), (1, vec![1]));
+        assert_eq!(JOE_KUO.raw_data(2), (1, vec![1, 0]));
+        assert_eq!(JOE_KUO.raw_data(3), (2, vec![0, 0]));
+        assert_eq!(JOE_KUO.raw_data(4), (1, vec![0, 1, 1]));
+        assert_eq!(JOE_KUO.raw_data(5), (4, vec![1, 2, 6]));
+        assert_eq!(JOE_KUO.raw_data(6), (2, vec![0, 2, 2, 8]));
+        assert_eq!(JOE_KUO.raw_data(7), (4, vec![0, 2, 2, 2]));
+        assert_eq!(JOE_KUO.raw_data(100), (4, vec![1, 0, 7, 2, 2, 18, 113, 111, 229]));
+        assert_eq!(
+            JOE_KUO.raw_data(1108),
+            (4091, vec![1, 0, 1, 13, 1, 1, 86, 195, 106, 401, 1640, 1603])
+        );
+        assert_eq!(
+            JOE_KUO.raw_data(1109),
+            (4094, vec![0, 2, 7, 9, 0, 3, 105, 78, 301, 201, 693, 791])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "sobol-high-dim")]
+    fn test_raw_data_high_dim() {
+        assert_eq!(
+            JOE_KUO.raw_data(1110),
+            (21, vec![1, 2, 6, 8, 26, 62, 6, 169, 361, 260, 206, 2900, 5225])
+        );
+        assert_eq!(
+            JOE_KUO.raw_data(21198),
+            (
+                131020,
+                vec![
+                    0, 2, 0, 9, 0, 41, 1, 212, 436, 971, 1967, 2128, 7293, 5914, 27608, 10981,
+                    19841
+                ]
+            )
+        );
+        assert_eq!(
+            JOE_KUO.raw_data(21199),
+            (
+                131059,
+                vec![
+                    0, 3, 5, 7, 3, 18, 119, 168, 122, 778, 1840, 3678, 4819, 13683, 13434, 57301,
+                    43158
+                ]
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_raw_data_panic() {
+        let index = if cfg!(feature = "sobol-high-dim") { 21200 } else { 1110 };
+        JOE_KUO.raw_data(index);
+    }
+}
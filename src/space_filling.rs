@@ -0,0 +1,163 @@
+use crate::discrepancy::check_points;
+
+/// Returns the Euclidean distances between all pairs of points.
+fn pairwise_distances(points: &[f64], ndim: usize) -> impl Iterator<Item = f64> + '_ {
+    points.chunks_exact(ndim).enumerate().flat_map(move |(i, x)| {
+        points
+            .chunks_exact(ndim)
+            .take(i)
+            .map(move |y| x.iter().zip(y).map(|(x, y)| (x - y) * (x - y)).sum::<f64>().sqrt())
+    })
+}
+
+/// Returns the smallest Euclidean distance between two distinct points of the point
+/// set.
+///
+/// The points are given in row-major order, i.e. each `ndim` consecutive values
+/// form a point. A maximin design is the one maximizing this distance, i.e. one
+/// with no two points too close to each other. The computation takes
+/// `O(n^2 ndim)` time.
+///
+/// Panics if `ndim` is zero, or if the number of values is not a positive multiple
+/// of `ndim` or there is only one point.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let sobol = SobolSeq::new(2).gen_points(64);
+/// let lhs: Vec<_> = LatinHypercube::new(2, 64, 0).iter().flatten().collect();
+/// assert!(min_distance(&sobol, 2) > min_distance(&lhs, 2));
+/// ```
+pub fn min_distance(points: &[f64], ndim: usize) -> f64 {
+    let n = check_points(points, ndim);
+    if n < 2 {
+        panic!("invalid number of points: {}", n);
+    }
+    pairwise_distances(points, ndim).fold(f64::INFINITY, f64::min)
+}
+
+/// Returns the Morris–Mitchell maximin criterion `phi_p` of the point set.
+///
+/// The criterion is `(sum d_ij^(-p))^(1/p)` over all pairs of distinct points, where
+/// `d_ij` is the Euclidean distance between the points ("Exploratory designs for
+/// computational experiments", Morris and Mitchell, 1995); lower values correspond
+/// to better space-filling designs. As `p` grows, ranking designs by this criterion
+/// becomes equivalent to ranking them by the minimum distance and the number of
+/// pairs at that distance; `p = 50` is a common choice. The computation takes
+/// `O(n^2 ndim)` time.
+///
+/// The points are given in row-major order, i.e. each `ndim` consecutive values
+/// form a point.
+///
+/// Panics if `ndim` is zero, if the number of values is not a positive multiple of
+/// `ndim` or there is only one point, or if `p` is not positive.
+pub fn maximin_criterion(points: &[f64], ndim: usize, p: f64) -> f64 {
+    let n = check_points(points, ndim);
+    if n < 2 {
+        panic!("invalid number of points: {}", n);
+    }
+    if p <= 0. || p.is_nan() {
+        panic!("invalid maximin exponent: {}", p);
+    }
+    // scale by the minimum distance to avoid overflow for large exponents
+    let d_min = min_distance(points, ndim);
+    if d_min == 0. {
+        return f64::INFINITY;
+    }
+    let sum: f64 = pairwise_distances(points, ndim).map(|d| (d_min / d).powf(p)).sum();
+    sum.powf(1. / p) / d_min
+}
+
+/// Returns the sample correlation matrix of the coordinates of the point set, as an
+/// `ndim x ndim` row-major matrix.
+///
+/// The points are given in row-major order, i.e. each `ndim` consecutive values
+/// form a point. Designs with smaller off-diagonal correlations allow estimating
+/// the effects of the inputs independently of each other. Constant coordinates
+/// have undefined correlations, which are returned as NaN.
+///
+/// Panics if `ndim` is zero, or if the number of values is zero or not a multiple
+/// of `ndim`.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let points = SobolSeq::new(3).gen_points(1024);
+/// let corr = correlation_matrix(&points, 3);
+/// assert_eq!(corr[4], 1.);
+/// assert!(corr[1].abs() < 0.01);
+/// ```
+pub fn correlation_matrix(points: &[f64], ndim: usize) -> Vec<f64> {
+    let n = check_points(points, ndim) as f64;
+    let mut mean = vec![0.; ndim];
+    for x in points.chunks_exact(ndim) {
+        mean.iter_mut().zip(x).for_each(|(m, x)| *m += x / n);
+    }
+    let mut cov = vec![0.; ndim * ndim];
+    let mut d = vec![0.; ndim];
+    for x in points.chunks_exact(ndim) {
+        d.iter_mut().zip(x).zip(&mean).for_each(|((d, x), m)| *d = x - m);
+        for (i, row) in cov.chunks_exact_mut(ndim).enumerate() {
+            row.iter_mut().zip(&d).for_each(|(c, d_j)| *c += d[i] * d_j);
+        }
+    }
+    let std: Vec<_> = (0..ndim).map(|i| cov[i * ndim + i].sqrt()).collect();
+    for (i, row) in cov.chunks_exact_mut(ndim).enumerate() {
+        row.iter_mut().zip(&std).for_each(|(c, s)| *c /= std[i] * s);
+        row[i] = if std[i] > 0. { 1. } else { f64::NAN };
+    }
+    cov
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{correlation_matrix, maximin_criterion, min_distance};
+    use crate::{LatinHypercube, PointSet, QRng, SobolSeq};
+
+    #[test]
+    fn test_min_distance() {
+        let corners = [0., 0., 0., 1., 1., 0., 1., 1., 0.5, 0.5];
+        assert!((min_distance(&corners, 2) - 0.5f64.sqrt()).abs() < 1e-15);
+        assert!((min_distance(&corners[..8], 2) - 1.).abs() < 1e-15);
+        assert_eq!(min_distance(&[0.25, 0.5, 0.25], 1), 0.);
+    }
+
+    #[test]
+    fn test_maximin_criterion() {
+        // four pairs at distance sqrt(1/2), four at distance 1 and two at sqrt(2)
+        let corners = [0., 0., 0., 1., 1., 0., 1., 1., 0.5, 0.5];
+        let expected = (4. * 2. + 4. + 2. * 0.5f64).sqrt();
+        assert!((maximin_criterion(&corners, 2, 2.) - expected).abs() < 1e-12);
+        // the criterion approaches the reciprocal of the minimum distance
+        let phi = maximin_criterion(&corners, 2, 1000.);
+        assert!((phi - 2f64.sqrt()).abs() < 1e-2);
+        assert!(maximin_criterion(&corners, 2, 1e4).is_finite());
+        assert_eq!(maximin_criterion(&[0.25, 0.5, 0.25], 1, 2.), f64::INFINITY);
+        // a Sobol net is a better design than a random Latin hypercube
+        let sobol = SobolSeq::new(3).gen_points(256);
+        let lhs: Vec<_> = LatinHypercube::new(3, 256, 1).iter().flatten().collect();
+        assert!(maximin_criterion(&sobol, 3, 50.) < maximin_criterion(&lhs, 3, 50.));
+    }
+
+    #[test]
+    fn test_correlation_matrix() {
+        let points = [0., 0., 1., 0.5, 0.5, 0.5, 1., 1., 0.];
+        let corr = correlation_matrix(&points, 3);
+        assert_eq!(corr.len(), 9);
+        let expected = [1., 1., -1., 1., 1., -1., -1., -1., 1.];
+        for (c, e) in corr.iter().zip(&expected) {
+            assert!((c - e).abs() < 1e-12);
+        }
+        let corr = correlation_matrix(&[0.5, 0.1, 0.5, 0.9], 2);
+        assert!(corr[0].is_nan() && corr[1].is_nan());
+        assert_eq!(corr[3], 1.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_min_distance_panic() {
+        min_distance(&[0.5, 0.5], 2);
+    }
+}
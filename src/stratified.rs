@@ -0,0 +1,110 @@
+use rand::Rng;
+
+use crate::traits::PointSet;
+
+/// Stratified jittered sampling finite point set.
+///
+/// The unit hypercube is split into a regular grid of strata, and each stratum
+/// contains exactly one point placed uniformly at random within it. This is the
+/// classical stratified sampling scheme and is mostly useful as a baseline to
+/// compare quasi-Monte Carlo point sets against, since the number of points grows
+/// exponentially with the dimensionality.
+///
+/// Points are ordered lexicographically by their strata, with the last coordinate
+/// changing the fastest.
+///
+/// This type is only available if the `rand` feature is enabled.
+#[derive(Clone)]
+pub struct StratifiedSet {
+    ndim: usize,
+    len: usize,
+    points: Vec<f64>,
+}
+
+impl StratifiedSet {
+    /// Returns a new stratified point set with dimensionality `ndim` and `k` strata
+    /// along each axis (`k^ndim` points in total), jittered with the given RNG.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let set = StratifiedSet::new(2, 4, &mut StdRng::seed_from_u64(42));
+    /// assert_eq!(set.len(), 16);
+    /// ```
+    #[inline]
+    pub fn new<R: Rng + ?Sized>(ndim: usize, k: usize, rng: &mut R) -> Self {
+        Self::with_strata(&vec![k; ndim], rng)
+    }
+
+    /// Returns a new stratified point set with `strata[j]` strata along the `j`-th
+    /// axis, jittered with the given RNG.
+    ///
+    /// The dimensionality of the point set is equal to the length of `strata`.
+    ///
+    /// Panics if the total number of points overflows `usize`.
+    pub fn with_strata<R: Rng + ?Sized>(strata: &[usize], rng: &mut R) -> Self {
+        let ndim = strata.len();
+        let len = strata
+            .iter()
+            .try_fold(1usize, |n, &k| n.checked_mul(k))
+            .expect("invalid stratified point set size");
+        let mut points = vec![0.; len * ndim];
+        for (i, x) in points.chunks_mut(ndim.max(1)).enumerate().take(len) {
+            let mut index = i;
+            for j in (0..ndim).rev() {
+                let k = strata[j];
+                x[j] = ((index % k) as f64 + rng.gen::<f64>()) / (k as f64);
+                index /= k;
+            }
+        }
+        Self { ndim, len, points }
+    }
+}
+
+impl PointSet for StratifiedSet {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    unsafe fn get_fill_unchecked(&self, index: usize, out: &mut [f64]) {
+        let point = self.points.get_unchecked(index * self.ndim..(index + 1) * self.ndim);
+        out.get_unchecked_mut(..self.ndim).copy_from_slice(point);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::StratifiedSet;
+    use crate::PointSet;
+
+    #[test]
+    fn test_stratified_set() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let set = StratifiedSet::with_strata(&[2, 3, 4], &mut rng);
+        assert_eq!(set.ndim(), 3);
+        assert_eq!(set.len(), 24);
+        for (i, x) in set.iter().enumerate() {
+            let cell = [(x[0] * 2.) as usize, (x[1] * 3.) as usize, (x[2] * 4.) as usize];
+            assert_eq!(cell, [i / 12, (i / 4) % 3, i % 4]);
+        }
+    }
+
+    #[test]
+    fn test_stratified_set_empty() {
+        let mut rng = StdRng::seed_from_u64(1);
+        assert!(StratifiedSet::new(2, 0, &mut rng).is_empty());
+        assert_eq!(StratifiedSet::new(0, 5, &mut rng).len(), 1);
+    }
+}
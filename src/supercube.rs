@@ -0,0 +1,137 @@
+use crate::{traits::QRng, util::SplitMix64};
+
+/// Latin supercube sampling (LSS) generator.
+///
+/// The coordinates are split into groups of size equal to the dimensionality of
+/// the base generator; each group receives the first `n` points of the base
+/// generator, with the order of the points randomly permuted independently for
+/// each group ("Latin Supercube Sampling for Very High-Dimensional Simulations",
+/// Owen, 1998). This allows generating point sets with dimensionality far beyond
+/// that supported by the base generator, with one-dimensional projections and
+/// projections onto coordinates within the same group as good as those of the
+/// base generator.
+///
+/// For the resulting estimates to be unbiased, the base generator should be
+/// randomized (e.g. scrambled). After `n` points, the point set repeats itself.
+#[derive(Clone)]
+pub struct LatinSupercube {
+    ndim: usize,
+    group_ndim: usize,
+    n: usize,
+    points: Vec<f64>,
+    perms: Vec<usize>,
+    index: usize,
+}
+
+impl LatinSupercube {
+    /// Returns a new Latin supercube generator with dimensionality `ndim` and `n`
+    /// points, built from the first `n` points of the base generator and randomized
+    /// with the given seed.
+    ///
+    /// Panics if `n` is zero or if the base generator is zero-dimensional.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = LatinSupercube::new(SobolSeq::new(1000), 50_000, 1024, 42).with_buf();
+    /// assert_eq!(seq.gen().len(), 50_000);
+    /// ```
+    pub fn new<R: QRng>(base: R, ndim: usize, n: usize, seed: u64) -> Self {
+        let group_ndim = base.ndim();
+        if group_ndim == 0 {
+            panic!("invalid Latin supercube base dimension: {}", group_ndim);
+        }
+        if n == 0 {
+            panic!("invalid Latin supercube size: {}", n);
+        }
+        let mut base = base;
+        let mut points = vec![0.; n * group_ndim];
+        for x in points.chunks_mut(group_ndim) {
+            unsafe { base.gen_fill_unchecked(x) };
+        }
+        let ngroups = ndim.div_ceil(group_ndim);
+        let mut rng = SplitMix64::new(seed);
+        let mut perms = vec![0; ngroups * n];
+        for perm in perms.chunks_mut(n) {
+            for (i, x) in perm.iter_mut().enumerate() {
+                *x = i;
+            }
+            for i in (1..n).rev() {
+                perm.swap(i, rng.next_below(i as u64 + 1) as usize);
+            }
+        }
+        Self { ndim, group_ndim, n, points, perms, index: 0 }
+    }
+
+    /// Returns the number of points after which the sequence repeats itself.
+    #[inline]
+    pub fn n(&self) -> usize {
+        self.n
+    }
+}
+
+impl QRng for LatinSupercube {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.index = 0;
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.index as u64
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        let (s, n) = (self.group_ndim, self.n);
+        for (g, start) in (0..self.ndim).step_by(s).enumerate() {
+            let k = *self.perms.get_unchecked(g * n + self.index);
+            let m = s.min(self.ndim - start);
+            let src = self.points.get_unchecked(k * s..k * s + m);
+            out.get_unchecked_mut(start..start + m).copy_from_slice(src);
+        }
+        self.index = (self.index + 1) % n;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LatinSupercube;
+    use crate::{QRng, SobolSeq};
+
+    #[test]
+    fn test_latin_supercube() {
+        const N: usize = 64;
+        let seq = LatinSupercube::new(SobolSeq::new(3), 8, N, 1);
+        assert_eq!(seq.n(), N);
+        let mut seq = seq.with_buf();
+        let mut sobol = SobolSeq::new(3).with_buf();
+        let base: Vec<_> = (0..N).map(|_| sobol.gen().to_vec()).collect();
+        let points: Vec<_> = (0..N).map(|_| seq.gen().to_vec()).collect();
+        // each group of coordinates is a permutation of the base points
+        let sorted = |mut x: Vec<Vec<f64>>| {
+            x.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            x
+        };
+        for &(start, end) in &[(0, 3), (3, 6), (6, 8)] {
+            let group = sorted(points.iter().map(|x| x[start..end].to_vec()).collect());
+            let expected = sorted(base.iter().map(|x| x[..end - start].to_vec()).collect());
+            assert_eq!(group, expected);
+        }
+        // the groups are permuted independently and the sequence repeats itself
+        assert!(points.iter().any(|x| x[0] != x[3]));
+        assert_eq!(seq.gen(), points[0].as_slice());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_latin_supercube_panic() {
+        LatinSupercube::new(SobolSeq::new(3), 8, 0, 1);
+    }
+}
@@ -1,4 +1,15 @@
-use crate::with_buf::QRngWithBuf;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+    iter::{PointSetIter, QRngIter},
+    partition::Leapfrog,
+    with_buf::QRngWithBuf,
+};
+
+/// Number of values generated by each task in parallel bulk generation.
+#[cfg(feature = "rayon")]
+const PAR_CHUNK_LEN: usize = 1 << 16;
 
 /// Multi-dimensional quasi-random sequence generator.
 ///
@@ -19,7 +30,9 @@ pub trait QRng: Clone {
     ///
     /// The output values are expected to be floating-point numbers between 0 and 1.
     ///
-    /// Note: this method does **not** perform bound checks and is not meant to be
+    /// # Safety
+    ///
+    /// This method does **not** perform bound checks and is not meant to be
     /// called directly from outside of this crate. If called, it is the user's
     /// responsibility to provide a buffer of length `ndim()` or higher.
     unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]);
@@ -50,6 +63,379 @@ pub trait QRng: Clone {
         }
     }
 
+    /// Writes the next element of the sequence to `out` as `f32` values (with a
+    /// bounds check).
+    ///
+    /// The values are rounded to the nearest `f32`, except that they are kept
+    /// strictly below 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut buf = [0f32; 2];
+    /// SobolSeq::new(2).gen_fill_f32(&mut buf);
+    /// assert_eq!(buf, [0.5, 0.5]);
+    /// ```
+    #[inline]
+    fn gen_fill_f32(&mut self, out: &mut [f32]) {
+        if out.len() < self.ndim() {
+            panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                out.len(),
+                self.ndim()
+            );
+        }
+        unsafe {
+            self.gen_fill_f32_unchecked(out);
+        }
+    }
+
+    /// Writes the next element of the sequence to `out` as `f32` values (no bounds
+    /// checks).
+    ///
+    /// The default implementation generates `f64` values and converts them;
+    /// generators producing fixed-point values override it to convert directly.
+    ///
+    /// # Safety
+    ///
+    /// The buffer must be of length `ndim()` or higher.
+    #[inline]
+    unsafe fn gen_fill_f32_unchecked(&mut self, out: &mut [f32]) {
+        let mut buf = vec![0.; self.ndim()];
+        self.gen_fill_unchecked(&mut buf);
+        for (y, &x) in out.iter_mut().zip(&buf) {
+            *y = f64_to_f32(x);
+        }
+    }
+
+    /// Resets the generator to its initial state, so that the sequence starts over.
+    ///
+    /// This is cheaper than constructing a new generator since the precomputed
+    /// tables (e.g. direction numbers) are reused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = SobolSeq::new(2).with_buf();
+    /// let first = seq.gen().to_vec();
+    /// seq.gen();
+    /// seq.reset();
+    /// assert_eq!(seq.gen(), first.as_slice());
+    /// ```
+    fn reset(&mut self);
+
+    /// Returns the index of the next point of the sequence, i.e. the number of points
+    /// generated since the beginning of the sequence (modulo its period).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = SobolSeq::new(2).with_buf();
+    /// seq.gen();
+    /// seq.gen();
+    /// assert_eq!(seq.index(), 2);
+    /// ```
+    fn index(&self) -> u64;
+
+    /// Skips the next `n` points of the sequence.
+    ///
+    /// The default implementation generates and discards the points; generators
+    /// that are able to jump ahead directly override it with an efficient
+    /// implementation (e.g. `O(log n)` for Sobol and Halton sequences).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = SobolSeq::new(2);
+    /// seq.discard(2);
+    /// assert_eq!(seq.with_buf().gen(), &[0.25, 0.75]);
+    /// ```
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        let mut buf = vec![0.; self.ndim()];
+        for _ in 0..n {
+            unsafe { self.gen_fill_unchecked(&mut buf) };
+        }
+    }
+
+    /// Generates the next `n` points of the sequence and returns them as a flat
+    /// vector of `n * ndim` values in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let points = SobolSeq::new(2).gen_points(3);
+    /// assert_eq!(points, vec![0.5, 0.5, 0.75, 0.25, 0.25, 0.75]);
+    /// ```
+    #[inline]
+    fn gen_points(&mut self, n: usize) -> Vec<f64> {
+        let mut out = vec![0.; n * self.ndim()];
+        self.gen_points_into(n, &mut out);
+        out
+    }
+
+    /// Writes the next `n` points of the sequence to `out` in row-major order (with
+    /// a bounds check).
+    ///
+    /// Panics if the length of `out` is less than `n * ndim`.
+    #[inline]
+    fn gen_points_into(&mut self, n: usize, out: &mut [f64]) {
+        let ndim = self.ndim();
+        if n != 0 && out.len() / n < ndim {
+            panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                out.len(),
+                n.saturating_mul(ndim)
+            );
+        }
+        unsafe { self.gen_points_unchecked(n, out) };
+    }
+
+    /// Fills `out` with as many consecutive points of the sequence as fit in it, in
+    /// row-major order, and returns the number of generated points.
+    ///
+    /// Trailing values that don't form a full point are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut buf = [0.; 7];
+    /// assert_eq!(SobolSeq::new(3).gen_fill_points(&mut buf), 2);
+    /// assert_eq!(buf, [0.5, 0.5, 0.5, 0.75, 0.25, 0.25, 0.]);
+    /// ```
+    #[inline]
+    fn gen_fill_points(&mut self, out: &mut [f64]) -> usize {
+        let ndim = self.ndim();
+        if ndim == 0 {
+            return 0;
+        }
+        let n = out.len() / ndim;
+        unsafe { self.gen_points_unchecked(n, out) };
+        n
+    }
+
+    /// Writes the next `n` points of the sequence to `out` in row-major order (no
+    /// bounds checks).
+    ///
+    /// The default implementation generates the points one by one; generators
+    /// may override it to amortize work across points.
+    ///
+    /// # Safety
+    ///
+    /// The buffer must be of length `n * ndim()` or higher.
+    #[inline]
+    unsafe fn gen_points_unchecked(&mut self, n: usize, out: &mut [f64]) {
+        let ndim = self.ndim();
+        if ndim == 0 {
+            self.discard(n as u64);
+            return;
+        }
+        for i in 0..n {
+            self.gen_fill_unchecked(out.get_unchecked_mut(i * ndim..(i + 1) * ndim));
+        }
+    }
+
+    /// Generates the next `n` points of the sequence and returns them as the rows of
+    /// an `n x ndim` array in standard (row-major) layout.
+    ///
+    /// This method is only available if the `ndarray` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let points = SobolSeq::new(3).gen_ndarray(100);
+    /// assert_eq!(points.dim(), (100, 3));
+    /// ```
+    #[cfg(feature = "ndarray")]
+    #[inline]
+    fn gen_ndarray(&mut self, n: usize) -> ndarray::Array2<f64> {
+        ndarray::Array2::from_shape_vec((n, self.ndim()), self.gen_points(n)).unwrap()
+    }
+
+    /// Fills the rows of an `n x ndim` array view with the next `n` points of the
+    /// sequence.
+    ///
+    /// Both row-major and column-major memory layouts are written to directly;
+    /// other layouts go through an intermediate buffer.
+    ///
+    /// Panics if the number of columns is not equal to the dimensionality.
+    ///
+    /// This method is only available if the `ndarray` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// use ndarray::{Array2, ShapeBuilder};
+    ///
+    /// let mut points = Array2::zeros((4, 2).f());
+    /// SobolSeq::new(2).gen_fill_ndarray(points.view_mut());
+    /// assert_eq!(points.row(1).to_vec(), vec![0.75, 0.25]);
+    /// ```
+    #[cfg(feature = "ndarray")]
+    fn gen_fill_ndarray(&mut self, mut out: ndarray::ArrayViewMut2<'_, f64>) {
+        let (n, ndim) = out.dim();
+        if ndim != self.ndim() {
+            panic!("invalid number of columns: {} (dimension is {})", ndim, self.ndim());
+        }
+        if let Some(slice) = out.as_slice_mut() {
+            unsafe { self.gen_points_unchecked(n, slice) };
+        } else if let Some(slice) = out.view_mut().reversed_axes().as_slice_mut() {
+            unsafe { self.gen_points_col_major_unchecked(n, slice) };
+        } else {
+            let mut buf = vec![0.; ndim];
+            for mut row in out.rows_mut() {
+                unsafe { self.gen_fill_unchecked(&mut buf) };
+                row.assign(&ndarray::aview1(&buf));
+            }
+        }
+    }
+
+    /// Generates the next `n` points of the sequence and returns them as the rows of
+    /// an `n x ndim` matrix.
+    ///
+    /// This method is only available if the `nalgebra` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let points = SobolSeq::new(3).gen_dmatrix(100);
+    /// assert_eq!(points.shape(), (100, 3));
+    /// ```
+    #[cfg(feature = "nalgebra")]
+    #[inline]
+    fn gen_dmatrix(&mut self, n: usize) -> nalgebra::DMatrix<f64> {
+        nalgebra::DMatrix::from_vec(n, self.ndim(), self.gen_points_col_major(n))
+    }
+
+    /// Fills the rows of an `n x ndim` matrix with the next `n` points of the
+    /// sequence.
+    ///
+    /// Panics if the number of columns is not equal to the dimensionality.
+    ///
+    /// This method is only available if the `nalgebra` feature is enabled.
+    #[cfg(feature = "nalgebra")]
+    #[inline]
+    fn gen_fill_dmatrix(&mut self, out: &mut nalgebra::DMatrix<f64>) {
+        let (n, ndim) = out.shape();
+        if ndim != self.ndim() {
+            panic!("invalid number of columns: {} (dimension is {})", ndim, self.ndim());
+        }
+        // the storage is column-major
+        unsafe { self.gen_points_col_major_unchecked(n, out.as_mut_slice()) };
+    }
+
+    /// Generates the next point of the sequence and returns it as a fixed-size
+    /// vector.
+    ///
+    /// Panics if `D` is not equal to the dimensionality.
+    ///
+    /// This method is only available if the `nalgebra` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let x = HaltonSeq::new(2).gen_svector::<2>();
+    /// assert_eq!(x, nalgebra::Vector2::new(0.5, 1. / 3.));
+    /// ```
+    #[cfg(feature = "nalgebra")]
+    #[inline]
+    fn gen_svector<const D: usize>(&mut self) -> nalgebra::SVector<f64, D> {
+        if D != self.ndim() {
+            panic!("invalid vector length: {} (dimension is {})", D, self.ndim());
+        }
+        let mut out = nalgebra::SVector::<f64, D>::zeros();
+        unsafe { self.gen_fill_unchecked(out.as_mut_slice()) };
+        out
+    }
+
+    /// Generates the next `n` points of the sequence and returns them as an Arrow
+    /// record batch with `n` rows and one non-nullable `Float64` column per
+    /// dimension, named `x0`, `x1`, and so on.
+    ///
+    /// The points are generated in column-major order into a single buffer shared
+    /// by all the columns, so no copies are made.
+    ///
+    /// This method is only available if the `arrow` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// use arrow_array::{cast::AsArray, types::Float64Type};
+    ///
+    /// let batch = SobolSeq::new(3).gen_record_batch(100);
+    /// assert_eq!((batch.num_rows(), batch.num_columns()), (100, 3));
+    /// let x1 = batch.column_by_name("x1").unwrap().as_primitive::<Float64Type>();
+    /// assert_eq!(x1.value(1), 0.25);
+    /// ```
+    #[cfg(feature = "arrow")]
+    #[inline]
+    fn gen_record_batch(&mut self, n: usize) -> arrow_array::RecordBatch {
+        crate::arrow::record_batch(self.gen_points_col_major(n), self.ndim(), n)
+    }
+
+    /// Generates the next `n` points of the sequence and returns them as a flat
+    /// vector of `n * ndim` values in column-major (dimension-major) order, i.e. the
+    /// `j`-th coordinate of the `i`-th point is at index `j * n + i`.
+    ///
+    /// This layout allows processing each coordinate contiguously, e.g. in
+    /// vectorized code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let points = SobolSeq::new(2).gen_points_col_major(3);
+    /// assert_eq!(points, vec![0.5, 0.75, 0.25, 0.5, 0.25, 0.75]);
+    /// ```
+    #[inline]
+    fn gen_points_col_major(&mut self, n: usize) -> Vec<f64> {
+        let mut out = vec![0.; n * self.ndim()];
+        self.gen_points_col_major_into(n, &mut out);
+        out
+    }
+
+    /// Writes the next `n` points of the sequence to `out` in column-major order
+    /// (with a bounds check).
+    ///
+    /// Panics if the length of `out` is less than `n * ndim`.
+    #[inline]
+    fn gen_points_col_major_into(&mut self, n: usize, out: &mut [f64]) {
+        let ndim = self.ndim();
+        if n != 0 && out.len() / n < ndim {
+            panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                out.len(),
+                n.saturating_mul(ndim)
+            );
+        }
+        unsafe { self.gen_points_col_major_unchecked(n, out) };
+    }
+
+    /// Writes the next `n` points of the sequence to `out` in column-major order
+    /// (no bounds checks).
+    ///
+    /// The default implementation generates the points one by one and scatters
+    /// them; generators override it with loops over each dimension where possible.
+    ///
+    /// # Safety
+    ///
+    /// The buffer must be of length `n * ndim()` or higher.
+    #[inline]
+    unsafe fn gen_points_col_major_unchecked(&mut self, n: usize, out: &mut [f64]) {
+        scatter_col_major(self, n, out);
+    }
+
     /// Returns a wrapper (TODO: ...).
     ///
     /// See [`QRngWithBuf`](struct.QRngWithBuf.html) (TODO: ...).
@@ -65,4 +451,623 @@ pub trait QRng: Clone {
     fn with_buf(self) -> QRngWithBuf<Self> {
         QRngWithBuf::new(self)
     }
+
+    /// Returns an infinite iterator over the points of the sequence, consuming
+    /// the generator.
+    ///
+    /// Each point is returned as a newly allocated vector; use
+    /// [`with_buf`](#method.with_buf) to avoid the allocations in hot loops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let points: Vec<_> = SobolSeq::new(3).iter_points().take(100).collect();
+    /// assert_eq!(points.len(), 100);
+    /// assert_eq!(points[0], vec![0.5; 3]);
+    /// ```
+    #[inline]
+    fn iter_points(self) -> QRngIter<Self> {
+        QRngIter::new(self)
+    }
+}
+
+/// The largest `f32` value below 1.
+pub(crate) const F32_BELOW_ONE: f32 = 1. - f32::EPSILON / 2.;
+
+/// Converts a value in `[0, 1)` to the nearest `f32`, keeping it below 1.
+#[inline]
+pub(crate) fn f64_to_f32(x: f64) -> f32 {
+    (x as f32).min(F32_BELOW_ONE)
+}
+
+/// Generates `n` points one by one and writes them to `out` in column-major order.
+#[inline]
+pub(crate) unsafe fn scatter_col_major<R: QRng>(seq: &mut R, n: usize, out: &mut [f64]) {
+    let mut buf = vec![0.; seq.ndim()];
+    for i in 0..n {
+        seq.gen_fill_unchecked(&mut buf);
+        for (j, &x) in buf.iter().enumerate() {
+            *out.get_unchecked_mut(j * n + i) = x;
+        }
+    }
+}
+
+/// Finite multi-dimensional quasi-random point set.
+///
+/// Unlike [`QRng`](trait.QRng.html) generators which produce an unbounded stream of
+/// points, implementors of this trait represent a fixed number of points that can
+/// be accessed in any order. Each point is an `f64` slice containing values
+/// between 0 and 1.
+pub trait PointSet {
+    /// Returns the point set dimensionality.
+    fn ndim(&self) -> usize;
+
+    /// Returns the number of points in the set.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the point set contains no points.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Writes the point with the given index to `out` (no bounds checks).
+    ///
+    /// # Safety
+    ///
+    /// This method does **not** perform bound checks. It is the caller's
+    /// responsibility to ensure that `index` is less than `len()` and to provide
+    /// a buffer of length `ndim()` or higher.
+    unsafe fn get_fill_unchecked(&self, index: usize, out: &mut [f64]);
+
+    /// Writes the point with the given index to `out` (with bounds checks).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut buf = vec![0.; 3];
+    /// let set = HammersleySet::new(3, 16);
+    /// set.get_fill(5, &mut buf);
+    /// ```
+    #[inline]
+    fn get_fill(&self, index: usize, out: &mut [f64]) {
+        if index >= self.len() {
+            panic!("index out of bounds: the len is {} but the index is {}", self.len(), index);
+        }
+        if out.len() < self.ndim() {
+            panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                out.len(),
+                self.ndim()
+            );
+        }
+        unsafe {
+            self.get_fill_unchecked(index, out);
+        }
+    }
+
+    /// Returns the point with the given index, or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let set = HammersleySet::new(2, 4);
+    /// assert_eq!(set.get(1), Some(vec![0.25, 0.5]));
+    /// assert_eq!(set.get(4), None);
+    /// ```
+    #[inline]
+    fn get(&self, index: usize) -> Option<Vec<f64>> {
+        if index >= self.len() {
+            return None;
+        }
+        let mut out = vec![0.; self.ndim()];
+        unsafe { self.get_fill_unchecked(index, &mut out) };
+        Some(out)
+    }
+
+    /// Returns an iterator over all points in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let set = HammersleySet::new(2, 8);
+    /// let points: Vec<_> = set.iter().collect();
+    /// assert_eq!(points.len(), 8);
+    /// ```
+    #[inline]
+    fn iter(&self) -> PointSetIter<'_, Self>
+    where
+        Self: Sized,
+    {
+        PointSetIter::new(self)
+    }
+}
+
+/// Quasi-random sequence generator with a digital construction in base 2.
+///
+/// Implementors of this trait can expose the binary digits of each coordinate
+/// of the generated points, which is required for digit-wise randomizations such
+/// as scrambling.
+pub trait DigitalQRng: QRng {
+    /// Writes the binary digits of the next element of the sequence to `out` (no
+    /// bounds checks).
+    ///
+    /// Each coordinate is written as a 64-bit integer, with the first binary digit
+    /// after the radix point stored in the most significant bit, so that the
+    /// coordinate value is `out[j] / 2^64`. This advances the sequence in the same
+    /// way as [`gen_fill_unchecked`](trait.QRng.html#tymethod.gen_fill_unchecked).
+    ///
+    /// # Safety
+    ///
+    /// This method does **not** perform bound checks. It is the caller's
+    /// responsibility to provide a buffer of length `ndim()` or higher.
+    unsafe fn gen_fill_digits_unchecked(&mut self, out: &mut [u64]);
+}
+
+/// Quasi-random sequence generator that can jump to an arbitrary position.
+///
+/// Implementors of this trait can reposition the generator without generating
+/// the intermediate points, which is typically much faster than discarding them
+/// and is used for splitting a sequence into multiple streams.
+pub trait SkipTo: QRng {
+    /// Moves the generator so that the next generated point is the one with the
+    /// given (zero-based) index, as if `index` points were generated since the
+    /// beginning of the sequence.
+    ///
+    /// Indices past the period of the sequence wrap around.
+    fn skip_to(&mut self, index: u64);
+
+    /// Returns a leapfrogged generator that yields every `k`-th point of the
+    /// sequence starting from `offset`.
+    ///
+    /// See [`Leapfrog`](struct.Leapfrog.html) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// // worker 1 of 4 gets points 1, 5, 9, ...
+    /// let mut seq = SobolSeq::new(2).leapfrog(4, 1).with_buf();
+    /// assert_eq!(seq.gen(), &[0.75, 0.25]);
+    /// ```
+    #[inline]
+    fn leapfrog(self, k: u64, offset: u64) -> Leapfrog<Self>
+    where
+        Self: Sized,
+    {
+        Leapfrog::new(self, k, offset)
+    }
+
+    /// Returns `n_blocks` copies of the generator positioned at the beginnings of
+    /// consecutive disjoint blocks of `block_len` points.
+    ///
+    /// The `i`-th returned generator starts at the point with index
+    /// `i * block_len`, so that consuming `block_len` points from each of them
+    /// covers the first `n_blocks * block_len` points of the sequence exactly once;
+    /// this is suited for map-reduce style parallel integration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let blocks = SobolSeq::new(2).split_blocks(4, 1024);
+    /// assert_eq!(blocks.len(), 4);
+    /// ```
+    #[inline]
+    fn split_blocks(&self, n_blocks: usize, block_len: u64) -> Vec<Self>
+    where
+        Self: Sized,
+    {
+        (0..n_blocks as u64)
+            .map(|i| {
+                let mut seq = self.clone();
+                seq.skip_to(i.wrapping_mul(block_len));
+                seq
+            })
+            .collect()
+    }
+
+    /// Generates the next `n` points of the sequence in parallel and returns them as
+    /// a flat vector of `n * ndim` values in row-major order.
+    ///
+    /// The result is exactly the same as that of
+    /// [`gen_points`](trait.QRng.html#method.gen_points): the range of indices is
+    /// split into blocks of consecutive points, and each block is generated into its
+    /// part of the output by a clone of the generator moved to the start of the
+    /// block, on the rayon thread pool. The generator itself is then moved past the
+    /// generated points. This pays off for large numbers of points, typically
+    /// millions.
+    ///
+    /// This method is only available if the `rayon` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let points = SobolSeq::new(3).gen_points_par(100_000);
+    /// assert_eq!(points, SobolSeq::new(3).gen_points(100_000));
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[inline]
+    fn gen_points_par(&mut self, n: usize) -> Vec<f64>
+    where
+        Self: Send + Sync,
+    {
+        let mut out = vec![0.; n * self.ndim()];
+        self.gen_points_par_into(n, &mut out);
+        out
+    }
+
+    /// Writes the next `n` points of the sequence to `out` in row-major order in
+    /// parallel (with a bounds check).
+    ///
+    /// See [`gen_points_par`](#method.gen_points_par) for details.
+    ///
+    /// Panics if the length of `out` is less than `n * ndim`.
+    ///
+    /// This method is only available if the `rayon` feature is enabled.
+    #[cfg(feature = "rayon")]
+    fn gen_points_par_into(&mut self, n: usize, out: &mut [f64])
+    where
+        Self: Send + Sync,
+    {
+        let ndim = self.ndim();
+        if n != 0 && out.len() / n < ndim {
+            panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                out.len(),
+                n.saturating_mul(ndim)
+            );
+        }
+        if ndim == 0 {
+            self.discard(n as u64);
+            return;
+        }
+        let start = self.index();
+        let chunk = (PAR_CHUNK_LEN / ndim).max(1);
+        let seq = &*self;
+        out[..n * ndim].par_chunks_mut(chunk * ndim).enumerate().for_each(|(i, block)| {
+            let mut seq = seq.clone();
+            seq.skip_to(start.wrapping_add((i * chunk) as u64));
+            unsafe { seq.gen_points_unchecked(block.len() / ndim, block) };
+        });
+        self.skip_to(start.wrapping_add(n as u64));
+    }
+}
+
+/// Quasi-random sequence generator that can be randomized with a seed.
+///
+/// Randomized quasi-Monte Carlo (RQMC) methods use independent randomizations of
+/// a low-discrepancy sequence to obtain unbiased estimates with error bounds.
+/// Each generator family is randomized in the way appropriate for it: digital
+/// sequences are Owen-scrambled, while the remaining sequences are randomly
+/// shifted modulo 1.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// fn estimate<R: Randomize>(seq: R, seed: u64) -> f64 {
+///     let mut seq = seq.randomize(seed).with_buf();
+///     (0..1000).map(|_| seq.gen()[0]).sum::<f64>() / 1000.
+/// }
+/// assert!((estimate(SobolSeq::new(2), 1) - 0.5).abs() < 1e-2);
+/// assert!((estimate(HaltonSeq::new(2), 1) - 0.5).abs() < 1e-2);
+/// ```
+pub trait Randomize: QRng {
+    /// The type of the randomized generator.
+    type Randomized: QRng;
+
+    /// Returns a randomized version of the generator, reproducible from the seed.
+    fn randomize(self, seed: u64) -> Self::Randomized;
+}
+
+/// The type of the randomized version of a generator.
+pub type Randomized<R> = <R as Randomize>::Randomized;
+
+/// Object-safe counterpart of [`QRng`](trait.QRng.html).
+///
+/// Since `QRng` requires `Clone`, it cannot be used as a trait object. This trait
+/// is implemented for all generators, and `Box<dyn DynQRng>` implements `QRng`,
+/// so generators selected at runtime can be stored behind a trait object and
+/// used like any other generator. The methods are prefixed with `dyn_` so as not
+/// to clash with those of `QRng`, and are not meant to be called directly.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// fn make(name: &str, ndim: usize) -> Box<dyn DynQRng> {
+///     match name {
+///         "sobol" => Box::new(SobolSeq::new(ndim)),
+///         _ => Box::new(HaltonSeq::new(ndim)),
+///     }
+/// }
+/// let mut seq = make("halton", 2).with_buf();
+/// assert_eq!(seq.gen(), &[0.5, 1. / 3.]);
+/// ```
+pub trait DynQRng {
+    /// See [`QRng::ndim`](trait.QRng.html#tymethod.ndim).
+    fn dyn_ndim(&self) -> usize;
+
+    /// See [`QRng::gen_fill_unchecked`](trait.QRng.html#tymethod.gen_fill_unchecked).
+    ///
+    /// # Safety
+    ///
+    /// The buffer must be of length `ndim()` or higher.
+    unsafe fn dyn_gen_fill_unchecked(&mut self, out: &mut [f64]);
+
+    /// See [`QRng::reset`](trait.QRng.html#tymethod.reset).
+    fn dyn_reset(&mut self);
+
+    /// See [`QRng::index`](trait.QRng.html#tymethod.index).
+    fn dyn_index(&self) -> u64;
+
+    /// See [`QRng::discard`](trait.QRng.html#method.discard).
+    fn dyn_discard(&mut self, n: u64);
+
+    /// Returns a boxed clone of the generator.
+    fn clone_box(&self) -> Box<dyn DynQRng>;
+}
+
+impl<R: QRng + 'static> DynQRng for R {
+    #[inline]
+    fn dyn_ndim(&self) -> usize {
+        self.ndim()
+    }
+
+    #[inline]
+    unsafe fn dyn_gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.gen_fill_unchecked(out);
+    }
+
+    #[inline]
+    fn dyn_reset(&mut self) {
+        self.reset();
+    }
+
+    #[inline]
+    fn dyn_index(&self) -> u64 {
+        self.index()
+    }
+
+    #[inline]
+    fn dyn_discard(&mut self, n: u64) {
+        self.discard(n);
+    }
+
+    #[inline]
+    fn clone_box(&self) -> Box<dyn DynQRng> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn DynQRng> {
+    #[inline]
+    fn clone(&self) -> Self {
+        (**self).clone_box()
+    }
+}
+
+impl QRng for Box<dyn DynQRng> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        (**self).dyn_ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        (**self).dyn_reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        (**self).dyn_index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        (**self).dyn_gen_fill_unchecked(out);
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        (**self).dyn_discard(n);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynQRng;
+    use crate::{
+        DigitalNet, FaureSeq, GeneralizedHaltonSeq, HaltonSeq, KroneckerSeq, LatticeRule,
+        NiederreiterSeq, PolyLatticeRule, QRng, RandomShift, Randomize, SkipTo, SobolSeq,
+        VanDerCorput,
+    };
+
+    /// Checks the provided methods of `QRng` against plain point-by-point generation.
+    fn check_qrng<R: QRng>(seq: R) {
+        let ndim = seq.ndim();
+        let mut plain = seq.clone().with_buf();
+        // the indices wrap around for finite point sets
+        let mut index = vec![0];
+        let points: Vec<_> = (0..300)
+            .map(|_| {
+                let x = plain.gen().to_vec();
+                index.push(plain.index());
+                x
+            })
+            .collect();
+
+        // reset and index
+        let mut s = seq.clone().with_buf();
+        for x in &points[..10] {
+            assert_eq!(s.gen(), x.as_slice());
+        }
+        assert_eq!(s.index(), index[10]);
+        s.reset();
+        assert_eq!(s.index(), 0);
+        for x in &points[..10] {
+            assert_eq!(s.gen(), x.as_slice());
+        }
+        s.discard(5);
+        assert_eq!(s.index(), index[15]);
+
+        // discard
+        for &n in &[0, 1, 2, 7, 64, 127, 200] {
+            let mut s = seq.clone();
+            s.discard(n as u64);
+            let mut s = s.with_buf();
+            for x in &points[n..n + 5] {
+                for (a, b) in s.gen().iter().zip(x) {
+                    assert!((a - b).abs() < 1e-15);
+                }
+            }
+        }
+
+        // bulk generation
+        let mut s = seq.clone();
+        let mut bulk = s.gen_points(10);
+        let mut buf = vec![-1.; 5 * ndim + 1];
+        s.gen_points_into(5, &mut buf);
+        assert_eq!(s.index(), index[15]);
+        assert_eq!(buf[5 * ndim], -1.);
+        let mut tail = vec![-1.; 4 * ndim - 1];
+        assert_eq!(s.gen_fill_points(&mut tail), 3);
+        assert!(tail[3 * ndim..].iter().all(|&x| x == -1.));
+        bulk.extend(&buf[..5 * ndim]);
+        bulk.extend(&tail[..3 * ndim]);
+        assert_eq!(bulk, points[..18].concat());
+
+        // column-major generation
+        let (mut s, mut offset) = (seq.clone(), 0);
+        for &n in &[1, 7, 64] {
+            let x = s.gen_points_col_major(n);
+            for i in 0..n {
+                for j in 0..ndim {
+                    assert_eq!(x[j * n + i], points[offset + i][j]);
+                }
+            }
+            offset += n;
+        }
+        assert_eq!(s.index(), index[offset]);
+
+        // single precision
+        let mut s = seq;
+        let mut buf = vec![0f32; ndim];
+        for x in &points {
+            s.gen_fill_f32(&mut buf);
+            for (&a, &b) in buf.iter().zip(x) {
+                assert!((0. ..1.).contains(&a));
+                assert!((f64::from(a) - b).abs() <= 1e-7);
+            }
+        }
+    }
+
+    #[test]
+    fn test_qrng() {
+        check_qrng(SobolSeq::new(4));
+        check_qrng(SobolSeq::new(5));
+        check_qrng(HaltonSeq::new(4));
+        check_qrng(HaltonSeq::leaped(3, 5));
+        check_qrng(HaltonSeq::leaped(4, 7));
+        check_qrng(VanDerCorput::new(3));
+        check_qrng(LatticeRule::korobov(101, 12, 4));
+        check_qrng(KroneckerSeq::roberts(4));
+        check_qrng(NiederreiterSeq::new(4));
+        check_qrng(DigitalNet::sobol(3));
+        check_qrng(DigitalNet::new(3, &[vec![4, 2, 1], vec![1, 2, 4]]));
+        check_qrng(PolyLatticeRule::new(0b1011, &[1, 0b110]));
+        check_qrng(FaureSeq::new(3));
+        check_qrng(GeneralizedHaltonSeq::new(3));
+        check_qrng(SobolSeq::new(4).randomize(1));
+        check_qrng(HaltonSeq::new(4).randomize(1));
+        check_qrng(SobolSeq::new(4).leapfrog(3, 1));
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_gen_ndarray() {
+        use ndarray::{s, Array2, ShapeBuilder};
+
+        let expected = SobolSeq::new(3).gen_ndarray(10);
+        let mut f = Array2::zeros((10, 3).f());
+        SobolSeq::new(3).gen_fill_ndarray(f.view_mut());
+        assert_eq!(f, expected);
+        let mut strided = Array2::zeros((20, 6));
+        SobolSeq::new(3).gen_fill_ndarray(strided.slice_mut(s![..;2, ..;2]));
+        assert_eq!(strided.slice(s![..;2, ..;2]), expected);
+        assert_eq!(strided.slice(s![1..;2, ..]).sum(), 0.);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_gen_dmatrix() {
+        let expected = SobolSeq::new(3).gen_points(10);
+        let mut seq = SobolSeq::new(3);
+        let m = seq.gen_dmatrix(5);
+        let mut tail = nalgebra::DMatrix::zeros(4, 3);
+        seq.gen_fill_dmatrix(&mut tail);
+        let v = seq.gen_svector::<3>();
+        for i in 0..10 {
+            for j in 0..3 {
+                let x = match i {
+                    0..=4 => m[(i, j)],
+                    5..=8 => tail[(i - 5, j)],
+                    _ => v[j],
+                };
+                assert_eq!(x, expected[i * 3 + j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_f64_to_f32() {
+        assert_eq!(crate::traits::f64_to_f32(1. - 1e-12), 1. - f32::EPSILON / 2.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gen_points_panic() {
+        SobolSeq::new(3).gen_points_into(2, &mut [0.; 5]);
+    }
+
+    #[test]
+    fn test_dyn_qrng() {
+        let boxed: Box<dyn DynQRng> = Box::new(SobolSeq::new(3));
+        let mut seq = RandomShift::new(boxed, 1).with_buf();
+        let mut expected = RandomShift::new(SobolSeq::new(3), 1).with_buf();
+        for _ in 0..10 {
+            assert_eq!(seq.gen().to_vec(), expected.gen());
+        }
+        let mut cloned = seq.clone();
+        seq.discard(5);
+        cloned.discard(5);
+        assert_eq!(seq.index(), 15);
+        assert_eq!(seq.gen().to_vec(), cloned.gen());
+        seq.reset();
+        assert_eq!(seq.index(), 0);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_gen_points_par() {
+        fn check<R: SkipTo + Send + Sync>(mut seq: R, n: usize) {
+            let mut expected = seq.clone();
+            seq.gen_points(7);
+            expected.gen_points(7);
+            assert_eq!(seq.gen_points_par(n), expected.gen_points(n));
+            assert_eq!(seq.index(), expected.index());
+            assert_eq!(seq.gen_points(3), expected.gen_points(3));
+        }
+        check(SobolSeq::new(3), 100_003);
+        check(SobolSeq::new(1000), 200);
+        check(HaltonSeq::leaped(5, 7), 30_000);
+        check(KroneckerSeq::roberts(2), 50_000);
+        check(SobolSeq::new(2), 0);
+    }
 }
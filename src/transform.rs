@@ -0,0 +1,631 @@
+use crate::{traits::QRng, util::inverse_normal_cdf};
+
+/// Sequence generator with the baker's (tent) transform applied to each coordinate.
+///
+/// Each coordinate `x` is mapped to `1 - |2x - 1|`. The transform preserves the
+/// uniform distribution, and applying it to randomly shifted lattice rules yields
+/// second-order convergence for smooth integrands without requiring them to be
+/// periodic ("Tent-transformed lattice rules for integration and approximation
+/// of multivariate non-periodic functions", Cools, Kuo, Nuyens and Suryanarayana,
+/// 2016; Hickernell, 2002).
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let lattice = LatticeRule::korobov(1021, 76, 3);
+/// let mut seq = TentTransformed::new(RandomShift::new(lattice, 42)).with_buf();
+/// let x = seq.gen();
+/// assert!(x.iter().all(|&x| 0. <= x && x <= 1.));
+/// ```
+#[derive(Clone)]
+pub struct TentTransformed<R: QRng> {
+    seq: R,
+}
+
+impl<R: QRng> TentTransformed<R> {
+    /// Returns a new generator applying the baker's transform to the given sequence.
+    #[inline]
+    pub fn new(seq: R) -> Self {
+        Self { seq }
+    }
+
+    /// Returns a reference to the underlying (untransformed) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng> QRng for TentTransformed<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(out);
+        for x in out.get_unchecked_mut(..self.seq.ndim()) {
+            *x = 1. - (2. * *x - 1.).abs();
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+/// Sequence generator mapping the unit hypercube onto a hyperrectangle.
+///
+/// Each coordinate `x_j` is mapped to `low_j + (high_j - low_j) * x_j`, so that the
+/// points are uniformly distributed over `[low_0, high_0) x ... x [low_n, high_n)`.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = Scaled::new(SobolSeq::new(2), &[-1., 10.], &[1., 20.]).with_buf();
+/// assert_eq!(seq.gen(), &[0., 15.]);
+/// ```
+#[derive(Clone)]
+pub struct Scaled<R: QRng> {
+    seq: R,
+    lows: Vec<f64>,
+    widths: Vec<f64>,
+}
+
+impl<R: QRng> Scaled<R> {
+    /// Returns a new generator mapping the points of the given sequence onto the
+    /// hyperrectangle with the given lower and upper bounds.
+    ///
+    /// Panics if the lengths of the bounds differ from the dimensionality of the
+    /// sequence.
+    pub fn new(seq: R, lows: &[f64], highs: &[f64]) -> Self {
+        let ndim = seq.ndim();
+        if lows.len() != ndim {
+            panic!("invalid number of lower bounds: {}", lows.len());
+        }
+        if highs.len() != ndim {
+            panic!("invalid number of upper bounds: {}", highs.len());
+        }
+        let widths = lows.iter().zip(highs).map(|(low, high)| high - low).collect();
+        Self { seq, lows: lows.to_vec(), widths }
+    }
+
+    /// Returns a reference to the underlying (unscaled) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng> QRng for Scaled<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(out);
+        for (j, x) in out.get_unchecked_mut(..self.lows.len()).iter_mut().enumerate() {
+            *x = *self.lows.get_unchecked(j) + *self.widths.get_unchecked(j) * *x;
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+/// Generator mapping the points of a sequence onto a grid of integers.
+///
+/// Each coordinate `x_j` is mapped to `low_j + floor((high_j - low_j) * x_j)`, so
+/// that each integer in `low_j..high_j` is hit with equal frequency. This is useful
+/// e.g. for quasi-random search over discrete hyperparameters.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = IntMapped::new(HaltonSeq::new(2), &[0, -5], &[10, 5]);
+/// let mut out = [0; 2];
+/// seq.gen_ints(&mut out);
+/// assert_eq!(out, [5, -2]);
+/// ```
+#[derive(Clone)]
+pub struct IntMapped<R: QRng> {
+    seq: R,
+    lows: Vec<i64>,
+    sizes: Vec<u64>,
+    buf: Vec<f64>,
+}
+
+impl<R: QRng> IntMapped<R> {
+    /// Returns a new generator mapping the points of the given sequence onto the
+    /// integer ranges `lows[j]..highs[j]`.
+    ///
+    /// Panics if the lengths of the bounds differ from the dimensionality of the
+    /// sequence, or if any of the ranges is empty.
+    pub fn new(seq: R, lows: &[i64], highs: &[i64]) -> Self {
+        let ndim = seq.ndim();
+        if lows.len() != ndim {
+            panic!("invalid number of lower bounds: {}", lows.len());
+        }
+        if highs.len() != ndim {
+            panic!("invalid number of upper bounds: {}", highs.len());
+        }
+        let sizes = lows
+            .iter()
+            .zip(highs)
+            .map(|(&low, &high)| {
+                if high <= low {
+                    panic!("invalid integer range: {}..{}", low, high);
+                }
+                high.wrapping_sub(low) as u64
+            })
+            .collect();
+        Self { seq, lows: lows.to_vec(), sizes, buf: vec![0.; ndim] }
+    }
+
+    /// Writes the next point of the sequence mapped onto the integer grid to `out`.
+    ///
+    /// Panics if the length of `out` is less than the dimensionality of the sequence.
+    #[inline]
+    pub fn gen_ints(&mut self, out: &mut [i64]) {
+        let ndim = self.buf.len();
+        if out.len() < ndim {
+            panic!("index out of bounds: the len is {} but the index is {}", out.len(), ndim);
+        }
+        unsafe { self.seq.gen_fill_unchecked(&mut self.buf) };
+        for (j, out) in out[..ndim].iter_mut().enumerate() {
+            let size = self.sizes[j];
+            // clamp in case the product is rounded up to the size
+            let k = ((self.buf[j] * size as f64) as u64).min(size - 1);
+            *out = self.lows[j].wrapping_add(k as i64);
+        }
+    }
+
+    /// Returns a reference to the underlying sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+
+    /// Returns a mutable reference to the underlying sequence.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut R {
+        &mut self.seq
+    }
+}
+
+/// Sequence generator producing standard normal quasi-random samples.
+///
+/// Each coordinate `x` is mapped through the inverse of the standard normal
+/// cumulative distribution function (see
+/// [`util::inverse_normal_cdf`](util/fn.inverse_normal_cdf.html)). Since the map is
+/// monotone, the low-discrepancy structure of the sequence is preserved.
+///
+/// Coordinates equal to zero are mapped to negative infinity; this happens e.g. for
+/// the first point of lattice rules and for the last point of the period of digital
+/// sequences, so such generators should be randomized (or the point skipped).
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let mut seq = Normalized::new(SobolSeq::new(2)).with_buf();
+/// assert_eq!(seq.gen(), &[0., 0.]);
+/// let x = seq.gen();
+/// assert!((x[0] - 0.6744897501960817).abs() < 1e-15);
+/// ```
+#[derive(Clone)]
+pub struct Normalized<R: QRng> {
+    seq: R,
+}
+
+impl<R: QRng> Normalized<R> {
+    /// Returns a new generator mapping the coordinates of the given sequence to
+    /// standard normal samples.
+    #[inline]
+    pub fn new(seq: R) -> Self {
+        Self { seq }
+    }
+
+    /// Returns a reference to the underlying (uniform) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng> QRng for Normalized<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(out);
+        for x in out.get_unchecked_mut(..self.seq.ndim()) {
+            *x = inverse_normal_cdf(*x);
+        }
+    }
+
+    #[inline]
+    unsafe fn gen_points_unchecked(&mut self, n: usize, out: &mut [f64]) {
+        self.seq.gen_points_unchecked(n, out);
+        for x in out.get_unchecked_mut(..n * self.seq.ndim()) {
+            *x = inverse_normal_cdf(*x);
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+/// Sequence generator producing correlated multivariate normal quasi-random samples.
+///
+/// Each point `x` of the underlying sequence is first mapped to a vector `z` of
+/// independent standard normal samples (as in [`Normalized`](struct.Normalized.html)),
+/// which is then mapped to `mean + L z`, where `L` is the lower-triangular Cholesky
+/// factor of the covariance matrix. Since `L` is lower-triangular, the leading
+/// coordinates of the sequence (which are usually the most uniform ones) have the
+/// biggest influence on the result.
+///
+/// Positive semidefinite covariance matrices are supported as well, in which case
+/// the coordinates corresponding to the degenerate directions are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let cov = [4., 1., 1., 1.];
+/// let mut seq = MultivariateNormal::new(HaltonSeq::new(2), &[1., -1.], &cov).with_buf();
+/// let x = seq.gen();
+/// assert_eq!(x[0], 1.);
+/// ```
+#[derive(Clone)]
+pub struct MultivariateNormal<R: QRng> {
+    seq: R,
+    mean: Vec<f64>,
+    factor: Vec<f64>,
+    buf: Vec<f64>,
+}
+
+impl<R: QRng> MultivariateNormal<R> {
+    /// Returns a new generator mapping the points of the given sequence to samples
+    /// from the multivariate normal distribution with the given mean and covariance
+    /// matrix (stored in row-major order).
+    ///
+    /// Panics if the length of the mean differs from the dimensionality of the
+    /// sequence, if the covariance matrix is of the wrong size or if it is not
+    /// symmetric positive semidefinite.
+    pub fn new(seq: R, mean: &[f64], cov: &[f64]) -> Self {
+        let ndim = seq.ndim();
+        if mean.len() != ndim {
+            panic!("invalid mean length: {}", mean.len());
+        }
+        if cov.len() != ndim * ndim {
+            panic!("invalid covariance matrix size: {}", cov.len());
+        }
+        let factor = cholesky(cov, ndim);
+        Self { seq, mean: mean.to_vec(), factor, buf: vec![0.; ndim] }
+    }
+
+    /// Returns the mean of the distribution.
+    #[inline]
+    pub fn mean(&self) -> &[f64] {
+        &self.mean
+    }
+
+    /// Returns the lower-triangular Cholesky factor of the covariance matrix, stored
+    /// in row-major order.
+    #[inline]
+    pub fn factor(&self) -> &[f64] {
+        &self.factor
+    }
+
+    /// Returns a reference to the underlying (uniform) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+/// Returns the lower-triangular Cholesky factor of the symmetric positive
+/// semidefinite matrix `a` of size `n x n`, both stored in row-major order.
+fn cholesky(a: &[f64], n: usize) -> Vec<f64> {
+    let mut l = vec![0.; n * n];
+    for i in 0..n {
+        for j in 0..=i {
+            if a[i * n + j] != a[j * n + i] {
+                panic!("invalid covariance matrix: not symmetric at ({}, {})", i, j);
+            }
+            let s = a[i * n + j] - (0..j).map(|k| l[i * n + k] * l[j * n + k]).sum::<f64>();
+            if i != j {
+                let d = l[j * n + j];
+                l[i * n + j] = if d > 0. { s / d } else { 0. };
+            } else {
+                // allow for rounding errors in semidefinite matrices
+                let tol = 1e-12 * a[i * n + i].abs().max(f64::MIN_POSITIVE);
+                if s < -tol {
+                    panic!("invalid covariance matrix: not positive semidefinite at row {}", i);
+                }
+                l[i * n + i] = s.max(0.).sqrt();
+            }
+        }
+    }
+    l
+}
+
+impl<R: QRng> QRng for MultivariateNormal<R> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        let n = self.buf.len();
+        self.seq.gen_fill_unchecked(&mut self.buf);
+        for z in &mut self.buf {
+            *z = inverse_normal_cdf(*z);
+        }
+        for (i, x) in out.get_unchecked_mut(..n).iter_mut().enumerate() {
+            let row = self.factor.get_unchecked(i * n..=i * n + i);
+            let dot = row.iter().zip(&self.buf).fold(0., |s, (l, z)| s + l * z);
+            *x = *self.mean.get_unchecked(i) + dot;
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+/// Sequence generator mapping each coordinate through a per-dimension function.
+///
+/// Each coordinate `x_j` is mapped to `f(j, x_j)`. When `f(j, .)` is the quantile
+/// function (inverse CDF) of some distribution, the `j`-th coordinates of the points
+/// follow that distribution; since quantile functions are monotone, the
+/// low-discrepancy structure of the sequence is preserved. This is the preferred
+/// way of targeting non-uniform marginals, as opposed to e.g. rejection sampling.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// // exponential distribution with rate 2 in the first coordinate, normal in the second
+/// let f = |j: usize, x: f64| match j {
+///     0 => -(1. - x).ln() / 2.,
+///     _ => util::inverse_normal_cdf(x),
+/// };
+/// let mut seq = Transformed::new(SobolSeq::new(2), f).with_buf();
+/// assert_eq!(seq.gen(), &[2f64.ln() / 2., 0.]);
+/// ```
+#[derive(Clone)]
+pub struct Transformed<R: QRng, F> {
+    seq: R,
+    f: F,
+}
+
+impl<R: QRng, F: Fn(usize, f64) -> f64 + Clone> Transformed<R, F> {
+    /// Returns a new generator mapping the `j`-th coordinate `x` of each point of the
+    /// given sequence to `f(j, x)`.
+    #[inline]
+    pub fn new(seq: R, f: F) -> Self {
+        Self { seq, f }
+    }
+
+    /// Returns a reference to the underlying (untransformed) sequence.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.seq
+    }
+}
+
+impl<R: QRng, F: Fn(usize, f64) -> f64 + Clone> QRng for Transformed<R, F> {
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.seq.ndim()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.seq.reset();
+    }
+
+    #[inline]
+    fn index(&self) -> u64 {
+        self.seq.index()
+    }
+
+    #[inline]
+    unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
+        self.seq.gen_fill_unchecked(out);
+        for (j, x) in out.get_unchecked_mut(..self.seq.ndim()).iter_mut().enumerate() {
+            *x = (self.f)(j, *x);
+        }
+    }
+
+    #[inline]
+    fn discard(&mut self, n: u64) {
+        self.seq.discard(n);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IntMapped, MultivariateNormal, Normalized, Scaled, TentTransformed, Transformed};
+    use crate::{util::inverse_normal_cdf, HaltonSeq, QRng, QRngExt, SobolSeq};
+
+    #[test]
+    fn test_tent_transformed() {
+        let expected = [[1., 2. / 3.], [0.5, 2. / 3.], [0.5, 2. / 9.]];
+        let mut seq = TentTransformed::new(HaltonSeq::new(2)).with_buf();
+        for e in &expected {
+            for (x, y) in seq.gen().iter().zip(e) {
+                assert!((x - y).abs() < 1e-15);
+            }
+        }
+    }
+
+    #[test]
+    fn test_scaled() {
+        let (lows, highs) = ([-2., 0., 5.], [2., 1e-3, 5.]);
+        let mut seq = Scaled::new(HaltonSeq::new(3), &lows, &highs).with_buf();
+        let mut halton = HaltonSeq::new(3).with_buf();
+        for _ in 0..100 {
+            let (x, y) = (seq.gen().to_vec(), halton.gen());
+            for j in 0..3 {
+                assert!((lows[j]..=highs[j]).contains(&x[j]));
+                assert!((x[j] - lows[j] - (highs[j] - lows[j]) * y[j]).abs() < 1e-15);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scaled_panic() {
+        Scaled::new(HaltonSeq::new(3), &[0.; 3], &[1.; 2]);
+    }
+
+    #[test]
+    fn test_int_mapped() {
+        const N: usize = 2 * 3 * 5;
+        let mut seq = IntMapped::new(HaltonSeq::new(3), &[0, -2, i64::MIN], &[2, 1, i64::MIN + 5]);
+        let mut counts = [0; N];
+        let mut out = [0; 3];
+        for _ in 0..N {
+            seq.gen_ints(&mut out);
+            let k = (out[2] - i64::MIN) as usize;
+            counts[(out[0] as usize * 3 + (out[1] + 2) as usize) * 5 + k] += 1;
+        }
+        // any N consecutive Halton points are stratified on the corresponding grid
+        assert!(counts.iter().all(|&c| c == 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_int_mapped_panic() {
+        IntMapped::new(HaltonSeq::new(2), &[0, 1], &[1, 1]);
+    }
+
+    #[test]
+    fn test_normalized() {
+        let mut seq = Normalized::new(HaltonSeq::new(3)).with_buf();
+        let mut halton = HaltonSeq::new(3).with_buf();
+        for _ in 0..100 {
+            let (x, y) = (seq.gen().to_vec(), halton.gen());
+            for j in 0..3 {
+                assert_eq!(x[j], inverse_normal_cdf(y[j]));
+            }
+        }
+        let points = Normalized::new(SobolSeq::new(2)).gen_points(1 << 12);
+        let n = points.len() as f64;
+        let mean = points.iter().sum::<f64>() / n;
+        let var = points.iter().map(|x| x * x).sum::<f64>() / n;
+        assert!(mean.abs() < 1e-3 && (var - 1.).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_multivariate_normal() {
+        let mean = [1., -2., 0.];
+        let cov = [4., 1.2, -0.6, 1.2, 1., 0.3, -0.6, 0.3, 0.5];
+        let mut seq = MultivariateNormal::new(SobolSeq::new(3).skip_points(1), &mean, &cov);
+        let l = seq.factor().to_vec();
+        for i in 0..3 {
+            for j in 0..3 {
+                let c: f64 = (0..3).map(|k| l[i * 3 + k] * l[j * 3 + k]).sum();
+                assert!((c - cov[i * 3 + j]).abs() < 1e-14);
+            }
+        }
+        let n = 1 << 14;
+        let points = seq.gen_points(n);
+        for i in 0..3 {
+            let m = points.iter().skip(i).step_by(3).sum::<f64>() / n as f64;
+            assert!((m - mean[i]).abs() < 1e-2);
+            for j in 0..3 {
+                let c = points.chunks(3).map(|x| (x[i] - mean[i]) * (x[j] - mean[j])).sum::<f64>()
+                    / n as f64;
+                assert!((c - cov[i * 3 + j]).abs() < 2e-2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_multivariate_normal_degenerate() {
+        let cov = [1., 2., 2., 4.];
+        let mut seq = MultivariateNormal::new(HaltonSeq::new(2), &[0., 0.], &cov).with_buf();
+        for _ in 0..100 {
+            let x = seq.gen();
+            assert!((x[1] - 2. * x[0]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_multivariate_normal_panic() {
+        MultivariateNormal::new(HaltonSeq::new(2), &[0., 0.], &[1., 2., 2., 1.]);
+    }
+
+    #[test]
+    fn test_transformed() {
+        let mut seq = HaltonSeq::new(3).transform(|j, x| x * (j + 1) as f64).with_buf();
+        let mut halton = HaltonSeq::new(3).with_buf();
+        for _ in 0..100 {
+            let (x, y) = (seq.gen().to_vec(), halton.gen());
+            assert_eq!(x, vec![y[0], 2. * y[1], 3. * y[2]]);
+        }
+        let mut seq = Transformed::new(SobolSeq::new(1), |_, x: f64| x * x).with_buf();
+        assert_eq!(seq.gen(), &[0.25]);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{
+    discrepancy::check_points,
+    util::{gamma_p, ln_gamma},
+};
+
+/// Outcome of a statistical goodness-of-fit test.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestResult {
+    /// Value of the test statistic.
+    pub statistic: f64,
+    /// Probability of observing a statistic at least as large as this one for a set
+    /// of independent uniformly distributed points.
+    pub p_value: f64,
+}
+
+/// Returns the survival function of the Kolmogorov distribution.
+fn kolmogorov_sf(lambda: f64) -> f64 {
+    if lambda < 0.2 {
+        return 1.;
+    }
+    let mut sum = 0.;
+    let mut sign = 1.;
+    for k in 1..=100 {
+        let term = (-2. * (k * k) as f64 * lambda * lambda).exp();
+        sum += sign * term;
+        if term < 1e-16 {
+            break;
+        }
+        sign = -sign;
+    }
+    (2. * sum).clamp(0., 1.)
+}
+
+/// Runs the Kolmogorov–Smirnov test against the uniform distribution on each of the
+/// coordinates of the point set separately.
+///
+/// The points are given in row-major order, i.e. each `ndim` consecutive values
+/// form a point. The statistic is the largest difference between the empirical and
+/// the uniform distribution functions, and the p-value is computed from its
+/// asymptotic distribution with Stephens' correction for finite sample sizes.
+///
+/// Since low-discrepancy point sets are much more uniform than independent random
+/// points, their statistics are expected to be tiny and their p-values close to 1;
+/// small p-values point out a biased or broken set.
+///
+/// Panics if `ndim` is zero, or if the number of values is zero or not a multiple
+/// of `ndim`.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let points = SobolSeq::new(3).gen_points(1000);
+/// assert!(ks_test(&points, 3).iter().all(|r| r.p_value > 0.99));
+/// ```
+pub fn ks_test(points: &[f64], ndim: usize) -> Vec<TestResult> {
+    let n = check_points(points, ndim);
+    let nf = n as f64;
+    let mut x = vec![0.; n];
+    (0..ndim)
+        .map(|j| {
+            x.iter_mut().zip(points.chunks_exact(ndim)).for_each(|(x, p)| *x = p[j]);
+            x.sort_by(f64::total_cmp);
+            let statistic = x
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| ((i + 1) as f64 / nf - x).max(x - i as f64 / nf))
+                .fold(0., f64::max);
+            let sqrt_n = nf.sqrt();
+            let p_value = kolmogorov_sf((sqrt_n + 0.12 + 0.11 / sqrt_n) * statistic);
+            TestResult { statistic, p_value }
+        })
+        .collect()
+}
+
+/// Runs Pearson's chi-square test for uniformity over a grid with `bins` cells per
+/// dimension.
+///
+/// The points are given in row-major order, i.e. each `ndim` consecutive values
+/// form a point. The unit hypercube is split into `bins^ndim` equal cells and the
+/// point counts in the cells are compared to the expected counts; the p-value is
+/// computed from the chi-square distribution with `bins^ndim - 1` degrees of
+/// freedom, so there should be at least a few points per cell on average.
+///
+/// As with [`ks_test`](fn.ks_test.html), low-discrepancy point sets typically have
+/// p-values close to 1.
+///
+/// Panics if `ndim` is zero, if the number of values is zero or not a multiple of
+/// `ndim`, if `bins` is less than 2, or if there are more than `2^32` cells.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let points = SobolSeq::new(2).gen_points(1024);
+/// assert!(chi_square_test(&points, 2, 8).p_value > 0.999);
+/// ```
+pub fn chi_square_test(points: &[f64], ndim: usize, bins: usize) -> TestResult {
+    let n = check_points(points, ndim);
+    if bins < 2 {
+        panic!("invalid number of bins: {}", bins);
+    }
+    let ncells = match bins.checked_pow(ndim as u32) {
+        Some(ncells) if ncells as u64 <= 1 << 32 => ncells,
+        _ => panic!("invalid number of cells: {}^{}", bins, ndim),
+    };
+    let mut counts = vec![0usize; ncells];
+    for x in points.chunks_exact(ndim) {
+        let cell =
+            x.iter().fold(0, |cell, &x| cell * bins + ((x * bins as f64) as usize).min(bins - 1));
+        counts[cell] += 1;
+    }
+    let expected = n as f64 / ncells as f64;
+    let statistic = counts.iter().map(|&c| (c as f64 - expected).powi(2)).sum::<f64>() / expected;
+    let a = (ncells - 1) as f64 / 2.;
+    let p_value = 1. - gamma_p(a, statistic / 2., ln_gamma(a));
+    TestResult { statistic, p_value }
+}
+
+/// Returns the sample correlations between each pair of adjacent dimensions of the
+/// point set, i.e. `ndim - 1` values, the `j`-th of which is the Pearson
+/// correlation coefficient between the coordinates `j` and `j + 1`.
+///
+/// The points are given in row-major order, i.e. each `ndim` consecutive values
+/// form a point. For independent uniformly distributed points, each correlation
+/// times `sqrt(n)` is approximately standard normal; large correlations between
+/// neighbouring dimensions are a typical symptom of e.g. Halton sequences with
+/// large bases used with too few points.
+///
+/// Panics if `ndim` is less than 2, or if the number of values is zero or not a
+/// multiple of `ndim`.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// let points = HaltonSeq::new(2).gen_points(1000);
+/// assert!(dimension_correlations(&points, 2)[0].abs() < 0.01);
+/// ```
+pub fn dimension_correlations(points: &[f64], ndim: usize) -> Vec<f64> {
+    if ndim < 2 {
+        panic!("invalid dimension: {}", ndim);
+    }
+    let n = check_points(points, ndim) as f64;
+    let mut mean = vec![0.; ndim];
+    for x in points.chunks_exact(ndim) {
+        mean.iter_mut().zip(x).for_each(|(m, x)| *m += x / n);
+    }
+    let (mut var, mut cov) = (vec![0.; ndim], vec![0.; ndim - 1]);
+    for x in points.chunks_exact(ndim) {
+        for j in 0..ndim {
+            let d = x[j] - mean[j];
+            var[j] += d * d;
+            if j + 1 < ndim {
+                cov[j] += d * (x[j + 1] - mean[j + 1]);
+            }
+        }
+    }
+    cov.iter().enumerate().map(|(j, c)| c / (var[j] * var[j + 1]).sqrt()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chi_square_test, dimension_correlations, ks_test};
+    use crate::{util::SplitMix64, HaltonSeq, QRng, SobolSeq};
+
+    #[test]
+    fn test_ks_test() {
+        let points = SobolSeq::new(4).gen_points(1 << 10);
+        for r in ks_test(&points, 4) {
+            assert!(r.statistic <= 1. / 1024.);
+            assert_eq!(r.p_value, 1.);
+        }
+        // the first coordinate is squeezed into [0, 0.9)
+        let biased: Vec<_> =
+            points.iter().enumerate().map(|(i, x)| if i % 4 == 0 { 0.9 * x } else { *x }).collect();
+        let r = ks_test(&biased, 4);
+        assert!((r[0].statistic - 0.1).abs() < 2e-3);
+        assert!(r[0].p_value < 1e-6);
+        assert!(r[1].p_value > 0.99);
+        // the largest gap is between 3/8 and 0.8
+        let r = &ks_test(&[0.1, 0.2, 0.35, 0.8, 0.85, 0.9, 0.95, 0.97], 1)[0];
+        assert!((r.statistic - 0.425).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_chi_square_test() {
+        let mut rng = SplitMix64::new(42);
+        let random: Vec<_> = (0..3 << 12).map(|_| rng.next_f64()).collect();
+        let r = chi_square_test(&random, 3, 4);
+        assert!(r.p_value > 1e-3 && r.p_value < 1. - 1e-3);
+        let halton = HaltonSeq::new(3).gen_points(1 << 12);
+        let h = chi_square_test(&halton, 3, 4);
+        assert!(h.statistic < r.statistic / 10.);
+        assert!(h.p_value > 0.999);
+        let clustered: Vec<_> = random.iter().map(|x| x * x).collect();
+        assert!(chi_square_test(&clustered, 3, 4).p_value < 1e-10);
+        // with one degree of freedom, P(chi2 > x) = erfc(sqrt(x / 2))
+        let r = chi_square_test(&[0.1, 0.2, 0.3, 0.4, 0.6, 0.7, 0.8, 0.9, 0.05, 0.15], 1, 2);
+        assert!((r.statistic - 0.4).abs() < 1e-12);
+        assert!((r.p_value - 0.5270892568655381).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_dimension_correlations() {
+        let points = SobolSeq::new(3).gen_points(1 << 10);
+        assert!(dimension_correlations(&points, 3).iter().all(|r| r.abs() < 5e-3));
+        let linked: Vec<_> = points.chunks(3).flat_map(|x| vec![x[0], 1. - x[0], x[1]]).collect();
+        let r = dimension_correlations(&linked, 3);
+        assert!((r[0] + 1.).abs() < 1e-12);
+        assert!(r[1].abs() < 5e-3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chi_square_test_panic() {
+        chi_square_test(&[0.5; 4], 2, 1);
+    }
+}
@@ -0,0 +1,496 @@
+//! Low-level utilities for building custom quasi-random samplers.
+
+/// Size of the first segment sieved by `PrimeSeq`.
+const MIN_SEGMENT: u64 = 256;
+/// Maximum size of the segments sieved by `PrimeSeq` (small enough to fit in cache).
+const MAX_SEGMENT: u64 = 1 << 15;
+
+/// Iterator over the primes, computed via a segmented sieve of Eratosthenes.
+///
+/// The segments grow geometrically, starting from `MIN_SEGMENT`, so that generating
+/// just a few primes stays cheap. Each segment is never larger than the numbers
+/// sieved before it, so all primes up to the square root of its end are known.
+#[derive(Clone)]
+pub(crate) struct PrimeSeq {
+    primes: Vec<u64>,
+    end: u64,
+    index: usize,
+}
+
+impl PrimeSeq {
+    #[inline]
+    pub fn new() -> Self {
+        Self { primes: Vec::new(), end: 0, index: 0 }
+    }
+
+    /// Finds all primes in the next segment of numbers.
+    #[cold]
+    fn sieve_segment(&mut self) {
+        let lo = self.end;
+        let hi = lo + if lo == 0 { MIN_SEGMENT } else { lo.min(MAX_SEGMENT) };
+        let mut composite = vec![false; (hi - lo) as usize];
+        let mark = |p: u64, composite: &mut [bool]| {
+            let mut x = (p * p).max(lo.div_ceil(p) * p);
+            while x < hi {
+                composite[(x - lo) as usize] = true;
+                x += p;
+            }
+        };
+        for &p in self.primes.iter().take_while(|&&p| p * p < hi) {
+            mark(p, &mut composite);
+        }
+        for x in lo.max(2)..hi {
+            if !composite[(x - lo) as usize] {
+                self.primes.push(x);
+                // only happens in the first segment
+                if x * x < hi {
+                    mark(x, &mut composite);
+                }
+            }
+        }
+        self.end = hi;
+    }
+}
+
+impl Iterator for PrimeSeq {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.primes.len() <= self.index {
+            self.sieve_segment();
+        }
+        self.index += 1;
+        Some(self.primes[self.index - 1])
+    }
+}
+
+pub(crate) fn primes() -> PrimeSeq {
+    PrimeSeq::new()
+}
+
+/// Returns the radical inverse of `index` in the given base.
+///
+/// The radical inverse is obtained by mirroring the base-`base` digits of `index`
+/// around the radix point, e.g. `6 = 110b` maps to `0.011b = 0.375` in base 2.
+/// This is the `index`-th element of the van der Corput sequence.
+///
+/// Panics if `base` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::util::radical_inverse;
+/// assert_eq!(radical_inverse(2, 6), 0.375);
+/// ```
+#[inline]
+pub fn radical_inverse(base: u32, mut index: u64) -> f64 {
+    if base < 2 {
+        panic!("invalid radical inverse base: {}", base);
+    }
+    let base = u64::from(base);
+    let inv_base = 1. / (base as f64);
+    let (mut r, mut f) = (0., inv_base);
+    while index > 0 {
+        r += ((index % base) as f64) * f;
+        index /= base;
+        f *= inv_base;
+    }
+    r
+}
+
+/// Evaluates a polynomial with the given coefficients (lowest degree first).
+#[inline]
+fn poly(coefs: &[f64; 8], x: f64) -> f64 {
+    coefs.iter().rev().fold(0., |y, &c| y * x + c)
+}
+
+/// Returns the inverse of the standard normal cumulative distribution function.
+///
+/// The implementation follows algorithm AS241 ("The Percentage Points of the Normal
+/// Distribution", Wichura, 1988), with relative accuracy of about `1e-16`. It only
+/// uses arithmetic, `ln` and `sqrt`, so it is cheap enough to be applied to every
+/// coordinate of every point.
+///
+/// Returns negative and positive infinity for `p = 0` and `p = 1` respectively, and
+/// NaN if `p` is outside of `[0, 1]`.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::util::inverse_normal_cdf;
+/// assert_eq!(inverse_normal_cdf(0.5), 0.);
+/// assert!((inverse_normal_cdf(0.975) - 1.959963984540054).abs() < 1e-15);
+/// ```
+#[allow(clippy::excessive_precision)]
+pub fn inverse_normal_cdf(p: f64) -> f64 {
+    const A: [f64; 8] = [
+        3.387132872796366608,
+        1.3314166789178437745e2,
+        1.9715909503065514427e3,
+        1.3731693765509461125e4,
+        4.5921953931549871457e4,
+        6.7265770927008700853e4,
+        3.3430575583588128105e4,
+        2.5090809287301226727e3,
+    ];
+    const B: [f64; 8] = [
+        1.,
+        4.2313330701600911252e1,
+        6.8718700749205790830e2,
+        5.3941960214247511077e3,
+        2.1213794301586595867e4,
+        3.9307895800092710610e4,
+        2.8729085735721942674e4,
+        5.2264952788528545610e3,
+    ];
+    const C: [f64; 8] = [
+        1.42343711074968357734,
+        4.63033784615654529590,
+        5.76949722146069140550,
+        3.64784832476320460504,
+        1.27045825245236838258,
+        2.41780725177450611770e-1,
+        2.27238449892691845833e-2,
+        7.74545014278341407640e-4,
+    ];
+    const D: [f64; 8] = [
+        1.,
+        2.05319162663775882187,
+        1.67638483018380384940,
+        6.89767334985100004550e-1,
+        1.48103976427480074590e-1,
+        1.51986665636164571966e-2,
+        5.47593808499534494600e-4,
+        1.05075007164441684324e-9,
+    ];
+    const E: [f64; 8] = [
+        6.65790464350110377720,
+        5.46378491116411436990,
+        1.78482653991729133580,
+        2.96560571828504891230e-1,
+        2.65321895265761230930e-2,
+        1.24266094738807843860e-3,
+        2.71155556874348757815e-5,
+        2.01033439929228813265e-7,
+    ];
+    const F: [f64; 8] = [
+        1.,
+        5.99832206555887937690e-1,
+        1.36929880922735805310e-1,
+        1.48753612908506148525e-2,
+        7.86869131145613259100e-4,
+        1.84631831751005468180e-5,
+        1.42151175831644588870e-7,
+        2.04426310338993978564e-15,
+    ];
+
+    let q = p - 0.5;
+    if q.abs() <= 0.425 {
+        let r = 0.180625 - q * q;
+        return q * poly(&A, r) / poly(&B, r);
+    }
+    if !(0. ..=1.).contains(&p) {
+        return f64::NAN;
+    }
+    let r = if q < 0. { p } else { 1. - p };
+    if r == 0. {
+        return if q < 0. { f64::NEG_INFINITY } else { f64::INFINITY };
+    }
+    let r = (-r.ln()).sqrt();
+    let x = if r <= 5. {
+        let r = r - 1.6;
+        poly(&C, r) / poly(&D, r)
+    } else {
+        let r = r - 5.;
+        poly(&E, r) / poly(&F, r)
+    };
+    if q < 0. {
+        -x
+    } else {
+        x
+    }
+}
+
+/// Returns the natural logarithm of the gamma function for positive `x`, via the
+/// Lanczos approximation as given in "Numerical Recipes" (3rd ed., 2007).
+#[allow(clippy::excessive_precision)]
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    const COEFS: [f64; 14] = [
+        57.1562356658629235,
+        -59.5979603554754912,
+        14.1360979747417471,
+        -0.491913816097620199,
+        0.339946499848118887e-4,
+        0.465236289270485756e-4,
+        -0.983744753048795646e-4,
+        0.158088703224912494e-3,
+        -0.210264441724104883e-3,
+        0.217439618115212643e-3,
+        -0.164318106536763890e-3,
+        0.844182239838527433e-4,
+        -0.261908384015814087e-4,
+        0.368991826595316234e-5,
+    ];
+    let tmp = x + 5.24218750000000000;
+    let tmp = (x + 0.5) * tmp.ln() - tmp;
+    let mut y = x;
+    let mut ser = 0.999999999999997092;
+    for &c in &COEFS {
+        y += 1.;
+        ser += c / y;
+    }
+    tmp + (2.5066282746310005 * ser / x).ln()
+}
+
+/// Returns the regularized lower incomplete gamma function `P(a, x)`, given
+/// `ln_gamma(a)`.
+pub(crate) fn gamma_p(a: f64, x: f64, gln: f64) -> f64 {
+    const EPS: f64 = f64::EPSILON;
+    const FPMIN: f64 = f64::MIN_POSITIVE / EPS;
+    if x <= 0. {
+        return 0.;
+    }
+    let prefactor = (-x + a * x.ln() - gln).exp();
+    if x < a + 1. {
+        // series expansion
+        let (mut ap, mut del) = (a, 1. / a);
+        let mut sum = del;
+        while del.abs() >= sum.abs() * EPS {
+            ap += 1.;
+            del *= x / ap;
+            sum += del;
+        }
+        sum * prefactor
+    } else {
+        // continued fraction for Q(a, x) via the modified Lentz's method
+        let mut b = x + 1. - a;
+        let mut c = 1. / FPMIN;
+        let mut d = 1. / b;
+        let mut h = d;
+        for i in 1..1000 {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.;
+            d = an * d + b;
+            if d.abs() < FPMIN {
+                d = FPMIN;
+            }
+            c = b + an / c;
+            if c.abs() < FPMIN {
+                c = FPMIN;
+            }
+            d = 1. / d;
+            let del = d * c;
+            h *= del;
+            if (del - 1.).abs() <= EPS {
+                break;
+            }
+        }
+        1. - prefactor * h
+    }
+}
+
+/// Returns the inverse of the cumulative distribution function of the gamma
+/// distribution with the given shape and unit scale.
+///
+/// The initial guess follows "Numerical Recipes" (3rd ed., 2007) and is refined
+/// via Halley's method, with relative accuracy of about `1e-14`.
+///
+/// Panics if the shape is not positive. Returns NaN if `p` is outside of `[0, 1]`.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::util::inverse_gamma_cdf;
+/// // the gamma distribution with unit shape is the exponential distribution
+/// assert!((inverse_gamma_cdf(1., 0.5) - 2f64.ln()).abs() < 1e-14);
+/// ```
+pub fn inverse_gamma_cdf(shape: f64, p: f64) -> f64 {
+    if shape <= 0. || shape.is_nan() {
+        panic!("invalid gamma shape: {}", shape);
+    }
+    if !(0. ..=1.).contains(&p) {
+        return f64::NAN;
+    } else if p == 0. {
+        return 0.;
+    } else if p == 1. {
+        return f64::INFINITY;
+    }
+    let a = shape;
+    let a1 = a - 1.;
+    let gln = ln_gamma(a);
+    let (lna1, afac) = if a > 1. { (a1.ln(), (a1 * (a1.ln() - 1.) - gln).exp()) } else { (0., 0.) };
+    let mut x = if a > 1. {
+        // Wilson–Hilferty approximation
+        let pp = if p < 0.5 { p } else { 1. - p };
+        let t = (-2. * pp.ln()).sqrt();
+        let mut x = (2.30753 + t * 0.27061) / (1. + t * (0.99229 + t * 0.04481)) - t;
+        if p < 0.5 {
+            x = -x;
+        }
+        (a * (1. - 1. / (9. * a) - x / (3. * a.sqrt())).powi(3)).max(1e-3)
+    } else {
+        let t = 1. - a * (0.253 + a * 0.12);
+        if p < t {
+            (p / t).powf(1. / a)
+        } else {
+            1. - (1. - (p - t) / (1. - t)).ln()
+        }
+    };
+    for _ in 0..100 {
+        if x <= 0. {
+            return 0.;
+        }
+        let err = gamma_p(a, x, gln) - p;
+        // density of the gamma distribution at x
+        let density = if a > 1. {
+            afac * (-(x - a1) + a1 * (x.ln() - lna1)).exp()
+        } else {
+            (-x + a1 * x.ln() - gln).exp()
+        };
+        let u = err / density;
+        let t = u / (1. - 0.5 * (u * (a1 / x - 1.)).min(1.));
+        x -= t;
+        if x <= 0. {
+            x = 0.5 * (x + t);
+        }
+        if t.abs() < 1e-15 * x {
+            break;
+        }
+    }
+    x
+}
+
+/// Bijective 64-bit hash function (the output function of `SplitMix64`).
+#[inline]
+pub(crate) fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Small and fast pseudo-random number generator used for randomization
+/// ("Fast Splittable Pseudorandom Number Generators", Steele, Lea and Flood, 2014).
+#[derive(Clone)]
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        mix64(self.state)
+    }
+
+    /// Returns a uniformly distributed float in `[0, 1)`.
+    #[inline]
+    pub fn next_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64) / ((1u64 << 53) as f64)
+    }
+
+    /// Returns a (nearly) uniformly distributed integer in `[0, n)`.
+    #[inline]
+    pub fn next_below(&mut self, n: u64) -> u64 {
+        ((u128::from(self.next_u64()) * u128::from(n)) >> 64) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        inverse_gamma_cdf, inverse_normal_cdf, ln_gamma, primes, radical_inverse, SplitMix64,
+    };
+
+    #[test]
+    fn test_prime_seq() {
+        assert_eq!(primes().take(10).collect::<Vec<_>>(), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        // compare to trial division across many segments
+        let expected: Vec<u64> = (2..200_000)
+            .filter(|&x| (2..x).take_while(|p| p * p <= x).all(|p| x % p != 0))
+            .collect();
+        assert_eq!(primes().take(expected.len()).collect::<Vec<_>>(), expected);
+        assert_eq!(primes().nth(9999), Some(104_729));
+    }
+
+    #[test]
+    fn test_radical_inverse() {
+        assert_eq!(radical_inverse(2, 0), 0.);
+        assert_eq!(radical_inverse(2, 1), 0.5);
+        assert_eq!(radical_inverse(2, 6), 0.375);
+        assert!((radical_inverse(3, 5) - 7. / 9.).abs() < 1e-15);
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn test_inverse_normal_cdf() {
+        let cases = [
+            (0.3, -0.524400512708040784),
+            (0.975, 1.959963984540054236),
+            (0.001, -3.090232306167813542),
+            (0.02425, -1.972961051311884850),
+            (1e-10, -6.361340902404056205),
+            (1e-30, -11.464024688443615727),
+            (1e-300, -37.047096299361199237),
+        ];
+        for &(p, x) in &cases {
+            assert!((inverse_normal_cdf(p) - x).abs() < 1e-15 * x.abs());
+            if p >= 1e-3 {
+                assert!((inverse_normal_cdf(1. - p) + x).abs() < 1e-12 * x.abs());
+            }
+        }
+        assert_eq!(inverse_normal_cdf(0.5), 0.);
+        assert_eq!(inverse_normal_cdf(0.), f64::NEG_INFINITY);
+        assert_eq!(inverse_normal_cdf(1.), f64::INFINITY);
+        assert!(inverse_normal_cdf(1.5).is_nan());
+        assert!(inverse_normal_cdf(-0.1).is_nan());
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
+    fn test_inverse_gamma_cdf() {
+        assert!((ln_gamma(0.5) - 0.57236494292470008707).abs() < 1e-14);
+        assert!((ln_gamma(10.) - 12.801827480081469611).abs() < 1e-13);
+        assert!((ln_gamma(0.01) - 4.5994798780420217016).abs() < 1e-14);
+        assert!((ln_gamma(100.5) - 361.43554046777762156).abs() < 1e-11);
+        let cases = [
+            (0.5, 0.3, 0.074235930916272719042),
+            (2.5, 0.9, 4.618178449890559527),
+            (0.1, 0.01, 6.0730483624078993461e-21),
+            (10., 0.5, 9.6687146147141311518),
+            (1., 0.7, 1.2039728043259358446),
+            (3., 1e-6, 0.018254282963279292332),
+            (0.05, 0.999, 2.736458598728675592),
+            (200., 0.05, 177.32048703304123738),
+        ];
+        for &(a, p, x) in &cases {
+            assert!((inverse_gamma_cdf(a, p) - x).abs() < 1e-12 * x);
+        }
+        assert_eq!(inverse_gamma_cdf(2., 0.), 0.);
+        assert_eq!(inverse_gamma_cdf(2., 1.), f64::INFINITY);
+        assert!(inverse_gamma_cdf(2., 1.5).is_nan());
+    }
+
+    #[test]
+    fn test_splitmix64() {
+        let mut rng = SplitMix64::new(1234567);
+        assert_eq!(rng.next_u64(), 6457827717110365317);
+        assert_eq!(rng.next_u64(), 3203168211198807973);
+        for _ in 0..1000 {
+            let x = rng.next_f64();
+            assert!((0. ..1.).contains(&x));
+            assert!(rng.next_below(7) < 7);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_radical_inverse_panic() {
+        radical_inverse(1, 5);
+    }
+}
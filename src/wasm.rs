@@ -0,0 +1,128 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{halton::HaltonSeq, sobol::SobolSeq, traits::QRng, traits::SkipTo};
+
+macro_rules! impl_js_qrng {
+    ($ty:ident, $js:ident) => {
+        #[wasm_bindgen(js_class = $js)]
+        impl $ty {
+            /// Returns the dimensionality of the sequence.
+            #[wasm_bindgen(getter)]
+            pub fn ndim(&self) -> usize {
+                self.0.ndim()
+            }
+
+            /// Returns the index of the next point of the sequence.
+            #[wasm_bindgen(getter)]
+            pub fn index(&self) -> u64 {
+                self.0.index()
+            }
+
+            /// Moves the generator back to the first point of the sequence.
+            pub fn reset(&mut self) {
+                self.0.reset();
+            }
+
+            /// Moves the generator to the point with the given index.
+            #[wasm_bindgen(js_name = skipTo)]
+            pub fn skip_to(&mut self, index: u64) {
+                self.0.skip_to(index);
+            }
+
+            /// Returns the next `n` points in row-major order as a `Float64Array`.
+            #[wasm_bindgen(js_name = genPoints)]
+            pub fn gen_points(&mut self, n: usize) -> Vec<f64> {
+                self.0.gen_points(n)
+            }
+
+            /// Returns the next `n` points in row-major order as a `Float32Array`,
+            /// e.g. to be uploaded directly into a WebGL vertex buffer.
+            #[wasm_bindgen(js_name = genPointsF32)]
+            pub fn gen_points_f32(&mut self, n: usize) -> Vec<f32> {
+                let ndim = self.0.ndim();
+                let mut out = vec![0.; n * ndim];
+                for point in out.chunks_exact_mut(ndim.max(1)) {
+                    self.0.gen_fill_f32(point);
+                }
+                out
+            }
+
+            /// Fills the given `Float64Array` with as many points as fit in it, in
+            /// row-major order, and returns the number of generated points.
+            #[wasm_bindgen(js_name = fillPoints)]
+            pub fn fill_points(&mut self, out: &mut [f64]) -> usize {
+                self.0.gen_fill_points(out)
+            }
+        }
+    };
+}
+
+/// Sobol sequence generator exported to JavaScript as `SobolSeq`.
+///
+/// All points requested by a single call are generated inside the wasm module and
+/// handed over to JavaScript as one typed array, so that the cost of crossing the
+/// wasm boundary doesn't depend on the number of points.
+///
+/// This type is only available if the `wasm` feature is enabled.
+#[wasm_bindgen(js_name = SobolSeq)]
+#[derive(Clone)]
+pub struct JsSobolSeq(SobolSeq);
+
+#[wasm_bindgen(js_class = SobolSeq)]
+impl JsSobolSeq {
+    /// Returns a new Sobol sequence generator with dimensionality `ndim`.
+    ///
+    /// Throws an error if the dimensionality is not supported.
+    #[wasm_bindgen(constructor)]
+    pub fn new(ndim: usize) -> Result<JsSobolSeq, JsError> {
+        Ok(Self(SobolSeq::try_new(ndim)?))
+    }
+}
+
+impl_js_qrng!(JsSobolSeq, SobolSeq);
+
+/// Halton sequence generator exported to JavaScript as `HaltonSeq`.
+///
+/// Like [`JsSobolSeq`], generates points in bulk into typed arrays.
+///
+/// This type is only available if the `wasm` feature is enabled.
+#[wasm_bindgen(js_name = HaltonSeq)]
+#[derive(Clone)]
+pub struct JsHaltonSeq(HaltonSeq);
+
+#[wasm_bindgen(js_class = HaltonSeq)]
+impl JsHaltonSeq {
+    /// Returns a new Halton sequence generator with dimensionality `ndim`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(ndim: usize) -> JsHaltonSeq {
+        Self(HaltonSeq::new(ndim))
+    }
+}
+
+impl_js_qrng!(JsHaltonSeq, HaltonSeq);
+
+#[cfg(test)]
+mod tests {
+    use super::{JsHaltonSeq, JsSobolSeq};
+    use crate::{HaltonSeq, QRng, SobolSeq};
+
+    #[test]
+    fn test_js_qrng_bulk() {
+        let mut seq = JsSobolSeq::new(3).unwrap();
+        assert_eq!(seq.ndim(), 3);
+        assert_eq!(seq.gen_points(100), SobolSeq::new(3).gen_points(100));
+        assert_eq!(seq.index(), 100);
+        let mut buf = [0.; 10];
+        assert_eq!(seq.fill_points(&mut buf), 3);
+        assert_eq!(buf[..9], SobolSeq::new_with_skip(3, 100).gen_points(3)[..]);
+        seq.skip_to(5);
+        let expected = SobolSeq::new_with_skip(3, 5).gen_points(2);
+        let points = seq.gen_points_f32(2);
+        assert!(points.iter().zip(&expected).all(|(&x, &y)| x == y as f32));
+
+        let mut seq = JsHaltonSeq::new(4);
+        seq.gen_points(7);
+        seq.reset();
+        assert_eq!(seq.gen_points(50), HaltonSeq::new(4).gen_points(50));
+    }
+}
@@ -18,6 +18,35 @@ impl<R: QRng> QRngWithBuf<R> {
         unsafe { self.qrng.gen_fill_unchecked(&mut self.buf) };
         &self.buf
     }
+
+    /// Generates the next point and returns it as a fixed-size array.
+    ///
+    /// Panics if `N` is not equal to the dimensionality of the generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use qrng::*;
+    /// let mut seq = HaltonSeq::new(3).with_buf();
+    /// let [x, y, z] = seq.gen_array();
+    /// assert_eq!([x, y, z], [0.5, 1. / 3., 0.2]);
+    /// ```
+    #[inline(always)]
+    pub fn gen_array<const N: usize>(&mut self) -> [f64; N] {
+        if N != self.buf.len() {
+            panic!("invalid array length: {} (dimension is {})", N, self.buf.len());
+        }
+        let mut out = [0.; N];
+        unsafe { self.qrng.gen_fill_unchecked(&mut out) };
+        out
+    }
+}
+
+impl<R: QRng> From<R> for QRngWithBuf<R> {
+    #[inline(always)]
+    fn from(qrng: R) -> Self {
+        Self::new(qrng)
+    }
 }
 
 impl<R: QRng> QRng for QRngWithBuf<R> {
@@ -26,8 +55,106 @@ impl<R: QRng> QRng for QRngWithBuf<R> {
         self.qrng.ndim()
     }
 
+    #[inline(always)]
+    fn reset(&mut self) {
+        self.qrng.reset();
+    }
+
+    #[inline(always)]
+    fn index(&self) -> u64 {
+        self.qrng.index()
+    }
+
     #[inline(always)]
     unsafe fn gen_fill_unchecked(&mut self, out: &mut [f64]) {
         self.qrng.gen_fill_unchecked(out);
     }
+
+    #[inline(always)]
+    fn discard(&mut self, n: u64) {
+        self.qrng.discard(n);
+    }
+
+    #[inline(always)]
+    unsafe fn gen_fill_f32_unchecked(&mut self, out: &mut [f32]) {
+        self.qrng.gen_fill_f32_unchecked(out);
+    }
+
+    #[inline(always)]
+    unsafe fn gen_points_unchecked(&mut self, n: usize, out: &mut [f64]) {
+        self.qrng.gen_points_unchecked(n, out);
+    }
+
+    #[inline(always)]
+    unsafe fn gen_points_col_major_unchecked(&mut self, n: usize, out: &mut [f64]) {
+        self.qrng.gen_points_col_major_unchecked(n, out);
+    }
+}
+
+// the buffer is scratch space, so only the wrapped generator is (de)serialized
+#[cfg(feature = "serde")]
+impl<R: QRng + serde::Serialize> serde::Serialize for QRngWithBuf<R> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.qrng.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, R: QRng + serde::Deserialize<'de>> serde::Deserialize<'de> for QRngWithBuf<R> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        R::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// Lending iterator over the points of the sequence, borrowing the internal buffer.
+///
+/// # Examples
+///
+/// ```
+/// # use qrng::*;
+/// use streaming_iterator::StreamingIterator;
+///
+/// let mut points = SobolSeq::new(2).with_buf();
+/// let sum = points.by_ref().take(1024).fold(0., |acc, x| acc + x[0] * x[1]);
+/// assert!((sum / 1024. - 0.25).abs() < 1e-2);
+/// assert_eq!(points.index(), 1024);
+/// ```
+#[cfg(feature = "streaming-iterator")]
+impl<R: QRng> streaming_iterator::StreamingIterator for QRngWithBuf<R> {
+    type Item = [f64];
+
+    #[inline(always)]
+    fn advance(&mut self) {
+        unsafe { self.qrng.gen_fill_unchecked(&mut self.buf) };
+    }
+
+    #[inline(always)]
+    fn get(&self) -> Option<&[f64]> {
+        Some(&self.buf)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{QRng, SobolSeq};
+
+    #[test]
+    fn test_gen_array() {
+        let mut a = SobolSeq::new(4).with_buf();
+        let mut b = SobolSeq::new(4).with_buf();
+        for _ in 0..10 {
+            assert_eq!(a.gen_array::<4>(), b.gen());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gen_array_panic() {
+        SobolSeq::new(4).with_buf().gen_array::<3>();
+    }
 }